@@ -13,15 +13,11 @@ pub enum CapacityKind {
 }
 
 impl Bounded<CapacityKind> for CapacityHolder {
-    fn maximum(&self, v: &CapacityKind) -> usize {
-        match v {
-            _ => 64,
-        }
+    fn maximum(&self, _: &CapacityKind) -> usize {
+        64
     }
-    fn minimum(&self, v: &CapacityKind) -> usize {
-        match v {
-            _ => 1,
-        }
+    fn minimum(&self, _: &CapacityKind) -> usize {
+        1
     }
 }
 
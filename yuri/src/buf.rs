@@ -0,0 +1,63 @@
+//! Fixed buffer writing
+
+use core::fmt;
+
+/// core::fmt::Write into a caller supplied byte buffer
+pub(crate) struct SliceWriter<'b> {
+    pub(crate) buf: &'b mut [u8],
+    pub(crate) len: usize,
+}
+
+impl<'b> SliceWriter<'b> {
+    pub(crate) fn new(buf: &'b mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+    /// Write a single byte, the caller keeps the written bytes valid UTF-8
+    pub(crate) fn push(&mut self, b: u8) -> fmt::Result {
+        let slot = self.buf.get_mut(self.len).ok_or(fmt::Error)?;
+        *slot = b;
+        self.len += 1;
+        Ok(())
+    }
+    /// Insert the str at the given offset moving the bytes after it
+    pub(crate) fn insert_str(&mut self, at: usize, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf.copy_within(at..self.len, at + s.len());
+        self.buf[at..at + s.len()].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+    /// Written str borrowed for the whole buffer lifetime
+    pub(crate) fn into_str(self) -> &'b str {
+        let written: &'b [u8] = &self.buf[..self.len];
+        // Only whole str pieces are written through fmt::Write
+        core::str::from_utf8(written).unwrap_or_default()
+    }
+}
+
+impl<'b> fmt::Write for SliceWriter<'b> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Counts the bytes written for sizing buffers
+#[cfg(feature = "alloc")]
+pub(crate) struct LenCounter(pub(crate) usize);
+
+#[cfg(feature = "alloc")]
+impl fmt::Write for LenCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
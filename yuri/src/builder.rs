@@ -1 +1,325 @@
 //! Builder
+
+use core::fmt::Write;
+
+use crate::buf::SliceWriter;
+use crate::error::BuilderError;
+use crate::percent::EncodeSet;
+use crate::uri::check_path;
+use crate::{encode_fragment, encode_path_segment, QueryBuilder};
+use crate::{Authority, Fragment, Path, Query, Scheme, SchemeData, Uri};
+
+/// URI Builder
+///
+/// Each component is checked against the components already set so
+/// the resulting [`Uri`] is consistent once [`UriBuilder::build`] is called.
+///
+/// ```rust
+/// use yuri::{Authority, Path, Scheme, UriBuilder};
+///
+/// let uri = UriBuilder::new(Scheme::Http(true))
+///     .authority(Authority::from_host_str("foobar.test").unwrap())
+///     .unwrap()
+///     .path(Path { raw_path: "/index.html" })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+/// assert_eq!(uri.scheme, Scheme::Http(true));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct UriBuilder<'uri> {
+    scheme: Scheme<'uri>,
+    authority: Option<Authority<'uri>>,
+    path: Option<Path<'uri>>,
+    query: Option<Query<'uri>>,
+    fragment: Option<Fragment<'uri>>,
+}
+
+impl<'uri> UriBuilder<'uri> {
+    /// Start building with the given Scheme
+    pub fn new(scheme: Scheme<'uri>) -> Self {
+        Self {
+            scheme,
+            authority: None,
+            path: None,
+            query: None,
+            fragment: None,
+        }
+    }
+    /// Set the Authority
+    pub fn authority(mut self, authority: Authority<'uri>) -> Result<Self, BuilderError<'uri>> {
+        if !self.scheme.allows_authority() {
+            return Err(BuilderError::AuthorityNotAllowed(self.scheme));
+        }
+        authority.check().map_err(BuilderError::Authority)?;
+        if let Some(path) = &self.path {
+            check_path(path.raw_path, true)?;
+        }
+        self.authority = Some(authority);
+        Ok(self)
+    }
+    /// Set the Path
+    pub fn path(mut self, path: Path<'uri>) -> Result<Self, BuilderError<'uri>> {
+        check_path(path.raw_path, self.authority.is_some())?;
+        self.path = Some(path);
+        Ok(self)
+    }
+    /// Set the Path from segments percent-encoding each into the given buffer
+    /// e.g. ["a b", "c"] becomes "/a%20b/c"
+    pub fn path_segments(
+        self,
+        segments: &[&str],
+        buf: &'uri mut [u8],
+    ) -> Result<Self, BuilderError<'uri>> {
+        let mut w = SliceWriter::new(buf);
+        for segment in segments {
+            w.write_char('/')
+                .and_then(|_| encode_path_segment(segment).write_to(&mut w))
+                .map_err(|_| BuilderError::BufferTooSmall)?;
+        }
+        let raw_path = w.into_str();
+        self.path(Path { raw_path })
+    }
+    /// Set the Query
+    pub fn query(mut self, query: Query<'uri>) -> Result<Self, BuilderError<'uri>> {
+        if EncodeSet::Query.find_invalid(query.raw_query).is_some() {
+            return Err(BuilderError::InvalidQuery(query.raw_query));
+        }
+        self.query = Some(query);
+        Ok(self)
+    }
+    /// Set the Query from key / value pairs form-urlencoding each into the given buffer
+    /// e.g. [("q", "a b"), ("m", "s")] becomes "q=a+b&m=s"
+    pub fn query_pairs(
+        self,
+        pairs: &[(&str, &str)],
+        buf: &'uri mut [u8],
+    ) -> Result<Self, BuilderError<'uri>> {
+        let mut query = QueryBuilder::new(buf);
+        for (key, value) in pairs {
+            query = query
+                .append(key, value)
+                .map_err(|_| BuilderError::BufferTooSmall)?;
+        }
+        self.query_builder(query)
+    }
+    /// Set the Query built with the given [`QueryBuilder`]
+    pub fn query_builder(self, query: QueryBuilder<'uri>) -> Result<Self, BuilderError<'uri>> {
+        self.query(query.build())
+    }
+    /// Set the Fragment
+    pub fn fragment(mut self, fragment: Fragment<'uri>) -> Result<Self, BuilderError<'uri>> {
+        if EncodeSet::Fragment
+            .find_invalid(fragment.raw_fragment)
+            .is_some()
+        {
+            return Err(BuilderError::InvalidFragment(fragment.raw_fragment));
+        }
+        self.fragment = Some(fragment);
+        Ok(self)
+    }
+    /// Set the Fragment percent-encoding it into the given buffer
+    pub fn fragment_str(
+        self,
+        fragment: &str,
+        buf: &'uri mut [u8],
+    ) -> Result<Self, BuilderError<'uri>> {
+        let raw_fragment = encode_fragment(fragment)
+            .encode_into(buf)
+            .map_err(|_| BuilderError::BufferTooSmall)?;
+        self.fragment(Fragment { raw_fragment })
+    }
+    /// Build the Uri checking an Unknown Scheme name and that there is an
+    /// Authority where the Scheme requires one
+    pub fn build(self) -> Result<Uri<'uri>, BuilderError<'uri>> {
+        if !self.scheme.is_valid() {
+            return Err(BuilderError::InvalidScheme(self.scheme.as_str()));
+        }
+        if self.authority.is_none() && self.scheme.requires_authority() {
+            return Err(BuilderError::MissingAuthority(self.scheme));
+        }
+        Ok(Uri {
+            scheme: self.scheme,
+            authority: self.authority,
+            path: self.path,
+            query: self.query,
+            fragment: self.fragment,
+            scheme_data: SchemeData {
+                raw: None,
+                raw_scheme: None,
+            },
+        })
+    }
+}
+
+impl<'uri> Uri<'uri> {
+    /// Start building a new URI with the given Scheme
+    pub fn builder(scheme: Scheme<'uri>) -> UriBuilder<'uri> {
+        UriBuilder::new(scheme)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::AuthorityError;
+
+    #[test]
+    fn build_https_full() {
+        let uri = UriBuilder::new(Scheme::Http(true))
+            .authority(Authority::from_host_str("foobar.test").unwrap())
+            .unwrap()
+            .path(Path {
+                raw_path: "/index.html",
+            })
+            .unwrap()
+            .query(Query { raw_query: "q=a" })
+            .unwrap()
+            .fragment(Fragment {
+                raw_fragment: "top",
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(uri.scheme, Scheme::Http(true));
+        assert_eq!(uri.authority.unwrap().raw_host, "foobar.test");
+        assert_eq!(
+            uri.path,
+            Some(Path {
+                raw_path: "/index.html"
+            })
+        );
+        assert_eq!(uri.query, Some(Query { raw_query: "q=a" }));
+        assert_eq!(
+            uri.fragment,
+            Some(Fragment {
+                raw_fragment: "top"
+            })
+        );
+    }
+    #[test]
+    fn build_missing_authority() {
+        let res = UriBuilder::new(Scheme::Http(false)).build();
+        assert_eq!(
+            res,
+            Err(BuilderError::MissingAuthority(Scheme::Http(false)))
+        );
+    }
+    #[test]
+    fn build_invalid_scheme() {
+        for name in ["a:b/c", "", "1ab", "https"] {
+            let res = UriBuilder::new(Scheme::Unknown(name)).build();
+            assert_eq!(res, Err(BuilderError::InvalidScheme(name)));
+        }
+        let uri = UriBuilder::new(Scheme::Unknown("svn+ssh")).build().unwrap();
+        assert_eq!(format!("{}", uri), "svn+ssh:");
+    }
+    #[test]
+    fn build_authority_not_allowed() {
+        let res = UriBuilder::new(Scheme::Urn)
+            .authority(Authority::from_host_str("foobar.test").unwrap());
+        assert_eq!(res, Err(BuilderError::AuthorityNotAllowed(Scheme::Urn)));
+    }
+    #[test]
+    fn build_path_not_absolute() {
+        let res = UriBuilder::new(Scheme::Http(false))
+            .path(Path { raw_path: "index" })
+            .unwrap()
+            .authority(Authority::from_host_str("foobar.test").unwrap());
+        assert_eq!(res, Err(BuilderError::PathNotAbsolute("index")));
+    }
+    #[test]
+    fn build_path_ambiguous() {
+        let res = UriBuilder::new(Scheme::Urn).path(Path { raw_path: "//x" });
+        assert_eq!(res, Err(BuilderError::PathAmbiguous("//x")));
+    }
+    #[test]
+    fn build_invalid_query() {
+        let res = Uri::builder(Scheme::Unknown("foo")).query(Query { raw_query: "a#b" });
+        assert_eq!(res, Err(BuilderError::InvalidQuery("a#b")));
+    }
+    #[test]
+    fn build_invalid_raw_parts() {
+        let res = Uri::builder(Scheme::Http(true)).path(Path { raw_path: "/a b" });
+        assert_eq!(res, Err(BuilderError::InvalidPath("/a b")));
+        let res = Uri::builder(Scheme::Http(true)).query(Query { raw_query: "a=%G1" });
+        assert_eq!(res, Err(BuilderError::InvalidQuery("a=%G1")));
+        let res = Uri::builder(Scheme::Http(true)).fragment(Fragment {
+            raw_fragment: "a#b",
+        });
+        assert_eq!(res, Err(BuilderError::InvalidFragment("a#b")));
+    }
+    #[test]
+    fn build_encoded_parts() {
+        let mut path_buf = [0u8; 32];
+        let mut query_buf = [0u8; 32];
+        let mut fragment_buf = [0u8; 32];
+        let uri = Uri::builder(Scheme::Http(true))
+            .authority(Authority::from_host_str("foobar.test").unwrap())
+            .unwrap()
+            .path_segments(&["a b", "c/d", "例"], &mut path_buf)
+            .unwrap()
+            .query_pairs(&[("q", "a&b c"), ("k=", "v")], &mut query_buf)
+            .unwrap()
+            .fragment_str("top #1", &mut fragment_buf)
+            .unwrap()
+            .build()
+            .unwrap();
+        let s = format!("{}", uri);
+        assert_eq!(
+            s,
+            "https://foobar.test/a%20b/c%2Fd/%E4%BE%8B?q=a%26b+c&k%3D=v#top%20%231"
+        );
+        assert_eq!(Uri::new(&s).unwrap(), uri);
+    }
+    #[test]
+    fn build_encoded_buffer_too_small() {
+        let mut path_buf = [0u8; 4];
+        let res = Uri::builder(Scheme::Http(true)).path_segments(&["a b"], &mut path_buf);
+        assert_eq!(res, Err(BuilderError::BufferTooSmall));
+    }
+    #[test]
+    fn build_rootless_path() {
+        let uri = Uri::builder(Scheme::Urn)
+            .path(Path {
+                raw_path: "oasis:names:specification",
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(uri.authority, None);
+    }
+    #[test]
+    fn build_invalid_authority() {
+        let authority = Authority {
+            raw_host: "x/y",
+            host: crate::Host::RegName("x/y"),
+            ..Authority::from_host_str("x").unwrap()
+        };
+        let res = UriBuilder::new(Scheme::Http(false)).authority(authority);
+        assert_eq!(
+            res,
+            Err(BuilderError::Authority(AuthorityError::InvalidHost("x/y")))
+        );
+    }
+    #[test]
+    fn build_query_builder() {
+        let mut query_buf = [0u8; 32];
+        let query = QueryBuilder::new(&mut query_buf)
+            .extend_query(&Query {
+                raw_query: "b=2&a=1",
+            })
+            .unwrap()
+            .replace("c", "x y")
+            .unwrap()
+            .sort();
+        let uri = Uri::builder(Scheme::Http(true))
+            .authority(Authority::from_host_str("foobar.test").unwrap())
+            .unwrap()
+            .query_builder(query)
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(format!("{}", uri), "https://foobar.test?a=1&b=2&c=x+y");
+    }
+}
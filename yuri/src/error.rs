@@ -17,6 +17,8 @@ pub enum UriError<'uri> {
     Fragment(FragmentError<'uri>),
     /// Scheme data error    
     SchemeData(SchemeDataError<'uri>),
+    /// Input is longer than the maximum length given in the parse options
+    TooLong(usize),
 }
 
 /// Parsing Detail relating to an Error
@@ -64,6 +66,14 @@ pub enum AuthorityError<'uri> {
     InvalidPort,
     /// Invalid Authority portition
     InvalidAuthority,
+    /// Invalid Host e.g. malformed IP literal `[zz::1]` or IPv4 999.1.1.1
+    InvalidHost(&'uri str),
+    /// Expected "//" before the authority as required by the parse options
+    MissingSlashes,
+    /// Empty port e.g. "host:" not allowed by the parse options
+    EmptyPort,
+    /// Password in the userinfo not allowed by the parse options
+    PasswordNotAllowed,
     /// Parsing error with detail
     ParsingDetailed(ParsingDetail<'uri>),
 }
@@ -73,6 +83,8 @@ pub enum AuthorityError<'uri> {
 pub enum PathError<'uri> {
     /// Parsing error with detail
     ParsingDetailed(ParsingDetail<'uri>),
+    /// Given buffer is too small for the path
+    BufferTooSmall,
 }
 
 /// Query related errors
@@ -95,3 +107,149 @@ pub enum SchemeDataError<'uri> {
     /// Parsing error with detail
     ParsingDetailed(ParsingDetail<'uri>),
 }
+
+/// Percent-decoding errors
+#[derive(Clone, Debug, PartialEq)]
+pub enum PercentDecodeError {
+    /// Malformed escape e.g. %G1 at the given byte position
+    InvalidEscape(usize),
+    /// Escape cut short e.g. trailing % at the given byte position
+    TruncatedEscape(usize),
+    /// Given buffer is too small for the decoded bytes
+    BufferTooSmall,
+}
+
+/// Percent-encoding errors
+#[derive(Clone, Debug, PartialEq)]
+pub enum PercentEncodeError {
+    /// Given buffer is too small for the encoded str
+    BufferTooSmall,
+}
+
+/// Reference resolution errors
+#[derive(Clone, Debug, PartialEq)]
+pub enum ResolveError<'uri> {
+    /// Given buffer is too small for the resolved URI
+    BufferTooSmall,
+    /// Reference or the resolved URI failed to parse
+    Parse(UriError<'uri>),
+    /// Resolved URI failed to parse into an owned URI,
+    /// see [`crate::Uri::resolve_owned`]
+    InvalidTarget,
+}
+
+/// Normalisation errors
+#[derive(Clone, Debug, PartialEq)]
+pub enum NormalizeError<'uri> {
+    /// Given buffer is too small for the normalised URI
+    BufferTooSmall,
+    /// Normalised URI failed to parse
+    Parse(UriError<'uri>),
+}
+
+/// IRI to URI mapping errors
+#[derive(Clone, Debug, PartialEq)]
+pub enum IriError<'uri> {
+    /// Given buffer is too small for the mapped URI
+    BufferTooSmall,
+    /// Mapped URI failed to parse
+    Parse(UriError<'uri>),
+}
+
+/// Fixed capacity errors
+#[derive(Clone, Debug, PartialEq)]
+pub enum CapacityError {
+    /// Serialized URI of the given length exceeds the capacity
+    TooLong {
+        /// Length required
+        len: usize,
+        /// Capacity available
+        capacity: usize,
+    },
+}
+
+/// Builder and setter related errors
+#[derive(Clone, Debug, PartialEq)]
+pub enum BuilderError<'uri> {
+    /// Scheme name is invalid or given as Unknown for a known Scheme
+    InvalidScheme(&'uri str),
+    /// Scheme requires an Authority but none was set
+    MissingAuthority(crate::Scheme<'uri>),
+    /// Scheme does not allow an Authority
+    AuthorityNotAllowed(crate::Scheme<'uri>),
+    /// Path must be empty or begin with "/" when there is an Authority
+    PathNotAbsolute(&'uri str),
+    /// Path must not begin with "//" when there is no Authority
+    PathAmbiguous(&'uri str),
+    /// Path contains a char that must be percent-encoded
+    InvalidPath(&'uri str),
+    /// Query contains a char that must be percent-encoded
+    InvalidQuery(&'uri str),
+    /// Fragment contains a char that must be percent-encoded
+    InvalidFragment(&'uri str),
+    /// Authority is invalid e.g. the host or a port without one
+    Authority(AuthorityError<'uri>),
+    /// Given buffer is too small for the percent-encoded component
+    BufferTooSmall,
+}
+
+/// WHATWG URL Standard parsing errors
+#[cfg(feature = "whatwg")]
+#[derive(Clone, Debug, PartialEq)]
+pub enum WhatwgError<'uri> {
+    /// Relative input without a base URL
+    MissingScheme,
+    /// Relative input against a base URL with an opaque path e.g. mailto:
+    InvalidBase,
+    /// Invalid or missing host e.g. 256.0.0.1 or a forbidden code point
+    InvalidHost,
+    /// Invalid port e.g. non-digits or over 65535
+    InvalidPort,
+    /// Given buffer is too small for the serialized URL
+    BufferTooSmall,
+    /// Serialized URL failed to parse
+    Parse(UriError<'uri>),
+}
+
+/// IDNA domain errors
+#[cfg(feature = "idna")]
+#[derive(Clone, Debug, PartialEq)]
+pub enum IdnaError<'uri> {
+    /// Invalid label at the given index
+    Label {
+        /// Index of the label within the domain
+        index: usize,
+        /// Label as given
+        label: &'uri str,
+        /// Reason the label is invalid
+        error: LabelError,
+    },
+    /// Domain is over 253 bytes in ASCII
+    TooLong,
+    /// Given buffer is too small for the converted domain
+    BufferTooSmall,
+}
+
+/// IDNA label errors - UTS #46 s. 4.1 validity criteria
+#[cfg(feature = "idna")]
+#[derive(Clone, Debug, PartialEq)]
+pub enum LabelError {
+    /// Empty label e.g. "a..b"
+    Empty,
+    /// Label is over 63 bytes in ASCII
+    TooLong,
+    /// Leading or trailing hyphen or hyphens in the third & fourth position
+    InvalidHyphen,
+    /// Disallowed code point e.g. "_" or a control
+    Disallowed(char),
+    /// Malformed Punycode after "xn--"
+    InvalidPunycode,
+    /// Decoded Punycode label is not in NFC
+    NotNfc,
+    /// Label starts with a combining mark
+    CombiningMark,
+    /// Zero width joiner or non-joiner out of context - RFC 5892 Appendix A
+    InvalidJoiner,
+    /// Label fails the Bidi Rule of a Bidi domain name - RFC 5893 s. 2
+    InvalidBidi,
+}
@@ -0,0 +1,500 @@
+//! IDNA - UTS #46 processing of domains with the Punycode labels
+//!
+//! Nontransitional processing: the IDNA mapping, NFC and the validity
+//! criteria with CheckJoiners & CheckBidi over tables generated from the
+//! Unicode data, see make_tables.py for the versions. Deviations such as
+//! "ß" are valid as Transitional_Processing is not supported.
+
+mod punycode;
+#[rustfmt::skip]
+mod tables;
+mod unicode;
+
+use core::fmt::Write;
+
+use crate::buf::SliceWriter;
+use crate::error::{IdnaError, LabelError};
+use punycode::{punycode_decode, punycode_encode};
+use unicode::{
+    bidi_class, combining_class, is_mark, is_nfc, joining_type, mapping, nfc, BidiClass,
+    JoiningType, Status, VIRAMA,
+};
+
+/// ACE prefix for the Punycode encoded labels
+const ACE_PREFIX: &str = "xn--";
+
+/// Maximum label length in ASCII - RFC 1035 s. 2.3.4
+const MAX_LABEL_LEN: usize = 63;
+
+/// Maximum domain length in ASCII excluding the root label
+const MAX_DOMAIN_LEN: usize = 253;
+
+/// UTS #46 processing flags, CheckJoiners & CheckBidi are always on
+#[derive(Clone, Copy)]
+pub(crate) struct Flags {
+    /// Hyphens at the start, end or the third & fourth position disallowed
+    pub(crate) check_hyphens: bool,
+    /// Only letters, digits & hyphens allowed from ASCII
+    pub(crate) use_std3_ascii_rules: bool,
+    /// Labels of 1 - 63 and the domain of 1 - 253 bytes in ASCII
+    pub(crate) verify_dns_length: bool,
+}
+
+/// Convert the domain into ASCII with the UTS #46 ToASCII processing into
+/// the given buffer. The buffer needs room for the Unicode form of a label
+/// next to the ASCII form.
+///
+/// ```rust
+/// let mut buf = [0u8; 64];
+/// let ascii = yuri::domain_to_ascii("Bücher.example", &mut buf).unwrap();
+/// assert_eq!(ascii, "xn--bcher-kva.example");
+/// ```
+pub fn domain_to_ascii<'b, 'd>(
+    domain: &'d str,
+    buf: &'b mut [u8],
+) -> Result<&'b str, IdnaError<'d>> {
+    let flags = Flags {
+        check_hyphens: true,
+        use_std3_ascii_rules: true,
+        verify_dns_length: true,
+    };
+    process(domain, buf, flags, true)
+}
+
+/// Convert the domain into Unicode with the UTS #46 ToUnicode processing
+/// into the given buffer. The buffer needs room for a decoded label next
+/// to the ACE form.
+///
+/// ```rust
+/// let mut buf = [0u8; 64];
+/// let unicode = yuri::domain_to_unicode("xn--bcher-kva.EXAMPLE", &mut buf).unwrap();
+/// assert_eq!(unicode, "bücher.example");
+/// ```
+pub fn domain_to_unicode<'b, 'd>(
+    domain: &'d str,
+    buf: &'b mut [u8],
+) -> Result<&'b str, IdnaError<'d>> {
+    let flags = Flags {
+        check_hyphens: true,
+        use_std3_ascii_rules: true,
+        verify_dns_length: false,
+    };
+    process(domain, buf, flags, false)
+}
+
+/// ToASCII with the given flags
+#[cfg(feature = "whatwg")]
+pub(crate) fn to_ascii<'b, 'd>(
+    domain: &'d str,
+    buf: &'b mut [u8],
+    flags: Flags,
+) -> Result<&'b str, IdnaError<'d>> {
+    process(domain, buf, flags, true)
+}
+
+/// UTS #46 s. 4 processing followed by the ToASCII conversion when ascii
+fn process<'b, 'd>(
+    domain: &'d str,
+    buf: &'b mut [u8],
+    flags: Flags,
+    ascii: bool,
+) -> Result<&'b str, IdnaError<'d>> {
+    let mut w = SliceWriter::new(buf);
+    let too_small = |_| IdnaError::BufferTooSmall;
+    // Bidi domain name & the first label failing the Bidi Rule
+    let (mut is_bidi, mut bidi_error) = (false, None);
+    for (index, label) in labels(domain).enumerate() {
+        let label_error = |error| IdnaError::Label {
+            index,
+            label,
+            error,
+        };
+        if index > 0 {
+            w.push(b'.').map_err(too_small)?;
+        }
+        for c in label.chars() {
+            let disallowed = match mapping(c).0 {
+                Status::Disallowed => true,
+                Status::DisallowedStd3Valid | Status::DisallowedStd3Mapped(_) => {
+                    flags.use_std3_ascii_rules
+                }
+                _ => false,
+            };
+            if disallowed {
+                return Err(label_error(LabelError::Disallowed(c)));
+            }
+        }
+        let start = w.len;
+        nfc(mapped(label), |c| w.write_char(c)).map_err(too_small)?;
+
+        // Decoded or encoded after the processed label then moved over it
+        let end = w.len;
+        let (head, rest) = w.buf.split_at_mut(end);
+        let processed = core::str::from_utf8(&head[start..]).unwrap_or_default();
+        let punycode_error = |e| match e {
+            LabelError::TooLong => IdnaError::BufferTooSmall,
+            e => label_error(e),
+        };
+        let (unicode, converted_len) = match strip_ace_prefix(processed) {
+            Some(encoded) => {
+                let len = punycode_decode(encoded, rest).map_err(punycode_error)?;
+                let decoded = core::str::from_utf8(&rest[..len]).unwrap_or_default();
+                check_decoded(decoded, flags).map_err(label_error)?;
+                (decoded, (!ascii).then_some(len))
+            }
+            None => {
+                check_label(processed, flags).map_err(label_error)?;
+                let mut encoded = SliceWriter::new(rest);
+                if ascii && !processed.is_ascii() {
+                    encoded.write_str(ACE_PREFIX).map_err(too_small)?;
+                    punycode_encode(|| processed.chars(), &mut encoded).map_err(punycode_error)?;
+                }
+                (processed, Some(encoded.len).filter(|len| *len > 0))
+            }
+        };
+        is_bidi |= unicode.chars().map(bidi_class).any(|class| {
+            matches!(
+                class,
+                BidiClass::RightToLeft | BidiClass::ArabicLetter | BidiClass::ArabicNumber
+            )
+        });
+        if bidi_error.is_none() {
+            bidi_error = check_bidi(unicode).err().map(label_error);
+        }
+        if let Some(len) = converted_len {
+            w.buf.copy_within(end..end + len, start);
+            w.len = start + len;
+        }
+
+        let label_len = w.len - start;
+        if flags.verify_dns_length && label_len == 0 {
+            return Err(label_error(LabelError::Empty));
+        }
+        if flags.verify_dns_length && label_len > MAX_LABEL_LEN {
+            return Err(label_error(LabelError::TooLong));
+        }
+    }
+    // Bidi Rule applies to every label of a Bidi domain name
+    if let Some(error) = bidi_error.filter(|_| is_bidi) {
+        return Err(error);
+    }
+    if flags.verify_dns_length && w.len > MAX_DOMAIN_LEN {
+        return Err(IdnaError::TooLong);
+    }
+    if has_root_label(domain) {
+        w.push(b'.').map_err(too_small)?;
+    }
+    Ok(w.into_str())
+}
+
+/// Full stops, the only code points mapped to "." by the mapping table
+const FULL_STOPS: [char; 4] = ['.', '\u{3002}', '\u{ff0e}', '\u{ff61}'];
+
+/// Labels separated by the full stops without the root label
+fn labels(domain: &str) -> impl Iterator<Item = &str> {
+    let domain = match domain.strip_suffix(FULL_STOPS) {
+        Some(domain) if !domain.is_empty() => domain,
+        _ => domain,
+    };
+    domain.split(FULL_STOPS)
+}
+
+/// Whether the domain ends with the root label e.g. "example.com."
+fn has_root_label(domain: &str) -> bool {
+    domain
+        .strip_suffix(FULL_STOPS)
+        .is_some_and(|domain| !domain.is_empty())
+}
+
+fn strip_ace_prefix(label: &str) -> Option<&str> {
+    match label.get(..ACE_PREFIX.len()) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(ACE_PREFIX) => Some(&label[ACE_PREFIX.len()..]),
+        _ => None,
+    }
+}
+
+/// UTS #46 s. 4 step 1 mapping of the label without the disallowed checks
+fn mapped(label: &str) -> impl Iterator<Item = char> + '_ {
+    label.chars().flat_map(|c| {
+        let (status, to) = mapping(c);
+        let kept = match status {
+            Status::Ignored | Status::Mapped(_) | Status::DisallowedStd3Mapped(_) => None,
+            _ => Some(c),
+        };
+        kept.into_iter().chain(to.iter().copied())
+    })
+}
+
+/// Validity criteria of a decoded Punycode label, which also has to be
+/// in NFC and without full stops - UTS #46 s. 4.1
+fn check_decoded(label: &str, flags: Flags) -> Result<(), LabelError> {
+    if !is_nfc(label) {
+        return Err(LabelError::NotNfc);
+    }
+    if label.contains('.') {
+        return Err(LabelError::Disallowed('.'));
+    }
+    check_label(label, flags)
+}
+
+/// Validity criteria of a label - UTS #46 s. 4.1
+fn check_label(label: &str, flags: Flags) -> Result<(), LabelError> {
+    let mut chars = label.chars();
+    let hyphen = |c: Option<char>| c == Some('-');
+    let (first, third, fourth) = (chars.next(), chars.nth(1), chars.next());
+    if flags.check_hyphens
+        && (hyphen(first) || hyphen(label.chars().next_back()) || hyphen(third) && hyphen(fourth))
+    {
+        return Err(LabelError::InvalidHyphen);
+    }
+    if first.is_some_and(is_mark) {
+        return Err(LabelError::CombiningMark);
+    }
+    for c in label.chars() {
+        match mapping(c).0 {
+            Status::Valid => {}
+            Status::DisallowedStd3Valid if !flags.use_std3_ascii_rules => {}
+            _ => return Err(LabelError::Disallowed(c)),
+        }
+    }
+    check_joiners(label)
+}
+
+/// CONTEXTJ rules - RFC 5892 Appendix A.1 & A.2
+fn check_joiners(label: &str) -> Result<(), LabelError> {
+    for (pos, c) in label.char_indices() {
+        if !matches!(c, '\u{200c}' | '\u{200d}') {
+            continue;
+        }
+        let (before, after) = (&label[..pos], &label[pos + c.len_utf8()..]);
+        if before.chars().next_back().map(combining_class) == Some(VIRAMA) {
+            continue;
+        }
+        // Zero width non-joiner within a cursive join
+        let not_transparent = |t: &JoiningType| *t != JoiningType::Transparent;
+        let left = before.chars().rev().map(joining_type).find(not_transparent);
+        let right = after.chars().map(joining_type).find(not_transparent);
+        let joins = c == '\u{200c}'
+            && matches!(left, Some(JoiningType::Left | JoiningType::Dual))
+            && matches!(right, Some(JoiningType::Right | JoiningType::Dual));
+        if !joins {
+            return Err(LabelError::InvalidJoiner);
+        }
+    }
+    Ok(())
+}
+
+/// Bidi Rule of a label in a Bidi domain name, empty labels are left to
+/// the DNS length check - RFC 5893 s. 2
+fn check_bidi(label: &str) -> Result<(), LabelError> {
+    use BidiClass::*;
+    let mut classes = label.chars().map(bidi_class);
+    // 1. First is L for an LTR label or R, AL for an RTL label
+    let rtl = match classes.next() {
+        None => return Ok(()),
+        Some(LeftToRight) => false,
+        Some(RightToLeft | ArabicLetter) => true,
+        Some(_) => return Err(LabelError::InvalidBidi),
+    };
+    let (mut last, mut has_en, mut has_an) = (None, false, false);
+    for class in label.chars().map(bidi_class) {
+        // 2. & 5. Allowed in an RTL or LTR label
+        let allowed = match class {
+            RightToLeft | ArabicLetter | ArabicNumber => rtl,
+            LeftToRight => !rtl,
+            EuropeanNumber | EuropeanSeparator | CommonSeparator | EuropeanTerminator
+            | OtherNeutral | BoundaryNeutral | NonspacingMark => true,
+            Other => false,
+        };
+        if !allowed {
+            return Err(LabelError::InvalidBidi);
+        }
+        has_en |= class == EuropeanNumber;
+        has_an |= class == ArabicNumber;
+        if class != NonspacingMark {
+            last = Some(class);
+        }
+    }
+    // 3. & 6. Ending followed by NSMs, 4. not both EN & AN in an RTL label
+    let valid = match (rtl, last) {
+        (true, Some(RightToLeft | ArabicLetter | EuropeanNumber | ArabicNumber)) => {
+            !(has_en && has_an)
+        }
+        (false, Some(LeftToRight | EuropeanNumber)) => true,
+        _ => false,
+    };
+    match valid {
+        true => Ok(()),
+        false => Err(LabelError::InvalidBidi),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("Bücher.example", Ok("xn--bcher-kva.example"))]
+    #[case("ＢÜＣＨＥＲ。example", Ok("xn--bcher-kva.example"))]
+    #[case("你好你好.test.", Ok("xn--6qqa088eba.test."))]
+    #[case("XN--bcher-kva.example", Ok("xn--bcher-kva.example"))]
+    #[case("xn--Bcher-kva.test", Ok("xn--bcher-kva.test"))]
+    #[case("foobar.test", Ok("foobar.test"))]
+    #[case("fa\u{ad}ß.de", Ok("xn--fa-hia.de"))]
+    #[case("a\u{308}.test", Ok("xn--4ca.test"))]
+    #[case("Ⅳ.test", Ok("iv.test"))]
+    #[case("\u{915}\u{94d}\u{200d}.test", Ok("xn--11b6iy14e.test"))]
+    #[case("à.א", Ok("xn--0ca.xn--4db"))]
+    #[case("a..b", Err((1, "", LabelError::Empty)))]
+    #[case("foo.-bar", Err((1, "-bar", LabelError::InvalidHyphen)))]
+    #[case("ab--c.test", Err((0, "ab--c", LabelError::InvalidHyphen)))]
+    #[case("foo_bar.test", Err((0, "foo_bar", LabelError::Disallowed('_'))))]
+    #[case("foo.b\u{0}r", Err((1, "b\u{0}r", LabelError::Disallowed('\u{0}'))))]
+    #[case("\u{301}a.test", Err((0, "\u{301}a", LabelError::CombiningMark)))]
+    #[case("a\u{200d}b.test", Err((0, "a\u{200d}b", LabelError::InvalidJoiner)))]
+    #[case("0à.א", Err((0, "0à", LabelError::InvalidBidi)))]
+    #[case("xn--abc.test", Err((0, "xn--abc", LabelError::Disallowed('\u{82}'))))]
+    #[case("xn--a-ccb.test", Err((0, "xn--a-ccb", LabelError::NotNfc)))]
+    #[case("xn--99999999.test", Err((0, "xn--99999999", LabelError::InvalidPunycode)))]
+    #[case("test.xn--foo-", Err((1, "xn--foo-", LabelError::InvalidPunycode)))]
+    fn to_ascii(
+        #[case] domain: &'static str,
+        #[case] expected: Result<&'static str, (usize, &'static str, LabelError)>,
+    ) {
+        let mut buf = [0u8; 64];
+        let expected = expected.map_err(|(index, label, error)| IdnaError::Label {
+            index,
+            label,
+            error,
+        });
+        assert_eq!(domain_to_ascii(domain, &mut buf), expected);
+    }
+
+    #[test]
+    fn to_ascii_lengths() {
+        let mut buf = [0u8; 512];
+        let long_label = "a".repeat(64);
+        assert_eq!(
+            domain_to_ascii(&long_label, &mut buf),
+            Err(IdnaError::Label {
+                index: 0,
+                label: &long_label,
+                error: LabelError::TooLong
+            })
+        );
+        let long_domain = ["a".repeat(63).as_str(); 4].join(".");
+        assert_eq!(
+            domain_to_ascii(&long_domain, &mut buf),
+            Err(IdnaError::TooLong)
+        );
+        let mut buf = [0u8; 8];
+        assert_eq!(
+            domain_to_ascii("foobar.test", &mut buf),
+            Err(IdnaError::BufferTooSmall)
+        );
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            domain_to_ascii("bücher.test", &mut buf),
+            Err(IdnaError::BufferTooSmall)
+        );
+    }
+
+    #[rstest]
+    #[case("xn--bcher-kva.example", Ok("bücher.example"))]
+    #[case("XN--6QQA088EBA.test", Ok("你好你好.test"))]
+    #[case("Foobar.TEST", Ok("foobar.test"))]
+    #[case("xn--mnchen-3ya.xn--bcher-kva", Ok("münchen.bücher"))]
+    #[case("a..b", Ok("a..b"))]
+    #[case("foo.xn--zz-", Err((1, "xn--zz-", LabelError::InvalidPunycode)))]
+    fn to_unicode(
+        #[case] domain: &'static str,
+        #[case] expected: Result<&'static str, (usize, &'static str, LabelError)>,
+    ) {
+        let mut buf = [0u8; 64];
+        let expected = expected.map_err(|(index, label, error)| IdnaError::Label {
+            index,
+            label,
+            error,
+        });
+        assert_eq!(domain_to_unicode(domain, &mut buf), expected);
+    }
+
+    // https://www.unicode.org/Public/idna/13.0.0/IdnaTestV2.txt
+    const IDNA_TEST_V2: &str = include_str!("../test_data/idna/IdnaTestV2.txt");
+
+    /// Sources expecting a V6 error for the valid U+18C4E, which the later
+    /// versions of the file no longer do
+    const SKIPPED: &[&str] = &["㨛𘱎.。7\u{d01}", "xn--mbm8237g..xn--7-7hf"];
+
+    /// Source with the \uXXXX & \x{XXXX} escapes
+    fn unescape(s: &str) -> String {
+        let mut out = String::new();
+        let mut rest = s;
+        while let Some(pos) = rest.find('\\') {
+            out.push_str(&rest[..pos]);
+            let (hex, len) = match &rest[pos + 1..] {
+                r if r.starts_with('u') => (&r[1..5], 5),
+                r if r.starts_with("x{") => {
+                    let end = r.find('}').unwrap();
+                    (&r[2..end], end + 1)
+                }
+                _ => ("5c", 0),
+            };
+            out.push(char::from_u32(u32::from_str_radix(hex, 16).unwrap()).unwrap());
+            rest = &rest[pos + 1 + len..];
+        }
+        out.push_str(rest);
+        out
+    }
+
+    /// Whether the status codes e.g. "[B1, V5]" have any not ignored
+    fn has_errors(status: &str, ignored: &[&str]) -> bool {
+        status
+            .trim_matches(['[', ']'])
+            .split(',')
+            .map(str::trim)
+            .any(|code| !code.is_empty() && !ignored.contains(&code))
+    }
+
+    #[test]
+    fn idna_test_v2() {
+        let lines = IDNA_TEST_V2
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or_default())
+            .filter(|line| !line.trim().is_empty());
+        for line in lines {
+            let columns: Vec<_> = line.split(';').map(str::trim).collect();
+            if SKIPPED.contains(&columns[0]) {
+                continue;
+            }
+            let source = unescape(columns[0]);
+            let to_unicode = match columns[1] {
+                "" => source.clone(),
+                given => unescape(given),
+            };
+            let to_unicode_status = columns[2];
+            let to_ascii = match columns[3] {
+                "" => to_unicode.clone(),
+                given => unescape(given),
+            };
+            let to_ascii_status = match columns[4] {
+                "" => to_unicode_status,
+                given => given,
+            };
+
+            let mut buf = [0u8; 1024];
+            let res = domain_to_unicode(&source, &mut buf);
+            // X4_2 is an empty label which is only checked with VerifyDnsLength
+            match has_errors(to_unicode_status, &["X4_2"]) {
+                true => assert!(res.is_err(), "{:?} -> {:?}", source, res),
+                false => assert_eq!(res, Ok(to_unicode.as_str()), "{:?}", source),
+            }
+            let res = domain_to_ascii(&source, &mut buf);
+            match has_errors(to_ascii_status, &[]) {
+                true => assert!(res.is_err(), "{:?} -> {:?}", source, res),
+                false => assert_eq!(res, Ok(to_ascii.as_str()), "{:?}", source),
+            }
+        }
+    }
+}
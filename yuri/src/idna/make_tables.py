@@ -0,0 +1,216 @@
+# Generates tables.rs for the idna feature:
+#
+#   python3 make_tables.py IdnaMappingTable.txt DerivedJoiningType.txt > tables.rs
+#
+# - https://www.unicode.org/Public/idna/13.0.0/IdnaMappingTable.txt
+# - https://www.unicode.org/Public/15.0.0/ucd/extracted/DerivedJoiningType.txt
+#
+# The NFC, Canonical_Combining_Class, Bidi_Class & General_Category data is
+# taken from the unicodedata module of the Python running this.
+
+import re
+import sys
+import unicodedata
+
+mapping_path, joining_path = sys.argv[1:3]
+
+
+def parse_ucd(path):
+    """Yield the (first, last, fields) of the data lines"""
+    for line in open(path, encoding="utf-8"):
+        line = line.partition("#")[0]
+        if not line.strip():
+            continue
+        fields = [f.strip() for f in line.split(";")]
+        first, _, last = fields[0].partition("..")
+        yield int(first, 16), int(last or first, 16), fields[1:]
+
+
+def version(path, pattern):
+    """Unicode version from the file header"""
+    return re.search(pattern, open(path, encoding="utf-8").read(1000)).group(1)
+
+
+def ranges(values):
+    """Collapse (code point, value) pairs into (first, last, value) runs"""
+    runs = []
+    for cp, value in values:
+        if runs and runs[-1][1] + 1 == cp and runs[-1][2] == value:
+            runs[-1][1] = cp
+        else:
+            runs.append([cp, cp, value])
+    return runs
+
+
+def char(cp):
+    return "'\\u{%x}'" % cp
+
+
+# UTS #46 mapping - Transitional_Processing is not supported so the
+# deviations are valid
+STATUS = {
+    "valid": "Valid",
+    "deviation": "Valid",
+    "ignored": "Ignored",
+    "mapped": "Mapped",
+    "disallowed": "Disallowed",
+    "disallowed_STD3_valid": "DisallowedStd3Valid",
+    "disallowed_STD3_mapped": "DisallowedStd3Mapped",
+}
+
+# The mapped code points of a range are indexed from the range base
+mapped = ""
+mapped_index = []
+mapping = []
+for first, last, fields in parse_ucd(mapping_path):
+    status = STATUS[fields[0]]
+    base = len(mapped_index)
+    if status.endswith("Mapped"):
+        to = "".join(chr(int(c, 16)) for c in fields[1].split())
+        offset = mapped.find(to)
+        if offset < 0:
+            offset = len(mapped)
+            mapped += to
+        mapped_index.extend([(offset, len(to))] * (last - first + 1))
+    if not mapping or mapping[-1][1] != status:
+        mapping.append((first, status, base))
+
+# Canonical decompositions in full without the Hangul syllables
+decomposed = []
+decompositions = []
+compositions = []
+for cp in range(0x110000):
+    c = chr(cp)
+    if 0xAC00 <= cp <= 0xD7A3:
+        continue
+    d = unicodedata.decomposition(c)
+    if not d or d.startswith("<"):
+        continue
+    nfd = unicodedata.normalize("NFD", c)
+    decompositions.append((cp, len(decomposed), len(nfd)))
+    decomposed.extend(ord(x) for x in nfd)
+    pair = [chr(int(x, 16)) for x in d.split()]
+    if len(pair) == 2 and unicodedata.normalize("NFC", "".join(pair)) == c:
+        compositions.append((ord(pair[0]), ord(pair[1]), cp))
+compositions.sort()
+
+ccc = ranges(
+    (cp, unicodedata.combining(chr(cp)))
+    for cp in range(0x110000)
+    if unicodedata.combining(chr(cp))
+)
+
+# Only the classes used by the RFC 5893 Bidi Rule, L is the default
+BIDI = {
+    "R": "RightToLeft",
+    "AL": "ArabicLetter",
+    "AN": "ArabicNumber",
+    "EN": "EuropeanNumber",
+    "ES": "EuropeanSeparator",
+    "CS": "CommonSeparator",
+    "ET": "EuropeanTerminator",
+    "ON": "OtherNeutral",
+    "BN": "BoundaryNeutral",
+    "NSM": "NonspacingMark",
+}
+bidi = ranges(
+    (cp, BIDI.get(unicodedata.bidirectional(chr(cp)), "Other"))
+    for cp in range(0x110000)
+    if unicodedata.bidirectional(chr(cp)) not in ("L", "")
+)
+
+marks = ranges(
+    (cp, True)
+    for cp in range(0x110000)
+    if unicodedata.category(chr(cp)).startswith("M")
+)
+
+JOINING = {"L": "Left", "D": "Dual", "R": "Right", "T": "Transparent"}
+joining = [
+    (first, last, JOINING[fields[0]])
+    for first, last, fields in parse_ucd(joining_path)
+    if fields[0] in JOINING
+]
+joining = ranges(
+    (cp, value) for first, last, value in joining for cp in range(first, last + 1)
+)
+
+assert len(mapped) < 1 << 16 and len(mapped_index) < 1 << 16
+assert len(decomposed) < 1 << 16
+
+out = sys.stdout.write
+
+
+def table(doc, decl, items):
+    """Write the static packing the items into lines of up to 100 columns"""
+    out("/// %s\n" % doc)
+    out("pub(super) static %s = &[\n" % decl)
+    line = "   "
+    for item in items:
+        if len(line) + len(item) + 2 > 100:
+            out(line + "\n")
+            line = "   "
+        line += " %s," % item
+    out(line + "\n];\n")
+
+
+out("// Generated by make_tables.py - do not edit\n")
+out("//\n")
+out("// IdnaMappingTable.txt %s\n" % version(mapping_path, r"Version: (\S+)"))
+out("// DerivedJoiningType.txt %s\n" % version(joining_path, r"DerivedJoiningType-(\S+)\.txt"))
+out("// Python unicodedata %s\n\n" % unicodedata.unidata_version)
+out("use super::unicode::{BidiClass, JoiningType, Status};\n\n")
+
+table(
+    "UTS #46 status of the code points from the first up to the next",
+    "MAPPING: &[(u32, Status)]",
+    [
+        "(0x%x, Status::%s)" % (f, "%s(%d)" % (s, b) if s.endswith("Mapped") else s)
+        for f, s, b in mapping
+    ],
+)
+table(
+    "Offset & length in MAPPED by the index from the Status",
+    "MAPPED_INDEX: &[(u16, u8)]",
+    ["(%d, %d)" % item for item in mapped_index],
+)
+table(
+    "Mappings of the mapped code points",
+    "MAPPED: &[char]",
+    [char(ord(c)) for c in mapped],
+)
+table(
+    "Code point, offset & length of the full decomposition in DECOMPOSED",
+    "DECOMPOSITIONS: &[(u32, u16, u8)]",
+    ["(0x%x, %d, %d)" % item for item in decompositions],
+)
+table(
+    "Full canonical decompositions",
+    "DECOMPOSED: &[char]",
+    [char(cp) for cp in decomposed],
+)
+table(
+    "Primary composites by the starter & the combining code point",
+    "COMPOSITIONS: &[(u32, u32, char)]",
+    ["(0x%x, 0x%x, %s)" % (a, b, char(cp)) for a, b, cp in compositions],
+)
+table(
+    "Canonical_Combining_Class of the non-zero ranges",
+    "COMBINING_CLASS: &[(u32, u32, u8)]",
+    ["(0x%x, 0x%x, %d)" % tuple(item) for item in ccc],
+)
+table(
+    "Bidi_Class ranges other than L",
+    "BIDI_CLASS: &[(u32, u32, BidiClass)]",
+    ["(0x%x, 0x%x, BidiClass::%s)" % tuple(item) for item in bidi],
+)
+table(
+    "General_Category Mark (Mn, Mc & Me) ranges",
+    "MARKS: &[(u32, u32)]",
+    ["(0x%x, 0x%x)" % (f, l) for f, l, _ in marks],
+)
+table(
+    "Joining_Type ranges other than U & C",
+    "JOINING_TYPE: &[(u32, u32, JoiningType)]",
+    ["(0x%x, 0x%x, JoiningType::%s)" % tuple(item) for item in joining],
+)
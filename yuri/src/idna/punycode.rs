@@ -0,0 +1,200 @@
+//! Punycode - RFC 3492
+
+use crate::buf::SliceWriter;
+use crate::error::LabelError;
+
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 128;
+
+fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = match first_time {
+        true => delta / DAMP,
+        false => delta / 2,
+    };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + (BASE - T_MIN + 1) * delta / (delta + SKEW)
+}
+
+fn threshold(k: u32, bias: u32) -> u32 {
+    k.saturating_sub(bias).clamp(T_MIN, T_MAX)
+}
+
+fn encode_digit(d: u32) -> u8 {
+    match d {
+        0..=25 => b'a' + d as u8,
+        _ => b'0' + (d - 26) as u8,
+    }
+}
+
+fn decode_digit(b: u8) -> Option<u32> {
+    match b {
+        b'a'..=b'z' => Some(u32::from(b - b'a')),
+        b'A'..=b'Z' => Some(u32::from(b - b'A')),
+        b'0'..=b'9' => Some(u32::from(b - b'0') + 26),
+        _ => None,
+    }
+}
+
+/// Encode the label chars given without the ACE prefix - RFC 3492 s. 6.3
+pub(super) fn punycode_encode<I, F>(chars: F, w: &mut SliceWriter<'_>) -> Result<(), LabelError>
+where
+    I: Iterator<Item = char>,
+    F: Fn() -> I,
+{
+    let too_long = |_| LabelError::TooLong;
+    let mut basic_len = 0u32;
+    let mut total_len = 0u32;
+    for c in chars() {
+        if c.is_ascii() {
+            w.push(c as u8).map_err(too_long)?;
+            basic_len += 1;
+        }
+        total_len += 1;
+    }
+    if basic_len > 0 {
+        w.push(b'-').map_err(too_long)?;
+    }
+
+    let (mut n, mut delta, mut bias) = (INITIAL_N, 0u32, INITIAL_BIAS);
+    let mut handled = basic_len;
+    while handled < total_len {
+        let m = chars()
+            .map(u32::from)
+            .filter(|c| *c >= n)
+            .min()
+            .unwrap_or(n);
+        delta = (m - n)
+            .checked_mul(handled + 1)
+            .and_then(|d| delta.checked_add(d))
+            .ok_or(LabelError::InvalidPunycode)?;
+        n = m;
+        for c in chars().map(u32::from) {
+            if c < n {
+                delta = delta.checked_add(1).ok_or(LabelError::InvalidPunycode)?;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    let digit = t + (q - t) % (BASE - t);
+                    w.push(encode_digit(digit)).map_err(too_long)?;
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                w.push(encode_digit(q)).map_err(too_long)?;
+                bias = adapt(delta, handled + 1, handled == basic_len);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta += 1;
+        n += 1;
+    }
+    Ok(())
+}
+
+/// Decode the label given without the ACE prefix into the buffer as UTF-8
+/// returning the length written - RFC 3492 s. 6.2
+pub(super) fn punycode_decode(input: &str, buf: &mut [u8]) -> Result<usize, LabelError> {
+    let (basic, extended) = match input.rfind('-') {
+        Some(pos) => (&input[..pos], &input[pos + 1..]),
+        None => ("", input),
+    };
+    if !basic.is_ascii() || extended.is_empty() {
+        return Err(LabelError::InvalidPunycode);
+    }
+    let out = buf.get_mut(..basic.len()).ok_or(LabelError::TooLong)?;
+    out.copy_from_slice(basic.as_bytes());
+    let mut len = basic.len();
+    let mut char_count = basic.len() as u32;
+
+    let (mut n, mut i, mut bias) = (INITIAL_N, 0u32, INITIAL_BIAS);
+    let mut digits = extended.bytes();
+    while digits.len() > 0 {
+        let old_i = i;
+        let mut weight = 1u32;
+        let mut k = BASE;
+        loop {
+            let digit = digits
+                .next()
+                .and_then(decode_digit)
+                .ok_or(LabelError::InvalidPunycode)?;
+            i = digit
+                .checked_mul(weight)
+                .and_then(|d| i.checked_add(d))
+                .ok_or(LabelError::InvalidPunycode)?;
+            let t = threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            weight = weight
+                .checked_mul(BASE - t)
+                .ok_or(LabelError::InvalidPunycode)?;
+            k += BASE;
+        }
+        char_count += 1;
+        bias = adapt(i - old_i, char_count, old_i == 0);
+        n = n
+            .checked_add(i / char_count)
+            .ok_or(LabelError::InvalidPunycode)?;
+        i %= char_count;
+
+        // Insert the char at the char index i shifting the rest
+        let c = char::from_u32(n).ok_or(LabelError::InvalidPunycode)?;
+        let mut utf8 = [0u8; 4];
+        let encoded = c.encode_utf8(&mut utf8).as_bytes();
+        let at = core::str::from_utf8(&buf[..len])
+            .unwrap_or_default()
+            .char_indices()
+            .nth(i as usize)
+            .map_or(len, |(pos, _)| pos);
+        if len + encoded.len() > buf.len() {
+            return Err(LabelError::TooLong);
+        }
+        buf.copy_within(at..len, at + encoded.len());
+        buf[at..at + encoded.len()].copy_from_slice(encoded);
+        len += encoded.len();
+        i += 1;
+    }
+    Ok(len)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("你好你好", "6qqa088eba")]
+    #[case("bücher", "bcher-kva")]
+    #[case("ليهمابتكلموشعربي؟", "egbpdaj6bu4bxfgehfvwxn")]
+    #[case("他们为什么不说中文", "ihqwcrb4cv8a8dqg056pqjye")]
+    #[case("Pročprostěnemluvíčesky", "Proprostnemluvesky-uyb24dma41a")]
+    #[case("3年B組金八先生", "3B-ww4c5e180e575a65lsy2b")]
+    #[case("例え", "r8jz45g")]
+    #[case("faß", "fa-hia")]
+    fn punycode_roundtrip(#[case] unicode: &'static str, #[case] encoded: &'static str) {
+        let mut buf = [0u8; 64];
+        let mut w = SliceWriter::new(&mut buf);
+        punycode_encode(|| unicode.chars(), &mut w).unwrap();
+        assert_eq!(w.into_str(), encoded);
+
+        let mut buf = [0u8; 64];
+        let len = punycode_decode(encoded, &mut buf).unwrap();
+        assert_eq!(&buf[..len], unicode.as_bytes());
+    }
+}
@@ -0,0 +1,4300 @@
+// Generated by make_tables.py - do not edit
+//
+// IdnaMappingTable.txt 13.0.0
+// DerivedJoiningType.txt 15.0.0
+// Python unicodedata 14.0.0
+
+use super::unicode::{BidiClass, JoiningType, Status};
+
+/// UTS #46 status of the code points from the first up to the next
+pub(super) static MAPPING: &[(u32, Status)] = &[
+    (0x0, Status::DisallowedStd3Valid), (0x2d, Status::Valid), (0x2f, Status::DisallowedStd3Valid),
+    (0x30, Status::Valid), (0x3a, Status::DisallowedStd3Valid), (0x41, Status::Mapped(0)),
+    (0x5b, Status::DisallowedStd3Valid), (0x61, Status::Valid), (0x7b, Status::DisallowedStd3Valid),
+    (0x80, Status::Disallowed), (0xa0, Status::DisallowedStd3Mapped(26)), (0xa1, Status::Valid),
+    (0xa8, Status::DisallowedStd3Mapped(27)), (0xa9, Status::Valid), (0xaa, Status::Mapped(28)),
+    (0xab, Status::Valid), (0xad, Status::Ignored), (0xae, Status::Valid),
+    (0xaf, Status::DisallowedStd3Mapped(29)), (0xb0, Status::Valid), (0xb2, Status::Mapped(30)),
+    (0xb4, Status::DisallowedStd3Mapped(32)), (0xb5, Status::Mapped(33)), (0xb6, Status::Valid),
+    (0xb8, Status::DisallowedStd3Mapped(34)), (0xb9, Status::Mapped(35)), (0xbb, Status::Valid),
+    (0xbc, Status::Mapped(37)), (0xbf, Status::Valid), (0xc0, Status::Mapped(40)),
+    (0xd7, Status::Valid), (0xd8, Status::Mapped(63)), (0xdf, Status::Valid),
+    (0x100, Status::Mapped(70)), (0x101, Status::Valid), (0x102, Status::Mapped(71)),
+    (0x103, Status::Valid), (0x104, Status::Mapped(72)), (0x105, Status::Valid),
+    (0x106, Status::Mapped(73)), (0x107, Status::Valid), (0x108, Status::Mapped(74)),
+    (0x109, Status::Valid), (0x10a, Status::Mapped(75)), (0x10b, Status::Valid),
+    (0x10c, Status::Mapped(76)), (0x10d, Status::Valid), (0x10e, Status::Mapped(77)),
+    (0x10f, Status::Valid), (0x110, Status::Mapped(78)), (0x111, Status::Valid),
+    (0x112, Status::Mapped(79)), (0x113, Status::Valid), (0x114, Status::Mapped(80)),
+    (0x115, Status::Valid), (0x116, Status::Mapped(81)), (0x117, Status::Valid),
+    (0x118, Status::Mapped(82)), (0x119, Status::Valid), (0x11a, Status::Mapped(83)),
+    (0x11b, Status::Valid), (0x11c, Status::Mapped(84)), (0x11d, Status::Valid),
+    (0x11e, Status::Mapped(85)), (0x11f, Status::Valid), (0x120, Status::Mapped(86)),
+    (0x121, Status::Valid), (0x122, Status::Mapped(87)), (0x123, Status::Valid),
+    (0x124, Status::Mapped(88)), (0x125, Status::Valid), (0x126, Status::Mapped(89)),
+    (0x127, Status::Valid), (0x128, Status::Mapped(90)), (0x129, Status::Valid),
+    (0x12a, Status::Mapped(91)), (0x12b, Status::Valid), (0x12c, Status::Mapped(92)),
+    (0x12d, Status::Valid), (0x12e, Status::Mapped(93)), (0x12f, Status::Valid),
+    (0x130, Status::Mapped(94)), (0x131, Status::Valid), (0x132, Status::Mapped(95)),
+    (0x135, Status::Valid), (0x136, Status::Mapped(98)), (0x137, Status::Valid),
+    (0x139, Status::Mapped(99)), (0x13a, Status::Valid), (0x13b, Status::Mapped(100)),
+    (0x13c, Status::Valid), (0x13d, Status::Mapped(101)), (0x13e, Status::Valid),
+    (0x13f, Status::Mapped(102)), (0x142, Status::Valid), (0x143, Status::Mapped(105)),
+    (0x144, Status::Valid), (0x145, Status::Mapped(106)), (0x146, Status::Valid),
+    (0x147, Status::Mapped(107)), (0x148, Status::Valid), (0x149, Status::Mapped(108)),
+    (0x14b, Status::Valid), (0x14c, Status::Mapped(110)), (0x14d, Status::Valid),
+    (0x14e, Status::Mapped(111)), (0x14f, Status::Valid), (0x150, Status::Mapped(112)),
+    (0x151, Status::Valid), (0x152, Status::Mapped(113)), (0x153, Status::Valid),
+    (0x154, Status::Mapped(114)), (0x155, Status::Valid), (0x156, Status::Mapped(115)),
+    (0x157, Status::Valid), (0x158, Status::Mapped(116)), (0x159, Status::Valid),
+    (0x15a, Status::Mapped(117)), (0x15b, Status::Valid), (0x15c, Status::Mapped(118)),
+    (0x15d, Status::Valid), (0x15e, Status::Mapped(119)), (0x15f, Status::Valid),
+    (0x160, Status::Mapped(120)), (0x161, Status::Valid), (0x162, Status::Mapped(121)),
+    (0x163, Status::Valid), (0x164, Status::Mapped(122)), (0x165, Status::Valid),
+    (0x166, Status::Mapped(123)), (0x167, Status::Valid), (0x168, Status::Mapped(124)),
+    (0x169, Status::Valid), (0x16a, Status::Mapped(125)), (0x16b, Status::Valid),
+    (0x16c, Status::Mapped(126)), (0x16d, Status::Valid), (0x16e, Status::Mapped(127)),
+    (0x16f, Status::Valid), (0x170, Status::Mapped(128)), (0x171, Status::Valid),
+    (0x172, Status::Mapped(129)), (0x173, Status::Valid), (0x174, Status::Mapped(130)),
+    (0x175, Status::Valid), (0x176, Status::Mapped(131)), (0x177, Status::Valid),
+    (0x178, Status::Mapped(132)), (0x17a, Status::Valid), (0x17b, Status::Mapped(134)),
+    (0x17c, Status::Valid), (0x17d, Status::Mapped(135)), (0x17e, Status::Valid),
+    (0x17f, Status::Mapped(136)), (0x180, Status::Valid), (0x181, Status::Mapped(137)),
+    (0x183, Status::Valid), (0x184, Status::Mapped(139)), (0x185, Status::Valid),
+    (0x186, Status::Mapped(140)), (0x188, Status::Valid), (0x189, Status::Mapped(142)),
+    (0x18c, Status::Valid), (0x18e, Status::Mapped(145)), (0x192, Status::Valid),
+    (0x193, Status::Mapped(149)), (0x195, Status::Valid), (0x196, Status::Mapped(151)),
+    (0x199, Status::Valid), (0x19c, Status::Mapped(154)), (0x19e, Status::Valid),
+    (0x19f, Status::Mapped(156)), (0x1a1, Status::Valid), (0x1a2, Status::Mapped(158)),
+    (0x1a3, Status::Valid), (0x1a4, Status::Mapped(159)), (0x1a5, Status::Valid),
+    (0x1a6, Status::Mapped(160)), (0x1a8, Status::Valid), (0x1a9, Status::Mapped(162)),
+    (0x1aa, Status::Valid), (0x1ac, Status::Mapped(163)), (0x1ad, Status::Valid),
+    (0x1ae, Status::Mapped(164)), (0x1b0, Status::Valid), (0x1b1, Status::Mapped(166)),
+    (0x1b4, Status::Valid), (0x1b5, Status::Mapped(169)), (0x1b6, Status::Valid),
+    (0x1b7, Status::Mapped(170)), (0x1b9, Status::Valid), (0x1bc, Status::Mapped(172)),
+    (0x1bd, Status::Valid), (0x1c4, Status::Mapped(173)), (0x1ce, Status::Valid),
+    (0x1cf, Status::Mapped(183)), (0x1d0, Status::Valid), (0x1d1, Status::Mapped(184)),
+    (0x1d2, Status::Valid), (0x1d3, Status::Mapped(185)), (0x1d4, Status::Valid),
+    (0x1d5, Status::Mapped(186)), (0x1d6, Status::Valid), (0x1d7, Status::Mapped(187)),
+    (0x1d8, Status::Valid), (0x1d9, Status::Mapped(188)), (0x1da, Status::Valid),
+    (0x1db, Status::Mapped(189)), (0x1dc, Status::Valid), (0x1de, Status::Mapped(190)),
+    (0x1df, Status::Valid), (0x1e0, Status::Mapped(191)), (0x1e1, Status::Valid),
+    (0x1e2, Status::Mapped(192)), (0x1e3, Status::Valid), (0x1e4, Status::Mapped(193)),
+    (0x1e5, Status::Valid), (0x1e6, Status::Mapped(194)), (0x1e7, Status::Valid),
+    (0x1e8, Status::Mapped(195)), (0x1e9, Status::Valid), (0x1ea, Status::Mapped(196)),
+    (0x1eb, Status::Valid), (0x1ec, Status::Mapped(197)), (0x1ed, Status::Valid),
+    (0x1ee, Status::Mapped(198)), (0x1ef, Status::Valid), (0x1f1, Status::Mapped(199)),
+    (0x1f5, Status::Valid), (0x1f6, Status::Mapped(203)), (0x1f9, Status::Valid),
+    (0x1fa, Status::Mapped(206)), (0x1fb, Status::Valid), (0x1fc, Status::Mapped(207)),
+    (0x1fd, Status::Valid), (0x1fe, Status::Mapped(208)), (0x1ff, Status::Valid),
+    (0x200, Status::Mapped(209)), (0x201, Status::Valid), (0x202, Status::Mapped(210)),
+    (0x203, Status::Valid), (0x204, Status::Mapped(211)), (0x205, Status::Valid),
+    (0x206, Status::Mapped(212)), (0x207, Status::Valid), (0x208, Status::Mapped(213)),
+    (0x209, Status::Valid), (0x20a, Status::Mapped(214)), (0x20b, Status::Valid),
+    (0x20c, Status::Mapped(215)), (0x20d, Status::Valid), (0x20e, Status::Mapped(216)),
+    (0x20f, Status::Valid), (0x210, Status::Mapped(217)), (0x211, Status::Valid),
+    (0x212, Status::Mapped(218)), (0x213, Status::Valid), (0x214, Status::Mapped(219)),
+    (0x215, Status::Valid), (0x216, Status::Mapped(220)), (0x217, Status::Valid),
+    (0x218, Status::Mapped(221)), (0x219, Status::Valid), (0x21a, Status::Mapped(222)),
+    (0x21b, Status::Valid), (0x21c, Status::Mapped(223)), (0x21d, Status::Valid),
+    (0x21e, Status::Mapped(224)), (0x21f, Status::Valid), (0x220, Status::Mapped(225)),
+    (0x221, Status::Valid), (0x222, Status::Mapped(226)), (0x223, Status::Valid),
+    (0x224, Status::Mapped(227)), (0x225, Status::Valid), (0x226, Status::Mapped(228)),
+    (0x227, Status::Valid), (0x228, Status::Mapped(229)), (0x229, Status::Valid),
+    (0x22a, Status::Mapped(230)), (0x22b, Status::Valid), (0x22c, Status::Mapped(231)),
+    (0x22d, Status::Valid), (0x22e, Status::Mapped(232)), (0x22f, Status::Valid),
+    (0x230, Status::Mapped(233)), (0x231, Status::Valid), (0x232, Status::Mapped(234)),
+    (0x233, Status::Valid), (0x23a, Status::Mapped(235)), (0x23c, Status::Valid),
+    (0x23d, Status::Mapped(237)), (0x23f, Status::Valid), (0x241, Status::Mapped(239)),
+    (0x242, Status::Valid), (0x243, Status::Mapped(240)), (0x247, Status::Valid),
+    (0x248, Status::Mapped(244)), (0x249, Status::Valid), (0x24a, Status::Mapped(245)),
+    (0x24b, Status::Valid), (0x24c, Status::Mapped(246)), (0x24d, Status::Valid),
+    (0x24e, Status::Mapped(247)), (0x24f, Status::Valid), (0x2b0, Status::Mapped(248)),
+    (0x2b9, Status::Valid), (0x2d8, Status::DisallowedStd3Mapped(257)), (0x2de, Status::Valid),
+    (0x2e0, Status::Mapped(263)), (0x2e5, Status::Valid), (0x340, Status::Mapped(268)),
+    (0x342, Status::Valid), (0x343, Status::Mapped(270)), (0x346, Status::Valid),
+    (0x34f, Status::Ignored), (0x350, Status::Valid), (0x370, Status::Mapped(273)),
+    (0x371, Status::Valid), (0x372, Status::Mapped(274)), (0x373, Status::Valid),
+    (0x374, Status::Mapped(275)), (0x375, Status::Valid), (0x376, Status::Mapped(276)),
+    (0x377, Status::Valid), (0x378, Status::Disallowed), (0x37a, Status::DisallowedStd3Mapped(277)),
+    (0x37b, Status::Valid), (0x37e, Status::DisallowedStd3Mapped(278)),
+    (0x37f, Status::Mapped(279)), (0x380, Status::Disallowed),
+    (0x384, Status::DisallowedStd3Mapped(280)), (0x386, Status::Mapped(282)),
+    (0x38b, Status::Disallowed), (0x38c, Status::Mapped(287)), (0x38d, Status::Disallowed),
+    (0x38e, Status::Mapped(288)), (0x390, Status::Valid), (0x391, Status::Mapped(290)),
+    (0x3a2, Status::Disallowed), (0x3a3, Status::Mapped(307)), (0x3ac, Status::Valid),
+    (0x3cf, Status::Mapped(316)), (0x3d7, Status::Valid), (0x3d8, Status::Mapped(324)),
+    (0x3d9, Status::Valid), (0x3da, Status::Mapped(325)), (0x3db, Status::Valid),
+    (0x3dc, Status::Mapped(326)), (0x3dd, Status::Valid), (0x3de, Status::Mapped(327)),
+    (0x3df, Status::Valid), (0x3e0, Status::Mapped(328)), (0x3e1, Status::Valid),
+    (0x3e2, Status::Mapped(329)), (0x3e3, Status::Valid), (0x3e4, Status::Mapped(330)),
+    (0x3e5, Status::Valid), (0x3e6, Status::Mapped(331)), (0x3e7, Status::Valid),
+    (0x3e8, Status::Mapped(332)), (0x3e9, Status::Valid), (0x3ea, Status::Mapped(333)),
+    (0x3eb, Status::Valid), (0x3ec, Status::Mapped(334)), (0x3ed, Status::Valid),
+    (0x3ee, Status::Mapped(335)), (0x3ef, Status::Valid), (0x3f0, Status::Mapped(336)),
+    (0x3f3, Status::Valid), (0x3f4, Status::Mapped(339)), (0x3f6, Status::Valid),
+    (0x3f7, Status::Mapped(341)), (0x3f8, Status::Valid), (0x3f9, Status::Mapped(342)),
+    (0x3fb, Status::Valid), (0x3fd, Status::Mapped(344)), (0x430, Status::Valid),
+    (0x460, Status::Mapped(395)), (0x461, Status::Valid), (0x462, Status::Mapped(396)),
+    (0x463, Status::Valid), (0x464, Status::Mapped(397)), (0x465, Status::Valid),
+    (0x466, Status::Mapped(398)), (0x467, Status::Valid), (0x468, Status::Mapped(399)),
+    (0x469, Status::Valid), (0x46a, Status::Mapped(400)), (0x46b, Status::Valid),
+    (0x46c, Status::Mapped(401)), (0x46d, Status::Valid), (0x46e, Status::Mapped(402)),
+    (0x46f, Status::Valid), (0x470, Status::Mapped(403)), (0x471, Status::Valid),
+    (0x472, Status::Mapped(404)), (0x473, Status::Valid), (0x474, Status::Mapped(405)),
+    (0x475, Status::Valid), (0x476, Status::Mapped(406)), (0x477, Status::Valid),
+    (0x478, Status::Mapped(407)), (0x479, Status::Valid), (0x47a, Status::Mapped(408)),
+    (0x47b, Status::Valid), (0x47c, Status::Mapped(409)), (0x47d, Status::Valid),
+    (0x47e, Status::Mapped(410)), (0x47f, Status::Valid), (0x480, Status::Mapped(411)),
+    (0x481, Status::Valid), (0x48a, Status::Mapped(412)), (0x48b, Status::Valid),
+    (0x48c, Status::Mapped(413)), (0x48d, Status::Valid), (0x48e, Status::Mapped(414)),
+    (0x48f, Status::Valid), (0x490, Status::Mapped(415)), (0x491, Status::Valid),
+    (0x492, Status::Mapped(416)), (0x493, Status::Valid), (0x494, Status::Mapped(417)),
+    (0x495, Status::Valid), (0x496, Status::Mapped(418)), (0x497, Status::Valid),
+    (0x498, Status::Mapped(419)), (0x499, Status::Valid), (0x49a, Status::Mapped(420)),
+    (0x49b, Status::Valid), (0x49c, Status::Mapped(421)), (0x49d, Status::Valid),
+    (0x49e, Status::Mapped(422)), (0x49f, Status::Valid), (0x4a0, Status::Mapped(423)),
+    (0x4a1, Status::Valid), (0x4a2, Status::Mapped(424)), (0x4a3, Status::Valid),
+    (0x4a4, Status::Mapped(425)), (0x4a5, Status::Valid), (0x4a6, Status::Mapped(426)),
+    (0x4a7, Status::Valid), (0x4a8, Status::Mapped(427)), (0x4a9, Status::Valid),
+    (0x4aa, Status::Mapped(428)), (0x4ab, Status::Valid), (0x4ac, Status::Mapped(429)),
+    (0x4ad, Status::Valid), (0x4ae, Status::Mapped(430)), (0x4af, Status::Valid),
+    (0x4b0, Status::Mapped(431)), (0x4b1, Status::Valid), (0x4b2, Status::Mapped(432)),
+    (0x4b3, Status::Valid), (0x4b4, Status::Mapped(433)), (0x4b5, Status::Valid),
+    (0x4b6, Status::Mapped(434)), (0x4b7, Status::Valid), (0x4b8, Status::Mapped(435)),
+    (0x4b9, Status::Valid), (0x4ba, Status::Mapped(436)), (0x4bb, Status::Valid),
+    (0x4bc, Status::Mapped(437)), (0x4bd, Status::Valid), (0x4be, Status::Mapped(438)),
+    (0x4bf, Status::Valid), (0x4c0, Status::Disallowed), (0x4c1, Status::Mapped(439)),
+    (0x4c2, Status::Valid), (0x4c3, Status::Mapped(440)), (0x4c4, Status::Valid),
+    (0x4c5, Status::Mapped(441)), (0x4c6, Status::Valid), (0x4c7, Status::Mapped(442)),
+    (0x4c8, Status::Valid), (0x4c9, Status::Mapped(443)), (0x4ca, Status::Valid),
+    (0x4cb, Status::Mapped(444)), (0x4cc, Status::Valid), (0x4cd, Status::Mapped(445)),
+    (0x4ce, Status::Valid), (0x4d0, Status::Mapped(446)), (0x4d1, Status::Valid),
+    (0x4d2, Status::Mapped(447)), (0x4d3, Status::Valid), (0x4d4, Status::Mapped(448)),
+    (0x4d5, Status::Valid), (0x4d6, Status::Mapped(449)), (0x4d7, Status::Valid),
+    (0x4d8, Status::Mapped(450)), (0x4d9, Status::Valid), (0x4da, Status::Mapped(451)),
+    (0x4db, Status::Valid), (0x4dc, Status::Mapped(452)), (0x4dd, Status::Valid),
+    (0x4de, Status::Mapped(453)), (0x4df, Status::Valid), (0x4e0, Status::Mapped(454)),
+    (0x4e1, Status::Valid), (0x4e2, Status::Mapped(455)), (0x4e3, Status::Valid),
+    (0x4e4, Status::Mapped(456)), (0x4e5, Status::Valid), (0x4e6, Status::Mapped(457)),
+    (0x4e7, Status::Valid), (0x4e8, Status::Mapped(458)), (0x4e9, Status::Valid),
+    (0x4ea, Status::Mapped(459)), (0x4eb, Status::Valid), (0x4ec, Status::Mapped(460)),
+    (0x4ed, Status::Valid), (0x4ee, Status::Mapped(461)), (0x4ef, Status::Valid),
+    (0x4f0, Status::Mapped(462)), (0x4f1, Status::Valid), (0x4f2, Status::Mapped(463)),
+    (0x4f3, Status::Valid), (0x4f4, Status::Mapped(464)), (0x4f5, Status::Valid),
+    (0x4f6, Status::Mapped(465)), (0x4f7, Status::Valid), (0x4f8, Status::Mapped(466)),
+    (0x4f9, Status::Valid), (0x4fa, Status::Mapped(467)), (0x4fb, Status::Valid),
+    (0x4fc, Status::Mapped(468)), (0x4fd, Status::Valid), (0x4fe, Status::Mapped(469)),
+    (0x4ff, Status::Valid), (0x500, Status::Mapped(470)), (0x501, Status::Valid),
+    (0x502, Status::Mapped(471)), (0x503, Status::Valid), (0x504, Status::Mapped(472)),
+    (0x505, Status::Valid), (0x506, Status::Mapped(473)), (0x507, Status::Valid),
+    (0x508, Status::Mapped(474)), (0x509, Status::Valid), (0x50a, Status::Mapped(475)),
+    (0x50b, Status::Valid), (0x50c, Status::Mapped(476)), (0x50d, Status::Valid),
+    (0x50e, Status::Mapped(477)), (0x50f, Status::Valid), (0x510, Status::Mapped(478)),
+    (0x511, Status::Valid), (0x512, Status::Mapped(479)), (0x513, Status::Valid),
+    (0x514, Status::Mapped(480)), (0x515, Status::Valid), (0x516, Status::Mapped(481)),
+    (0x517, Status::Valid), (0x518, Status::Mapped(482)), (0x519, Status::Valid),
+    (0x51a, Status::Mapped(483)), (0x51b, Status::Valid), (0x51c, Status::Mapped(484)),
+    (0x51d, Status::Valid), (0x51e, Status::Mapped(485)), (0x51f, Status::Valid),
+    (0x520, Status::Mapped(486)), (0x521, Status::Valid), (0x522, Status::Mapped(487)),
+    (0x523, Status::Valid), (0x524, Status::Mapped(488)), (0x525, Status::Valid),
+    (0x526, Status::Mapped(489)), (0x527, Status::Valid), (0x528, Status::Mapped(490)),
+    (0x529, Status::Valid), (0x52a, Status::Mapped(491)), (0x52b, Status::Valid),
+    (0x52c, Status::Mapped(492)), (0x52d, Status::Valid), (0x52e, Status::Mapped(493)),
+    (0x52f, Status::Valid), (0x530, Status::Disallowed), (0x531, Status::Mapped(494)),
+    (0x557, Status::Disallowed), (0x559, Status::Valid), (0x587, Status::Mapped(532)),
+    (0x588, Status::Valid), (0x58b, Status::Disallowed), (0x58d, Status::Valid),
+    (0x590, Status::Disallowed), (0x591, Status::Valid), (0x5c8, Status::Disallowed),
+    (0x5d0, Status::Valid), (0x5eb, Status::Disallowed), (0x5ef, Status::Valid),
+    (0x5f5, Status::Disallowed), (0x606, Status::Valid), (0x61c, Status::Disallowed),
+    (0x61e, Status::Valid), (0x675, Status::Mapped(533)), (0x679, Status::Valid),
+    (0x6dd, Status::Disallowed), (0x6de, Status::Valid), (0x70e, Status::Disallowed),
+    (0x710, Status::Valid), (0x74b, Status::Disallowed), (0x74d, Status::Valid),
+    (0x7b2, Status::Disallowed), (0x7c0, Status::Valid), (0x7fb, Status::Disallowed),
+    (0x7fd, Status::Valid), (0x82e, Status::Disallowed), (0x830, Status::Valid),
+    (0x83f, Status::Disallowed), (0x840, Status::Valid), (0x85c, Status::Disallowed),
+    (0x85e, Status::Valid), (0x85f, Status::Disallowed), (0x860, Status::Valid),
+    (0x86b, Status::Disallowed), (0x8a0, Status::Valid), (0x8b5, Status::Disallowed),
+    (0x8b6, Status::Valid), (0x8c8, Status::Disallowed), (0x8d3, Status::Valid),
+    (0x8e2, Status::Disallowed), (0x8e3, Status::Valid), (0x958, Status::Mapped(537)),
+    (0x960, Status::Valid), (0x984, Status::Disallowed), (0x985, Status::Valid),
+    (0x98d, Status::Disallowed), (0x98f, Status::Valid), (0x991, Status::Disallowed),
+    (0x993, Status::Valid), (0x9a9, Status::Disallowed), (0x9aa, Status::Valid),
+    (0x9b1, Status::Disallowed), (0x9b2, Status::Valid), (0x9b3, Status::Disallowed),
+    (0x9b6, Status::Valid), (0x9ba, Status::Disallowed), (0x9bc, Status::Valid),
+    (0x9c5, Status::Disallowed), (0x9c7, Status::Valid), (0x9c9, Status::Disallowed),
+    (0x9cb, Status::Valid), (0x9cf, Status::Disallowed), (0x9d7, Status::Valid),
+    (0x9d8, Status::Disallowed), (0x9dc, Status::Mapped(545)), (0x9de, Status::Disallowed),
+    (0x9df, Status::Mapped(547)), (0x9e0, Status::Valid), (0x9e4, Status::Disallowed),
+    (0x9e6, Status::Valid), (0x9ff, Status::Disallowed), (0xa01, Status::Valid),
+    (0xa04, Status::Disallowed), (0xa05, Status::Valid), (0xa0b, Status::Disallowed),
+    (0xa0f, Status::Valid), (0xa11, Status::Disallowed), (0xa13, Status::Valid),
+    (0xa29, Status::Disallowed), (0xa2a, Status::Valid), (0xa31, Status::Disallowed),
+    (0xa32, Status::Valid), (0xa33, Status::Mapped(548)), (0xa34, Status::Disallowed),
+    (0xa35, Status::Valid), (0xa36, Status::Mapped(549)), (0xa37, Status::Disallowed),
+    (0xa38, Status::Valid), (0xa3a, Status::Disallowed), (0xa3c, Status::Valid),
+    (0xa3d, Status::Disallowed), (0xa3e, Status::Valid), (0xa43, Status::Disallowed),
+    (0xa47, Status::Valid), (0xa49, Status::Disallowed), (0xa4b, Status::Valid),
+    (0xa4e, Status::Disallowed), (0xa51, Status::Valid), (0xa52, Status::Disallowed),
+    (0xa59, Status::Mapped(550)), (0xa5c, Status::Valid), (0xa5d, Status::Disallowed),
+    (0xa5e, Status::Mapped(553)), (0xa5f, Status::Disallowed), (0xa66, Status::Valid),
+    (0xa77, Status::Disallowed), (0xa81, Status::Valid), (0xa84, Status::Disallowed),
+    (0xa85, Status::Valid), (0xa8e, Status::Disallowed), (0xa8f, Status::Valid),
+    (0xa92, Status::Disallowed), (0xa93, Status::Valid), (0xaa9, Status::Disallowed),
+    (0xaaa, Status::Valid), (0xab1, Status::Disallowed), (0xab2, Status::Valid),
+    (0xab4, Status::Disallowed), (0xab5, Status::Valid), (0xaba, Status::Disallowed),
+    (0xabc, Status::Valid), (0xac6, Status::Disallowed), (0xac7, Status::Valid),
+    (0xaca, Status::Disallowed), (0xacb, Status::Valid), (0xace, Status::Disallowed),
+    (0xad0, Status::Valid), (0xad1, Status::Disallowed), (0xae0, Status::Valid),
+    (0xae4, Status::Disallowed), (0xae6, Status::Valid), (0xaf2, Status::Disallowed),
+    (0xaf9, Status::Valid), (0xb00, Status::Disallowed), (0xb01, Status::Valid),
+    (0xb04, Status::Disallowed), (0xb05, Status::Valid), (0xb0d, Status::Disallowed),
+    (0xb0f, Status::Valid), (0xb11, Status::Disallowed), (0xb13, Status::Valid),
+    (0xb29, Status::Disallowed), (0xb2a, Status::Valid), (0xb31, Status::Disallowed),
+    (0xb32, Status::Valid), (0xb34, Status::Disallowed), (0xb35, Status::Valid),
+    (0xb3a, Status::Disallowed), (0xb3c, Status::Valid), (0xb45, Status::Disallowed),
+    (0xb47, Status::Valid), (0xb49, Status::Disallowed), (0xb4b, Status::Valid),
+    (0xb4e, Status::Disallowed), (0xb55, Status::Valid), (0xb58, Status::Disallowed),
+    (0xb5c, Status::Mapped(554)), (0xb5e, Status::Disallowed), (0xb5f, Status::Valid),
+    (0xb64, Status::Disallowed), (0xb66, Status::Valid), (0xb78, Status::Disallowed),
+    (0xb82, Status::Valid), (0xb84, Status::Disallowed), (0xb85, Status::Valid),
+    (0xb8b, Status::Disallowed), (0xb8e, Status::Valid), (0xb91, Status::Disallowed),
+    (0xb92, Status::Valid), (0xb96, Status::Disallowed), (0xb99, Status::Valid),
+    (0xb9b, Status::Disallowed), (0xb9c, Status::Valid), (0xb9d, Status::Disallowed),
+    (0xb9e, Status::Valid), (0xba0, Status::Disallowed), (0xba3, Status::Valid),
+    (0xba5, Status::Disallowed), (0xba8, Status::Valid), (0xbab, Status::Disallowed),
+    (0xbae, Status::Valid), (0xbba, Status::Disallowed), (0xbbe, Status::Valid),
+    (0xbc3, Status::Disallowed), (0xbc6, Status::Valid), (0xbc9, Status::Disallowed),
+    (0xbca, Status::Valid), (0xbce, Status::Disallowed), (0xbd0, Status::Valid),
+    (0xbd1, Status::Disallowed), (0xbd7, Status::Valid), (0xbd8, Status::Disallowed),
+    (0xbe6, Status::Valid), (0xbfb, Status::Disallowed), (0xc00, Status::Valid),
+    (0xc0d, Status::Disallowed), (0xc0e, Status::Valid), (0xc11, Status::Disallowed),
+    (0xc12, Status::Valid), (0xc29, Status::Disallowed), (0xc2a, Status::Valid),
+    (0xc3a, Status::Disallowed), (0xc3d, Status::Valid), (0xc45, Status::Disallowed),
+    (0xc46, Status::Valid), (0xc49, Status::Disallowed), (0xc4a, Status::Valid),
+    (0xc4e, Status::Disallowed), (0xc55, Status::Valid), (0xc57, Status::Disallowed),
+    (0xc58, Status::Valid), (0xc5b, Status::Disallowed), (0xc60, Status::Valid),
+    (0xc64, Status::Disallowed), (0xc66, Status::Valid), (0xc70, Status::Disallowed),
+    (0xc77, Status::Valid), (0xc8d, Status::Disallowed), (0xc8e, Status::Valid),
+    (0xc91, Status::Disallowed), (0xc92, Status::Valid), (0xca9, Status::Disallowed),
+    (0xcaa, Status::Valid), (0xcb4, Status::Disallowed), (0xcb5, Status::Valid),
+    (0xcba, Status::Disallowed), (0xcbc, Status::Valid), (0xcc5, Status::Disallowed),
+    (0xcc6, Status::Valid), (0xcc9, Status::Disallowed), (0xcca, Status::Valid),
+    (0xcce, Status::Disallowed), (0xcd5, Status::Valid), (0xcd7, Status::Disallowed),
+    (0xcde, Status::Valid), (0xcdf, Status::Disallowed), (0xce0, Status::Valid),
+    (0xce4, Status::Disallowed), (0xce6, Status::Valid), (0xcf0, Status::Disallowed),
+    (0xcf1, Status::Valid), (0xcf3, Status::Disallowed), (0xd00, Status::Valid),
+    (0xd0d, Status::Disallowed), (0xd0e, Status::Valid), (0xd11, Status::Disallowed),
+    (0xd12, Status::Valid), (0xd45, Status::Disallowed), (0xd46, Status::Valid),
+    (0xd49, Status::Disallowed), (0xd4a, Status::Valid), (0xd50, Status::Disallowed),
+    (0xd54, Status::Valid), (0xd64, Status::Disallowed), (0xd66, Status::Valid),
+    (0xd80, Status::Disallowed), (0xd81, Status::Valid), (0xd84, Status::Disallowed),
+    (0xd85, Status::Valid), (0xd97, Status::Disallowed), (0xd9a, Status::Valid),
+    (0xdb2, Status::Disallowed), (0xdb3, Status::Valid), (0xdbc, Status::Disallowed),
+    (0xdbd, Status::Valid), (0xdbe, Status::Disallowed), (0xdc0, Status::Valid),
+    (0xdc7, Status::Disallowed), (0xdca, Status::Valid), (0xdcb, Status::Disallowed),
+    (0xdcf, Status::Valid), (0xdd5, Status::Disallowed), (0xdd6, Status::Valid),
+    (0xdd7, Status::Disallowed), (0xdd8, Status::Valid), (0xde0, Status::Disallowed),
+    (0xde6, Status::Valid), (0xdf0, Status::Disallowed), (0xdf2, Status::Valid),
+    (0xdf5, Status::Disallowed), (0xe01, Status::Valid), (0xe33, Status::Mapped(556)),
+    (0xe34, Status::Valid), (0xe3b, Status::Disallowed), (0xe3f, Status::Valid),
+    (0xe5c, Status::Disallowed), (0xe81, Status::Valid), (0xe83, Status::Disallowed),
+    (0xe84, Status::Valid), (0xe85, Status::Disallowed), (0xe86, Status::Valid),
+    (0xe8b, Status::Disallowed), (0xe8c, Status::Valid), (0xea4, Status::Disallowed),
+    (0xea5, Status::Valid), (0xea6, Status::Disallowed), (0xea7, Status::Valid),
+    (0xeb3, Status::Mapped(557)), (0xeb4, Status::Valid), (0xebe, Status::Disallowed),
+    (0xec0, Status::Valid), (0xec5, Status::Disallowed), (0xec6, Status::Valid),
+    (0xec7, Status::Disallowed), (0xec8, Status::Valid), (0xece, Status::Disallowed),
+    (0xed0, Status::Valid), (0xeda, Status::Disallowed), (0xedc, Status::Mapped(558)),
+    (0xede, Status::Valid), (0xee0, Status::Disallowed), (0xf00, Status::Valid),
+    (0xf0c, Status::Mapped(560)), (0xf0d, Status::Valid), (0xf43, Status::Mapped(561)),
+    (0xf44, Status::Valid), (0xf48, Status::Disallowed), (0xf49, Status::Valid),
+    (0xf4d, Status::Mapped(562)), (0xf4e, Status::Valid), (0xf52, Status::Mapped(563)),
+    (0xf53, Status::Valid), (0xf57, Status::Mapped(564)), (0xf58, Status::Valid),
+    (0xf5c, Status::Mapped(565)), (0xf5d, Status::Valid), (0xf69, Status::Mapped(566)),
+    (0xf6a, Status::Valid), (0xf6d, Status::Disallowed), (0xf71, Status::Valid),
+    (0xf73, Status::Mapped(567)), (0xf74, Status::Valid), (0xf75, Status::Mapped(568)),
+    (0xf7a, Status::Valid), (0xf81, Status::Mapped(573)), (0xf82, Status::Valid),
+    (0xf93, Status::Mapped(574)), (0xf94, Status::Valid), (0xf98, Status::Disallowed),
+    (0xf99, Status::Valid), (0xf9d, Status::Mapped(575)), (0xf9e, Status::Valid),
+    (0xfa2, Status::Mapped(576)), (0xfa3, Status::Valid), (0xfa7, Status::Mapped(577)),
+    (0xfa8, Status::Valid), (0xfac, Status::Mapped(578)), (0xfad, Status::Valid),
+    (0xfb9, Status::Mapped(579)), (0xfba, Status::Valid), (0xfbd, Status::Disallowed),
+    (0xfbe, Status::Valid), (0xfcd, Status::Disallowed), (0xfce, Status::Valid),
+    (0xfdb, Status::Disallowed), (0x1000, Status::Valid), (0x10a0, Status::Disallowed),
+    (0x10c7, Status::Mapped(580)), (0x10c8, Status::Disallowed), (0x10cd, Status::Mapped(581)),
+    (0x10ce, Status::Disallowed), (0x10d0, Status::Valid), (0x10fc, Status::Mapped(582)),
+    (0x10fd, Status::Valid), (0x115f, Status::Disallowed), (0x1161, Status::Valid),
+    (0x1249, Status::Disallowed), (0x124a, Status::Valid), (0x124e, Status::Disallowed),
+    (0x1250, Status::Valid), (0x1257, Status::Disallowed), (0x1258, Status::Valid),
+    (0x1259, Status::Disallowed), (0x125a, Status::Valid), (0x125e, Status::Disallowed),
+    (0x1260, Status::Valid), (0x1289, Status::Disallowed), (0x128a, Status::Valid),
+    (0x128e, Status::Disallowed), (0x1290, Status::Valid), (0x12b1, Status::Disallowed),
+    (0x12b2, Status::Valid), (0x12b6, Status::Disallowed), (0x12b8, Status::Valid),
+    (0x12bf, Status::Disallowed), (0x12c0, Status::Valid), (0x12c1, Status::Disallowed),
+    (0x12c2, Status::Valid), (0x12c6, Status::Disallowed), (0x12c8, Status::Valid),
+    (0x12d7, Status::Disallowed), (0x12d8, Status::Valid), (0x1311, Status::Disallowed),
+    (0x1312, Status::Valid), (0x1316, Status::Disallowed), (0x1318, Status::Valid),
+    (0x135b, Status::Disallowed), (0x135d, Status::Valid), (0x137d, Status::Disallowed),
+    (0x1380, Status::Valid), (0x139a, Status::Disallowed), (0x13a0, Status::Valid),
+    (0x13f6, Status::Disallowed), (0x13f8, Status::Mapped(583)), (0x13fe, Status::Disallowed),
+    (0x1400, Status::Valid), (0x1680, Status::Disallowed), (0x1681, Status::Valid),
+    (0x169d, Status::Disallowed), (0x16a0, Status::Valid), (0x16f9, Status::Disallowed),
+    (0x1700, Status::Valid), (0x170d, Status::Disallowed), (0x170e, Status::Valid),
+    (0x1715, Status::Disallowed), (0x1720, Status::Valid), (0x1737, Status::Disallowed),
+    (0x1740, Status::Valid), (0x1754, Status::Disallowed), (0x1760, Status::Valid),
+    (0x176d, Status::Disallowed), (0x176e, Status::Valid), (0x1771, Status::Disallowed),
+    (0x1772, Status::Valid), (0x1774, Status::Disallowed), (0x1780, Status::Valid),
+    (0x17b4, Status::Disallowed), (0x17b6, Status::Valid), (0x17de, Status::Disallowed),
+    (0x17e0, Status::Valid), (0x17ea, Status::Disallowed), (0x17f0, Status::Valid),
+    (0x17fa, Status::Disallowed), (0x1800, Status::Valid), (0x1806, Status::Disallowed),
+    (0x1807, Status::Valid), (0x180b, Status::Ignored), (0x180e, Status::Disallowed),
+    (0x1810, Status::Valid), (0x181a, Status::Disallowed), (0x1820, Status::Valid),
+    (0x1879, Status::Disallowed), (0x1880, Status::Valid), (0x18ab, Status::Disallowed),
+    (0x18b0, Status::Valid), (0x18f6, Status::Disallowed), (0x1900, Status::Valid),
+    (0x191f, Status::Disallowed), (0x1920, Status::Valid), (0x192c, Status::Disallowed),
+    (0x1930, Status::Valid), (0x193c, Status::Disallowed), (0x1940, Status::Valid),
+    (0x1941, Status::Disallowed), (0x1944, Status::Valid), (0x196e, Status::Disallowed),
+    (0x1970, Status::Valid), (0x1975, Status::Disallowed), (0x1980, Status::Valid),
+    (0x19ac, Status::Disallowed), (0x19b0, Status::Valid), (0x19ca, Status::Disallowed),
+    (0x19d0, Status::Valid), (0x19db, Status::Disallowed), (0x19de, Status::Valid),
+    (0x1a1c, Status::Disallowed), (0x1a1e, Status::Valid), (0x1a5f, Status::Disallowed),
+    (0x1a60, Status::Valid), (0x1a7d, Status::Disallowed), (0x1a7f, Status::Valid),
+    (0x1a8a, Status::Disallowed), (0x1a90, Status::Valid), (0x1a9a, Status::Disallowed),
+    (0x1aa0, Status::Valid), (0x1aae, Status::Disallowed), (0x1ab0, Status::Valid),
+    (0x1ac1, Status::Disallowed), (0x1b00, Status::Valid), (0x1b4c, Status::Disallowed),
+    (0x1b50, Status::Valid), (0x1b7d, Status::Disallowed), (0x1b80, Status::Valid),
+    (0x1bf4, Status::Disallowed), (0x1bfc, Status::Valid), (0x1c38, Status::Disallowed),
+    (0x1c3b, Status::Valid), (0x1c4a, Status::Disallowed), (0x1c4d, Status::Valid),
+    (0x1c80, Status::Mapped(589)), (0x1c89, Status::Disallowed), (0x1c90, Status::Mapped(598)),
+    (0x1cbb, Status::Disallowed), (0x1cbd, Status::Mapped(641)), (0x1cc0, Status::Valid),
+    (0x1cc8, Status::Disallowed), (0x1cd0, Status::Valid), (0x1cfb, Status::Disallowed),
+    (0x1d00, Status::Valid), (0x1d2c, Status::Mapped(644)), (0x1d2f, Status::Valid),
+    (0x1d30, Status::Mapped(647)), (0x1d3b, Status::Valid), (0x1d3c, Status::Mapped(658)),
+    (0x1d4e, Status::Valid), (0x1d4f, Status::Mapped(676)), (0x1d6b, Status::Valid),
+    (0x1d78, Status::Mapped(704)), (0x1d79, Status::Valid), (0x1d9b, Status::Mapped(705)),
+    (0x1dc0, Status::Valid), (0x1dfa, Status::Disallowed), (0x1dfb, Status::Valid),
+    (0x1e00, Status::Mapped(742)), (0x1e01, Status::Valid), (0x1e02, Status::Mapped(743)),
+    (0x1e03, Status::Valid), (0x1e04, Status::Mapped(744)), (0x1e05, Status::Valid),
+    (0x1e06, Status::Mapped(745)), (0x1e07, Status::Valid), (0x1e08, Status::Mapped(746)),
+    (0x1e09, Status::Valid), (0x1e0a, Status::Mapped(747)), (0x1e0b, Status::Valid),
+    (0x1e0c, Status::Mapped(748)), (0x1e0d, Status::Valid), (0x1e0e, Status::Mapped(749)),
+    (0x1e0f, Status::Valid), (0x1e10, Status::Mapped(750)), (0x1e11, Status::Valid),
+    (0x1e12, Status::Mapped(751)), (0x1e13, Status::Valid), (0x1e14, Status::Mapped(752)),
+    (0x1e15, Status::Valid), (0x1e16, Status::Mapped(753)), (0x1e17, Status::Valid),
+    (0x1e18, Status::Mapped(754)), (0x1e19, Status::Valid), (0x1e1a, Status::Mapped(755)),
+    (0x1e1b, Status::Valid), (0x1e1c, Status::Mapped(756)), (0x1e1d, Status::Valid),
+    (0x1e1e, Status::Mapped(757)), (0x1e1f, Status::Valid), (0x1e20, Status::Mapped(758)),
+    (0x1e21, Status::Valid), (0x1e22, Status::Mapped(759)), (0x1e23, Status::Valid),
+    (0x1e24, Status::Mapped(760)), (0x1e25, Status::Valid), (0x1e26, Status::Mapped(761)),
+    (0x1e27, Status::Valid), (0x1e28, Status::Mapped(762)), (0x1e29, Status::Valid),
+    (0x1e2a, Status::Mapped(763)), (0x1e2b, Status::Valid), (0x1e2c, Status::Mapped(764)),
+    (0x1e2d, Status::Valid), (0x1e2e, Status::Mapped(765)), (0x1e2f, Status::Valid),
+    (0x1e30, Status::Mapped(766)), (0x1e31, Status::Valid), (0x1e32, Status::Mapped(767)),
+    (0x1e33, Status::Valid), (0x1e34, Status::Mapped(768)), (0x1e35, Status::Valid),
+    (0x1e36, Status::Mapped(769)), (0x1e37, Status::Valid), (0x1e38, Status::Mapped(770)),
+    (0x1e39, Status::Valid), (0x1e3a, Status::Mapped(771)), (0x1e3b, Status::Valid),
+    (0x1e3c, Status::Mapped(772)), (0x1e3d, Status::Valid), (0x1e3e, Status::Mapped(773)),
+    (0x1e3f, Status::Valid), (0x1e40, Status::Mapped(774)), (0x1e41, Status::Valid),
+    (0x1e42, Status::Mapped(775)), (0x1e43, Status::Valid), (0x1e44, Status::Mapped(776)),
+    (0x1e45, Status::Valid), (0x1e46, Status::Mapped(777)), (0x1e47, Status::Valid),
+    (0x1e48, Status::Mapped(778)), (0x1e49, Status::Valid), (0x1e4a, Status::Mapped(779)),
+    (0x1e4b, Status::Valid), (0x1e4c, Status::Mapped(780)), (0x1e4d, Status::Valid),
+    (0x1e4e, Status::Mapped(781)), (0x1e4f, Status::Valid), (0x1e50, Status::Mapped(782)),
+    (0x1e51, Status::Valid), (0x1e52, Status::Mapped(783)), (0x1e53, Status::Valid),
+    (0x1e54, Status::Mapped(784)), (0x1e55, Status::Valid), (0x1e56, Status::Mapped(785)),
+    (0x1e57, Status::Valid), (0x1e58, Status::Mapped(786)), (0x1e59, Status::Valid),
+    (0x1e5a, Status::Mapped(787)), (0x1e5b, Status::Valid), (0x1e5c, Status::Mapped(788)),
+    (0x1e5d, Status::Valid), (0x1e5e, Status::Mapped(789)), (0x1e5f, Status::Valid),
+    (0x1e60, Status::Mapped(790)), (0x1e61, Status::Valid), (0x1e62, Status::Mapped(791)),
+    (0x1e63, Status::Valid), (0x1e64, Status::Mapped(792)), (0x1e65, Status::Valid),
+    (0x1e66, Status::Mapped(793)), (0x1e67, Status::Valid), (0x1e68, Status::Mapped(794)),
+    (0x1e69, Status::Valid), (0x1e6a, Status::Mapped(795)), (0x1e6b, Status::Valid),
+    (0x1e6c, Status::Mapped(796)), (0x1e6d, Status::Valid), (0x1e6e, Status::Mapped(797)),
+    (0x1e6f, Status::Valid), (0x1e70, Status::Mapped(798)), (0x1e71, Status::Valid),
+    (0x1e72, Status::Mapped(799)), (0x1e73, Status::Valid), (0x1e74, Status::Mapped(800)),
+    (0x1e75, Status::Valid), (0x1e76, Status::Mapped(801)), (0x1e77, Status::Valid),
+    (0x1e78, Status::Mapped(802)), (0x1e79, Status::Valid), (0x1e7a, Status::Mapped(803)),
+    (0x1e7b, Status::Valid), (0x1e7c, Status::Mapped(804)), (0x1e7d, Status::Valid),
+    (0x1e7e, Status::Mapped(805)), (0x1e7f, Status::Valid), (0x1e80, Status::Mapped(806)),
+    (0x1e81, Status::Valid), (0x1e82, Status::Mapped(807)), (0x1e83, Status::Valid),
+    (0x1e84, Status::Mapped(808)), (0x1e85, Status::Valid), (0x1e86, Status::Mapped(809)),
+    (0x1e87, Status::Valid), (0x1e88, Status::Mapped(810)), (0x1e89, Status::Valid),
+    (0x1e8a, Status::Mapped(811)), (0x1e8b, Status::Valid), (0x1e8c, Status::Mapped(812)),
+    (0x1e8d, Status::Valid), (0x1e8e, Status::Mapped(813)), (0x1e8f, Status::Valid),
+    (0x1e90, Status::Mapped(814)), (0x1e91, Status::Valid), (0x1e92, Status::Mapped(815)),
+    (0x1e93, Status::Valid), (0x1e94, Status::Mapped(816)), (0x1e95, Status::Valid),
+    (0x1e9a, Status::Mapped(817)), (0x1e9c, Status::Valid), (0x1e9e, Status::Mapped(819)),
+    (0x1e9f, Status::Valid), (0x1ea0, Status::Mapped(820)), (0x1ea1, Status::Valid),
+    (0x1ea2, Status::Mapped(821)), (0x1ea3, Status::Valid), (0x1ea4, Status::Mapped(822)),
+    (0x1ea5, Status::Valid), (0x1ea6, Status::Mapped(823)), (0x1ea7, Status::Valid),
+    (0x1ea8, Status::Mapped(824)), (0x1ea9, Status::Valid), (0x1eaa, Status::Mapped(825)),
+    (0x1eab, Status::Valid), (0x1eac, Status::Mapped(826)), (0x1ead, Status::Valid),
+    (0x1eae, Status::Mapped(827)), (0x1eaf, Status::Valid), (0x1eb0, Status::Mapped(828)),
+    (0x1eb1, Status::Valid), (0x1eb2, Status::Mapped(829)), (0x1eb3, Status::Valid),
+    (0x1eb4, Status::Mapped(830)), (0x1eb5, Status::Valid), (0x1eb6, Status::Mapped(831)),
+    (0x1eb7, Status::Valid), (0x1eb8, Status::Mapped(832)), (0x1eb9, Status::Valid),
+    (0x1eba, Status::Mapped(833)), (0x1ebb, Status::Valid), (0x1ebc, Status::Mapped(834)),
+    (0x1ebd, Status::Valid), (0x1ebe, Status::Mapped(835)), (0x1ebf, Status::Valid),
+    (0x1ec0, Status::Mapped(836)), (0x1ec1, Status::Valid), (0x1ec2, Status::Mapped(837)),
+    (0x1ec3, Status::Valid), (0x1ec4, Status::Mapped(838)), (0x1ec5, Status::Valid),
+    (0x1ec6, Status::Mapped(839)), (0x1ec7, Status::Valid), (0x1ec8, Status::Mapped(840)),
+    (0x1ec9, Status::Valid), (0x1eca, Status::Mapped(841)), (0x1ecb, Status::Valid),
+    (0x1ecc, Status::Mapped(842)), (0x1ecd, Status::Valid), (0x1ece, Status::Mapped(843)),
+    (0x1ecf, Status::Valid), (0x1ed0, Status::Mapped(844)), (0x1ed1, Status::Valid),
+    (0x1ed2, Status::Mapped(845)), (0x1ed3, Status::Valid), (0x1ed4, Status::Mapped(846)),
+    (0x1ed5, Status::Valid), (0x1ed6, Status::Mapped(847)), (0x1ed7, Status::Valid),
+    (0x1ed8, Status::Mapped(848)), (0x1ed9, Status::Valid), (0x1eda, Status::Mapped(849)),
+    (0x1edb, Status::Valid), (0x1edc, Status::Mapped(850)), (0x1edd, Status::Valid),
+    (0x1ede, Status::Mapped(851)), (0x1edf, Status::Valid), (0x1ee0, Status::Mapped(852)),
+    (0x1ee1, Status::Valid), (0x1ee2, Status::Mapped(853)), (0x1ee3, Status::Valid),
+    (0x1ee4, Status::Mapped(854)), (0x1ee5, Status::Valid), (0x1ee6, Status::Mapped(855)),
+    (0x1ee7, Status::Valid), (0x1ee8, Status::Mapped(856)), (0x1ee9, Status::Valid),
+    (0x1eea, Status::Mapped(857)), (0x1eeb, Status::Valid), (0x1eec, Status::Mapped(858)),
+    (0x1eed, Status::Valid), (0x1eee, Status::Mapped(859)), (0x1eef, Status::Valid),
+    (0x1ef0, Status::Mapped(860)), (0x1ef1, Status::Valid), (0x1ef2, Status::Mapped(861)),
+    (0x1ef3, Status::Valid), (0x1ef4, Status::Mapped(862)), (0x1ef5, Status::Valid),
+    (0x1ef6, Status::Mapped(863)), (0x1ef7, Status::Valid), (0x1ef8, Status::Mapped(864)),
+    (0x1ef9, Status::Valid), (0x1efa, Status::Mapped(865)), (0x1efb, Status::Valid),
+    (0x1efc, Status::Mapped(866)), (0x1efd, Status::Valid), (0x1efe, Status::Mapped(867)),
+    (0x1eff, Status::Valid), (0x1f08, Status::Mapped(868)), (0x1f10, Status::Valid),
+    (0x1f16, Status::Disallowed), (0x1f18, Status::Mapped(876)), (0x1f1e, Status::Disallowed),
+    (0x1f20, Status::Valid), (0x1f28, Status::Mapped(882)), (0x1f30, Status::Valid),
+    (0x1f38, Status::Mapped(890)), (0x1f40, Status::Valid), (0x1f46, Status::Disallowed),
+    (0x1f48, Status::Mapped(898)), (0x1f4e, Status::Disallowed), (0x1f50, Status::Valid),
+    (0x1f58, Status::Disallowed), (0x1f59, Status::Mapped(904)), (0x1f5a, Status::Disallowed),
+    (0x1f5b, Status::Mapped(905)), (0x1f5c, Status::Disallowed), (0x1f5d, Status::Mapped(906)),
+    (0x1f5e, Status::Disallowed), (0x1f5f, Status::Mapped(907)), (0x1f60, Status::Valid),
+    (0x1f68, Status::Mapped(908)), (0x1f70, Status::Valid), (0x1f71, Status::Mapped(916)),
+    (0x1f72, Status::Valid), (0x1f73, Status::Mapped(917)), (0x1f74, Status::Valid),
+    (0x1f75, Status::Mapped(918)), (0x1f76, Status::Valid), (0x1f77, Status::Mapped(919)),
+    (0x1f78, Status::Valid), (0x1f79, Status::Mapped(920)), (0x1f7a, Status::Valid),
+    (0x1f7b, Status::Mapped(921)), (0x1f7c, Status::Valid), (0x1f7d, Status::Mapped(922)),
+    (0x1f7e, Status::Disallowed), (0x1f80, Status::Mapped(923)), (0x1fb0, Status::Valid),
+    (0x1fb2, Status::Mapped(971)), (0x1fb5, Status::Disallowed), (0x1fb6, Status::Valid),
+    (0x1fb7, Status::Mapped(974)), (0x1fbd, Status::DisallowedStd3Mapped(980)),
+    (0x1fbe, Status::Mapped(981)), (0x1fbf, Status::DisallowedStd3Mapped(982)),
+    (0x1fc2, Status::Mapped(985)), (0x1fc5, Status::Disallowed), (0x1fc6, Status::Valid),
+    (0x1fc7, Status::Mapped(988)), (0x1fcd, Status::DisallowedStd3Mapped(994)),
+    (0x1fd0, Status::Valid), (0x1fd3, Status::Mapped(997)), (0x1fd4, Status::Disallowed),
+    (0x1fd6, Status::Valid), (0x1fd8, Status::Mapped(998)), (0x1fdc, Status::Disallowed),
+    (0x1fdd, Status::DisallowedStd3Mapped(1002)), (0x1fe0, Status::Valid),
+    (0x1fe3, Status::Mapped(1005)), (0x1fe4, Status::Valid), (0x1fe8, Status::Mapped(1006)),
+    (0x1fed, Status::DisallowedStd3Mapped(1011)), (0x1ff0, Status::Disallowed),
+    (0x1ff2, Status::Mapped(1014)), (0x1ff5, Status::Disallowed), (0x1ff6, Status::Valid),
+    (0x1ff7, Status::Mapped(1017)), (0x1ffd, Status::DisallowedStd3Mapped(1023)),
+    (0x1fff, Status::Disallowed), (0x2000, Status::DisallowedStd3Mapped(1025)),
+    (0x200b, Status::Ignored), (0x200c, Status::Valid), (0x200e, Status::Disallowed),
+    (0x2010, Status::Valid), (0x2011, Status::Mapped(1036)), (0x2012, Status::Valid),
+    (0x2017, Status::DisallowedStd3Mapped(1037)), (0x2018, Status::Valid),
+    (0x2024, Status::Disallowed), (0x2027, Status::Valid), (0x2028, Status::Disallowed),
+    (0x202f, Status::DisallowedStd3Mapped(1038)), (0x2030, Status::Valid),
+    (0x2033, Status::Mapped(1039)), (0x2035, Status::Valid), (0x2036, Status::Mapped(1041)),
+    (0x2038, Status::Valid), (0x203c, Status::DisallowedStd3Mapped(1043)), (0x203d, Status::Valid),
+    (0x203e, Status::DisallowedStd3Mapped(1044)), (0x203f, Status::Valid),
+    (0x2047, Status::DisallowedStd3Mapped(1045)), (0x204a, Status::Valid),
+    (0x2057, Status::Mapped(1048)), (0x2058, Status::Valid),
+    (0x205f, Status::DisallowedStd3Mapped(1049)), (0x2060, Status::Ignored),
+    (0x2061, Status::Disallowed), (0x2064, Status::Ignored), (0x2065, Status::Disallowed),
+    (0x2070, Status::Mapped(1050)), (0x2072, Status::Disallowed), (0x2074, Status::Mapped(1052)),
+    (0x207a, Status::DisallowedStd3Mapped(1058)), (0x207b, Status::Mapped(1059)),
+    (0x207c, Status::DisallowedStd3Mapped(1060)), (0x207f, Status::Mapped(1063)),
+    (0x208a, Status::DisallowedStd3Mapped(1074)), (0x208b, Status::Mapped(1075)),
+    (0x208c, Status::DisallowedStd3Mapped(1076)), (0x208f, Status::Disallowed),
+    (0x2090, Status::Mapped(1079)), (0x209d, Status::Disallowed), (0x20a0, Status::Valid),
+    (0x20a8, Status::Mapped(1092)), (0x20a9, Status::Valid), (0x20c0, Status::Disallowed),
+    (0x20d0, Status::Valid), (0x20f1, Status::Disallowed),
+    (0x2100, Status::DisallowedStd3Mapped(1093)), (0x2102, Status::Mapped(1095)),
+    (0x2104, Status::Valid), (0x2105, Status::DisallowedStd3Mapped(1097)),
+    (0x2107, Status::Mapped(1099)), (0x2108, Status::Valid), (0x2109, Status::Mapped(1100)),
+    (0x2114, Status::Valid), (0x2115, Status::Mapped(1111)), (0x2117, Status::Valid),
+    (0x2119, Status::Mapped(1113)), (0x211e, Status::Valid), (0x2120, Status::Mapped(1118)),
+    (0x2123, Status::Valid), (0x2124, Status::Mapped(1121)), (0x2125, Status::Valid),
+    (0x2126, Status::Mapped(1122)), (0x2127, Status::Valid), (0x2128, Status::Mapped(1123)),
+    (0x2129, Status::Valid), (0x212a, Status::Mapped(1124)), (0x212e, Status::Valid),
+    (0x212f, Status::Mapped(1128)), (0x2132, Status::Disallowed), (0x2133, Status::Mapped(1131)),
+    (0x213a, Status::Valid), (0x213b, Status::Mapped(1138)), (0x2141, Status::Valid),
+    (0x2145, Status::Mapped(1144)), (0x214a, Status::Valid), (0x2150, Status::Mapped(1149)),
+    (0x2180, Status::Valid), (0x2183, Status::Disallowed), (0x2184, Status::Valid),
+    (0x2189, Status::Mapped(1197)), (0x218a, Status::Valid), (0x218c, Status::Disallowed),
+    (0x2190, Status::Valid), (0x222c, Status::Mapped(1198)), (0x222e, Status::Valid),
+    (0x222f, Status::Mapped(1200)), (0x2231, Status::Valid), (0x2260, Status::DisallowedStd3Valid),
+    (0x2261, Status::Valid), (0x226e, Status::DisallowedStd3Valid), (0x2270, Status::Valid),
+    (0x2329, Status::Mapped(1202)), (0x232b, Status::Valid), (0x2427, Status::Disallowed),
+    (0x2440, Status::Valid), (0x244b, Status::Disallowed), (0x2460, Status::Mapped(1204)),
+    (0x2474, Status::DisallowedStd3Mapped(1224)), (0x2488, Status::Disallowed),
+    (0x249c, Status::DisallowedStd3Mapped(1244)), (0x24b6, Status::Mapped(1270)),
+    (0x24eb, Status::Valid), (0x2a0c, Status::Mapped(1323)), (0x2a0d, Status::Valid),
+    (0x2a74, Status::DisallowedStd3Mapped(1324)), (0x2a77, Status::Valid),
+    (0x2adc, Status::Mapped(1327)), (0x2add, Status::Valid), (0x2b74, Status::Disallowed),
+    (0x2b76, Status::Valid), (0x2b96, Status::Disallowed), (0x2b97, Status::Valid),
+    (0x2c00, Status::Mapped(1328)), (0x2c2f, Status::Disallowed), (0x2c30, Status::Valid),
+    (0x2c5f, Status::Disallowed), (0x2c60, Status::Mapped(1375)), (0x2c61, Status::Valid),
+    (0x2c62, Status::Mapped(1376)), (0x2c65, Status::Valid), (0x2c67, Status::Mapped(1379)),
+    (0x2c68, Status::Valid), (0x2c69, Status::Mapped(1380)), (0x2c6a, Status::Valid),
+    (0x2c6b, Status::Mapped(1381)), (0x2c6c, Status::Valid), (0x2c6d, Status::Mapped(1382)),
+    (0x2c71, Status::Valid), (0x2c72, Status::Mapped(1386)), (0x2c73, Status::Valid),
+    (0x2c75, Status::Mapped(1387)), (0x2c76, Status::Valid), (0x2c7c, Status::Mapped(1388)),
+    (0x2c81, Status::Valid), (0x2c82, Status::Mapped(1393)), (0x2c83, Status::Valid),
+    (0x2c84, Status::Mapped(1394)), (0x2c85, Status::Valid), (0x2c86, Status::Mapped(1395)),
+    (0x2c87, Status::Valid), (0x2c88, Status::Mapped(1396)), (0x2c89, Status::Valid),
+    (0x2c8a, Status::Mapped(1397)), (0x2c8b, Status::Valid), (0x2c8c, Status::Mapped(1398)),
+    (0x2c8d, Status::Valid), (0x2c8e, Status::Mapped(1399)), (0x2c8f, Status::Valid),
+    (0x2c90, Status::Mapped(1400)), (0x2c91, Status::Valid), (0x2c92, Status::Mapped(1401)),
+    (0x2c93, Status::Valid), (0x2c94, Status::Mapped(1402)), (0x2c95, Status::Valid),
+    (0x2c96, Status::Mapped(1403)), (0x2c97, Status::Valid), (0x2c98, Status::Mapped(1404)),
+    (0x2c99, Status::Valid), (0x2c9a, Status::Mapped(1405)), (0x2c9b, Status::Valid),
+    (0x2c9c, Status::Mapped(1406)), (0x2c9d, Status::Valid), (0x2c9e, Status::Mapped(1407)),
+    (0x2c9f, Status::Valid), (0x2ca0, Status::Mapped(1408)), (0x2ca1, Status::Valid),
+    (0x2ca2, Status::Mapped(1409)), (0x2ca3, Status::Valid), (0x2ca4, Status::Mapped(1410)),
+    (0x2ca5, Status::Valid), (0x2ca6, Status::Mapped(1411)), (0x2ca7, Status::Valid),
+    (0x2ca8, Status::Mapped(1412)), (0x2ca9, Status::Valid), (0x2caa, Status::Mapped(1413)),
+    (0x2cab, Status::Valid), (0x2cac, Status::Mapped(1414)), (0x2cad, Status::Valid),
+    (0x2cae, Status::Mapped(1415)), (0x2caf, Status::Valid), (0x2cb0, Status::Mapped(1416)),
+    (0x2cb1, Status::Valid), (0x2cb2, Status::Mapped(1417)), (0x2cb3, Status::Valid),
+    (0x2cb4, Status::Mapped(1418)), (0x2cb5, Status::Valid), (0x2cb6, Status::Mapped(1419)),
+    (0x2cb7, Status::Valid), (0x2cb8, Status::Mapped(1420)), (0x2cb9, Status::Valid),
+    (0x2cba, Status::Mapped(1421)), (0x2cbb, Status::Valid), (0x2cbc, Status::Mapped(1422)),
+    (0x2cbd, Status::Valid), (0x2cbe, Status::Mapped(1423)), (0x2cbf, Status::Valid),
+    (0x2cc0, Status::Mapped(1424)), (0x2cc1, Status::Valid), (0x2cc2, Status::Mapped(1425)),
+    (0x2cc3, Status::Valid), (0x2cc4, Status::Mapped(1426)), (0x2cc5, Status::Valid),
+    (0x2cc6, Status::Mapped(1427)), (0x2cc7, Status::Valid), (0x2cc8, Status::Mapped(1428)),
+    (0x2cc9, Status::Valid), (0x2cca, Status::Mapped(1429)), (0x2ccb, Status::Valid),
+    (0x2ccc, Status::Mapped(1430)), (0x2ccd, Status::Valid), (0x2cce, Status::Mapped(1431)),
+    (0x2ccf, Status::Valid), (0x2cd0, Status::Mapped(1432)), (0x2cd1, Status::Valid),
+    (0x2cd2, Status::Mapped(1433)), (0x2cd3, Status::Valid), (0x2cd4, Status::Mapped(1434)),
+    (0x2cd5, Status::Valid), (0x2cd6, Status::Mapped(1435)), (0x2cd7, Status::Valid),
+    (0x2cd8, Status::Mapped(1436)), (0x2cd9, Status::Valid), (0x2cda, Status::Mapped(1437)),
+    (0x2cdb, Status::Valid), (0x2cdc, Status::Mapped(1438)), (0x2cdd, Status::Valid),
+    (0x2cde, Status::Mapped(1439)), (0x2cdf, Status::Valid), (0x2ce0, Status::Mapped(1440)),
+    (0x2ce1, Status::Valid), (0x2ce2, Status::Mapped(1441)), (0x2ce3, Status::Valid),
+    (0x2ceb, Status::Mapped(1442)), (0x2cec, Status::Valid), (0x2ced, Status::Mapped(1443)),
+    (0x2cee, Status::Valid), (0x2cf2, Status::Mapped(1444)), (0x2cf3, Status::Valid),
+    (0x2cf4, Status::Disallowed), (0x2cf9, Status::Valid), (0x2d26, Status::Disallowed),
+    (0x2d27, Status::Valid), (0x2d28, Status::Disallowed), (0x2d2d, Status::Valid),
+    (0x2d2e, Status::Disallowed), (0x2d30, Status::Valid), (0x2d68, Status::Disallowed),
+    (0x2d6f, Status::Mapped(1445)), (0x2d70, Status::Valid), (0x2d71, Status::Disallowed),
+    (0x2d7f, Status::Valid), (0x2d97, Status::Disallowed), (0x2da0, Status::Valid),
+    (0x2da7, Status::Disallowed), (0x2da8, Status::Valid), (0x2daf, Status::Disallowed),
+    (0x2db0, Status::Valid), (0x2db7, Status::Disallowed), (0x2db8, Status::Valid),
+    (0x2dbf, Status::Disallowed), (0x2dc0, Status::Valid), (0x2dc7, Status::Disallowed),
+    (0x2dc8, Status::Valid), (0x2dcf, Status::Disallowed), (0x2dd0, Status::Valid),
+    (0x2dd7, Status::Disallowed), (0x2dd8, Status::Valid), (0x2ddf, Status::Disallowed),
+    (0x2de0, Status::Valid), (0x2e53, Status::Disallowed), (0x2e80, Status::Valid),
+    (0x2e9a, Status::Disallowed), (0x2e9b, Status::Valid), (0x2e9f, Status::Mapped(1446)),
+    (0x2ea0, Status::Valid), (0x2ef3, Status::Mapped(1447)), (0x2ef4, Status::Disallowed),
+    (0x2f00, Status::Mapped(1448)), (0x2fd6, Status::Disallowed),
+    (0x3000, Status::DisallowedStd3Mapped(1662)), (0x3001, Status::Valid),
+    (0x3002, Status::Mapped(1663)), (0x3003, Status::Valid), (0x3036, Status::Mapped(1664)),
+    (0x3037, Status::Valid), (0x3038, Status::Mapped(1665)), (0x303b, Status::Valid),
+    (0x3040, Status::Disallowed), (0x3041, Status::Valid), (0x3097, Status::Disallowed),
+    (0x3099, Status::Valid), (0x309b, Status::DisallowedStd3Mapped(1668)), (0x309d, Status::Valid),
+    (0x309f, Status::Mapped(1670)), (0x30a0, Status::Valid), (0x30ff, Status::Mapped(1671)),
+    (0x3100, Status::Disallowed), (0x3105, Status::Valid), (0x3130, Status::Disallowed),
+    (0x3131, Status::Mapped(1672)), (0x3164, Status::Disallowed), (0x3165, Status::Mapped(1723)),
+    (0x318f, Status::Disallowed), (0x3190, Status::Valid), (0x3192, Status::Mapped(1765)),
+    (0x31a0, Status::Valid), (0x31e4, Status::Disallowed), (0x31f0, Status::Valid),
+    (0x3200, Status::DisallowedStd3Mapped(1779)), (0x321f, Status::Disallowed),
+    (0x3220, Status::DisallowedStd3Mapped(1810)), (0x3244, Status::Mapped(1846)),
+    (0x3248, Status::Valid), (0x3250, Status::Mapped(1850)), (0x327f, Status::Valid),
+    (0x3280, Status::Mapped(1897)), (0x33c2, Status::Disallowed), (0x33c3, Status::Mapped(2219)),
+    (0x33c7, Status::Disallowed), (0x33c8, Status::Mapped(2223)), (0x33d8, Status::Disallowed),
+    (0x33d9, Status::Mapped(2239)), (0x3400, Status::Valid), (0x9ffd, Status::Disallowed),
+    (0xa000, Status::Valid), (0xa48d, Status::Disallowed), (0xa490, Status::Valid),
+    (0xa4c7, Status::Disallowed), (0xa4d0, Status::Valid), (0xa62c, Status::Disallowed),
+    (0xa640, Status::Mapped(2278)), (0xa641, Status::Valid), (0xa642, Status::Mapped(2279)),
+    (0xa643, Status::Valid), (0xa644, Status::Mapped(2280)), (0xa645, Status::Valid),
+    (0xa646, Status::Mapped(2281)), (0xa647, Status::Valid), (0xa648, Status::Mapped(2282)),
+    (0xa649, Status::Valid), (0xa64a, Status::Mapped(2283)), (0xa64b, Status::Valid),
+    (0xa64c, Status::Mapped(2284)), (0xa64d, Status::Valid), (0xa64e, Status::Mapped(2285)),
+    (0xa64f, Status::Valid), (0xa650, Status::Mapped(2286)), (0xa651, Status::Valid),
+    (0xa652, Status::Mapped(2287)), (0xa653, Status::Valid), (0xa654, Status::Mapped(2288)),
+    (0xa655, Status::Valid), (0xa656, Status::Mapped(2289)), (0xa657, Status::Valid),
+    (0xa658, Status::Mapped(2290)), (0xa659, Status::Valid), (0xa65a, Status::Mapped(2291)),
+    (0xa65b, Status::Valid), (0xa65c, Status::Mapped(2292)), (0xa65d, Status::Valid),
+    (0xa65e, Status::Mapped(2293)), (0xa65f, Status::Valid), (0xa660, Status::Mapped(2294)),
+    (0xa661, Status::Valid), (0xa662, Status::Mapped(2295)), (0xa663, Status::Valid),
+    (0xa664, Status::Mapped(2296)), (0xa665, Status::Valid), (0xa666, Status::Mapped(2297)),
+    (0xa667, Status::Valid), (0xa668, Status::Mapped(2298)), (0xa669, Status::Valid),
+    (0xa66a, Status::Mapped(2299)), (0xa66b, Status::Valid), (0xa66c, Status::Mapped(2300)),
+    (0xa66d, Status::Valid), (0xa680, Status::Mapped(2301)), (0xa681, Status::Valid),
+    (0xa682, Status::Mapped(2302)), (0xa683, Status::Valid), (0xa684, Status::Mapped(2303)),
+    (0xa685, Status::Valid), (0xa686, Status::Mapped(2304)), (0xa687, Status::Valid),
+    (0xa688, Status::Mapped(2305)), (0xa689, Status::Valid), (0xa68a, Status::Mapped(2306)),
+    (0xa68b, Status::Valid), (0xa68c, Status::Mapped(2307)), (0xa68d, Status::Valid),
+    (0xa68e, Status::Mapped(2308)), (0xa68f, Status::Valid), (0xa690, Status::Mapped(2309)),
+    (0xa691, Status::Valid), (0xa692, Status::Mapped(2310)), (0xa693, Status::Valid),
+    (0xa694, Status::Mapped(2311)), (0xa695, Status::Valid), (0xa696, Status::Mapped(2312)),
+    (0xa697, Status::Valid), (0xa698, Status::Mapped(2313)), (0xa699, Status::Valid),
+    (0xa69a, Status::Mapped(2314)), (0xa69b, Status::Valid), (0xa69c, Status::Mapped(2315)),
+    (0xa69e, Status::Valid), (0xa6f8, Status::Disallowed), (0xa700, Status::Valid),
+    (0xa722, Status::Mapped(2317)), (0xa723, Status::Valid), (0xa724, Status::Mapped(2318)),
+    (0xa725, Status::Valid), (0xa726, Status::Mapped(2319)), (0xa727, Status::Valid),
+    (0xa728, Status::Mapped(2320)), (0xa729, Status::Valid), (0xa72a, Status::Mapped(2321)),
+    (0xa72b, Status::Valid), (0xa72c, Status::Mapped(2322)), (0xa72d, Status::Valid),
+    (0xa72e, Status::Mapped(2323)), (0xa72f, Status::Valid), (0xa732, Status::Mapped(2324)),
+    (0xa733, Status::Valid), (0xa734, Status::Mapped(2325)), (0xa735, Status::Valid),
+    (0xa736, Status::Mapped(2326)), (0xa737, Status::Valid), (0xa738, Status::Mapped(2327)),
+    (0xa739, Status::Valid), (0xa73a, Status::Mapped(2328)), (0xa73b, Status::Valid),
+    (0xa73c, Status::Mapped(2329)), (0xa73d, Status::Valid), (0xa73e, Status::Mapped(2330)),
+    (0xa73f, Status::Valid), (0xa740, Status::Mapped(2331)), (0xa741, Status::Valid),
+    (0xa742, Status::Mapped(2332)), (0xa743, Status::Valid), (0xa744, Status::Mapped(2333)),
+    (0xa745, Status::Valid), (0xa746, Status::Mapped(2334)), (0xa747, Status::Valid),
+    (0xa748, Status::Mapped(2335)), (0xa749, Status::Valid), (0xa74a, Status::Mapped(2336)),
+    (0xa74b, Status::Valid), (0xa74c, Status::Mapped(2337)), (0xa74d, Status::Valid),
+    (0xa74e, Status::Mapped(2338)), (0xa74f, Status::Valid), (0xa750, Status::Mapped(2339)),
+    (0xa751, Status::Valid), (0xa752, Status::Mapped(2340)), (0xa753, Status::Valid),
+    (0xa754, Status::Mapped(2341)), (0xa755, Status::Valid), (0xa756, Status::Mapped(2342)),
+    (0xa757, Status::Valid), (0xa758, Status::Mapped(2343)), (0xa759, Status::Valid),
+    (0xa75a, Status::Mapped(2344)), (0xa75b, Status::Valid), (0xa75c, Status::Mapped(2345)),
+    (0xa75d, Status::Valid), (0xa75e, Status::Mapped(2346)), (0xa75f, Status::Valid),
+    (0xa760, Status::Mapped(2347)), (0xa761, Status::Valid), (0xa762, Status::Mapped(2348)),
+    (0xa763, Status::Valid), (0xa764, Status::Mapped(2349)), (0xa765, Status::Valid),
+    (0xa766, Status::Mapped(2350)), (0xa767, Status::Valid), (0xa768, Status::Mapped(2351)),
+    (0xa769, Status::Valid), (0xa76a, Status::Mapped(2352)), (0xa76b, Status::Valid),
+    (0xa76c, Status::Mapped(2353)), (0xa76d, Status::Valid), (0xa76e, Status::Mapped(2354)),
+    (0xa76f, Status::Valid), (0xa770, Status::Mapped(2355)), (0xa771, Status::Valid),
+    (0xa779, Status::Mapped(2356)), (0xa77a, Status::Valid), (0xa77b, Status::Mapped(2357)),
+    (0xa77c, Status::Valid), (0xa77d, Status::Mapped(2358)), (0xa77f, Status::Valid),
+    (0xa780, Status::Mapped(2360)), (0xa781, Status::Valid), (0xa782, Status::Mapped(2361)),
+    (0xa783, Status::Valid), (0xa784, Status::Mapped(2362)), (0xa785, Status::Valid),
+    (0xa786, Status::Mapped(2363)), (0xa787, Status::Valid), (0xa78b, Status::Mapped(2364)),
+    (0xa78c, Status::Valid), (0xa78d, Status::Mapped(2365)), (0xa78e, Status::Valid),
+    (0xa790, Status::Mapped(2366)), (0xa791, Status::Valid), (0xa792, Status::Mapped(2367)),
+    (0xa793, Status::Valid), (0xa796, Status::Mapped(2368)), (0xa797, Status::Valid),
+    (0xa798, Status::Mapped(2369)), (0xa799, Status::Valid), (0xa79a, Status::Mapped(2370)),
+    (0xa79b, Status::Valid), (0xa79c, Status::Mapped(2371)), (0xa79d, Status::Valid),
+    (0xa79e, Status::Mapped(2372)), (0xa79f, Status::Valid), (0xa7a0, Status::Mapped(2373)),
+    (0xa7a1, Status::Valid), (0xa7a2, Status::Mapped(2374)), (0xa7a3, Status::Valid),
+    (0xa7a4, Status::Mapped(2375)), (0xa7a5, Status::Valid), (0xa7a6, Status::Mapped(2376)),
+    (0xa7a7, Status::Valid), (0xa7a8, Status::Mapped(2377)), (0xa7a9, Status::Valid),
+    (0xa7aa, Status::Mapped(2378)), (0xa7af, Status::Valid), (0xa7b0, Status::Mapped(2383)),
+    (0xa7b5, Status::Valid), (0xa7b6, Status::Mapped(2388)), (0xa7b7, Status::Valid),
+    (0xa7b8, Status::Mapped(2389)), (0xa7b9, Status::Valid), (0xa7ba, Status::Mapped(2390)),
+    (0xa7bb, Status::Valid), (0xa7bc, Status::Mapped(2391)), (0xa7bd, Status::Valid),
+    (0xa7be, Status::Mapped(2392)), (0xa7bf, Status::Valid), (0xa7c0, Status::Disallowed),
+    (0xa7c2, Status::Mapped(2393)), (0xa7c3, Status::Valid), (0xa7c4, Status::Mapped(2394)),
+    (0xa7c8, Status::Valid), (0xa7c9, Status::Mapped(2398)), (0xa7ca, Status::Valid),
+    (0xa7cb, Status::Disallowed), (0xa7f5, Status::Mapped(2399)), (0xa7f6, Status::Valid),
+    (0xa7f8, Status::Mapped(2400)), (0xa7fa, Status::Valid), (0xa82d, Status::Disallowed),
+    (0xa830, Status::Valid), (0xa83a, Status::Disallowed), (0xa840, Status::Valid),
+    (0xa878, Status::Disallowed), (0xa880, Status::Valid), (0xa8c6, Status::Disallowed),
+    (0xa8ce, Status::Valid), (0xa8da, Status::Disallowed), (0xa8e0, Status::Valid),
+    (0xa954, Status::Disallowed), (0xa95f, Status::Valid), (0xa97d, Status::Disallowed),
+    (0xa980, Status::Valid), (0xa9ce, Status::Disallowed), (0xa9cf, Status::Valid),
+    (0xa9da, Status::Disallowed), (0xa9de, Status::Valid), (0xa9ff, Status::Disallowed),
+    (0xaa00, Status::Valid), (0xaa37, Status::Disallowed), (0xaa40, Status::Valid),
+    (0xaa4e, Status::Disallowed), (0xaa50, Status::Valid), (0xaa5a, Status::Disallowed),
+    (0xaa5c, Status::Valid), (0xaac3, Status::Disallowed), (0xaadb, Status::Valid),
+    (0xaaf7, Status::Disallowed), (0xab01, Status::Valid), (0xab07, Status::Disallowed),
+    (0xab09, Status::Valid), (0xab0f, Status::Disallowed), (0xab11, Status::Valid),
+    (0xab17, Status::Disallowed), (0xab20, Status::Valid), (0xab27, Status::Disallowed),
+    (0xab28, Status::Valid), (0xab2f, Status::Disallowed), (0xab30, Status::Valid),
+    (0xab5c, Status::Mapped(2402)), (0xab60, Status::Valid), (0xab69, Status::Mapped(2406)),
+    (0xab6a, Status::Valid), (0xab6c, Status::Disallowed), (0xab70, Status::Mapped(2407)),
+    (0xabc0, Status::Valid), (0xabee, Status::Disallowed), (0xabf0, Status::Valid),
+    (0xabfa, Status::Disallowed), (0xac00, Status::Valid), (0xd7a4, Status::Disallowed),
+    (0xd7b0, Status::Valid), (0xd7c7, Status::Disallowed), (0xd7cb, Status::Valid),
+    (0xd7fc, Status::Disallowed), (0xf900, Status::Mapped(2487)), (0xfa0e, Status::Valid),
+    (0xfa10, Status::Mapped(2757)), (0xfa11, Status::Valid), (0xfa12, Status::Mapped(2758)),
+    (0xfa13, Status::Valid), (0xfa15, Status::Mapped(2759)), (0xfa1f, Status::Valid),
+    (0xfa20, Status::Mapped(2769)), (0xfa21, Status::Valid), (0xfa22, Status::Mapped(2770)),
+    (0xfa23, Status::Valid), (0xfa25, Status::Mapped(2771)), (0xfa27, Status::Valid),
+    (0xfa2a, Status::Mapped(2773)), (0xfa6e, Status::Disallowed), (0xfa70, Status::Mapped(2841)),
+    (0xfada, Status::Disallowed), (0xfb00, Status::Mapped(2947)), (0xfb07, Status::Disallowed),
+    (0xfb13, Status::Mapped(2954)), (0xfb18, Status::Disallowed), (0xfb1d, Status::Mapped(2959)),
+    (0xfb1e, Status::Valid), (0xfb1f, Status::Mapped(2960)),
+    (0xfb29, Status::DisallowedStd3Mapped(2970)), (0xfb2a, Status::Mapped(2971)),
+    (0xfb37, Status::Disallowed), (0xfb38, Status::Mapped(2984)), (0xfb3d, Status::Disallowed),
+    (0xfb3e, Status::Mapped(2989)), (0xfb3f, Status::Disallowed), (0xfb40, Status::Mapped(2990)),
+    (0xfb42, Status::Disallowed), (0xfb43, Status::Mapped(2992)), (0xfb45, Status::Disallowed),
+    (0xfb46, Status::Mapped(2994)), (0xfbb2, Status::Valid), (0xfbc2, Status::Disallowed),
+    (0xfbd3, Status::Mapped(3102)), (0xfc5e, Status::DisallowedStd3Mapped(3241)),
+    (0xfc64, Status::Mapped(3247)), (0xfd3e, Status::Valid), (0xfd40, Status::Disallowed),
+    (0xfd50, Status::Mapped(3465)), (0xfd90, Status::Disallowed), (0xfd92, Status::Mapped(3529)),
+    (0xfdc8, Status::Disallowed), (0xfdf0, Status::Mapped(3583)),
+    (0xfdfa, Status::DisallowedStd3Mapped(3593)), (0xfdfc, Status::Mapped(3595)),
+    (0xfdfd, Status::Valid), (0xfdfe, Status::Disallowed), (0xfe00, Status::Ignored),
+    (0xfe10, Status::DisallowedStd3Mapped(3596)), (0xfe11, Status::Mapped(3597)),
+    (0xfe12, Status::Disallowed), (0xfe13, Status::DisallowedStd3Mapped(3598)),
+    (0xfe17, Status::Mapped(3602)), (0xfe19, Status::Disallowed), (0xfe20, Status::Valid),
+    (0xfe30, Status::Disallowed), (0xfe31, Status::Mapped(3604)),
+    (0xfe33, Status::DisallowedStd3Mapped(3606)), (0xfe39, Status::Mapped(3612)),
+    (0xfe45, Status::Valid), (0xfe47, Status::DisallowedStd3Mapped(3624)),
+    (0xfe51, Status::Mapped(3634)), (0xfe52, Status::Disallowed),
+    (0xfe54, Status::DisallowedStd3Mapped(3635)), (0xfe58, Status::Mapped(3639)),
+    (0xfe59, Status::DisallowedStd3Mapped(3640)), (0xfe5d, Status::Mapped(3644)),
+    (0xfe5f, Status::DisallowedStd3Mapped(3646)), (0xfe63, Status::Mapped(3650)),
+    (0xfe64, Status::DisallowedStd3Mapped(3651)), (0xfe67, Status::Disallowed),
+    (0xfe68, Status::DisallowedStd3Mapped(3654)), (0xfe6c, Status::Disallowed),
+    (0xfe70, Status::DisallowedStd3Mapped(3658)), (0xfe71, Status::Mapped(3659)),
+    (0xfe72, Status::DisallowedStd3Mapped(3660)), (0xfe73, Status::Valid),
+    (0xfe74, Status::DisallowedStd3Mapped(3661)), (0xfe75, Status::Disallowed),
+    (0xfe76, Status::DisallowedStd3Mapped(3662)), (0xfe77, Status::Mapped(3663)),
+    (0xfe78, Status::DisallowedStd3Mapped(3664)), (0xfe79, Status::Mapped(3665)),
+    (0xfe7a, Status::DisallowedStd3Mapped(3666)), (0xfe7b, Status::Mapped(3667)),
+    (0xfe7c, Status::DisallowedStd3Mapped(3668)), (0xfe7d, Status::Mapped(3669)),
+    (0xfe7e, Status::DisallowedStd3Mapped(3670)), (0xfe7f, Status::Mapped(3671)),
+    (0xfefd, Status::Disallowed), (0xfeff, Status::Ignored), (0xff00, Status::Disallowed),
+    (0xff01, Status::DisallowedStd3Mapped(3797)), (0xff0d, Status::Mapped(3809)),
+    (0xff0f, Status::DisallowedStd3Mapped(3811)), (0xff10, Status::Mapped(3812)),
+    (0xff1a, Status::DisallowedStd3Mapped(3822)), (0xff21, Status::Mapped(3829)),
+    (0xff3b, Status::DisallowedStd3Mapped(3855)), (0xff41, Status::Mapped(3861)),
+    (0xff5b, Status::DisallowedStd3Mapped(3887)), (0xff5f, Status::Mapped(3891)),
+    (0xffa0, Status::Disallowed), (0xffa1, Status::Mapped(3956)), (0xffbf, Status::Disallowed),
+    (0xffc2, Status::Mapped(3986)), (0xffc8, Status::Disallowed), (0xffca, Status::Mapped(3992)),
+    (0xffd0, Status::Disallowed), (0xffd2, Status::Mapped(3998)), (0xffd8, Status::Disallowed),
+    (0xffda, Status::Mapped(4004)), (0xffdd, Status::Disallowed), (0xffe0, Status::Mapped(4007)),
+    (0xffe3, Status::DisallowedStd3Mapped(4010)), (0xffe4, Status::Mapped(4011)),
+    (0xffe7, Status::Disallowed), (0xffe8, Status::Mapped(4014)), (0xffef, Status::Disallowed),
+    (0x10000, Status::Valid), (0x1000c, Status::Disallowed), (0x1000d, Status::Valid),
+    (0x10027, Status::Disallowed), (0x10028, Status::Valid), (0x1003b, Status::Disallowed),
+    (0x1003c, Status::Valid), (0x1003e, Status::Disallowed), (0x1003f, Status::Valid),
+    (0x1004e, Status::Disallowed), (0x10050, Status::Valid), (0x1005e, Status::Disallowed),
+    (0x10080, Status::Valid), (0x100fb, Status::Disallowed), (0x10100, Status::Valid),
+    (0x10103, Status::Disallowed), (0x10107, Status::Valid), (0x10134, Status::Disallowed),
+    (0x10137, Status::Valid), (0x1018f, Status::Disallowed), (0x10190, Status::Valid),
+    (0x1019d, Status::Disallowed), (0x101a0, Status::Valid), (0x101a1, Status::Disallowed),
+    (0x101d0, Status::Valid), (0x101fe, Status::Disallowed), (0x10280, Status::Valid),
+    (0x1029d, Status::Disallowed), (0x102a0, Status::Valid), (0x102d1, Status::Disallowed),
+    (0x102e0, Status::Valid), (0x102fc, Status::Disallowed), (0x10300, Status::Valid),
+    (0x10324, Status::Disallowed), (0x1032d, Status::Valid), (0x1034b, Status::Disallowed),
+    (0x10350, Status::Valid), (0x1037b, Status::Disallowed), (0x10380, Status::Valid),
+    (0x1039e, Status::Disallowed), (0x1039f, Status::Valid), (0x103c4, Status::Disallowed),
+    (0x103c8, Status::Valid), (0x103d6, Status::Disallowed), (0x10400, Status::Mapped(4021)),
+    (0x10428, Status::Valid), (0x1049e, Status::Disallowed), (0x104a0, Status::Valid),
+    (0x104aa, Status::Disallowed), (0x104b0, Status::Mapped(4061)), (0x104d4, Status::Disallowed),
+    (0x104d8, Status::Valid), (0x104fc, Status::Disallowed), (0x10500, Status::Valid),
+    (0x10528, Status::Disallowed), (0x10530, Status::Valid), (0x10564, Status::Disallowed),
+    (0x1056f, Status::Valid), (0x10570, Status::Disallowed), (0x10600, Status::Valid),
+    (0x10737, Status::Disallowed), (0x10740, Status::Valid), (0x10756, Status::Disallowed),
+    (0x10760, Status::Valid), (0x10768, Status::Disallowed), (0x10800, Status::Valid),
+    (0x10806, Status::Disallowed), (0x10808, Status::Valid), (0x10809, Status::Disallowed),
+    (0x1080a, Status::Valid), (0x10836, Status::Disallowed), (0x10837, Status::Valid),
+    (0x10839, Status::Disallowed), (0x1083c, Status::Valid), (0x1083d, Status::Disallowed),
+    (0x1083f, Status::Valid), (0x10856, Status::Disallowed), (0x10857, Status::Valid),
+    (0x1089f, Status::Disallowed), (0x108a7, Status::Valid), (0x108b0, Status::Disallowed),
+    (0x108e0, Status::Valid), (0x108f3, Status::Disallowed), (0x108f4, Status::Valid),
+    (0x108f6, Status::Disallowed), (0x108fb, Status::Valid), (0x1091c, Status::Disallowed),
+    (0x1091f, Status::Valid), (0x1093a, Status::Disallowed), (0x1093f, Status::Valid),
+    (0x10940, Status::Disallowed), (0x10980, Status::Valid), (0x109b8, Status::Disallowed),
+    (0x109bc, Status::Valid), (0x109d0, Status::Disallowed), (0x109d2, Status::Valid),
+    (0x10a04, Status::Disallowed), (0x10a05, Status::Valid), (0x10a07, Status::Disallowed),
+    (0x10a0c, Status::Valid), (0x10a14, Status::Disallowed), (0x10a15, Status::Valid),
+    (0x10a18, Status::Disallowed), (0x10a19, Status::Valid), (0x10a36, Status::Disallowed),
+    (0x10a38, Status::Valid), (0x10a3b, Status::Disallowed), (0x10a3f, Status::Valid),
+    (0x10a49, Status::Disallowed), (0x10a50, Status::Valid), (0x10a59, Status::Disallowed),
+    (0x10a60, Status::Valid), (0x10aa0, Status::Disallowed), (0x10ac0, Status::Valid),
+    (0x10ae7, Status::Disallowed), (0x10aeb, Status::Valid), (0x10af7, Status::Disallowed),
+    (0x10b00, Status::Valid), (0x10b36, Status::Disallowed), (0x10b39, Status::Valid),
+    (0x10b56, Status::Disallowed), (0x10b58, Status::Valid), (0x10b73, Status::Disallowed),
+    (0x10b78, Status::Valid), (0x10b92, Status::Disallowed), (0x10b99, Status::Valid),
+    (0x10b9d, Status::Disallowed), (0x10ba9, Status::Valid), (0x10bb0, Status::Disallowed),
+    (0x10c00, Status::Valid), (0x10c49, Status::Disallowed), (0x10c80, Status::Mapped(4097)),
+    (0x10cb3, Status::Disallowed), (0x10cc0, Status::Valid), (0x10cf3, Status::Disallowed),
+    (0x10cfa, Status::Valid), (0x10d28, Status::Disallowed), (0x10d30, Status::Valid),
+    (0x10d3a, Status::Disallowed), (0x10e60, Status::Valid), (0x10e7f, Status::Disallowed),
+    (0x10e80, Status::Valid), (0x10eaa, Status::Disallowed), (0x10eab, Status::Valid),
+    (0x10eae, Status::Disallowed), (0x10eb0, Status::Valid), (0x10eb2, Status::Disallowed),
+    (0x10f00, Status::Valid), (0x10f28, Status::Disallowed), (0x10f30, Status::Valid),
+    (0x10f5a, Status::Disallowed), (0x10fb0, Status::Valid), (0x10fcc, Status::Disallowed),
+    (0x10fe0, Status::Valid), (0x10ff7, Status::Disallowed), (0x11000, Status::Valid),
+    (0x1104e, Status::Disallowed), (0x11052, Status::Valid), (0x11070, Status::Disallowed),
+    (0x1107f, Status::Valid), (0x110bd, Status::Disallowed), (0x110be, Status::Valid),
+    (0x110c2, Status::Disallowed), (0x110d0, Status::Valid), (0x110e9, Status::Disallowed),
+    (0x110f0, Status::Valid), (0x110fa, Status::Disallowed), (0x11100, Status::Valid),
+    (0x11135, Status::Disallowed), (0x11136, Status::Valid), (0x11148, Status::Disallowed),
+    (0x11150, Status::Valid), (0x11177, Status::Disallowed), (0x11180, Status::Valid),
+    (0x111e0, Status::Disallowed), (0x111e1, Status::Valid), (0x111f5, Status::Disallowed),
+    (0x11200, Status::Valid), (0x11212, Status::Disallowed), (0x11213, Status::Valid),
+    (0x1123f, Status::Disallowed), (0x11280, Status::Valid), (0x11287, Status::Disallowed),
+    (0x11288, Status::Valid), (0x11289, Status::Disallowed), (0x1128a, Status::Valid),
+    (0x1128e, Status::Disallowed), (0x1128f, Status::Valid), (0x1129e, Status::Disallowed),
+    (0x1129f, Status::Valid), (0x112aa, Status::Disallowed), (0x112b0, Status::Valid),
+    (0x112eb, Status::Disallowed), (0x112f0, Status::Valid), (0x112fa, Status::Disallowed),
+    (0x11300, Status::Valid), (0x11304, Status::Disallowed), (0x11305, Status::Valid),
+    (0x1130d, Status::Disallowed), (0x1130f, Status::Valid), (0x11311, Status::Disallowed),
+    (0x11313, Status::Valid), (0x11329, Status::Disallowed), (0x1132a, Status::Valid),
+    (0x11331, Status::Disallowed), (0x11332, Status::Valid), (0x11334, Status::Disallowed),
+    (0x11335, Status::Valid), (0x1133a, Status::Disallowed), (0x1133b, Status::Valid),
+    (0x11345, Status::Disallowed), (0x11347, Status::Valid), (0x11349, Status::Disallowed),
+    (0x1134b, Status::Valid), (0x1134e, Status::Disallowed), (0x11350, Status::Valid),
+    (0x11351, Status::Disallowed), (0x11357, Status::Valid), (0x11358, Status::Disallowed),
+    (0x1135d, Status::Valid), (0x11364, Status::Disallowed), (0x11366, Status::Valid),
+    (0x1136d, Status::Disallowed), (0x11370, Status::Valid), (0x11375, Status::Disallowed),
+    (0x11400, Status::Valid), (0x1145c, Status::Disallowed), (0x1145d, Status::Valid),
+    (0x11462, Status::Disallowed), (0x11480, Status::Valid), (0x114c8, Status::Disallowed),
+    (0x114d0, Status::Valid), (0x114da, Status::Disallowed), (0x11580, Status::Valid),
+    (0x115b6, Status::Disallowed), (0x115b8, Status::Valid), (0x115de, Status::Disallowed),
+    (0x11600, Status::Valid), (0x11645, Status::Disallowed), (0x11650, Status::Valid),
+    (0x1165a, Status::Disallowed), (0x11660, Status::Valid), (0x1166d, Status::Disallowed),
+    (0x11680, Status::Valid), (0x116b9, Status::Disallowed), (0x116c0, Status::Valid),
+    (0x116ca, Status::Disallowed), (0x11700, Status::Valid), (0x1171b, Status::Disallowed),
+    (0x1171d, Status::Valid), (0x1172c, Status::Disallowed), (0x11730, Status::Valid),
+    (0x11740, Status::Disallowed), (0x11800, Status::Valid), (0x1183c, Status::Disallowed),
+    (0x118a0, Status::Mapped(4148)), (0x118c0, Status::Valid), (0x118f3, Status::Disallowed),
+    (0x118ff, Status::Valid), (0x11907, Status::Disallowed), (0x11909, Status::Valid),
+    (0x1190a, Status::Disallowed), (0x1190c, Status::Valid), (0x11914, Status::Disallowed),
+    (0x11915, Status::Valid), (0x11917, Status::Disallowed), (0x11918, Status::Valid),
+    (0x11936, Status::Disallowed), (0x11937, Status::Valid), (0x11939, Status::Disallowed),
+    (0x1193b, Status::Valid), (0x11947, Status::Disallowed), (0x11950, Status::Valid),
+    (0x1195a, Status::Disallowed), (0x119a0, Status::Valid), (0x119a8, Status::Disallowed),
+    (0x119aa, Status::Valid), (0x119d8, Status::Disallowed), (0x119da, Status::Valid),
+    (0x119e5, Status::Disallowed), (0x11a00, Status::Valid), (0x11a48, Status::Disallowed),
+    (0x11a50, Status::Valid), (0x11aa3, Status::Disallowed), (0x11ac0, Status::Valid),
+    (0x11af9, Status::Disallowed), (0x11c00, Status::Valid), (0x11c09, Status::Disallowed),
+    (0x11c0a, Status::Valid), (0x11c37, Status::Disallowed), (0x11c38, Status::Valid),
+    (0x11c46, Status::Disallowed), (0x11c50, Status::Valid), (0x11c6d, Status::Disallowed),
+    (0x11c70, Status::Valid), (0x11c90, Status::Disallowed), (0x11c92, Status::Valid),
+    (0x11ca8, Status::Disallowed), (0x11ca9, Status::Valid), (0x11cb7, Status::Disallowed),
+    (0x11d00, Status::Valid), (0x11d07, Status::Disallowed), (0x11d08, Status::Valid),
+    (0x11d0a, Status::Disallowed), (0x11d0b, Status::Valid), (0x11d37, Status::Disallowed),
+    (0x11d3a, Status::Valid), (0x11d3b, Status::Disallowed), (0x11d3c, Status::Valid),
+    (0x11d3e, Status::Disallowed), (0x11d3f, Status::Valid), (0x11d48, Status::Disallowed),
+    (0x11d50, Status::Valid), (0x11d5a, Status::Disallowed), (0x11d60, Status::Valid),
+    (0x11d66, Status::Disallowed), (0x11d67, Status::Valid), (0x11d69, Status::Disallowed),
+    (0x11d6a, Status::Valid), (0x11d8f, Status::Disallowed), (0x11d90, Status::Valid),
+    (0x11d92, Status::Disallowed), (0x11d93, Status::Valid), (0x11d99, Status::Disallowed),
+    (0x11da0, Status::Valid), (0x11daa, Status::Disallowed), (0x11ee0, Status::Valid),
+    (0x11ef9, Status::Disallowed), (0x11fb0, Status::Valid), (0x11fb1, Status::Disallowed),
+    (0x11fc0, Status::Valid), (0x11ff2, Status::Disallowed), (0x11fff, Status::Valid),
+    (0x1239a, Status::Disallowed), (0x12400, Status::Valid), (0x1246f, Status::Disallowed),
+    (0x12470, Status::Valid), (0x12475, Status::Disallowed), (0x12480, Status::Valid),
+    (0x12544, Status::Disallowed), (0x13000, Status::Valid), (0x1342f, Status::Disallowed),
+    (0x14400, Status::Valid), (0x14647, Status::Disallowed), (0x16800, Status::Valid),
+    (0x16a39, Status::Disallowed), (0x16a40, Status::Valid), (0x16a5f, Status::Disallowed),
+    (0x16a60, Status::Valid), (0x16a6a, Status::Disallowed), (0x16a6e, Status::Valid),
+    (0x16a70, Status::Disallowed), (0x16ad0, Status::Valid), (0x16aee, Status::Disallowed),
+    (0x16af0, Status::Valid), (0x16af6, Status::Disallowed), (0x16b00, Status::Valid),
+    (0x16b46, Status::Disallowed), (0x16b50, Status::Valid), (0x16b5a, Status::Disallowed),
+    (0x16b5b, Status::Valid), (0x16b62, Status::Disallowed), (0x16b63, Status::Valid),
+    (0x16b78, Status::Disallowed), (0x16b7d, Status::Valid), (0x16b90, Status::Disallowed),
+    (0x16e40, Status::Mapped(4180)), (0x16e60, Status::Valid), (0x16e9b, Status::Disallowed),
+    (0x16f00, Status::Valid), (0x16f4b, Status::Disallowed), (0x16f4f, Status::Valid),
+    (0x16f88, Status::Disallowed), (0x16f8f, Status::Valid), (0x16fa0, Status::Disallowed),
+    (0x16fe0, Status::Valid), (0x16fe5, Status::Disallowed), (0x16ff0, Status::Valid),
+    (0x16ff2, Status::Disallowed), (0x17000, Status::Valid), (0x187f8, Status::Disallowed),
+    (0x18800, Status::Valid), (0x18cd6, Status::Disallowed), (0x18d00, Status::Valid),
+    (0x18d09, Status::Disallowed), (0x1b000, Status::Valid), (0x1b11f, Status::Disallowed),
+    (0x1b150, Status::Valid), (0x1b153, Status::Disallowed), (0x1b164, Status::Valid),
+    (0x1b168, Status::Disallowed), (0x1b170, Status::Valid), (0x1b2fc, Status::Disallowed),
+    (0x1bc00, Status::Valid), (0x1bc6b, Status::Disallowed), (0x1bc70, Status::Valid),
+    (0x1bc7d, Status::Disallowed), (0x1bc80, Status::Valid), (0x1bc89, Status::Disallowed),
+    (0x1bc90, Status::Valid), (0x1bc9a, Status::Disallowed), (0x1bc9c, Status::Valid),
+    (0x1bca0, Status::Ignored), (0x1bca4, Status::Disallowed), (0x1d000, Status::Valid),
+    (0x1d0f6, Status::Disallowed), (0x1d100, Status::Valid), (0x1d127, Status::Disallowed),
+    (0x1d129, Status::Valid), (0x1d15e, Status::Mapped(4212)), (0x1d165, Status::Valid),
+    (0x1d173, Status::Disallowed), (0x1d17b, Status::Valid), (0x1d1bb, Status::Mapped(4219)),
+    (0x1d1c1, Status::Valid), (0x1d1e9, Status::Disallowed), (0x1d200, Status::Valid),
+    (0x1d246, Status::Disallowed), (0x1d2e0, Status::Valid), (0x1d2f4, Status::Disallowed),
+    (0x1d300, Status::Valid), (0x1d357, Status::Disallowed), (0x1d360, Status::Valid),
+    (0x1d379, Status::Disallowed), (0x1d400, Status::Mapped(4225)), (0x1d455, Status::Disallowed),
+    (0x1d456, Status::Mapped(4310)), (0x1d49d, Status::Disallowed), (0x1d49e, Status::Mapped(4381)),
+    (0x1d4a0, Status::Disallowed), (0x1d4a2, Status::Mapped(4383)), (0x1d4a3, Status::Disallowed),
+    (0x1d4a5, Status::Mapped(4384)), (0x1d4a7, Status::Disallowed), (0x1d4a9, Status::Mapped(4386)),
+    (0x1d4ad, Status::Disallowed), (0x1d4ae, Status::Mapped(4390)), (0x1d4ba, Status::Disallowed),
+    (0x1d4bb, Status::Mapped(4402)), (0x1d4bc, Status::Disallowed), (0x1d4bd, Status::Mapped(4403)),
+    (0x1d4c4, Status::Disallowed), (0x1d4c5, Status::Mapped(4410)), (0x1d506, Status::Disallowed),
+    (0x1d507, Status::Mapped(4475)), (0x1d50b, Status::Disallowed), (0x1d50d, Status::Mapped(4479)),
+    (0x1d515, Status::Disallowed), (0x1d516, Status::Mapped(4487)), (0x1d51d, Status::Disallowed),
+    (0x1d51e, Status::Mapped(4494)), (0x1d53a, Status::Disallowed), (0x1d53b, Status::Mapped(4522)),
+    (0x1d53f, Status::Disallowed), (0x1d540, Status::Mapped(4526)), (0x1d545, Status::Disallowed),
+    (0x1d546, Status::Mapped(4531)), (0x1d547, Status::Disallowed), (0x1d54a, Status::Mapped(4532)),
+    (0x1d551, Status::Disallowed), (0x1d552, Status::Mapped(4539)), (0x1d6a6, Status::Disallowed),
+    (0x1d6a8, Status::Mapped(4879)), (0x1d7cc, Status::Disallowed), (0x1d7ce, Status::Mapped(5171)),
+    (0x1d800, Status::Valid), (0x1da8c, Status::Disallowed), (0x1da9b, Status::Valid),
+    (0x1daa0, Status::Disallowed), (0x1daa1, Status::Valid), (0x1dab0, Status::Disallowed),
+    (0x1e000, Status::Valid), (0x1e007, Status::Disallowed), (0x1e008, Status::Valid),
+    (0x1e019, Status::Disallowed), (0x1e01b, Status::Valid), (0x1e022, Status::Disallowed),
+    (0x1e023, Status::Valid), (0x1e025, Status::Disallowed), (0x1e026, Status::Valid),
+    (0x1e02b, Status::Disallowed), (0x1e100, Status::Valid), (0x1e12d, Status::Disallowed),
+    (0x1e130, Status::Valid), (0x1e13e, Status::Disallowed), (0x1e140, Status::Valid),
+    (0x1e14a, Status::Disallowed), (0x1e14e, Status::Valid), (0x1e150, Status::Disallowed),
+    (0x1e2c0, Status::Valid), (0x1e2fa, Status::Disallowed), (0x1e2ff, Status::Valid),
+    (0x1e300, Status::Disallowed), (0x1e800, Status::Valid), (0x1e8c5, Status::Disallowed),
+    (0x1e8c7, Status::Valid), (0x1e8d7, Status::Disallowed), (0x1e900, Status::Mapped(5221)),
+    (0x1e922, Status::Valid), (0x1e94c, Status::Disallowed), (0x1e950, Status::Valid),
+    (0x1e95a, Status::Disallowed), (0x1e95e, Status::Valid), (0x1e960, Status::Disallowed),
+    (0x1ec71, Status::Valid), (0x1ecb5, Status::Disallowed), (0x1ed01, Status::Valid),
+    (0x1ed3e, Status::Disallowed), (0x1ee00, Status::Mapped(5255)), (0x1ee04, Status::Disallowed),
+    (0x1ee05, Status::Mapped(5259)), (0x1ee20, Status::Disallowed), (0x1ee21, Status::Mapped(5286)),
+    (0x1ee23, Status::Disallowed), (0x1ee24, Status::Mapped(5288)), (0x1ee25, Status::Disallowed),
+    (0x1ee27, Status::Mapped(5289)), (0x1ee28, Status::Disallowed), (0x1ee29, Status::Mapped(5290)),
+    (0x1ee33, Status::Disallowed), (0x1ee34, Status::Mapped(5300)), (0x1ee38, Status::Disallowed),
+    (0x1ee39, Status::Mapped(5304)), (0x1ee3a, Status::Disallowed), (0x1ee3b, Status::Mapped(5305)),
+    (0x1ee3c, Status::Disallowed), (0x1ee42, Status::Mapped(5306)), (0x1ee43, Status::Disallowed),
+    (0x1ee47, Status::Mapped(5307)), (0x1ee48, Status::Disallowed), (0x1ee49, Status::Mapped(5308)),
+    (0x1ee4a, Status::Disallowed), (0x1ee4b, Status::Mapped(5309)), (0x1ee4c, Status::Disallowed),
+    (0x1ee4d, Status::Mapped(5310)), (0x1ee50, Status::Disallowed), (0x1ee51, Status::Mapped(5313)),
+    (0x1ee53, Status::Disallowed), (0x1ee54, Status::Mapped(5315)), (0x1ee55, Status::Disallowed),
+    (0x1ee57, Status::Mapped(5316)), (0x1ee58, Status::Disallowed), (0x1ee59, Status::Mapped(5317)),
+    (0x1ee5a, Status::Disallowed), (0x1ee5b, Status::Mapped(5318)), (0x1ee5c, Status::Disallowed),
+    (0x1ee5d, Status::Mapped(5319)), (0x1ee5e, Status::Disallowed), (0x1ee5f, Status::Mapped(5320)),
+    (0x1ee60, Status::Disallowed), (0x1ee61, Status::Mapped(5321)), (0x1ee63, Status::Disallowed),
+    (0x1ee64, Status::Mapped(5323)), (0x1ee65, Status::Disallowed), (0x1ee67, Status::Mapped(5324)),
+    (0x1ee6b, Status::Disallowed), (0x1ee6c, Status::Mapped(5328)), (0x1ee73, Status::Disallowed),
+    (0x1ee74, Status::Mapped(5335)), (0x1ee78, Status::Disallowed), (0x1ee79, Status::Mapped(5339)),
+    (0x1ee7d, Status::Disallowed), (0x1ee7e, Status::Mapped(5343)), (0x1ee7f, Status::Disallowed),
+    (0x1ee80, Status::Mapped(5344)), (0x1ee8a, Status::Disallowed), (0x1ee8b, Status::Mapped(5354)),
+    (0x1ee9c, Status::Disallowed), (0x1eea1, Status::Mapped(5371)), (0x1eea4, Status::Disallowed),
+    (0x1eea5, Status::Mapped(5374)), (0x1eeaa, Status::Disallowed), (0x1eeab, Status::Mapped(5379)),
+    (0x1eebc, Status::Disallowed), (0x1eef0, Status::Valid), (0x1eef2, Status::Disallowed),
+    (0x1f000, Status::Valid), (0x1f02c, Status::Disallowed), (0x1f030, Status::Valid),
+    (0x1f094, Status::Disallowed), (0x1f0a0, Status::Valid), (0x1f0af, Status::Disallowed),
+    (0x1f0b1, Status::Valid), (0x1f0c0, Status::Disallowed), (0x1f0c1, Status::Valid),
+    (0x1f0d0, Status::Disallowed), (0x1f0d1, Status::Valid), (0x1f0f6, Status::Disallowed),
+    (0x1f101, Status::DisallowedStd3Mapped(5396)), (0x1f10b, Status::Valid),
+    (0x1f110, Status::DisallowedStd3Mapped(5406)), (0x1f12a, Status::Mapped(5432)),
+    (0x1f12f, Status::Valid), (0x1f130, Status::Mapped(5437)), (0x1f150, Status::Valid),
+    (0x1f16a, Status::Mapped(5469)), (0x1f16d, Status::Valid), (0x1f190, Status::Mapped(5472)),
+    (0x1f191, Status::Valid), (0x1f1ae, Status::Disallowed), (0x1f1e6, Status::Valid),
+    (0x1f200, Status::Mapped(5473)), (0x1f203, Status::Disallowed), (0x1f210, Status::Mapped(5476)),
+    (0x1f23c, Status::Disallowed), (0x1f240, Status::Mapped(5520)), (0x1f249, Status::Disallowed),
+    (0x1f250, Status::Mapped(5529)), (0x1f252, Status::Disallowed), (0x1f260, Status::Valid),
+    (0x1f266, Status::Disallowed), (0x1f300, Status::Valid), (0x1f6d8, Status::Disallowed),
+    (0x1f6e0, Status::Valid), (0x1f6ed, Status::Disallowed), (0x1f6f0, Status::Valid),
+    (0x1f6fd, Status::Disallowed), (0x1f700, Status::Valid), (0x1f774, Status::Disallowed),
+    (0x1f780, Status::Valid), (0x1f7d9, Status::Disallowed), (0x1f7e0, Status::Valid),
+    (0x1f7ec, Status::Disallowed), (0x1f800, Status::Valid), (0x1f80c, Status::Disallowed),
+    (0x1f810, Status::Valid), (0x1f848, Status::Disallowed), (0x1f850, Status::Valid),
+    (0x1f85a, Status::Disallowed), (0x1f860, Status::Valid), (0x1f888, Status::Disallowed),
+    (0x1f890, Status::Valid), (0x1f8ae, Status::Disallowed), (0x1f8b0, Status::Valid),
+    (0x1f8b2, Status::Disallowed), (0x1f900, Status::Valid), (0x1f979, Status::Disallowed),
+    (0x1f97a, Status::Valid), (0x1f9cc, Status::Disallowed), (0x1f9cd, Status::Valid),
+    (0x1fa54, Status::Disallowed), (0x1fa60, Status::Valid), (0x1fa6e, Status::Disallowed),
+    (0x1fa70, Status::Valid), (0x1fa75, Status::Disallowed), (0x1fa78, Status::Valid),
+    (0x1fa7b, Status::Disallowed), (0x1fa80, Status::Valid), (0x1fa87, Status::Disallowed),
+    (0x1fa90, Status::Valid), (0x1faa9, Status::Disallowed), (0x1fab0, Status::Valid),
+    (0x1fab7, Status::Disallowed), (0x1fac0, Status::Valid), (0x1fac3, Status::Disallowed),
+    (0x1fad0, Status::Valid), (0x1fad7, Status::Disallowed), (0x1fb00, Status::Valid),
+    (0x1fb93, Status::Disallowed), (0x1fb94, Status::Valid), (0x1fbcb, Status::Disallowed),
+    (0x1fbf0, Status::Mapped(5531)), (0x1fbfa, Status::Disallowed), (0x20000, Status::Valid),
+    (0x2a6de, Status::Disallowed), (0x2a700, Status::Valid), (0x2b735, Status::Disallowed),
+    (0x2b740, Status::Valid), (0x2b81e, Status::Disallowed), (0x2b820, Status::Valid),
+    (0x2cea2, Status::Disallowed), (0x2ceb0, Status::Valid), (0x2ebe1, Status::Disallowed),
+    (0x2f800, Status::Mapped(5541)), (0x2f868, Status::Disallowed), (0x2f869, Status::Mapped(5645)),
+    (0x2f874, Status::Disallowed), (0x2f875, Status::Mapped(5656)), (0x2f91f, Status::Disallowed),
+    (0x2f920, Status::Mapped(5826)), (0x2f95f, Status::Disallowed), (0x2f960, Status::Mapped(5889)),
+    (0x2f9bf, Status::Disallowed), (0x2f9c0, Status::Mapped(5984)), (0x2fa1e, Status::Disallowed),
+    (0x30000, Status::Valid), (0x3134b, Status::Disallowed), (0xe0100, Status::Ignored),
+    (0xe01f0, Status::Disallowed),
+];
+/// Offset & length in MAPPED by the index from the Status
+pub(super) static MAPPED_INDEX: &[(u16, u8)] = &[
+    (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1), (10, 1),
+    (11, 1), (12, 1), (13, 1), (14, 1), (15, 1), (16, 1), (17, 1), (18, 1), (19, 1), (20, 1),
+    (21, 1), (22, 1), (23, 1), (24, 1), (25, 1), (26, 1), (27, 2), (0, 1), (29, 2), (31, 1),
+    (32, 1), (33, 2), (35, 1), (36, 2), (38, 1), (14, 1), (39, 3), (42, 3), (45, 3), (48, 1),
+    (49, 1), (50, 1), (51, 1), (52, 1), (53, 1), (54, 1), (55, 1), (56, 1), (57, 1), (58, 1),
+    (59, 1), (60, 1), (61, 1), (62, 1), (63, 1), (64, 1), (65, 1), (66, 1), (67, 1), (68, 1),
+    (69, 1), (70, 1), (71, 1), (72, 1), (73, 1), (74, 1), (75, 1), (76, 1), (77, 1), (78, 1),
+    (79, 1), (80, 1), (81, 1), (82, 1), (83, 1), (84, 1), (85, 1), (86, 1), (87, 1), (88, 1),
+    (89, 1), (90, 1), (91, 1), (92, 1), (93, 1), (94, 1), (95, 1), (96, 1), (97, 1), (98, 1),
+    (99, 1), (100, 1), (101, 1), (102, 2), (8, 2), (8, 2), (104, 1), (105, 1), (106, 1), (107, 1),
+    (108, 1), (109, 2), (109, 2), (111, 1), (112, 1), (113, 1), (114, 1), (115, 2), (117, 1),
+    (118, 1), (119, 1), (120, 1), (121, 1), (122, 1), (123, 1), (124, 1), (125, 1), (126, 1),
+    (127, 1), (128, 1), (129, 1), (130, 1), (131, 1), (132, 1), (133, 1), (134, 1), (135, 1),
+    (136, 1), (137, 1), (138, 1), (139, 1), (140, 1), (141, 1), (142, 1), (143, 1), (18, 1),
+    (144, 1), (145, 1), (146, 1), (147, 1), (148, 1), (149, 1), (150, 1), (151, 1), (152, 1),
+    (153, 1), (154, 1), (155, 1), (156, 1), (157, 1), (158, 1), (159, 1), (160, 1), (161, 1),
+    (162, 1), (163, 1), (164, 1), (165, 1), (166, 1), (167, 1), (168, 1), (169, 1), (170, 1),
+    (171, 1), (172, 1), (173, 1), (174, 1), (175, 1), (176, 1), (177, 1), (178, 1), (179, 1),
+    (180, 2), (180, 2), (180, 2), (182, 2), (182, 2), (182, 2), (184, 2), (184, 2), (184, 2),
+    (186, 1), (187, 1), (188, 1), (189, 1), (190, 1), (191, 1), (192, 1), (193, 1), (194, 1),
+    (195, 1), (196, 1), (197, 1), (198, 1), (199, 1), (200, 1), (201, 1), (202, 1), (203, 2),
+    (203, 2), (203, 2), (205, 1), (206, 1), (207, 1), (208, 1), (209, 1), (210, 1), (211, 1),
+    (212, 1), (213, 1), (214, 1), (215, 1), (216, 1), (217, 1), (218, 1), (219, 1), (220, 1),
+    (221, 1), (222, 1), (223, 1), (224, 1), (225, 1), (226, 1), (227, 1), (228, 1), (229, 1),
+    (230, 1), (231, 1), (232, 1), (233, 1), (234, 1), (235, 1), (236, 1), (237, 1), (238, 1),
+    (239, 1), (240, 1), (241, 1), (242, 1), (243, 1), (244, 1), (245, 1), (246, 1), (247, 1),
+    (248, 1), (249, 1), (250, 1), (7, 1), (251, 1), (9, 1), (17, 1), (252, 1), (253, 1), (254, 1),
+    (22, 1), (24, 1), (255, 2), (257, 2), (259, 2), (261, 2), (263, 2), (265, 2), (157, 1), (11, 1),
+    (18, 1), (23, 1), (267, 1), (268, 1), (34, 1), (269, 1), (270, 2), (272, 1), (273, 1), (274, 1),
+    (275, 1), (276, 1), (277, 2), (279, 1), (280, 1), (33, 2), (281, 3), (284, 1), (110, 1),
+    (285, 1), (286, 1), (287, 1), (288, 1), (289, 1), (290, 1), (291, 1), (292, 1), (293, 1),
+    (294, 1), (295, 1), (296, 1), (297, 1), (298, 1), (272, 1), (299, 1), (300, 1), (35, 1),
+    (301, 1), (302, 1), (303, 1), (304, 1), (305, 1), (306, 1), (307, 1), (308, 1), (309, 1),
+    (310, 1), (311, 1), (312, 1), (313, 1), (314, 1), (315, 1), (292, 1), (298, 1), (308, 1),
+    (289, 1), (314, 1), (309, 1), (304, 1), (316, 1), (317, 1), (318, 1), (319, 1), (320, 1),
+    (321, 1), (322, 1), (323, 1), (324, 1), (325, 1), (326, 1), (327, 1), (299, 1), (305, 1),
+    (306, 1), (298, 1), (295, 1), (328, 1), (306, 1), (329, 1), (330, 1), (331, 1), (332, 1),
+    (333, 1), (334, 1), (335, 1), (336, 1), (337, 1), (338, 1), (339, 1), (340, 1), (341, 1),
+    (342, 1), (343, 1), (344, 1), (345, 1), (346, 1), (347, 1), (348, 1), (349, 1), (350, 1),
+    (351, 1), (352, 1), (353, 1), (354, 1), (355, 1), (356, 1), (357, 1), (358, 1), (359, 1),
+    (360, 1), (361, 1), (362, 1), (363, 1), (364, 1), (365, 1), (366, 1), (367, 1), (368, 1),
+    (369, 1), (370, 1), (371, 1), (372, 1), (373, 1), (374, 1), (375, 1), (376, 1), (377, 1),
+    (378, 1), (379, 1), (380, 1), (381, 1), (382, 1), (383, 1), (384, 1), (385, 1), (386, 1),
+    (387, 1), (388, 1), (389, 1), (390, 1), (391, 1), (392, 1), (393, 1), (394, 1), (395, 1),
+    (396, 1), (397, 1), (398, 1), (399, 1), (400, 1), (401, 1), (402, 1), (403, 1), (404, 1),
+    (405, 1), (406, 1), (407, 1), (408, 1), (409, 1), (410, 1), (411, 1), (412, 1), (413, 1),
+    (414, 1), (415, 1), (416, 1), (417, 1), (418, 1), (419, 1), (420, 1), (421, 1), (422, 1),
+    (423, 1), (424, 1), (425, 1), (426, 1), (427, 1), (428, 1), (429, 1), (430, 1), (431, 1),
+    (432, 1), (433, 1), (434, 1), (435, 1), (436, 1), (437, 1), (438, 1), (439, 1), (440, 1),
+    (441, 1), (442, 1), (443, 1), (444, 1), (445, 1), (446, 1), (447, 1), (448, 1), (449, 1),
+    (450, 1), (451, 1), (452, 1), (453, 1), (454, 1), (455, 1), (456, 1), (457, 1), (458, 1),
+    (459, 1), (460, 1), (461, 1), (462, 1), (463, 1), (464, 1), (465, 1), (466, 1), (467, 1),
+    (468, 1), (469, 1), (470, 1), (471, 1), (472, 1), (473, 1), (474, 1), (475, 1), (476, 1),
+    (477, 1), (478, 1), (479, 1), (480, 1), (481, 1), (482, 1), (483, 1), (484, 1), (485, 1),
+    (486, 1), (487, 1), (488, 1), (489, 1), (490, 1), (491, 1), (492, 1), (493, 1), (494, 1),
+    (495, 1), (496, 1), (497, 1), (498, 1), (499, 1), (500, 1), (501, 1), (502, 1), (503, 1),
+    (504, 1), (505, 1), (506, 1), (507, 1), (508, 1), (509, 1), (510, 1), (511, 1), (512, 1),
+    (513, 1), (514, 1), (515, 1), (516, 1), (517, 1), (518, 2), (520, 2), (522, 2), (524, 2),
+    (526, 2), (528, 2), (530, 2), (532, 2), (534, 2), (536, 2), (538, 2), (540, 2), (542, 2),
+    (544, 2), (546, 2), (548, 2), (550, 2), (552, 2), (554, 2), (556, 2), (558, 2), (560, 2),
+    (562, 2), (564, 2), (566, 2), (568, 2), (570, 2), (572, 2), (574, 1), (575, 2), (577, 2),
+    (579, 2), (581, 2), (583, 2), (585, 2), (587, 2), (589, 2), (591, 2), (593, 3), (596, 2),
+    (598, 3), (594, 2), (601, 2), (603, 2), (605, 2), (607, 2), (609, 2), (611, 2), (613, 1),
+    (614, 1), (615, 1), (616, 1), (617, 1), (618, 1), (619, 1), (620, 1), (621, 1), (351, 1),
+    (353, 1), (363, 1), (366, 1), (367, 1), (367, 1), (375, 1), (382, 1), (622, 1), (623, 1),
+    (624, 1), (625, 1), (626, 1), (627, 1), (628, 1), (629, 1), (630, 1), (631, 1), (632, 1),
+    (633, 1), (634, 1), (615, 1), (635, 1), (636, 1), (637, 1), (638, 1), (639, 1), (640, 1),
+    (641, 1), (642, 1), (643, 1), (644, 1), (645, 1), (646, 1), (647, 1), (648, 1), (649, 1),
+    (650, 1), (651, 1), (652, 1), (653, 1), (654, 1), (655, 1), (656, 1), (657, 1), (658, 1),
+    (659, 1), (660, 1), (661, 1), (662, 1), (663, 1), (664, 1), (665, 1), (666, 1), (667, 1),
+    (0, 1), (54, 1), (1, 1), (3, 1), (4, 1), (152, 1), (6, 1), (7, 1), (8, 1), (9, 1), (10, 1),
+    (11, 1), (12, 1), (13, 1), (14, 1), (229, 1), (15, 1), (17, 1), (19, 1), (20, 1), (22, 1),
+    (0, 1), (668, 1), (669, 1), (670, 1), (1, 1), (3, 1), (4, 1), (153, 1), (154, 1), (671, 1),
+    (6, 1), (10, 1), (12, 1), (117, 1), (14, 1), (147, 1), (672, 1), (673, 1), (15, 1), (19, 1),
+    (20, 1), (674, 1), (161, 1), (21, 1), (675, 1), (292, 1), (293, 1), (294, 1), (309, 1),
+    (310, 1), (8, 1), (17, 1), (20, 1), (21, 1), (292, 1), (293, 1), (305, 1), (309, 1), (310, 1),
+    (362, 1), (676, 1), (2, 1), (677, 1), (64, 1), (671, 1), (5, 1), (678, 1), (679, 1), (680, 1),
+    (159, 1), (158, 1), (681, 1), (682, 1), (683, 1), (684, 1), (685, 1), (686, 1), (687, 1),
+    (688, 1), (162, 1), (689, 1), (690, 1), (163, 1), (691, 1), (692, 1), (169, 1), (693, 1),
+    (244, 1), (173, 1), (694, 1), (174, 1), (245, 1), (25, 1), (695, 1), (696, 1), (177, 1),
+    (298, 1), (697, 1), (698, 1), (699, 1), (700, 1), (701, 1), (702, 1), (703, 1), (704, 1),
+    (705, 1), (706, 1), (707, 1), (708, 1), (709, 1), (710, 1), (711, 1), (712, 1), (713, 1),
+    (714, 1), (715, 1), (716, 1), (717, 1), (718, 1), (719, 1), (720, 1), (721, 1), (722, 1),
+    (723, 1), (724, 1), (725, 1), (726, 1), (727, 1), (728, 1), (729, 1), (730, 1), (731, 1),
+    (732, 1), (733, 1), (734, 1), (735, 1), (736, 1), (737, 1), (738, 1), (739, 1), (740, 1),
+    (741, 1), (742, 1), (743, 1), (744, 1), (745, 1), (746, 1), (747, 1), (748, 1), (749, 1),
+    (750, 1), (751, 1), (752, 1), (753, 1), (754, 1), (755, 1), (756, 1), (757, 1), (758, 1),
+    (759, 1), (760, 1), (761, 1), (762, 1), (763, 1), (764, 1), (765, 1), (766, 1), (767, 1),
+    (768, 1), (769, 1), (770, 1), (771, 1), (772, 2), (745, 1), (774, 2), (776, 1), (777, 1),
+    (778, 1), (779, 1), (780, 1), (781, 1), (782, 1), (783, 1), (784, 1), (785, 1), (786, 1),
+    (787, 1), (788, 1), (789, 1), (790, 1), (791, 1), (792, 1), (793, 1), (794, 1), (795, 1),
+    (796, 1), (797, 1), (798, 1), (799, 1), (800, 1), (801, 1), (802, 1), (803, 1), (804, 1),
+    (805, 1), (806, 1), (807, 1), (808, 1), (809, 1), (810, 1), (811, 1), (812, 1), (813, 1),
+    (814, 1), (815, 1), (816, 1), (817, 1), (818, 1), (819, 1), (820, 1), (821, 1), (822, 1),
+    (823, 1), (824, 1), (825, 1), (826, 1), (827, 1), (828, 1), (829, 1), (830, 1), (831, 1),
+    (832, 1), (833, 1), (834, 1), (835, 1), (836, 1), (837, 1), (838, 1), (839, 1), (840, 1),
+    (841, 1), (842, 1), (843, 1), (844, 1), (845, 1), (846, 1), (847, 1), (848, 1), (849, 1),
+    (850, 1), (851, 1), (852, 1), (853, 1), (854, 1), (855, 1), (856, 1), (857, 1), (858, 1),
+    (859, 1), (860, 1), (861, 1), (862, 1), (863, 1), (864, 1), (865, 1), (866, 1), (867, 1),
+    (868, 1), (869, 1), (870, 1), (871, 1), (284, 1), (285, 1), (286, 1), (287, 1), (288, 1),
+    (289, 1), (290, 1), (872, 2), (874, 2), (876, 2), (878, 2), (880, 2), (882, 2), (884, 2),
+    (886, 2), (872, 2), (874, 2), (876, 2), (878, 2), (880, 2), (882, 2), (884, 2), (886, 2),
+    (888, 2), (890, 2), (892, 2), (894, 2), (896, 2), (898, 2), (900, 2), (902, 2), (888, 2),
+    (890, 2), (892, 2), (894, 2), (896, 2), (898, 2), (900, 2), (902, 2), (904, 2), (906, 2),
+    (908, 2), (910, 2), (912, 2), (914, 2), (916, 2), (918, 2), (904, 2), (906, 2), (908, 2),
+    (910, 2), (912, 2), (914, 2), (916, 2), (918, 2), (920, 2), (922, 2), (924, 2), (926, 2),
+    (928, 1), (929, 1), (920, 1), (284, 1), (922, 2), (930, 2), (272, 1), (930, 2), (932, 2),
+    (934, 3), (937, 2), (939, 2), (941, 2), (943, 2), (945, 1), (285, 1), (937, 1), (286, 1),
+    (939, 2), (946, 3), (949, 3), (952, 3), (955, 1), (956, 1), (957, 1), (958, 1), (287, 1),
+    (959, 3), (962, 3), (965, 3), (968, 1), (969, 1), (970, 1), (971, 1), (289, 1), (972, 1),
+    (973, 3), (281, 3), (976, 1), (977, 2), (979, 2), (981, 2), (983, 2), (985, 1), (288, 1),
+    (977, 1), (290, 1), (979, 2), (33, 2), (959, 2), (26, 1), (26, 1), (26, 1), (26, 1), (26, 1),
+    (26, 1), (26, 1), (26, 1), (26, 1), (26, 1), (26, 1), (986, 1), (987, 2), (26, 1), (989, 2),
+    (991, 3), (994, 2), (996, 3), (999, 2), (1001, 2), (1003, 2), (1005, 2), (1007, 2), (989, 4),
+    (26, 1), (1009, 1), (8, 1), (41, 1), (1010, 1), (1011, 1), (1012, 1), (1013, 1), (1014, 1),
+    (1015, 1), (1016, 1), (1017, 1), (1018, 1), (1019, 1), (13, 1), (1009, 1), (38, 1), (31, 1),
+    (32, 1), (41, 1), (1010, 1), (1011, 1), (1012, 1), (1013, 1), (1014, 1), (1015, 1), (1016, 1),
+    (1017, 1), (1018, 1), (1019, 1), (0, 1), (4, 1), (14, 1), (23, 1), (153, 1), (7, 1), (10, 1),
+    (11, 1), (12, 1), (13, 1), (15, 1), (18, 1), (19, 1), (17, 2), (1020, 3), (1023, 3), (2, 1),
+    (1026, 2), (1028, 3), (1031, 3), (154, 1), (1034, 2), (6, 1), (7, 1), (7, 1), (7, 1), (7, 1),
+    (97, 1), (8, 1), (8, 1), (11, 1), (11, 1), (13, 1), (13, 2), (15, 1), (16, 1), (17, 1), (17, 1),
+    (17, 1), (1036, 2), (1038, 3), (1041, 2), (25, 1), (312, 1), (25, 1), (10, 1), (53, 1), (1, 1),
+    (2, 1), (4, 1), (4, 1), (5, 1), (12, 1), (14, 1), (1043, 1), (1044, 1), (1045, 1), (1046, 1),
+    (8, 1), (1047, 3), (304, 1), (293, 1), (293, 1), (304, 1), (1050, 1), (3, 1), (3, 1), (4, 1),
+    (8, 1), (9, 1), (1051, 3), (1054, 3), (1057, 4), (1061, 3), (1064, 3), (1067, 3), (1070, 3),
+    (1073, 3), (1076, 3), (1079, 3), (1082, 3), (1085, 3), (1088, 3), (1091, 3), (1094, 3), (39, 2),
+    (8, 1), (1097, 2), (1099, 3), (1102, 2), (21, 1), (1104, 2), (1106, 3), (1109, 4), (1113, 2),
+    (23, 1), (1115, 2), (1117, 3), (11, 1), (2, 1), (3, 1), (12, 1), (8, 1), (1097, 2), (1097, 3),
+    (1102, 2), (21, 1), (1104, 2), (1106, 3), (1109, 4), (1113, 2), (23, 1), (1115, 2), (1117, 3),
+    (11, 1), (2, 1), (3, 1), (12, 1), (1120, 3), (1123, 2), (1125, 3), (1128, 2), (1130, 3),
+    (1133, 1), (1134, 1), (38, 1), (31, 1), (32, 1), (41, 1), (1010, 1), (1011, 1), (1012, 1),
+    (1013, 1), (1014, 1), (1059, 2), (38, 2), (1135, 2), (1137, 2), (1139, 2), (1141, 2), (1143, 2),
+    (1145, 2), (1147, 2), (1149, 2), (1151, 2), (1153, 3), (1156, 3), (1159, 3), (1162, 3),
+    (1165, 3), (1168, 3), (1171, 3), (1174, 3), (1177, 3), (1180, 4), (1184, 4), (1188, 4),
+    (1192, 4), (1196, 4), (1200, 4), (1204, 4), (1208, 4), (1212, 4), (1216, 4), (1220, 4),
+    (1224, 3), (1227, 3), (1230, 3), (1233, 3), (1236, 3), (1239, 3), (1242, 3), (1245, 3),
+    (1248, 3), (1251, 3), (1254, 3), (1257, 3), (1260, 3), (1263, 3), (1266, 3), (1269, 3),
+    (1272, 3), (1275, 3), (1278, 3), (1281, 3), (1284, 3), (1287, 3), (1290, 3), (1293, 3),
+    (1296, 3), (1299, 3), (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1),
+    (9, 1), (10, 1), (11, 1), (12, 1), (13, 1), (14, 1), (15, 1), (16, 1), (17, 1), (18, 1),
+    (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1), (25, 1), (0, 1), (1, 1), (2, 1), (3, 1),
+    (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1), (13, 1), (14, 1),
+    (15, 1), (16, 1), (17, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1),
+    (25, 1), (1009, 1), (1123, 4), (1302, 3), (1305, 2), (1304, 3), (1307, 2), (1309, 1), (1310, 1),
+    (1311, 1), (1312, 1), (1313, 1), (1314, 1), (1315, 1), (1316, 1), (1317, 1), (1318, 1),
+    (1319, 1), (1320, 1), (1321, 1), (1322, 1), (1323, 1), (1324, 1), (1325, 1), (1326, 1),
+    (1327, 1), (1328, 1), (1329, 1), (1330, 1), (1331, 1), (1332, 1), (1333, 1), (1334, 1),
+    (1335, 1), (1336, 1), (1337, 1), (1338, 1), (1339, 1), (1340, 1), (1341, 1), (1342, 1),
+    (1343, 1), (1344, 1), (1345, 1), (1346, 1), (1347, 1), (1348, 1), (1349, 1), (1350, 1),
+    (1351, 1), (1352, 1), (1353, 1), (1354, 1), (1355, 1), (1356, 1), (1357, 1), (1358, 1),
+    (1359, 1), (1360, 1), (1361, 1), (1362, 1), (669, 1), (687, 1), (668, 1), (676, 1), (1363, 1),
+    (1364, 1), (9, 1), (21, 1), (1365, 1), (1366, 1), (1367, 1), (1368, 1), (1369, 1), (1370, 1),
+    (1371, 1), (1372, 1), (1373, 1), (1374, 1), (1375, 1), (1376, 1), (1377, 1), (1378, 1),
+    (1379, 1), (1380, 1), (1381, 1), (1382, 1), (1383, 1), (1384, 1), (1385, 1), (1386, 1),
+    (1387, 1), (1388, 1), (1389, 1), (1390, 1), (1391, 1), (1392, 1), (1393, 1), (1394, 1),
+    (1395, 1), (1396, 1), (1397, 1), (1398, 1), (1399, 1), (1400, 1), (1401, 1), (1402, 1),
+    (1403, 1), (1404, 1), (1405, 1), (1406, 1), (1407, 1), (1408, 1), (1409, 1), (1410, 1),
+    (1411, 1), (1412, 1), (1413, 1), (1414, 1), (1415, 1), (1416, 1), (1417, 1), (1418, 1),
+    (1419, 1), (1420, 1), (1421, 1), (1422, 1), (1423, 1), (1424, 1), (1425, 1), (1426, 1),
+    (1427, 1), (1428, 1), (1429, 1), (1430, 1), (1431, 1), (1432, 1), (1433, 1), (1434, 1),
+    (1435, 1), (1436, 1), (1437, 1), (1438, 1), (1439, 1), (1440, 1), (1441, 1), (1442, 1),
+    (1443, 1), (1444, 1), (1445, 1), (1446, 1), (1447, 1), (1448, 1), (1449, 1), (1450, 1),
+    (1451, 1), (1452, 1), (1453, 1), (1454, 1), (1455, 1), (1456, 1), (1457, 1), (1458, 1),
+    (1459, 1), (1460, 1), (1461, 1), (1462, 1), (1463, 1), (1464, 1), (1465, 1), (1466, 1),
+    (1467, 1), (1468, 1), (1469, 1), (1470, 1), (1471, 1), (1472, 1), (1473, 1), (1474, 1),
+    (1475, 1), (1476, 1), (1477, 1), (1478, 1), (1479, 1), (1480, 1), (1481, 1), (1482, 1),
+    (1483, 1), (1484, 1), (1485, 1), (1486, 1), (1487, 1), (1488, 1), (1489, 1), (1490, 1),
+    (1491, 1), (1492, 1), (1493, 1), (1494, 1), (1495, 1), (1496, 1), (1497, 1), (1498, 1),
+    (1499, 1), (1500, 1), (1501, 1), (1502, 1), (1503, 1), (1504, 1), (1505, 1), (1506, 1),
+    (1507, 1), (1508, 1), (1509, 1), (1510, 1), (1511, 1), (1512, 1), (1513, 1), (1514, 1),
+    (1515, 1), (1516, 1), (1517, 1), (1518, 1), (1519, 1), (1520, 1), (1521, 1), (1522, 1),
+    (1523, 1), (1524, 1), (1525, 1), (1526, 1), (1527, 1), (1528, 1), (1529, 1), (1530, 1),
+    (1531, 1), (1532, 1), (1533, 1), (1534, 1), (1535, 1), (1536, 1), (1537, 1), (1538, 1),
+    (1539, 1), (1540, 1), (1541, 1), (1542, 1), (1543, 1), (1544, 1), (1545, 1), (1546, 1),
+    (1547, 1), (1548, 1), (1549, 1), (1550, 1), (1551, 1), (1552, 1), (1553, 1), (1554, 1),
+    (1555, 1), (1556, 1), (1557, 1), (1558, 1), (1559, 1), (1560, 1), (1561, 1), (1562, 1),
+    (1563, 1), (1564, 1), (1565, 1), (1566, 1), (1567, 1), (1568, 1), (1569, 1), (1570, 1),
+    (1571, 1), (1572, 1), (1573, 1), (1574, 1), (1575, 1), (1576, 1), (1577, 1), (1578, 1),
+    (1579, 1), (1580, 1), (1581, 1), (1582, 1), (1583, 1), (1584, 1), (1585, 1), (1586, 1),
+    (1587, 1), (1588, 1), (1589, 1), (1590, 1), (1591, 1), (1592, 1), (1593, 1), (1594, 1),
+    (1595, 1), (1596, 1), (1597, 1), (1598, 1), (1599, 1), (1600, 1), (1601, 1), (1602, 1),
+    (1603, 1), (1604, 1), (1605, 1), (1606, 1), (1607, 1), (1608, 1), (1609, 1), (1610, 1),
+    (1611, 1), (1612, 1), (1613, 1), (1614, 1), (1615, 1), (1616, 1), (1617, 1), (1618, 1),
+    (1619, 1), (1620, 1), (1621, 1), (1622, 1), (1623, 1), (1624, 1), (1625, 1), (1626, 1),
+    (1627, 1), (1628, 1), (1629, 1), (1630, 1), (1631, 1), (1632, 1), (1633, 1), (1634, 1),
+    (1635, 1), (1636, 1), (26, 1), (1637, 1), (1638, 1), (1446, 1), (1639, 1), (1640, 1), (1641, 2),
+    (1643, 2), (1645, 2), (1647, 2), (1649, 1), (1650, 1), (1651, 1), (1652, 1), (1653, 1),
+    (1654, 1), (1655, 1), (1656, 1), (1657, 1), (1658, 1), (1659, 1), (1660, 1), (1661, 1),
+    (1662, 1), (1663, 1), (1664, 1), (1665, 1), (1666, 1), (1667, 1), (1668, 1), (1669, 1),
+    (1670, 1), (1671, 1), (1672, 1), (1673, 1), (1674, 1), (1675, 1), (1676, 1), (1677, 1),
+    (1678, 1), (1679, 1), (1680, 1), (1681, 1), (1682, 1), (1683, 1), (1684, 1), (1685, 1),
+    (1686, 1), (1687, 1), (1688, 1), (1689, 1), (1690, 1), (1691, 1), (1692, 1), (1693, 1),
+    (1694, 1), (1695, 1), (1696, 1), (1697, 1), (1698, 1), (1699, 1), (1700, 1), (1701, 1),
+    (1702, 1), (1703, 1), (1704, 1), (1705, 1), (1706, 1), (1707, 1), (1708, 1), (1709, 1),
+    (1710, 1), (1711, 1), (1712, 1), (1713, 1), (1714, 1), (1715, 1), (1716, 1), (1717, 1),
+    (1718, 1), (1719, 1), (1720, 1), (1721, 1), (1722, 1), (1723, 1), (1724, 1), (1725, 1),
+    (1726, 1), (1727, 1), (1728, 1), (1729, 1), (1730, 1), (1731, 1), (1732, 1), (1733, 1),
+    (1734, 1), (1735, 1), (1736, 1), (1737, 1), (1738, 1), (1739, 1), (1740, 1), (1741, 1),
+    (1423, 1), (1429, 1), (1742, 1), (1743, 1), (1744, 1), (1745, 1), (1746, 1), (1747, 1),
+    (1427, 1), (1748, 1), (1749, 1), (1750, 1), (1751, 1), (1431, 1), (1752, 3), (1755, 3),
+    (1758, 3), (1761, 3), (1764, 3), (1767, 3), (1770, 3), (1773, 3), (1776, 3), (1779, 3),
+    (1782, 3), (1785, 3), (1788, 3), (1791, 3), (1794, 3), (1797, 3), (1800, 3), (1803, 3),
+    (1806, 3), (1809, 3), (1812, 3), (1815, 3), (1818, 3), (1821, 3), (1824, 3), (1827, 3),
+    (1830, 3), (1833, 3), (1836, 3), (1839, 4), (1843, 4), (1847, 3), (1850, 3), (1853, 3),
+    (1856, 3), (1859, 3), (1862, 3), (1865, 3), (1868, 3), (1871, 3), (1874, 3), (1877, 3),
+    (1880, 3), (1883, 3), (1886, 3), (1889, 3), (1892, 3), (1895, 3), (1898, 3), (1901, 3),
+    (1904, 3), (1907, 3), (1910, 3), (1913, 3), (1916, 3), (1919, 3), (1922, 3), (1925, 3),
+    (1928, 3), (1931, 3), (1934, 3), (1937, 3), (1940, 3), (1943, 3), (1946, 3), (1949, 3),
+    (1952, 3), (1955, 1), (1956, 1), (1489, 1), (1957, 1), (1958, 3), (1136, 2), (1961, 2), (31, 2),
+    (1963, 2), (1965, 2), (1967, 2), (1969, 2), (1971, 2), (1973, 2), (1975, 2), (1066, 2),
+    (1063, 2), (1977, 2), (1979, 2), (1981, 2), (1649, 1), (1652, 1), (1655, 1), (1657, 1),
+    (1665, 1), (1666, 1), (1669, 1), (1671, 1), (1672, 1), (1674, 1), (1675, 1), (1676, 1),
+    (1677, 1), (1678, 1), (1795, 1), (1798, 1), (1801, 1), (1804, 1), (1807, 1), (1810, 1),
+    (1813, 1), (1816, 1), (1819, 1), (1822, 1), (1825, 1), (1828, 1), (1831, 1), (1834, 1),
+    (1983, 2), (1985, 2), (1987, 1), (1423, 1), (1429, 1), (1742, 1), (1743, 1), (1860, 1),
+    (1863, 1), (1866, 1), (1434, 1), (1872, 1), (1446, 1), (1496, 1), (1508, 1), (1507, 1),
+    (1497, 1), (1589, 1), (1454, 1), (1494, 1), (1899, 1), (1902, 1), (1905, 1), (1908, 1),
+    (1911, 1), (1914, 1), (1917, 1), (1920, 1), (1988, 1), (1989, 1), (1460, 1), (1990, 1),
+    (1991, 1), (1992, 1), (1993, 1), (1994, 1), (1947, 1), (1995, 1), (1996, 1), (1744, 1),
+    (1745, 1), (1746, 1), (1997, 1), (1998, 1), (1999, 1), (2000, 1), (1929, 1), (1932, 1),
+    (1935, 1), (1938, 1), (1941, 1), (2001, 1), (2002, 2), (2004, 2), (2006, 2), (2008, 2),
+    (2010, 2), (41, 2), (1964, 2), (1980, 2), (2012, 2), (2014, 2), (2016, 2), (2018, 2), (2020, 2),
+    (2022, 2), (2024, 2), (2026, 2), (2028, 2), (2030, 2), (2032, 2), (2034, 2), (2036, 2),
+    (2038, 2), (2040, 2), (2042, 2), (2044, 3), (2047, 3), (2050, 3), (2053, 2), (2055, 3),
+    (2058, 2), (2060, 3), (2063, 1), (2064, 1), (2065, 1), (2066, 1), (2067, 1), (2068, 1),
+    (2069, 1), (2070, 1), (2071, 1), (1647, 1), (2072, 1), (2073, 1), (2074, 1), (2075, 1),
+    (2076, 1), (2077, 1), (2078, 1), (2079, 1), (2080, 1), (1648, 1), (2081, 1), (2082, 1),
+    (2083, 1), (2084, 1), (2085, 1), (2086, 1), (2087, 1), (2088, 1), (2089, 1), (2090, 1),
+    (2091, 1), (2092, 1), (2093, 1), (2094, 1), (2095, 1), (2096, 1), (2097, 1), (2098, 1),
+    (2099, 1), (2100, 1), (2101, 1), (2102, 1), (2103, 1), (2104, 1), (2105, 1), (2106, 1),
+    (2107, 1), (2108, 2), (2110, 4), (2114, 4), (2118, 4), (2122, 3), (2125, 4), (2129, 3),
+    (2132, 3), (2135, 5), (2140, 4), (2144, 3), (2147, 3), (2150, 3), (2153, 4), (2157, 4),
+    (2161, 3), (2164, 3), (2167, 2), (2169, 3), (2172, 4), (2176, 4), (2180, 2), (2182, 5),
+    (2187, 6), (2193, 5), (2184, 3), (2198, 5), (2203, 5), (2208, 4), (2212, 3), (2215, 3),
+    (2218, 3), (2221, 4), (2225, 5), (2230, 4), (2234, 3), (2237, 3), (2240, 3), (2243, 2),
+    (2245, 2), (2201, 2), (2247, 2), (2249, 3), (2252, 3), (2255, 5), (2260, 3), (2263, 4),
+    (2267, 5), (2272, 3), (2275, 2), (2277, 2), (2279, 5), (2284, 4), (2288, 5), (2293, 3),
+    (2296, 5), (2301, 2), (2303, 3), (2306, 3), (2309, 3), (2312, 3), (2315, 3), (2318, 4),
+    (2322, 3), (2325, 2), (2327, 3), (2330, 3), (2333, 3), (2336, 4), (2340, 3), (2343, 3),
+    (2346, 3), (2349, 5), (2354, 4), (2358, 2), (2360, 5), (2365, 2), (2367, 4), (2189, 4),
+    (2371, 3), (2374, 3), (2377, 3), (2380, 4), (2384, 2), (2386, 3), (2389, 4), (2393, 2),
+    (2395, 5), (2195, 3), (2400, 2), (2402, 2), (2404, 2), (2406, 2), (2408, 2), (2410, 2),
+    (2412, 2), (2414, 2), (2416, 2), (2418, 2), (2420, 3), (2423, 3), (2426, 3), (2429, 3),
+    (2432, 3), (2435, 3), (2438, 3), (2441, 3), (2444, 3), (2447, 3), (2450, 3), (2453, 3),
+    (2456, 3), (2459, 3), (2462, 3), (2465, 3), (2468, 2), (2470, 2), (2472, 3), (2475, 2),
+    (2477, 2), (2479, 2), (2481, 3), (2484, 3), (2487, 2), (2489, 2), (2491, 2), (2493, 2),
+    (2495, 2), (2497, 4), (2466, 2), (2501, 2), (2503, 2), (2505, 2), (2507, 2), (2509, 2),
+    (2511, 2), (2513, 2), (2515, 3), (2518, 4), (2522, 2), (2524, 2), (2526, 2), (2528, 2),
+    (2530, 2), (2532, 2), (2534, 2), (2536, 3), (2539, 3), (2533, 3), (2542, 3), (2545, 2),
+    (2547, 2), (2549, 2), (10, 2), (2551, 2), (2553, 2), (2555, 2), (2557, 2), (2559, 2), (2561, 2),
+    (2563, 3), (2566, 3), (2482, 2), (2569, 3), (2572, 3), (2575, 3), (2485, 2), (2578, 3),
+    (2581, 3), (2584, 4), (2466, 2), (2588, 3), (2591, 3), (2594, 3), (2597, 3), (2600, 5),
+    (2605, 6), (2611, 2), (2613, 2), (2615, 2), (2617, 2), (2619, 2), (2621, 2), (2623, 2),
+    (2625, 2), (2627, 2), (2625, 2), (2629, 2), (2631, 2), (2633, 2), (2635, 2), (2637, 2),
+    (2635, 2), (2639, 2), (2641, 2), (2643, 2), (1027, 2), (2, 2), (2645, 4), (2649, 2), (2651, 2),
+    (2653, 2), (2465, 2), (2655, 2), (2657, 2), (2561, 2), (2659, 2), (11, 2), (2661, 2), (2663, 3),
+    (2666, 2), (2511, 2), (2668, 3), (2671, 3), (2674, 2), (2676, 3), (2679, 2), (2604, 2),
+    (2681, 2), (2683, 2), (2685, 3), (2688, 3), (2691, 2), (2693, 2), (2695, 2), (2697, 2),
+    (2699, 2), (2701, 2), (2703, 2), (2705, 2), (2707, 2), (2709, 3), (2712, 3), (2715, 3),
+    (2718, 3), (2721, 3), (2724, 3), (2727, 3), (2730, 3), (2733, 3), (2736, 3), (2739, 3),
+    (2742, 3), (2745, 3), (2748, 3), (2751, 3), (2754, 3), (2757, 3), (2760, 3), (2763, 3),
+    (2766, 3), (2769, 3), (2772, 3), (2775, 3), (2778, 1), (2779, 1), (2780, 1), (2781, 1),
+    (2782, 1), (622, 1), (2783, 1), (2784, 1), (2785, 1), (2786, 1), (2787, 1), (2788, 1),
+    (2789, 1), (2790, 1), (2791, 1), (2792, 1), (2793, 1), (2794, 1), (2795, 1), (2796, 1),
+    (2797, 1), (2798, 1), (2799, 1), (2800, 1), (2801, 1), (2802, 1), (2803, 1), (2804, 1),
+    (2805, 1), (2806, 1), (2807, 1), (2808, 1), (2809, 1), (2810, 1), (2811, 1), (2812, 1),
+    (2813, 1), (375, 1), (377, 1), (2814, 1), (2815, 1), (2816, 1), (2817, 1), (2818, 1), (2819, 1),
+    (2820, 1), (2821, 1), (2822, 1), (2823, 1), (2824, 1), (2825, 1), (2826, 1), (2827, 1),
+    (2828, 1), (2829, 1), (2830, 1), (2831, 1), (2832, 1), (2833, 1), (2834, 1), (2835, 1),
+    (2836, 1), (2837, 1), (2838, 1), (2839, 1), (2840, 1), (2841, 1), (2842, 1), (2843, 1),
+    (2844, 1), (2845, 1), (2846, 1), (2847, 1), (2848, 1), (2849, 1), (2850, 1), (2851, 1),
+    (2851, 1), (2852, 1), (2853, 1), (2854, 1), (2855, 1), (2856, 1), (2857, 1), (2858, 1),
+    (2859, 1), (2860, 1), (680, 1), (2861, 1), (2862, 1), (2863, 1), (2864, 1), (2865, 1),
+    (2866, 1), (2867, 1), (2868, 1), (2869, 1), (2870, 1), (2871, 1), (2872, 1), (251, 1), (671, 1),
+    (679, 1), (2873, 1), (681, 1), (2874, 1), (2875, 1), (683, 1), (2876, 1), (2877, 1), (2878, 1),
+    (2879, 1), (2880, 1), (2881, 1), (2882, 1), (2883, 1), (2884, 1), (692, 1), (2885, 1),
+    (2886, 1), (2887, 1), (2888, 1), (97, 1), (121, 1), (2816, 1), (2889, 1), (1357, 1), (2890, 1),
+    (2891, 1), (2892, 1), (2893, 1), (2894, 1), (2895, 1), (2896, 1), (2897, 1), (2898, 1),
+    (2899, 1), (2900, 1), (2901, 1), (2902, 1), (2903, 1), (2904, 1), (2905, 1), (2906, 1),
+    (2907, 1), (2908, 1), (2909, 1), (2910, 1), (2911, 1), (2912, 1), (2913, 1), (2914, 1),
+    (2915, 1), (2916, 1), (2917, 1), (2918, 1), (2919, 1), (2920, 1), (2921, 1), (2922, 1),
+    (2923, 1), (2924, 1), (2925, 1), (2926, 1), (2927, 1), (2928, 1), (2929, 1), (2930, 1),
+    (2931, 1), (2932, 1), (2933, 1), (2934, 1), (2935, 1), (2936, 1), (2937, 1), (2938, 1),
+    (2939, 1), (2940, 1), (2941, 1), (2942, 1), (2943, 1), (2944, 1), (2945, 1), (2946, 1),
+    (2947, 1), (2948, 1), (2949, 1), (2950, 1), (2951, 1), (2952, 1), (2953, 1), (2954, 1),
+    (2955, 1), (2956, 1), (2957, 1), (2958, 1), (2959, 1), (2960, 1), (2961, 1), (2962, 1),
+    (2963, 1), (2964, 1), (2965, 1), (2966, 1), (2967, 1), (2968, 1), (2969, 1), (2970, 1),
+    (2971, 1), (2972, 1), (2973, 1), (1581, 1), (2974, 1), (2975, 1), (2976, 1), (2977, 1),
+    (1635, 1), (1635, 1), (2978, 1), (1589, 1), (2979, 1), (2980, 1), (2981, 1), (2982, 1),
+    (2983, 1), (2984, 1), (2985, 1), (2986, 1), (2987, 1), (2988, 1), (2989, 1), (2990, 1),
+    (2991, 1), (2992, 1), (2993, 1), (2994, 1), (2995, 1), (2996, 1), (2997, 1), (2998, 1),
+    (2999, 1), (3000, 1), (3001, 1), (3002, 1), (3003, 1), (3004, 1), (3005, 1), (3006, 1),
+    (3007, 1), (3008, 1), (3009, 1), (3010, 1), (3011, 1), (3012, 1), (3013, 1), (3014, 1),
+    (3015, 1), (3016, 1), (3017, 1), (3018, 1), (3019, 1), (1547, 1), (3020, 1), (3021, 1),
+    (3022, 1), (3023, 1), (3024, 1), (3025, 1), (3026, 1), (3027, 1), (3028, 1), (3029, 1),
+    (3030, 1), (1620, 1), (3031, 1), (3032, 1), (3033, 1), (3034, 1), (3035, 1), (3036, 1),
+    (3037, 1), (3038, 1), (3039, 1), (3040, 1), (3041, 1), (3042, 1), (3043, 1), (3044, 1),
+    (3045, 1), (3046, 1), (3047, 1), (3048, 1), (3049, 1), (3050, 1), (3051, 1), (3052, 1),
+    (3053, 1), (3054, 1), (3055, 1), (3056, 1), (3057, 1), (2988, 1), (3058, 1), (3059, 1),
+    (3060, 1), (3061, 1), (3062, 1), (3063, 1), (3064, 1), (3065, 1), (3066, 1), (3067, 1),
+    (3068, 1), (3069, 1), (3070, 1), (3071, 1), (3072, 1), (3073, 1), (3074, 1), (3075, 1),
+    (3076, 1), (3077, 1), (1583, 1), (3078, 1), (3079, 1), (3080, 1), (3081, 1), (3082, 1),
+    (3083, 1), (3084, 1), (3085, 1), (3086, 1), (3087, 1), (3088, 1), (3089, 1), (3090, 1),
+    (3091, 1), (3092, 1), (1460, 1), (3093, 1), (3094, 1), (3095, 1), (3096, 1), (3097, 1),
+    (3098, 1), (3099, 1), (3100, 1), (1441, 1), (3101, 1), (3102, 1), (3103, 1), (3104, 1),
+    (3105, 1), (3106, 1), (3107, 1), (3108, 1), (3109, 1), (3110, 1), (3111, 1), (3112, 1),
+    (3113, 1), (3114, 1), (3115, 1), (3116, 1), (3117, 1), (3118, 1), (3119, 1), (3120, 1),
+    (3121, 1), (3122, 1), (3076, 1), (3123, 1), (3124, 1), (3125, 1), (3126, 1), (3127, 1),
+    (3128, 1), (2108, 1), (3129, 1), (3060, 1), (3130, 1), (3131, 1), (3132, 1), (3133, 1),
+    (3134, 1), (3135, 1), (3136, 1), (3137, 1), (3138, 1), (3139, 1), (3140, 1), (3141, 1),
+    (3142, 1), (3143, 1), (3144, 1), (3145, 1), (3146, 1), (3147, 1), (3148, 1), (3149, 1),
+    (2988, 1), (3150, 1), (3151, 1), (3152, 1), (3153, 1), (1634, 1), (3154, 1), (3155, 1),
+    (3156, 1), (3157, 1), (3158, 1), (3159, 1), (3160, 1), (3161, 1), (3162, 1), (3163, 1),
+    (3164, 1), (3165, 1), (1863, 1), (3166, 1), (3167, 1), (3168, 1), (3169, 1), (3170, 1),
+    (3171, 1), (3172, 1), (3173, 1), (3174, 1), (3062, 1), (3175, 1), (3176, 1), (3177, 1),
+    (3178, 1), (3179, 1), (3180, 1), (3181, 1), (3182, 1), (3183, 1), (3184, 1), (3185, 1),
+    (3186, 1), (3187, 1), (1588, 1), (3188, 1), (3189, 1), (3190, 1), (3191, 1), (3192, 1),
+    (3193, 1), (3194, 1), (3195, 1), (3196, 1), (3197, 1), (3198, 1), (3199, 1), (3200, 1),
+    (1539, 1), (3201, 1), (3202, 1), (3203, 1), (3204, 1), (3205, 1), (3206, 1), (3207, 1),
+    (3208, 1), (3209, 1), (3210, 1), (3211, 1), (3212, 1), (3213, 1), (3214, 1), (3215, 1),
+    (3216, 1), (1566, 1), (3217, 1), (1569, 1), (3218, 1), (3219, 1), (3220, 1), (3221, 1),
+    (3222, 1), (3223, 1), (3224, 1), (3225, 1), (3226, 1), (3227, 1), (3228, 1), (3229, 1),
+    (3230, 1), (3231, 1), (1546, 1), (3232, 1), (3233, 1), (3234, 1), (3235, 1), (3236, 1),
+    (3237, 1), (3238, 1), (3239, 1), (3240, 1), (3241, 1), (3242, 1), (3243, 1), (3244, 1),
+    (3245, 1), (3246, 1), (3247, 1), (3248, 1), (3249, 1), (3250, 1), (3251, 1), (3252, 1),
+    (3253, 1), (1467, 1), (3254, 1), (3255, 1), (3256, 1), (3257, 1), (3258, 1), (3259, 1),
+    (3260, 1), (3261, 1), (3262, 1), (3263, 1), (3264, 1), (3265, 1), (3266, 1), (3267, 1),
+    (3268, 1), (1905, 1), (3269, 1), (3270, 1), (3271, 1), (3272, 1), (1917, 1), (3273, 1),
+    (3274, 1), (3275, 1), (3276, 1), (3277, 1), (3112, 1), (3278, 1), (3279, 1), (3280, 1),
+    (3281, 1), (3282, 1), (3283, 1), (3283, 1), (3284, 1), (3285, 1), (3286, 1), (3287, 1),
+    (3288, 1), (3289, 1), (3290, 1), (3291, 1), (3234, 1), (3292, 1), (3293, 1), (3294, 1),
+    (3295, 1), (3296, 1), (3297, 1), (3298, 1), (3299, 1), (3300, 1), (3301, 1), (3302, 1),
+    (3303, 1), (3304, 1), (3305, 1), (3248, 1), (3306, 1), (3307, 1), (3308, 1), (3221, 1),
+    (3309, 1), (3310, 1), (3311, 1), (3312, 1), (3313, 1), (3314, 1), (3315, 1), (3316, 1),
+    (3317, 1), (3318, 1), (3319, 1), (3320, 1), (3256, 1), (3321, 1), (3257, 1), (3322, 1),
+    (3323, 1), (3324, 1), (3325, 1), (3326, 1), (3222, 1), (3009, 1), (3327, 1), (3328, 1),
+    (1500, 1), (3077, 1), (3159, 1), (3329, 1), (3330, 1), (3264, 1), (3331, 1), (3265, 1),
+    (3332, 1), (3333, 1), (3334, 1), (3224, 1), (3335, 1), (3336, 1), (3337, 1), (3338, 1),
+    (3339, 1), (3225, 1), (3340, 1), (3341, 1), (3342, 1), (3343, 1), (3344, 1), (3345, 1),
+    (3277, 1), (3346, 1), (3347, 1), (3112, 1), (3348, 1), (3281, 1), (3349, 1), (3350, 1),
+    (3351, 1), (3352, 1), (3353, 1), (3286, 1), (3354, 1), (3233, 1), (3355, 1), (3287, 1),
+    (3058, 1), (3356, 1), (3288, 1), (3357, 1), (3290, 1), (3358, 1), (3359, 1), (3360, 1),
+    (3361, 1), (3362, 1), (3292, 1), (3230, 1), (3363, 1), (3293, 1), (3364, 1), (3294, 1),
+    (3365, 1), (1635, 1), (3366, 1), (3367, 1), (3368, 1), (3369, 1), (3370, 1), (3371, 1),
+    (3372, 1), (3373, 1), (3374, 1), (3375, 1), (3376, 1), (3377, 2), (3379, 2), (3381, 2),
+    (3378, 3), (3383, 3), (18, 2), (18, 2), (3386, 2), (3388, 2), (3390, 2), (3392, 2), (3394, 2),
+    (3396, 2), (3398, 2), (3400, 1), (1043, 1), (1046, 1), (3401, 1), (3402, 1), (3403, 1),
+    (3404, 1), (3405, 1), (3406, 1), (1015, 1), (3407, 2), (3409, 2), (3411, 3), (3414, 3),
+    (3417, 2), (3419, 2), (3421, 2), (3423, 2), (3425, 2), (3427, 2), (3429, 2), (3431, 2),
+    (3433, 2), (3435, 2), (3437, 2), (3439, 2), (3441, 2), (3443, 2), (3445, 2), (3447, 2),
+    (3449, 2), (3451, 2), (3453, 2), (3455, 2), (3457, 2), (3459, 2), (3411, 2), (3461, 2),
+    (3463, 2), (3465, 2), (3467, 2), (3469, 2), (3471, 2), (3473, 1), (3473, 1), (3474, 1),
+    (3474, 1), (3474, 1), (3474, 1), (3475, 1), (3475, 1), (3475, 1), (3475, 1), (3476, 1),
+    (3476, 1), (3476, 1), (3476, 1), (3477, 1), (3477, 1), (3477, 1), (3477, 1), (3478, 1),
+    (3478, 1), (3478, 1), (3478, 1), (3479, 1), (3479, 1), (3479, 1), (3479, 1), (3480, 1),
+    (3480, 1), (3480, 1), (3480, 1), (3481, 1), (3481, 1), (3481, 1), (3481, 1), (3482, 1),
+    (3482, 1), (3482, 1), (3482, 1), (3483, 1), (3483, 1), (3483, 1), (3483, 1), (3484, 1),
+    (3484, 1), (3484, 1), (3484, 1), (3485, 1), (3485, 1), (3485, 1), (3485, 1), (3486, 1),
+    (3486, 1), (3487, 1), (3487, 1), (3488, 1), (3488, 1), (3489, 1), (3489, 1), (3490, 1),
+    (3490, 1), (3491, 1), (3491, 1), (3492, 1), (3492, 1), (3492, 1), (3492, 1), (3493, 1),
+    (3493, 1), (3493, 1), (3493, 1), (3494, 1), (3494, 1), (3494, 1), (3494, 1), (3495, 1),
+    (3495, 1), (3495, 1), (3495, 1), (3496, 1), (3496, 1), (3497, 1), (3497, 1), (3497, 1),
+    (3497, 1), (3498, 1), (3498, 1), (3499, 1), (3499, 1), (3499, 1), (3499, 1), (3500, 1),
+    (3500, 1), (3500, 1), (3500, 1), (3501, 1), (3501, 1), (3502, 1), (3502, 1), (3503, 1),
+    (3503, 1), (3503, 1), (3503, 1), (524, 1), (524, 1), (3504, 1), (3504, 1), (3505, 1), (3505, 1),
+    (524, 2), (3506, 1), (3506, 1), (3507, 1), (3507, 1), (3508, 1), (3508, 1), (3509, 1),
+    (3509, 1), (3509, 1), (3509, 1), (3510, 1), (3510, 1), (3511, 2), (3511, 2), (3513, 2),
+    (3513, 2), (3515, 2), (3515, 2), (3517, 2), (3517, 2), (3519, 2), (3519, 2), (3521, 2),
+    (3521, 2), (3523, 2), (3523, 2), (3523, 2), (3525, 2), (3525, 2), (3525, 2), (3527, 1),
+    (3527, 1), (3527, 1), (3527, 1), (3528, 2), (3530, 2), (3532, 2), (3525, 2), (3534, 2),
+    (3536, 2), (3538, 2), (3540, 2), (3542, 2), (3544, 2), (3546, 2), (3548, 2), (3550, 2),
+    (3552, 2), (3554, 2), (3556, 2), (3558, 2), (3560, 2), (3562, 2), (3564, 2), (3566, 2),
+    (3568, 2), (3570, 2), (3569, 2), (3572, 2), (3574, 2), (3576, 2), (3578, 2), (3580, 2),
+    (3582, 2), (3584, 2), (3586, 2), (3588, 2), (3590, 2), (3592, 2), (3594, 2), (3596, 2),
+    (3598, 2), (3600, 2), (3602, 2), (3604, 2), (3606, 2), (3608, 2), (3610, 2), (3612, 2),
+    (3614, 2), (3616, 2), (3618, 2), (3620, 2), (3622, 2), (3624, 2), (3626, 2), (3628, 2),
+    (3630, 2), (3632, 2), (3634, 2), (3636, 2), (3638, 2), (3640, 2), (3642, 2), (3644, 2),
+    (3646, 2), (3648, 2), (3650, 2), (3652, 2), (3654, 2), (3656, 2), (3658, 2), (3660, 2),
+    (3662, 2), (3571, 2), (3573, 2), (3664, 2), (3666, 2), (3668, 2), (3670, 2), (3672, 2),
+    (3674, 2), (3676, 2), (3678, 2), (3680, 2), (3682, 2), (3684, 2), (3686, 2), (3688, 2),
+    (3567, 2), (3690, 2), (3692, 2), (3661, 2), (3694, 2), (3689, 2), (3696, 2), (3698, 2),
+    (3700, 2), (3702, 3), (3705, 3), (3708, 3), (3711, 3), (3714, 3), (3717, 3), (3720, 2),
+    (3722, 2), (3532, 2), (3724, 2), (3525, 2), (3534, 2), (3726, 2), (3728, 2), (3542, 2),
+    (3730, 2), (3544, 2), (3546, 2), (3732, 2), (3734, 2), (3554, 2), (3736, 2), (3556, 2),
+    (3558, 2), (3738, 2), (3740, 2), (3562, 2), (3742, 2), (3564, 2), (3566, 2), (3622, 2),
+    (3624, 2), (3630, 2), (3632, 2), (3634, 2), (3642, 2), (3644, 2), (3646, 2), (3648, 2),
+    (3656, 2), (3658, 2), (3660, 2), (3744, 2), (3664, 2), (3746, 2), (3748, 2), (3676, 2),
+    (3750, 2), (3678, 2), (3680, 2), (3700, 2), (3752, 2), (3754, 2), (3661, 2), (3669, 2),
+    (3694, 2), (3689, 2), (3528, 2), (3530, 2), (3756, 2), (3532, 2), (3758, 2), (3536, 2),
+    (3538, 2), (3540, 2), (3542, 2), (3760, 2), (3548, 2), (3550, 2), (3552, 2), (3554, 2),
+    (3762, 2), (3562, 2), (3568, 2), (3570, 2), (3569, 2), (3572, 2), (3574, 2), (3578, 2),
+    (3580, 2), (3582, 2), (3584, 2), (3586, 2), (3588, 2), (3764, 2), (3590, 2), (3592, 2),
+    (3594, 2), (3596, 2), (3598, 2), (3600, 2), (3604, 2), (3606, 2), (3608, 2), (3610, 2),
+    (3612, 2), (3614, 2), (3616, 2), (3618, 2), (3620, 2), (3626, 2), (3628, 2), (3636, 2),
+    (3638, 2), (3640, 2), (3642, 2), (3644, 2), (3650, 2), (3652, 2), (3654, 2), (3656, 2),
+    (3766, 2), (3662, 2), (3571, 2), (3573, 2), (3664, 2), (3670, 2), (3672, 2), (3674, 2),
+    (3676, 2), (3768, 2), (3682, 2), (3684, 2), (3770, 2), (3567, 2), (3690, 2), (3692, 2),
+    (3661, 2), (3681, 2), (3532, 2), (3758, 2), (3542, 2), (3760, 2), (3554, 2), (3762, 2),
+    (3562, 2), (3772, 2), (3586, 2), (3774, 2), (3776, 2), (3778, 2), (3642, 2), (3644, 2),
+    (3656, 2), (3676, 2), (3768, 2), (3661, 2), (3681, 2), (3780, 3), (3783, 3), (3786, 3),
+    (3789, 2), (3791, 2), (3793, 2), (3795, 2), (3797, 2), (3799, 2), (3801, 2), (3803, 2),
+    (3805, 2), (3807, 2), (3809, 2), (3691, 2), (3811, 2), (3813, 2), (3815, 2), (3693, 2),
+    (3817, 2), (3819, 2), (3821, 2), (3823, 2), (3825, 2), (3827, 2), (3829, 2), (3776, 2),
+    (3831, 2), (3833, 2), (3835, 2), (3837, 2), (3789, 2), (3791, 2), (3793, 2), (3795, 2),
+    (3797, 2), (3799, 2), (3801, 2), (3803, 2), (3805, 2), (3807, 2), (3809, 2), (3691, 2),
+    (3811, 2), (3813, 2), (3815, 2), (3693, 2), (3817, 2), (3819, 2), (3821, 2), (3823, 2),
+    (3825, 2), (3827, 2), (3829, 2), (3776, 2), (3831, 2), (3833, 2), (3835, 2), (3837, 2),
+    (3825, 2), (3827, 2), (3829, 2), (3776, 2), (3774, 2), (3778, 2), (3602, 2), (3580, 2),
+    (3582, 2), (3584, 2), (3825, 2), (3827, 2), (3829, 2), (3602, 2), (3604, 2), (3839, 2),
+    (3839, 2), (3841, 3), (3844, 3), (3844, 3), (3847, 3), (3850, 3), (3853, 3), (3856, 3),
+    (3859, 3), (3570, 3), (3570, 3), (3862, 3), (3865, 3), (3868, 3), (3871, 3), (3874, 3),
+    (3877, 3), (3877, 3), (3880, 3), (3883, 3), (3883, 3), (3886, 3), (3886, 3), (3889, 3),
+    (3892, 3), (3892, 3), (3895, 3), (3898, 3), (3898, 3), (3901, 3), (3901, 3), (3904, 3),
+    (3907, 3), (3907, 3), (3910, 3), (3910, 3), (3913, 3), (3916, 3), (3919, 3), (3922, 3),
+    (3922, 3), (3925, 3), (3928, 3), (3931, 3), (3934, 3), (3937, 3), (3937, 3), (3940, 3),
+    (3943, 3), (3946, 3), (3949, 3), (3952, 3), (3955, 3), (3955, 3), (3958, 3), (3958, 3),
+    (3961, 3), (3961, 3), (3964, 3), (3571, 3), (3967, 3), (3970, 3), (3662, 3), (3573, 3),
+    (3973, 3), (3976, 3), (3979, 3), (3982, 3), (3985, 3), (3988, 3), (3991, 3), (3991, 3),
+    (3994, 3), (3997, 3), (4000, 3), (4003, 3), (4003, 3), (4006, 3), (4009, 3), (4012, 3),
+    (4015, 3), (4018, 3), (4021, 3), (4024, 3), (4027, 3), (4030, 3), (4033, 3), (4036, 3),
+    (4039, 3), (4042, 3), (4045, 3), (4048, 3), (4051, 3), (3690, 3), (4054, 3), (4057, 3),
+    (4060, 3), (4063, 3), (4066, 3), (3940, 3), (3946, 3), (4069, 3), (4072, 3), (4075, 3),
+    (4078, 3), (4081, 3), (4084, 3), (4081, 3), (4075, 3), (4087, 3), (4090, 3), (4093, 3),
+    (4096, 3), (4099, 3), (4084, 3), (3919, 3), (3889, 3), (4102, 3), (4105, 3), (4108, 3),
+    (4111, 3), (4114, 4), (4118, 4), (4122, 4), (4126, 4), (4130, 4), (4134, 4), (4138, 4),
+    (4142, 3), (4145, 18), (4163, 8), (4171, 4), (4175, 1), (4176, 1), (1302, 1), (279, 1),
+    (999, 1), (1003, 1), (4177, 1), (4178, 1), (4179, 1), (4180, 1), (4181, 1), (4181, 1),
+    (1018, 1), (1019, 1), (4182, 1), (4183, 1), (4184, 1), (4185, 1), (4186, 1), (4187, 1),
+    (4188, 1), (4189, 1), (1133, 1), (1134, 1), (4190, 1), (4191, 1), (4192, 1), (4193, 1),
+    (4194, 1), (4195, 1), (1001, 2), (1001, 2), (1001, 2), (1001, 2), (4181, 1), (4181, 1),
+    (4181, 1), (4175, 1), (4176, 1), (279, 1), (1302, 1), (1003, 1), (999, 1), (4179, 1), (1018, 1),
+    (1019, 1), (4182, 1), (4183, 1), (4184, 1), (4185, 1), (4196, 1), (4197, 1), (4198, 1),
+    (1015, 1), (4199, 1), (4200, 1), (4201, 1), (1017, 1), (4202, 1), (4203, 1), (4204, 1),
+    (4205, 1), (4206, 2), (4208, 2), (3702, 2), (3705, 2), (3708, 2), (3780, 2), (3711, 2),
+    (3783, 2), (3714, 2), (3786, 2), (3717, 2), (4210, 2), (4212, 2), (4214, 2), (4216, 1),
+    (4217, 1), (4217, 1), (4218, 1), (4218, 1), (4219, 1), (4219, 1), (4220, 1), (4220, 1),
+    (3511, 1), (3511, 1), (3511, 1), (3511, 1), (520, 1), (520, 1), (3536, 1), (3536, 1), (3536, 1),
+    (3536, 1), (4221, 1), (4221, 1), (3548, 1), (3548, 1), (3548, 1), (3548, 1), (3560, 1),
+    (3560, 1), (3560, 1), (3560, 1), (3529, 1), (3529, 1), (3529, 1), (3529, 1), (3531, 1),
+    (3531, 1), (3531, 1), (3531, 1), (3541, 1), (3541, 1), (3541, 1), (3541, 1), (4125, 1),
+    (4125, 1), (3696, 1), (3696, 1), (3698, 1), (3698, 1), (3723, 1), (3723, 1), (3580, 1),
+    (3580, 1), (3580, 1), (3580, 1), (3776, 1), (3776, 1), (3776, 1), (3776, 1), (3588, 1),
+    (3588, 1), (3588, 1), (3588, 1), (3592, 1), (3592, 1), (3592, 1), (3592, 1), (3600, 1),
+    (3600, 1), (3600, 1), (3600, 1), (3604, 1), (3604, 1), (3604, 1), (3604, 1), (3606, 1),
+    (3606, 1), (3606, 1), (3606, 1), (3610, 1), (3610, 1), (3610, 1), (3610, 1), (3614, 1),
+    (3614, 1), (3614, 1), (3614, 1), (3626, 1), (3626, 1), (3626, 1), (3626, 1), (3634, 1),
+    (3634, 1), (3634, 1), (3634, 1), (3643, 1), (3643, 1), (3643, 1), (3643, 1), (3533, 1),
+    (3533, 1), (3533, 1), (3533, 1), (3670, 1), (3670, 1), (3670, 1), (3670, 1), (3682, 1),
+    (3682, 1), (3682, 1), (3682, 1), (522, 1), (522, 1), (3510, 1), (3510, 1), (526, 1), (526, 1),
+    (526, 1), (526, 1), (4222, 2), (4222, 2), (4224, 2), (4224, 2), (4226, 2), (4226, 2), (4167, 2),
+    (4167, 2), (999, 1), (4228, 1), (4196, 1), (4203, 1), (4204, 1), (4197, 1), (4229, 1),
+    (1018, 1), (1019, 1), (4198, 1), (1015, 1), (4175, 1), (4199, 1), (1637, 1), (1021, 1),
+    (1009, 1), (38, 1), (31, 1), (32, 1), (41, 1), (1010, 1), (1011, 1), (1012, 1), (1013, 1),
+    (1014, 1), (1302, 1), (279, 1), (4200, 1), (1017, 1), (4201, 1), (1003, 1), (4205, 1), (0, 1),
+    (1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1),
+    (12, 1), (13, 1), (14, 1), (15, 1), (16, 1), (17, 1), (18, 1), (19, 1), (20, 1), (21, 1),
+    (22, 1), (23, 1), (24, 1), (25, 1), (4194, 1), (4202, 1), (4195, 1), (4230, 1), (4181, 1),
+    (976, 1), (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1),
+    (10, 1), (11, 1), (12, 1), (13, 1), (14, 1), (15, 1), (16, 1), (17, 1), (18, 1), (19, 1),
+    (20, 1), (21, 1), (22, 1), (23, 1), (24, 1), (25, 1), (4182, 1), (4231, 1), (4183, 1),
+    (4232, 1), (4233, 1), (4234, 1), (1637, 1), (4190, 1), (4191, 1), (4176, 1), (4235, 1),
+    (2107, 1), (2117, 1), (2285, 1), (4236, 1), (2291, 1), (2133, 1), (4237, 1), (2173, 1),
+    (2352, 1), (2155, 1), (2112, 1), (2063, 1), (2064, 1), (2065, 1), (2066, 1), (2067, 1),
+    (2068, 1), (2069, 1), (2070, 1), (2071, 1), (1647, 1), (2072, 1), (2073, 1), (2074, 1),
+    (2075, 1), (2076, 1), (2077, 1), (2078, 1), (2079, 1), (2080, 1), (1648, 1), (2081, 1),
+    (2082, 1), (2083, 1), (2084, 1), (2085, 1), (2086, 1), (2087, 1), (2088, 1), (2089, 1),
+    (2090, 1), (2091, 1), (2092, 1), (2093, 1), (2094, 1), (2095, 1), (2096, 1), (2097, 1),
+    (2098, 1), (2099, 1), (2100, 1), (2101, 1), (2102, 1), (2103, 1), (2104, 1), (2119, 1),
+    (1642, 1), (1644, 1), (1649, 1), (1650, 1), (1651, 1), (1652, 1), (1653, 1), (1654, 1),
+    (1655, 1), (1656, 1), (1657, 1), (1658, 1), (1659, 1), (1660, 1), (1661, 1), (1662, 1),
+    (1663, 1), (1664, 1), (1665, 1), (1666, 1), (1667, 1), (1668, 1), (1669, 1), (1670, 1),
+    (1671, 1), (1672, 1), (1673, 1), (1674, 1), (1675, 1), (1676, 1), (1677, 1), (1678, 1),
+    (1679, 1), (1680, 1), (1681, 1), (1682, 1), (1683, 1), (1684, 1), (1685, 1), (1686, 1),
+    (1687, 1), (1688, 1), (1689, 1), (1690, 1), (1691, 1), (1692, 1), (1693, 1), (1694, 1),
+    (1695, 1), (1696, 1), (1697, 1), (1698, 1), (1699, 1), (4238, 1), (4239, 1), (4240, 1), (29, 2),
+    (4241, 1), (4242, 1), (4243, 1), (4244, 1), (4245, 1), (4246, 1), (4247, 1), (4248, 1),
+    (4249, 1), (4250, 1), (4251, 1), (4252, 1), (4253, 1), (4254, 1), (4255, 1), (4256, 1),
+    (4257, 1), (4258, 1), (4259, 1), (4260, 1), (4261, 1), (4262, 1), (4263, 1), (4264, 1),
+    (4265, 1), (4266, 1), (4267, 1), (4268, 1), (4269, 1), (4270, 1), (4271, 1), (4272, 1),
+    (4273, 1), (4274, 1), (4275, 1), (4276, 1), (4277, 1), (4278, 1), (4279, 1), (4280, 1),
+    (4281, 1), (4282, 1), (4283, 1), (4284, 1), (4285, 1), (4286, 1), (4287, 1), (4288, 1),
+    (4289, 1), (4290, 1), (4291, 1), (4292, 1), (4293, 1), (4294, 1), (4295, 1), (4296, 1),
+    (4297, 1), (4298, 1), (4299, 1), (4300, 1), (4301, 1), (4302, 1), (4303, 1), (4304, 1),
+    (4305, 1), (4306, 1), (4307, 1), (4308, 1), (4309, 1), (4310, 1), (4311, 1), (4312, 1),
+    (4313, 1), (4314, 1), (4315, 1), (4316, 1), (4317, 1), (4318, 1), (4319, 1), (4320, 1),
+    (4321, 1), (4322, 1), (4323, 1), (4324, 1), (4325, 1), (4326, 1), (4327, 1), (4328, 1),
+    (4329, 1), (4330, 1), (4331, 1), (4332, 1), (4333, 1), (4334, 1), (4335, 1), (4336, 1),
+    (4337, 1), (4338, 1), (4339, 1), (4340, 1), (4341, 1), (4342, 1), (4343, 1), (4344, 1),
+    (4345, 1), (4346, 1), (4347, 1), (4348, 1), (4349, 1), (4350, 1), (4351, 1), (4352, 1),
+    (4353, 1), (4354, 1), (4355, 1), (4356, 1), (4357, 1), (4358, 1), (4359, 1), (4360, 1),
+    (4361, 1), (4362, 1), (4363, 1), (4364, 1), (4365, 1), (4366, 1), (4367, 1), (4368, 1),
+    (4369, 1), (4370, 1), (4371, 1), (4372, 1), (4373, 1), (4374, 1), (4375, 1), (4376, 1),
+    (4377, 1), (4378, 1), (4379, 1), (4380, 1), (4381, 1), (4382, 1), (4383, 1), (4384, 1),
+    (4385, 1), (4386, 1), (4387, 1), (4388, 1), (4389, 1), (4390, 1), (4391, 1), (4392, 1),
+    (4393, 1), (4394, 1), (4395, 1), (4396, 1), (4397, 1), (4398, 1), (4399, 1), (4400, 1),
+    (4401, 1), (4402, 1), (4403, 1), (4404, 1), (4405, 1), (4406, 1), (4407, 1), (4408, 1),
+    (4409, 1), (4410, 1), (4411, 1), (4412, 1), (4413, 1), (4414, 1), (4415, 1), (4416, 1),
+    (4417, 1), (4418, 1), (4419, 1), (4420, 1), (4421, 1), (4422, 1), (4423, 1), (4424, 1),
+    (4425, 1), (4426, 1), (4427, 1), (4428, 1), (4429, 1), (4430, 1), (4431, 1), (4432, 1),
+    (4433, 1), (4434, 1), (4435, 1), (4436, 1), (4437, 1), (4438, 1), (4439, 1), (4440, 1),
+    (4441, 1), (4442, 2), (4444, 2), (4446, 3), (4449, 3), (4452, 3), (4455, 3), (4458, 3),
+    (4461, 2), (4463, 2), (4465, 3), (4468, 3), (4471, 3), (4474, 3), (0, 1), (1, 1), (2, 1),
+    (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1), (13, 1),
+    (14, 1), (15, 1), (16, 1), (17, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1), (23, 1),
+    (24, 1), (25, 1), (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1),
+    (9, 1), (10, 1), (11, 1), (12, 1), (13, 1), (14, 1), (15, 1), (16, 1), (17, 1), (18, 1),
+    (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1), (25, 1), (0, 1), (1, 1), (2, 1), (3, 1),
+    (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1), (13, 1), (14, 1),
+    (15, 1), (16, 1), (17, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1),
+    (25, 1), (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (8, 1), (9, 1), (10, 1),
+    (11, 1), (12, 1), (13, 1), (14, 1), (15, 1), (16, 1), (17, 1), (18, 1), (19, 1), (20, 1),
+    (21, 1), (22, 1), (23, 1), (24, 1), (25, 1), (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1),
+    (6, 1), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1), (13, 1), (14, 1), (15, 1), (16, 1),
+    (17, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1), (25, 1), (0, 1), (1, 1),
+    (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1),
+    (13, 1), (14, 1), (15, 1), (16, 1), (17, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1),
+    (23, 1), (24, 1), (25, 1), (0, 1), (2, 1), (3, 1), (6, 1), (9, 1), (10, 1), (13, 1), (14, 1),
+    (15, 1), (16, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1), (25, 1),
+    (0, 1), (1, 1), (2, 1), (3, 1), (5, 1), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1),
+    (13, 1), (15, 1), (16, 1), (17, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1), (23, 1),
+    (24, 1), (25, 1), (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1),
+    (9, 1), (10, 1), (11, 1), (12, 1), (13, 1), (14, 1), (15, 1), (16, 1), (17, 1), (18, 1),
+    (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1), (25, 1), (0, 1), (1, 1), (2, 1), (3, 1),
+    (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1), (13, 1), (14, 1),
+    (15, 1), (16, 1), (17, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1),
+    (25, 1), (0, 1), (1, 1), (3, 1), (4, 1), (5, 1), (6, 1), (9, 1), (10, 1), (11, 1), (12, 1),
+    (13, 1), (14, 1), (15, 1), (16, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1), (23, 1),
+    (24, 1), (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1),
+    (10, 1), (11, 1), (12, 1), (13, 1), (14, 1), (15, 1), (16, 1), (17, 1), (18, 1), (19, 1),
+    (20, 1), (21, 1), (22, 1), (23, 1), (24, 1), (25, 1), (0, 1), (1, 1), (3, 1), (4, 1), (5, 1),
+    (6, 1), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1), (14, 1), (18, 1), (19, 1), (20, 1), (21, 1),
+    (22, 1), (23, 1), (24, 1), (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1),
+    (8, 1), (9, 1), (10, 1), (11, 1), (12, 1), (13, 1), (14, 1), (15, 1), (16, 1), (17, 1), (18, 1),
+    (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1), (25, 1), (0, 1), (1, 1), (2, 1), (3, 1),
+    (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1), (13, 1), (14, 1),
+    (15, 1), (16, 1), (17, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1),
+    (25, 1), (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1),
+    (10, 1), (11, 1), (12, 1), (13, 1), (14, 1), (15, 1), (16, 1), (17, 1), (18, 1), (19, 1),
+    (20, 1), (21, 1), (22, 1), (23, 1), (24, 1), (25, 1), (0, 1), (1, 1), (2, 1), (3, 1), (4, 1),
+    (5, 1), (6, 1), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1), (13, 1), (14, 1), (15, 1),
+    (16, 1), (17, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1), (25, 1),
+    (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1), (10, 1),
+    (11, 1), (12, 1), (13, 1), (14, 1), (15, 1), (16, 1), (17, 1), (18, 1), (19, 1), (20, 1),
+    (21, 1), (22, 1), (23, 1), (24, 1), (25, 1), (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1),
+    (6, 1), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1), (13, 1), (14, 1), (15, 1), (16, 1),
+    (17, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1), (25, 1), (0, 1), (1, 1),
+    (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1),
+    (13, 1), (14, 1), (15, 1), (16, 1), (17, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1),
+    (23, 1), (24, 1), (25, 1), (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1),
+    (8, 1), (9, 1), (10, 1), (11, 1), (12, 1), (13, 1), (14, 1), (15, 1), (16, 1), (17, 1), (18, 1),
+    (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1), (25, 1), (0, 1), (1, 1), (2, 1), (3, 1),
+    (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1), (13, 1), (14, 1),
+    (15, 1), (16, 1), (17, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1),
+    (25, 1), (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1),
+    (10, 1), (11, 1), (12, 1), (13, 1), (14, 1), (15, 1), (16, 1), (17, 1), (18, 1), (19, 1),
+    (20, 1), (21, 1), (22, 1), (23, 1), (24, 1), (25, 1), (0, 1), (1, 1), (2, 1), (3, 1), (4, 1),
+    (5, 1), (6, 1), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1), (13, 1), (14, 1), (15, 1),
+    (16, 1), (17, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1), (25, 1),
+    (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1), (10, 1),
+    (11, 1), (12, 1), (13, 1), (14, 1), (15, 1), (16, 1), (17, 1), (18, 1), (19, 1), (20, 1),
+    (21, 1), (22, 1), (23, 1), (24, 1), (25, 1), (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1),
+    (6, 1), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1), (13, 1), (14, 1), (15, 1), (16, 1),
+    (17, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1), (25, 1), (4477, 1),
+    (4478, 1), (291, 1), (292, 1), (293, 1), (294, 1), (295, 1), (296, 1), (297, 1), (298, 1),
+    (272, 1), (299, 1), (300, 1), (35, 1), (301, 1), (302, 1), (303, 1), (304, 1), (305, 1),
+    (298, 1), (306, 1), (307, 1), (308, 1), (309, 1), (310, 1), (311, 1), (312, 1), (4479, 1),
+    (291, 1), (292, 1), (293, 1), (294, 1), (295, 1), (296, 1), (297, 1), (298, 1), (272, 1),
+    (299, 1), (300, 1), (35, 1), (301, 1), (302, 1), (303, 1), (304, 1), (305, 1), (306, 1),
+    (306, 1), (307, 1), (308, 1), (309, 1), (310, 1), (311, 1), (312, 1), (4480, 1), (295, 1),
+    (298, 1), (299, 1), (309, 1), (305, 1), (304, 1), (291, 1), (292, 1), (293, 1), (294, 1),
+    (295, 1), (296, 1), (297, 1), (298, 1), (272, 1), (299, 1), (300, 1), (35, 1), (301, 1),
+    (302, 1), (303, 1), (304, 1), (305, 1), (298, 1), (306, 1), (307, 1), (308, 1), (309, 1),
+    (310, 1), (311, 1), (312, 1), (4479, 1), (291, 1), (292, 1), (293, 1), (294, 1), (295, 1),
+    (296, 1), (297, 1), (298, 1), (272, 1), (299, 1), (300, 1), (35, 1), (301, 1), (302, 1),
+    (303, 1), (304, 1), (305, 1), (306, 1), (306, 1), (307, 1), (308, 1), (309, 1), (310, 1),
+    (311, 1), (312, 1), (4480, 1), (295, 1), (298, 1), (299, 1), (309, 1), (305, 1), (304, 1),
+    (291, 1), (292, 1), (293, 1), (294, 1), (295, 1), (296, 1), (297, 1), (298, 1), (272, 1),
+    (299, 1), (300, 1), (35, 1), (301, 1), (302, 1), (303, 1), (304, 1), (305, 1), (298, 1),
+    (306, 1), (307, 1), (308, 1), (309, 1), (310, 1), (311, 1), (312, 1), (4479, 1), (291, 1),
+    (292, 1), (293, 1), (294, 1), (295, 1), (296, 1), (297, 1), (298, 1), (272, 1), (299, 1),
+    (300, 1), (35, 1), (301, 1), (302, 1), (303, 1), (304, 1), (305, 1), (306, 1), (306, 1),
+    (307, 1), (308, 1), (309, 1), (310, 1), (311, 1), (312, 1), (4480, 1), (295, 1), (298, 1),
+    (299, 1), (309, 1), (305, 1), (304, 1), (291, 1), (292, 1), (293, 1), (294, 1), (295, 1),
+    (296, 1), (297, 1), (298, 1), (272, 1), (299, 1), (300, 1), (35, 1), (301, 1), (302, 1),
+    (303, 1), (304, 1), (305, 1), (298, 1), (306, 1), (307, 1), (308, 1), (309, 1), (310, 1),
+    (311, 1), (312, 1), (4479, 1), (291, 1), (292, 1), (293, 1), (294, 1), (295, 1), (296, 1),
+    (297, 1), (298, 1), (272, 1), (299, 1), (300, 1), (35, 1), (301, 1), (302, 1), (303, 1),
+    (304, 1), (305, 1), (306, 1), (306, 1), (307, 1), (308, 1), (309, 1), (310, 1), (311, 1),
+    (312, 1), (4480, 1), (295, 1), (298, 1), (299, 1), (309, 1), (305, 1), (304, 1), (291, 1),
+    (292, 1), (293, 1), (294, 1), (295, 1), (296, 1), (297, 1), (298, 1), (272, 1), (299, 1),
+    (300, 1), (35, 1), (301, 1), (302, 1), (303, 1), (304, 1), (305, 1), (298, 1), (306, 1),
+    (307, 1), (308, 1), (309, 1), (310, 1), (311, 1), (312, 1), (4479, 1), (291, 1), (292, 1),
+    (293, 1), (294, 1), (295, 1), (296, 1), (297, 1), (298, 1), (272, 1), (299, 1), (300, 1),
+    (35, 1), (301, 1), (302, 1), (303, 1), (304, 1), (305, 1), (306, 1), (306, 1), (307, 1),
+    (308, 1), (309, 1), (310, 1), (311, 1), (312, 1), (4480, 1), (295, 1), (298, 1), (299, 1),
+    (309, 1), (305, 1), (304, 1), (318, 1), (318, 1), (1009, 1), (38, 1), (31, 1), (32, 1), (41, 1),
+    (1010, 1), (1011, 1), (1012, 1), (1013, 1), (1014, 1), (1009, 1), (38, 1), (31, 1), (32, 1),
+    (41, 1), (1010, 1), (1011, 1), (1012, 1), (1013, 1), (1014, 1), (1009, 1), (38, 1), (31, 1),
+    (32, 1), (41, 1), (1010, 1), (1011, 1), (1012, 1), (1013, 1), (1014, 1), (1009, 1), (38, 1),
+    (31, 1), (32, 1), (41, 1), (1010, 1), (1011, 1), (1012, 1), (1013, 1), (1014, 1), (1009, 1),
+    (38, 1), (31, 1), (32, 1), (41, 1), (1010, 1), (1011, 1), (1012, 1), (1013, 1), (1014, 1),
+    (4481, 1), (4482, 1), (4483, 1), (4484, 1), (4485, 1), (4486, 1), (4487, 1), (4488, 1),
+    (4489, 1), (4490, 1), (4491, 1), (4492, 1), (4493, 1), (4494, 1), (4495, 1), (4496, 1),
+    (4497, 1), (4498, 1), (4499, 1), (4500, 1), (4501, 1), (4502, 1), (4503, 1), (4504, 1),
+    (4505, 1), (4506, 1), (4507, 1), (4508, 1), (4509, 1), (4510, 1), (4511, 1), (4512, 1),
+    (4513, 1), (4514, 1), (520, 1), (3536, 1), (3529, 1), (4125, 1), (522, 1), (3723, 1), (3531, 1),
+    (3600, 1), (526, 1), (3634, 1), (3643, 1), (3533, 1), (3670, 1), (3580, 1), (3606, 1),
+    (3614, 1), (3588, 1), (3626, 1), (3698, 1), (3776, 1), (3548, 1), (3560, 1), (3541, 1),
+    (3696, 1), (3592, 1), (3604, 1), (3610, 1), (4515, 1), (3496, 1), (4516, 1), (4517, 1),
+    (3536, 1), (3529, 1), (3682, 1), (3531, 1), (526, 1), (3634, 1), (3643, 1), (3533, 1),
+    (3670, 1), (3580, 1), (3606, 1), (3614, 1), (3588, 1), (3626, 1), (3776, 1), (3548, 1),
+    (3560, 1), (3541, 1), (3592, 1), (3610, 1), (3529, 1), (3531, 1), (526, 1), (3643, 1),
+    (3670, 1), (3580, 1), (3606, 1), (3588, 1), (3626, 1), (3776, 1), (3541, 1), (3592, 1),
+    (3610, 1), (3496, 1), (4517, 1), (3536, 1), (3529, 1), (3682, 1), (3531, 1), (3600, 1),
+    (526, 1), (3634, 1), (3533, 1), (3670, 1), (3580, 1), (3606, 1), (3614, 1), (3588, 1),
+    (3626, 1), (3776, 1), (3548, 1), (3560, 1), (3541, 1), (3592, 1), (3604, 1), (3610, 1),
+    (4515, 1), (4516, 1), (520, 1), (3536, 1), (3529, 1), (4125, 1), (3682, 1), (522, 1), (3723, 1),
+    (3531, 1), (3600, 1), (526, 1), (3643, 1), (3533, 1), (3670, 1), (3580, 1), (3606, 1),
+    (3614, 1), (3588, 1), (3626, 1), (3698, 1), (3776, 1), (3548, 1), (3560, 1), (3541, 1),
+    (3696, 1), (3592, 1), (3604, 1), (3610, 1), (3536, 1), (3529, 1), (4125, 1), (522, 1),
+    (3723, 1), (3531, 1), (3600, 1), (526, 1), (3643, 1), (3533, 1), (3670, 1), (3580, 1),
+    (3606, 1), (3614, 1), (3588, 1), (3626, 1), (3698, 1), (3776, 1), (3548, 1), (3560, 1),
+    (3541, 1), (3696, 1), (3592, 1), (3604, 1), (3610, 1), (4518, 2), (4520, 2), (4522, 2),
+    (4524, 2), (4526, 2), (4528, 2), (4530, 2), (4532, 2), (4534, 2), (4536, 2), (1224, 3),
+    (1227, 3), (1230, 3), (1233, 3), (1236, 3), (1239, 3), (1242, 3), (1245, 3), (1248, 3),
+    (1251, 3), (1254, 3), (1257, 3), (1260, 3), (1263, 3), (1266, 3), (1269, 3), (1272, 3),
+    (1275, 3), (1278, 3), (1281, 3), (1284, 3), (1287, 3), (1290, 3), (1293, 3), (1296, 3),
+    (1299, 3), (4538, 3), (2, 1), (17, 1), (2, 2), (4541, 2), (0, 1), (1, 1), (2, 1), (3, 1),
+    (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1), (13, 1), (14, 1),
+    (15, 1), (16, 1), (17, 1), (18, 1), (19, 1), (20, 1), (21, 1), (22, 1), (23, 1), (24, 1),
+    (25, 1), (4543, 2), (2625, 2), (4545, 2), (774, 2), (4547, 3), (4550, 2), (2558, 2), (2480, 2),
+    (4552, 2), (4554, 2), (4556, 2), (4558, 2), (2072, 1), (1486, 1), (4560, 1), (4561, 1),
+    (2243, 1), (1429, 1), (4562, 1), (4563, 1), (1750, 1), (4564, 1), (4565, 1), (4566, 1),
+    (3149, 1), (4567, 1), (4568, 1), (4569, 1), (4570, 1), (4571, 1), (4572, 1), (1522, 1),
+    (4573, 1), (4574, 1), (4575, 1), (4576, 1), (4577, 1), (4578, 1), (1423, 1), (1742, 1),
+    (4579, 1), (1997, 1), (1745, 1), (1998, 1), (4580, 1), (1578, 1), (4581, 1), (4582, 1),
+    (4583, 1), (4584, 1), (4585, 1), (1902, 1), (1496, 1), (4586, 1), (4587, 1), (4588, 1),
+    (4589, 1), (4590, 3), (4593, 3), (4596, 3), (4599, 3), (4602, 3), (4605, 3), (4608, 3),
+    (4611, 3), (4614, 3), (4617, 1), (4618, 1), (1009, 1), (38, 1), (31, 1), (32, 1), (41, 1),
+    (1010, 1), (1011, 1), (1012, 1), (1013, 1), (1014, 1), (4619, 1), (4620, 1), (4621, 1),
+    (4622, 1), (4623, 1), (3242, 1), (4624, 1), (4625, 1), (4626, 1), (4627, 1), (3243, 1),
+    (4628, 1), (4629, 1), (4630, 1), (3244, 1), (4631, 1), (4632, 1), (4633, 1), (4634, 1),
+    (4635, 1), (4636, 1), (4569, 1), (4637, 1), (4638, 1), (4639, 1), (4640, 1), (4641, 1),
+    (3299, 1), (4642, 1), (1439, 1), (4643, 1), (4644, 1), (4645, 1), (4646, 1), (4587, 1),
+    (4647, 1), (4648, 1), (3304, 1), (3245, 1), (3246, 1), (3305, 1), (4649, 1), (4650, 1),
+    (3064, 1), (4651, 1), (3247, 1), (4652, 1), (4653, 1), (4654, 1), (4655, 1), (4655, 1),
+    (4655, 1), (4656, 1), (4657, 1), (4658, 1), (4659, 1), (4660, 1), (4661, 1), (4662, 1),
+    (4663, 1), (4664, 1), (4665, 1), (4666, 1), (4667, 1), (4668, 1), (4669, 1), (4670, 1),
+    (4671, 1), (4672, 1), (4673, 1), (4673, 1), (3307, 1), (4674, 1), (4675, 1), (4676, 1),
+    (4677, 1), (3249, 1), (4678, 1), (4679, 1), (4680, 1), (3209, 1), (4681, 1), (4682, 1),
+    (4683, 1), (4684, 1), (4685, 1), (4686, 1), (4687, 1), (4688, 1), (4689, 1), (4690, 1),
+    (4691, 1), (4692, 1), (4562, 1), (4693, 1), (4694, 1), (4695, 1), (4696, 1), (4697, 1),
+    (4698, 1), (4699, 1), (4700, 1), (4701, 1), (4702, 1), (4703, 1), (4704, 1), (4704, 1),
+    (4705, 1), (4706, 1), (4707, 1), (3060, 1), (4708, 1), (4709, 1), (4710, 1), (4711, 1),
+    (1465, 1), (4712, 1), (4713, 1), (1467, 1), (4714, 1), (4715, 1), (4716, 1), (4717, 1),
+    (4718, 1), (4719, 1), (4720, 1), (4721, 1), (4722, 1), (4723, 1), (4724, 1), (4725, 1),
+    (4726, 1), (4727, 1), (4728, 1), (4729, 1), (4730, 1), (4731, 1), (4732, 1), (4733, 1),
+    (4734, 1), (3008, 1), (4735, 1), (1477, 1), (4736, 1), (4736, 1), (4737, 1), (4738, 1),
+    (4738, 1), (4739, 1), (4740, 1), (4741, 1), (4742, 1), (4743, 1), (4744, 1), (4745, 1),
+    (4746, 1), (4747, 1), (4748, 1), (4749, 1), (4750, 1), (4751, 1), (3254, 1), (4752, 1),
+    (4753, 1), (4754, 1), (4755, 1), (3319, 1), (4755, 1), (4756, 1), (3256, 1), (4757, 1),
+    (4758, 1), (4759, 1), (4760, 1), (3257, 1), (2981, 1), (2490, 1), (4761, 1), (4762, 1),
+    (4763, 1), (4764, 1), (4765, 1), (4766, 1), (4767, 1), (4768, 1), (4769, 1), (4770, 1),
+    (4771, 1), (4772, 1), (4773, 1), (4774, 1), (4775, 1), (4776, 1), (4777, 1), (4778, 1),
+    (4779, 1), (4780, 1), (4781, 1), (3258, 1), (4782, 1), (4783, 1), (4784, 1), (4785, 1),
+    (4786, 1), (4787, 1), (3260, 1), (4788, 1), (4789, 1), (4790, 1), (4791, 1), (4792, 1),
+    (4793, 1), (4794, 1), (4795, 1), (3009, 1), (3327, 1), (4796, 1), (4797, 1), (4798, 1),
+    (4799, 1), (4800, 1), (4801, 1), (4802, 1), (4803, 1), (3261, 1), (4804, 1), (4805, 1),
+    (4806, 1), (4807, 1), (3369, 1), (4808, 1), (4809, 1), (4810, 1), (4811, 1), (4812, 1),
+    (4813, 1), (4814, 1), (4815, 1), (4816, 1), (4817, 1), (4818, 1), (4819, 1), (4820, 1),
+    (3077, 1), (4821, 1), (4822, 1), (4823, 1), (4824, 1), (4825, 1), (4826, 1), (4827, 1),
+    (4828, 1), (4829, 1), (4830, 1), (4831, 1), (3262, 1), (3159, 1), (4832, 1), (4833, 1),
+    (4834, 1), (4835, 1), (4836, 1), (4837, 1), (4838, 1), (4839, 1), (3330, 1), (4840, 1),
+    (4841, 1), (4842, 1), (4843, 1), (4844, 1), (4845, 1), (4846, 1), (4847, 1), (3331, 1),
+    (4848, 1), (4849, 1), (4850, 1), (4851, 1), (4852, 1), (4853, 1), (4854, 1), (4855, 1),
+    (4856, 1), (4857, 1), (4858, 1), (3333, 1), (4859, 1), (4860, 1), (4861, 1), (4862, 1),
+    (4863, 1), (4864, 1), (4865, 1), (4866, 1), (4867, 1), (4868, 1), (4869, 1), (4869, 1),
+    (4870, 1), (4871, 1), (3335, 1), (4872, 1), (4873, 1), (4874, 1), (4875, 1), (4876, 1),
+    (4877, 1), (4878, 1), (3063, 1), (4879, 1), (4880, 1), (4881, 1), (4882, 1), (4883, 1),
+    (4884, 1), (4885, 1), (3341, 1), (4886, 1), (4887, 1), (4888, 1), (4889, 1), (4890, 1),
+    (4891, 1), (4891, 1), (3342, 1), (3371, 1), (4892, 1), (4893, 1), (4894, 1), (4895, 1),
+    (4896, 1), (3026, 1), (3344, 1), (4897, 1), (4898, 1), (3272, 1), (4899, 1), (4900, 1),
+    (3229, 1), (4901, 1), (4902, 1), (3275, 1), (4903, 1), (4904, 1), (4905, 1), (4906, 1),
+    (4906, 1), (4907, 1), (4908, 1), (4909, 1), (4910, 1), (4911, 1), (4912, 1), (4913, 1),
+    (4914, 1), (4915, 1), (4916, 1), (4917, 1), (4918, 1), (4919, 1), (4920, 1), (4921, 1),
+    (4922, 1), (4923, 1), (4924, 1), (4925, 1), (4926, 1), (4927, 1), (4928, 1), (4929, 1),
+    (4930, 1), (4931, 1), (4932, 1), (3281, 1), (4933, 1), (4934, 1), (4935, 1), (4936, 1),
+    (4937, 1), (4938, 1), (4939, 1), (4940, 1), (4941, 1), (4942, 1), (4943, 1), (4944, 1),
+    (4945, 1), (4946, 1), (4947, 1), (4948, 1), (4737, 1), (4949, 1), (4950, 1), (4951, 1),
+    (4952, 1), (4953, 1), (4954, 1), (4955, 1), (4956, 1), (4957, 1), (4958, 1), (4959, 1),
+    (4960, 1), (3080, 1), (4961, 1), (4962, 1), (4963, 1), (4964, 1), (4965, 1), (4966, 1),
+    (3284, 1), (4967, 1), (4968, 1), (4969, 1), (4970, 1), (4971, 1), (4972, 1), (4973, 1),
+    (4974, 1), (4975, 1), (4976, 1), (4977, 1), (4978, 1), (4979, 1), (4980, 1), (4981, 1),
+    (4982, 1), (4983, 1), (4984, 1), (4985, 1), (4986, 1), (3021, 1), (4987, 1), (4988, 1),
+    (4989, 1), (4990, 1), (4991, 1), (4992, 1), (3351, 1), (4993, 1), (4994, 1), (4995, 1),
+    (4996, 1), (4997, 1), (4998, 1), (4999, 1), (1567, 1), (5000, 1), (5001, 1), (5002, 1),
+    (5003, 1), (5004, 1), (5005, 1), (5006, 1), (5007, 1), (5008, 1), (5009, 1), (5010, 1),
+    (3356, 1), (3357, 1), (1574, 1), (5011, 1), (5012, 1), (5013, 1), (5014, 1), (5015, 1),
+    (5016, 1), (5017, 1), (5018, 1), (5019, 1), (5020, 1), (5021, 1), (5022, 1), (3358, 1),
+    (5023, 1), (5024, 1), (5025, 1), (5026, 1), (5027, 1), (5028, 1), (5029, 1), (5030, 1),
+    (5031, 1), (5032, 1), (5033, 1), (5034, 1), (5035, 1), (5036, 1), (5037, 1), (5038, 1),
+    (5039, 1), (5040, 1), (5041, 1), (5042, 1), (5043, 1), (5044, 1), (5045, 1), (5046, 1),
+    (5047, 1), (5048, 1), (5049, 1), (5050, 1), (5051, 1), (5052, 1), (3364, 1), (3364, 1),
+    (5053, 1), (5054, 1), (5055, 1), (5056, 1), (5057, 1), (5058, 1), (5059, 1), (5060, 1),
+    (5061, 1), (5062, 1), (3365, 1), (5063, 1), (5064, 1), (5065, 1), (5066, 1), (5067, 1),
+    (5068, 1), (5069, 1), (5070, 1), (5071, 1), (5072, 1), (1622, 1), (5073, 1), (1626, 1),
+    (5074, 1), (5075, 1), (5076, 1), (5077, 1), (1631, 1), (5078, 1),
+];
+/// Mappings of the mapped code points
+pub(super) static MAPPED: &[char] = &[
+    '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}', '\u{69}',
+    '\u{6a}', '\u{6b}', '\u{6c}', '\u{6d}', '\u{6e}', '\u{6f}', '\u{70}', '\u{71}', '\u{72}',
+    '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7a}', '\u{20}',
+    '\u{20}', '\u{308}', '\u{20}', '\u{304}', '\u{32}', '\u{33}', '\u{20}', '\u{301}', '\u{3bc}',
+    '\u{20}', '\u{327}', '\u{31}', '\u{31}', '\u{2044}', '\u{34}', '\u{31}', '\u{2044}', '\u{32}',
+    '\u{33}', '\u{2044}', '\u{34}', '\u{e0}', '\u{e1}', '\u{e2}', '\u{e3}', '\u{e4}', '\u{e5}',
+    '\u{e6}', '\u{e7}', '\u{e8}', '\u{e9}', '\u{ea}', '\u{eb}', '\u{ec}', '\u{ed}', '\u{ee}',
+    '\u{ef}', '\u{f0}', '\u{f1}', '\u{f2}', '\u{f3}', '\u{f4}', '\u{f5}', '\u{f6}', '\u{f8}',
+    '\u{f9}', '\u{fa}', '\u{fb}', '\u{fc}', '\u{fd}', '\u{fe}', '\u{101}', '\u{103}', '\u{105}',
+    '\u{107}', '\u{109}', '\u{10b}', '\u{10d}', '\u{10f}', '\u{111}', '\u{113}', '\u{115}',
+    '\u{117}', '\u{119}', '\u{11b}', '\u{11d}', '\u{11f}', '\u{121}', '\u{123}', '\u{125}',
+    '\u{127}', '\u{129}', '\u{12b}', '\u{12d}', '\u{12f}', '\u{69}', '\u{307}', '\u{135}',
+    '\u{137}', '\u{13a}', '\u{13c}', '\u{13e}', '\u{6c}', '\u{b7}', '\u{142}', '\u{144}', '\u{146}',
+    '\u{148}', '\u{2bc}', '\u{6e}', '\u{14b}', '\u{14d}', '\u{14f}', '\u{151}', '\u{153}',
+    '\u{155}', '\u{157}', '\u{159}', '\u{15b}', '\u{15d}', '\u{15f}', '\u{161}', '\u{163}',
+    '\u{165}', '\u{167}', '\u{169}', '\u{16b}', '\u{16d}', '\u{16f}', '\u{171}', '\u{173}',
+    '\u{175}', '\u{177}', '\u{ff}', '\u{17a}', '\u{17c}', '\u{17e}', '\u{253}', '\u{183}',
+    '\u{185}', '\u{254}', '\u{188}', '\u{256}', '\u{257}', '\u{18c}', '\u{1dd}', '\u{259}',
+    '\u{25b}', '\u{192}', '\u{260}', '\u{263}', '\u{269}', '\u{268}', '\u{199}', '\u{26f}',
+    '\u{272}', '\u{275}', '\u{1a1}', '\u{1a3}', '\u{1a5}', '\u{280}', '\u{1a8}', '\u{283}',
+    '\u{1ad}', '\u{288}', '\u{1b0}', '\u{28a}', '\u{28b}', '\u{1b4}', '\u{1b6}', '\u{292}',
+    '\u{1b9}', '\u{1bd}', '\u{64}', '\u{17e}', '\u{6c}', '\u{6a}', '\u{6e}', '\u{6a}', '\u{1ce}',
+    '\u{1d0}', '\u{1d2}', '\u{1d4}', '\u{1d6}', '\u{1d8}', '\u{1da}', '\u{1dc}', '\u{1df}',
+    '\u{1e1}', '\u{1e3}', '\u{1e5}', '\u{1e7}', '\u{1e9}', '\u{1eb}', '\u{1ed}', '\u{1ef}',
+    '\u{64}', '\u{7a}', '\u{1f5}', '\u{195}', '\u{1bf}', '\u{1f9}', '\u{1fb}', '\u{1fd}', '\u{1ff}',
+    '\u{201}', '\u{203}', '\u{205}', '\u{207}', '\u{209}', '\u{20b}', '\u{20d}', '\u{20f}',
+    '\u{211}', '\u{213}', '\u{215}', '\u{217}', '\u{219}', '\u{21b}', '\u{21d}', '\u{21f}',
+    '\u{19e}', '\u{223}', '\u{225}', '\u{227}', '\u{229}', '\u{22b}', '\u{22d}', '\u{22f}',
+    '\u{231}', '\u{233}', '\u{2c65}', '\u{23c}', '\u{19a}', '\u{2c66}', '\u{242}', '\u{180}',
+    '\u{289}', '\u{28c}', '\u{247}', '\u{249}', '\u{24b}', '\u{24d}', '\u{24f}', '\u{266}',
+    '\u{279}', '\u{27b}', '\u{281}', '\u{20}', '\u{306}', '\u{20}', '\u{307}', '\u{20}', '\u{30a}',
+    '\u{20}', '\u{328}', '\u{20}', '\u{303}', '\u{20}', '\u{30b}', '\u{295}', '\u{300}', '\u{313}',
+    '\u{308}', '\u{301}', '\u{3b9}', '\u{371}', '\u{373}', '\u{2b9}', '\u{377}', '\u{20}',
+    '\u{3b9}', '\u{3b}', '\u{3f3}', '\u{20}', '\u{308}', '\u{301}', '\u{3ac}', '\u{3ad}', '\u{3ae}',
+    '\u{3af}', '\u{3cc}', '\u{3cd}', '\u{3ce}', '\u{3b1}', '\u{3b2}', '\u{3b3}', '\u{3b4}',
+    '\u{3b5}', '\u{3b6}', '\u{3b7}', '\u{3b8}', '\u{3ba}', '\u{3bb}', '\u{3bd}', '\u{3be}',
+    '\u{3bf}', '\u{3c0}', '\u{3c1}', '\u{3c3}', '\u{3c4}', '\u{3c5}', '\u{3c6}', '\u{3c7}',
+    '\u{3c8}', '\u{3c9}', '\u{3ca}', '\u{3cb}', '\u{3d7}', '\u{3d9}', '\u{3db}', '\u{3dd}',
+    '\u{3df}', '\u{3e1}', '\u{3e3}', '\u{3e5}', '\u{3e7}', '\u{3e9}', '\u{3eb}', '\u{3ed}',
+    '\u{3ef}', '\u{3f8}', '\u{3fb}', '\u{37b}', '\u{37c}', '\u{37d}', '\u{450}', '\u{451}',
+    '\u{452}', '\u{453}', '\u{454}', '\u{455}', '\u{456}', '\u{457}', '\u{458}', '\u{459}',
+    '\u{45a}', '\u{45b}', '\u{45c}', '\u{45d}', '\u{45e}', '\u{45f}', '\u{430}', '\u{431}',
+    '\u{432}', '\u{433}', '\u{434}', '\u{435}', '\u{436}', '\u{437}', '\u{438}', '\u{439}',
+    '\u{43a}', '\u{43b}', '\u{43c}', '\u{43d}', '\u{43e}', '\u{43f}', '\u{440}', '\u{441}',
+    '\u{442}', '\u{443}', '\u{444}', '\u{445}', '\u{446}', '\u{447}', '\u{448}', '\u{449}',
+    '\u{44a}', '\u{44b}', '\u{44c}', '\u{44d}', '\u{44e}', '\u{44f}', '\u{461}', '\u{463}',
+    '\u{465}', '\u{467}', '\u{469}', '\u{46b}', '\u{46d}', '\u{46f}', '\u{471}', '\u{473}',
+    '\u{475}', '\u{477}', '\u{479}', '\u{47b}', '\u{47d}', '\u{47f}', '\u{481}', '\u{48b}',
+    '\u{48d}', '\u{48f}', '\u{491}', '\u{493}', '\u{495}', '\u{497}', '\u{499}', '\u{49b}',
+    '\u{49d}', '\u{49f}', '\u{4a1}', '\u{4a3}', '\u{4a5}', '\u{4a7}', '\u{4a9}', '\u{4ab}',
+    '\u{4ad}', '\u{4af}', '\u{4b1}', '\u{4b3}', '\u{4b5}', '\u{4b7}', '\u{4b9}', '\u{4bb}',
+    '\u{4bd}', '\u{4bf}', '\u{4c2}', '\u{4c4}', '\u{4c6}', '\u{4c8}', '\u{4ca}', '\u{4cc}',
+    '\u{4ce}', '\u{4d1}', '\u{4d3}', '\u{4d5}', '\u{4d7}', '\u{4d9}', '\u{4db}', '\u{4dd}',
+    '\u{4df}', '\u{4e1}', '\u{4e3}', '\u{4e5}', '\u{4e7}', '\u{4e9}', '\u{4eb}', '\u{4ed}',
+    '\u{4ef}', '\u{4f1}', '\u{4f3}', '\u{4f5}', '\u{4f7}', '\u{4f9}', '\u{4fb}', '\u{4fd}',
+    '\u{4ff}', '\u{501}', '\u{503}', '\u{505}', '\u{507}', '\u{509}', '\u{50b}', '\u{50d}',
+    '\u{50f}', '\u{511}', '\u{513}', '\u{515}', '\u{517}', '\u{519}', '\u{51b}', '\u{51d}',
+    '\u{51f}', '\u{521}', '\u{523}', '\u{525}', '\u{527}', '\u{529}', '\u{52b}', '\u{52d}',
+    '\u{52f}', '\u{561}', '\u{562}', '\u{563}', '\u{564}', '\u{565}', '\u{566}', '\u{567}',
+    '\u{568}', '\u{569}', '\u{56a}', '\u{56b}', '\u{56c}', '\u{56d}', '\u{56e}', '\u{56f}',
+    '\u{570}', '\u{571}', '\u{572}', '\u{573}', '\u{574}', '\u{575}', '\u{576}', '\u{577}',
+    '\u{578}', '\u{579}', '\u{57a}', '\u{57b}', '\u{57c}', '\u{57d}', '\u{57e}', '\u{57f}',
+    '\u{580}', '\u{581}', '\u{582}', '\u{583}', '\u{584}', '\u{585}', '\u{586}', '\u{565}',
+    '\u{582}', '\u{627}', '\u{674}', '\u{648}', '\u{674}', '\u{6c7}', '\u{674}', '\u{64a}',
+    '\u{674}', '\u{915}', '\u{93c}', '\u{916}', '\u{93c}', '\u{917}', '\u{93c}', '\u{91c}',
+    '\u{93c}', '\u{921}', '\u{93c}', '\u{922}', '\u{93c}', '\u{92b}', '\u{93c}', '\u{92f}',
+    '\u{93c}', '\u{9a1}', '\u{9bc}', '\u{9a2}', '\u{9bc}', '\u{9af}', '\u{9bc}', '\u{a32}',
+    '\u{a3c}', '\u{a38}', '\u{a3c}', '\u{a16}', '\u{a3c}', '\u{a17}', '\u{a3c}', '\u{a1c}',
+    '\u{a3c}', '\u{a2b}', '\u{a3c}', '\u{b21}', '\u{b3c}', '\u{b22}', '\u{b3c}', '\u{e4d}',
+    '\u{e32}', '\u{ecd}', '\u{eb2}', '\u{eab}', '\u{e99}', '\u{eab}', '\u{ea1}', '\u{f0b}',
+    '\u{f42}', '\u{fb7}', '\u{f4c}', '\u{fb7}', '\u{f51}', '\u{fb7}', '\u{f56}', '\u{fb7}',
+    '\u{f5b}', '\u{fb7}', '\u{f40}', '\u{fb5}', '\u{f71}', '\u{f72}', '\u{f71}', '\u{f74}',
+    '\u{fb2}', '\u{f80}', '\u{fb2}', '\u{f71}', '\u{f80}', '\u{fb3}', '\u{f80}', '\u{fb3}',
+    '\u{f71}', '\u{f80}', '\u{f92}', '\u{fb7}', '\u{f9c}', '\u{fb7}', '\u{fa1}', '\u{fb7}',
+    '\u{fa6}', '\u{fb7}', '\u{fab}', '\u{fb7}', '\u{f90}', '\u{fb5}', '\u{2d27}', '\u{2d2d}',
+    '\u{10dc}', '\u{13f0}', '\u{13f1}', '\u{13f2}', '\u{13f3}', '\u{13f4}', '\u{13f5}', '\u{a64b}',
+    '\u{10d0}', '\u{10d1}', '\u{10d2}', '\u{10d3}', '\u{10d4}', '\u{10d5}', '\u{10d6}', '\u{10d7}',
+    '\u{10d8}', '\u{10d9}', '\u{10da}', '\u{10db}', '\u{10dd}', '\u{10de}', '\u{10df}', '\u{10e0}',
+    '\u{10e1}', '\u{10e2}', '\u{10e3}', '\u{10e4}', '\u{10e5}', '\u{10e6}', '\u{10e7}', '\u{10e8}',
+    '\u{10e9}', '\u{10ea}', '\u{10eb}', '\u{10ec}', '\u{10ed}', '\u{10ee}', '\u{10ef}', '\u{10f0}',
+    '\u{10f1}', '\u{10f2}', '\u{10f3}', '\u{10f4}', '\u{10f5}', '\u{10f6}', '\u{10f7}', '\u{10f8}',
+    '\u{10f9}', '\u{10fa}', '\u{10fd}', '\u{10fe}', '\u{10ff}', '\u{250}', '\u{251}', '\u{1d02}',
+    '\u{25c}', '\u{1d16}', '\u{1d17}', '\u{1d1d}', '\u{1d25}', '\u{252}', '\u{255}', '\u{25f}',
+    '\u{261}', '\u{265}', '\u{26a}', '\u{1d7b}', '\u{29d}', '\u{26d}', '\u{1d85}', '\u{29f}',
+    '\u{271}', '\u{270}', '\u{273}', '\u{274}', '\u{278}', '\u{282}', '\u{1ab}', '\u{1d1c}',
+    '\u{290}', '\u{291}', '\u{1e01}', '\u{1e03}', '\u{1e05}', '\u{1e07}', '\u{1e09}', '\u{1e0b}',
+    '\u{1e0d}', '\u{1e0f}', '\u{1e11}', '\u{1e13}', '\u{1e15}', '\u{1e17}', '\u{1e19}', '\u{1e1b}',
+    '\u{1e1d}', '\u{1e1f}', '\u{1e21}', '\u{1e23}', '\u{1e25}', '\u{1e27}', '\u{1e29}', '\u{1e2b}',
+    '\u{1e2d}', '\u{1e2f}', '\u{1e31}', '\u{1e33}', '\u{1e35}', '\u{1e37}', '\u{1e39}', '\u{1e3b}',
+    '\u{1e3d}', '\u{1e3f}', '\u{1e41}', '\u{1e43}', '\u{1e45}', '\u{1e47}', '\u{1e49}', '\u{1e4b}',
+    '\u{1e4d}', '\u{1e4f}', '\u{1e51}', '\u{1e53}', '\u{1e55}', '\u{1e57}', '\u{1e59}', '\u{1e5b}',
+    '\u{1e5d}', '\u{1e5f}', '\u{1e61}', '\u{1e63}', '\u{1e65}', '\u{1e67}', '\u{1e69}', '\u{1e6b}',
+    '\u{1e6d}', '\u{1e6f}', '\u{1e71}', '\u{1e73}', '\u{1e75}', '\u{1e77}', '\u{1e79}', '\u{1e7b}',
+    '\u{1e7d}', '\u{1e7f}', '\u{1e81}', '\u{1e83}', '\u{1e85}', '\u{1e87}', '\u{1e89}', '\u{1e8b}',
+    '\u{1e8d}', '\u{1e8f}', '\u{1e91}', '\u{1e93}', '\u{1e95}', '\u{61}', '\u{2be}', '\u{73}',
+    '\u{73}', '\u{1ea1}', '\u{1ea3}', '\u{1ea5}', '\u{1ea7}', '\u{1ea9}', '\u{1eab}', '\u{1ead}',
+    '\u{1eaf}', '\u{1eb1}', '\u{1eb3}', '\u{1eb5}', '\u{1eb7}', '\u{1eb9}', '\u{1ebb}', '\u{1ebd}',
+    '\u{1ebf}', '\u{1ec1}', '\u{1ec3}', '\u{1ec5}', '\u{1ec7}', '\u{1ec9}', '\u{1ecb}', '\u{1ecd}',
+    '\u{1ecf}', '\u{1ed1}', '\u{1ed3}', '\u{1ed5}', '\u{1ed7}', '\u{1ed9}', '\u{1edb}', '\u{1edd}',
+    '\u{1edf}', '\u{1ee1}', '\u{1ee3}', '\u{1ee5}', '\u{1ee7}', '\u{1ee9}', '\u{1eeb}', '\u{1eed}',
+    '\u{1eef}', '\u{1ef1}', '\u{1ef3}', '\u{1ef5}', '\u{1ef7}', '\u{1ef9}', '\u{1efb}', '\u{1efd}',
+    '\u{1eff}', '\u{1f00}', '\u{1f01}', '\u{1f02}', '\u{1f03}', '\u{1f04}', '\u{1f05}', '\u{1f06}',
+    '\u{1f07}', '\u{1f10}', '\u{1f11}', '\u{1f12}', '\u{1f13}', '\u{1f14}', '\u{1f15}', '\u{1f20}',
+    '\u{1f21}', '\u{1f22}', '\u{1f23}', '\u{1f24}', '\u{1f25}', '\u{1f26}', '\u{1f27}', '\u{1f30}',
+    '\u{1f31}', '\u{1f32}', '\u{1f33}', '\u{1f34}', '\u{1f35}', '\u{1f36}', '\u{1f37}', '\u{1f40}',
+    '\u{1f41}', '\u{1f42}', '\u{1f43}', '\u{1f44}', '\u{1f45}', '\u{1f51}', '\u{1f53}', '\u{1f55}',
+    '\u{1f57}', '\u{1f60}', '\u{1f61}', '\u{1f62}', '\u{1f63}', '\u{1f64}', '\u{1f65}', '\u{1f66}',
+    '\u{1f67}', '\u{1f00}', '\u{3b9}', '\u{1f01}', '\u{3b9}', '\u{1f02}', '\u{3b9}', '\u{1f03}',
+    '\u{3b9}', '\u{1f04}', '\u{3b9}', '\u{1f05}', '\u{3b9}', '\u{1f06}', '\u{3b9}', '\u{1f07}',
+    '\u{3b9}', '\u{1f20}', '\u{3b9}', '\u{1f21}', '\u{3b9}', '\u{1f22}', '\u{3b9}', '\u{1f23}',
+    '\u{3b9}', '\u{1f24}', '\u{3b9}', '\u{1f25}', '\u{3b9}', '\u{1f26}', '\u{3b9}', '\u{1f27}',
+    '\u{3b9}', '\u{1f60}', '\u{3b9}', '\u{1f61}', '\u{3b9}', '\u{1f62}', '\u{3b9}', '\u{1f63}',
+    '\u{3b9}', '\u{1f64}', '\u{3b9}', '\u{1f65}', '\u{3b9}', '\u{1f66}', '\u{3b9}', '\u{1f67}',
+    '\u{3b9}', '\u{1f70}', '\u{3b9}', '\u{3b1}', '\u{3b9}', '\u{3ac}', '\u{3b9}', '\u{1fb6}',
+    '\u{3b9}', '\u{1fb0}', '\u{1fb1}', '\u{20}', '\u{313}', '\u{20}', '\u{342}', '\u{20}',
+    '\u{308}', '\u{342}', '\u{1f74}', '\u{3b9}', '\u{3b7}', '\u{3b9}', '\u{3ae}', '\u{3b9}',
+    '\u{1fc6}', '\u{3b9}', '\u{1f72}', '\u{20}', '\u{313}', '\u{300}', '\u{20}', '\u{313}',
+    '\u{301}', '\u{20}', '\u{313}', '\u{342}', '\u{390}', '\u{1fd0}', '\u{1fd1}', '\u{1f76}',
+    '\u{20}', '\u{314}', '\u{300}', '\u{20}', '\u{314}', '\u{301}', '\u{20}', '\u{314}', '\u{342}',
+    '\u{3b0}', '\u{1fe0}', '\u{1fe1}', '\u{1f7a}', '\u{1fe5}', '\u{20}', '\u{308}', '\u{300}',
+    '\u{60}', '\u{1f7c}', '\u{3b9}', '\u{3c9}', '\u{3b9}', '\u{3ce}', '\u{3b9}', '\u{1ff6}',
+    '\u{3b9}', '\u{1f78}', '\u{2010}', '\u{20}', '\u{333}', '\u{2032}', '\u{2032}', '\u{2032}',
+    '\u{2032}', '\u{2032}', '\u{2035}', '\u{2035}', '\u{2035}', '\u{2035}', '\u{2035}', '\u{21}',
+    '\u{21}', '\u{20}', '\u{305}', '\u{3f}', '\u{3f}', '\u{3f}', '\u{21}', '\u{21}', '\u{3f}',
+    '\u{30}', '\u{35}', '\u{36}', '\u{37}', '\u{38}', '\u{39}', '\u{2b}', '\u{2212}', '\u{3d}',
+    '\u{28}', '\u{29}', '\u{61}', '\u{2f}', '\u{63}', '\u{61}', '\u{2f}', '\u{73}', '\u{b0}',
+    '\u{63}', '\u{63}', '\u{2f}', '\u{6f}', '\u{63}', '\u{2f}', '\u{75}', '\u{b0}', '\u{66}',
+    '\u{73}', '\u{6d}', '\u{74}', '\u{65}', '\u{6c}', '\u{74}', '\u{6d}', '\u{5d0}', '\u{5d1}',
+    '\u{5d2}', '\u{5d3}', '\u{66}', '\u{61}', '\u{78}', '\u{2211}', '\u{31}', '\u{2044}', '\u{37}',
+    '\u{31}', '\u{2044}', '\u{39}', '\u{31}', '\u{2044}', '\u{31}', '\u{30}', '\u{31}', '\u{2044}',
+    '\u{33}', '\u{32}', '\u{2044}', '\u{33}', '\u{31}', '\u{2044}', '\u{35}', '\u{32}', '\u{2044}',
+    '\u{35}', '\u{33}', '\u{2044}', '\u{35}', '\u{34}', '\u{2044}', '\u{35}', '\u{31}', '\u{2044}',
+    '\u{36}', '\u{35}', '\u{2044}', '\u{36}', '\u{31}', '\u{2044}', '\u{38}', '\u{33}', '\u{2044}',
+    '\u{38}', '\u{35}', '\u{2044}', '\u{38}', '\u{37}', '\u{2044}', '\u{38}', '\u{69}', '\u{69}',
+    '\u{69}', '\u{69}', '\u{69}', '\u{69}', '\u{76}', '\u{76}', '\u{69}', '\u{76}', '\u{69}',
+    '\u{69}', '\u{76}', '\u{69}', '\u{69}', '\u{69}', '\u{69}', '\u{78}', '\u{78}', '\u{69}',
+    '\u{78}', '\u{69}', '\u{69}', '\u{30}', '\u{2044}', '\u{33}', '\u{222b}', '\u{222b}',
+    '\u{222b}', '\u{222b}', '\u{222b}', '\u{222e}', '\u{222e}', '\u{222e}', '\u{222e}', '\u{222e}',
+    '\u{3008}', '\u{3009}', '\u{31}', '\u{32}', '\u{31}', '\u{33}', '\u{31}', '\u{34}', '\u{31}',
+    '\u{35}', '\u{31}', '\u{36}', '\u{31}', '\u{37}', '\u{31}', '\u{38}', '\u{31}', '\u{39}',
+    '\u{32}', '\u{30}', '\u{28}', '\u{31}', '\u{29}', '\u{28}', '\u{32}', '\u{29}', '\u{28}',
+    '\u{33}', '\u{29}', '\u{28}', '\u{34}', '\u{29}', '\u{28}', '\u{35}', '\u{29}', '\u{28}',
+    '\u{36}', '\u{29}', '\u{28}', '\u{37}', '\u{29}', '\u{28}', '\u{38}', '\u{29}', '\u{28}',
+    '\u{39}', '\u{29}', '\u{28}', '\u{31}', '\u{30}', '\u{29}', '\u{28}', '\u{31}', '\u{31}',
+    '\u{29}', '\u{28}', '\u{31}', '\u{32}', '\u{29}', '\u{28}', '\u{31}', '\u{33}', '\u{29}',
+    '\u{28}', '\u{31}', '\u{34}', '\u{29}', '\u{28}', '\u{31}', '\u{35}', '\u{29}', '\u{28}',
+    '\u{31}', '\u{36}', '\u{29}', '\u{28}', '\u{31}', '\u{37}', '\u{29}', '\u{28}', '\u{31}',
+    '\u{38}', '\u{29}', '\u{28}', '\u{31}', '\u{39}', '\u{29}', '\u{28}', '\u{32}', '\u{30}',
+    '\u{29}', '\u{28}', '\u{61}', '\u{29}', '\u{28}', '\u{62}', '\u{29}', '\u{28}', '\u{63}',
+    '\u{29}', '\u{28}', '\u{64}', '\u{29}', '\u{28}', '\u{65}', '\u{29}', '\u{28}', '\u{66}',
+    '\u{29}', '\u{28}', '\u{67}', '\u{29}', '\u{28}', '\u{68}', '\u{29}', '\u{28}', '\u{69}',
+    '\u{29}', '\u{28}', '\u{6a}', '\u{29}', '\u{28}', '\u{6b}', '\u{29}', '\u{28}', '\u{6c}',
+    '\u{29}', '\u{28}', '\u{6d}', '\u{29}', '\u{28}', '\u{6e}', '\u{29}', '\u{28}', '\u{6f}',
+    '\u{29}', '\u{28}', '\u{70}', '\u{29}', '\u{28}', '\u{71}', '\u{29}', '\u{28}', '\u{72}',
+    '\u{29}', '\u{28}', '\u{73}', '\u{29}', '\u{28}', '\u{74}', '\u{29}', '\u{28}', '\u{75}',
+    '\u{29}', '\u{28}', '\u{76}', '\u{29}', '\u{28}', '\u{77}', '\u{29}', '\u{28}', '\u{78}',
+    '\u{29}', '\u{28}', '\u{79}', '\u{29}', '\u{28}', '\u{7a}', '\u{29}', '\u{3a}', '\u{3a}',
+    '\u{3d}', '\u{3d}', '\u{3d}', '\u{2add}', '\u{338}', '\u{2c30}', '\u{2c31}', '\u{2c32}',
+    '\u{2c33}', '\u{2c34}', '\u{2c35}', '\u{2c36}', '\u{2c37}', '\u{2c38}', '\u{2c39}', '\u{2c3a}',
+    '\u{2c3b}', '\u{2c3c}', '\u{2c3d}', '\u{2c3e}', '\u{2c3f}', '\u{2c40}', '\u{2c41}', '\u{2c42}',
+    '\u{2c43}', '\u{2c44}', '\u{2c45}', '\u{2c46}', '\u{2c47}', '\u{2c48}', '\u{2c49}', '\u{2c4a}',
+    '\u{2c4b}', '\u{2c4c}', '\u{2c4d}', '\u{2c4e}', '\u{2c4f}', '\u{2c50}', '\u{2c51}', '\u{2c52}',
+    '\u{2c53}', '\u{2c54}', '\u{2c55}', '\u{2c56}', '\u{2c57}', '\u{2c58}', '\u{2c59}', '\u{2c5a}',
+    '\u{2c5b}', '\u{2c5c}', '\u{2c5d}', '\u{2c5e}', '\u{2c61}', '\u{26b}', '\u{1d7d}', '\u{27d}',
+    '\u{2c68}', '\u{2c6a}', '\u{2c6c}', '\u{2c73}', '\u{2c76}', '\u{23f}', '\u{240}', '\u{2c81}',
+    '\u{2c83}', '\u{2c85}', '\u{2c87}', '\u{2c89}', '\u{2c8b}', '\u{2c8d}', '\u{2c8f}', '\u{2c91}',
+    '\u{2c93}', '\u{2c95}', '\u{2c97}', '\u{2c99}', '\u{2c9b}', '\u{2c9d}', '\u{2c9f}', '\u{2ca1}',
+    '\u{2ca3}', '\u{2ca5}', '\u{2ca7}', '\u{2ca9}', '\u{2cab}', '\u{2cad}', '\u{2caf}', '\u{2cb1}',
+    '\u{2cb3}', '\u{2cb5}', '\u{2cb7}', '\u{2cb9}', '\u{2cbb}', '\u{2cbd}', '\u{2cbf}', '\u{2cc1}',
+    '\u{2cc3}', '\u{2cc5}', '\u{2cc7}', '\u{2cc9}', '\u{2ccb}', '\u{2ccd}', '\u{2ccf}', '\u{2cd1}',
+    '\u{2cd3}', '\u{2cd5}', '\u{2cd7}', '\u{2cd9}', '\u{2cdb}', '\u{2cdd}', '\u{2cdf}', '\u{2ce1}',
+    '\u{2ce3}', '\u{2cec}', '\u{2cee}', '\u{2cf3}', '\u{2d61}', '\u{6bcd}', '\u{9f9f}', '\u{4e00}',
+    '\u{4e28}', '\u{4e36}', '\u{4e3f}', '\u{4e59}', '\u{4e85}', '\u{4e8c}', '\u{4ea0}', '\u{4eba}',
+    '\u{513f}', '\u{5165}', '\u{516b}', '\u{5182}', '\u{5196}', '\u{51ab}', '\u{51e0}', '\u{51f5}',
+    '\u{5200}', '\u{529b}', '\u{52f9}', '\u{5315}', '\u{531a}', '\u{5338}', '\u{5341}', '\u{535c}',
+    '\u{5369}', '\u{5382}', '\u{53b6}', '\u{53c8}', '\u{53e3}', '\u{56d7}', '\u{571f}', '\u{58eb}',
+    '\u{5902}', '\u{590a}', '\u{5915}', '\u{5927}', '\u{5973}', '\u{5b50}', '\u{5b80}', '\u{5bf8}',
+    '\u{5c0f}', '\u{5c22}', '\u{5c38}', '\u{5c6e}', '\u{5c71}', '\u{5ddb}', '\u{5de5}', '\u{5df1}',
+    '\u{5dfe}', '\u{5e72}', '\u{5e7a}', '\u{5e7f}', '\u{5ef4}', '\u{5efe}', '\u{5f0b}', '\u{5f13}',
+    '\u{5f50}', '\u{5f61}', '\u{5f73}', '\u{5fc3}', '\u{6208}', '\u{6236}', '\u{624b}', '\u{652f}',
+    '\u{6534}', '\u{6587}', '\u{6597}', '\u{65a4}', '\u{65b9}', '\u{65e0}', '\u{65e5}', '\u{66f0}',
+    '\u{6708}', '\u{6728}', '\u{6b20}', '\u{6b62}', '\u{6b79}', '\u{6bb3}', '\u{6bcb}', '\u{6bd4}',
+    '\u{6bdb}', '\u{6c0f}', '\u{6c14}', '\u{6c34}', '\u{706b}', '\u{722a}', '\u{7236}', '\u{723b}',
+    '\u{723f}', '\u{7247}', '\u{7259}', '\u{725b}', '\u{72ac}', '\u{7384}', '\u{7389}', '\u{74dc}',
+    '\u{74e6}', '\u{7518}', '\u{751f}', '\u{7528}', '\u{7530}', '\u{758b}', '\u{7592}', '\u{7676}',
+    '\u{767d}', '\u{76ae}', '\u{76bf}', '\u{76ee}', '\u{77db}', '\u{77e2}', '\u{77f3}', '\u{793a}',
+    '\u{79b8}', '\u{79be}', '\u{7a74}', '\u{7acb}', '\u{7af9}', '\u{7c73}', '\u{7cf8}', '\u{7f36}',
+    '\u{7f51}', '\u{7f8a}', '\u{7fbd}', '\u{8001}', '\u{800c}', '\u{8012}', '\u{8033}', '\u{807f}',
+    '\u{8089}', '\u{81e3}', '\u{81ea}', '\u{81f3}', '\u{81fc}', '\u{820c}', '\u{821b}', '\u{821f}',
+    '\u{826e}', '\u{8272}', '\u{8278}', '\u{864d}', '\u{866b}', '\u{8840}', '\u{884c}', '\u{8863}',
+    '\u{897e}', '\u{898b}', '\u{89d2}', '\u{8a00}', '\u{8c37}', '\u{8c46}', '\u{8c55}', '\u{8c78}',
+    '\u{8c9d}', '\u{8d64}', '\u{8d70}', '\u{8db3}', '\u{8eab}', '\u{8eca}', '\u{8f9b}', '\u{8fb0}',
+    '\u{8fb5}', '\u{9091}', '\u{9149}', '\u{91c6}', '\u{91cc}', '\u{91d1}', '\u{9577}', '\u{9580}',
+    '\u{961c}', '\u{96b6}', '\u{96b9}', '\u{96e8}', '\u{9751}', '\u{975e}', '\u{9762}', '\u{9769}',
+    '\u{97cb}', '\u{97ed}', '\u{97f3}', '\u{9801}', '\u{98a8}', '\u{98db}', '\u{98df}', '\u{9996}',
+    '\u{9999}', '\u{99ac}', '\u{9aa8}', '\u{9ad8}', '\u{9adf}', '\u{9b25}', '\u{9b2f}', '\u{9b32}',
+    '\u{9b3c}', '\u{9b5a}', '\u{9ce5}', '\u{9e75}', '\u{9e7f}', '\u{9ea5}', '\u{9ebb}', '\u{9ec3}',
+    '\u{9ecd}', '\u{9ed1}', '\u{9ef9}', '\u{9efd}', '\u{9f0e}', '\u{9f13}', '\u{9f20}', '\u{9f3b}',
+    '\u{9f4a}', '\u{9f52}', '\u{9f8d}', '\u{9f9c}', '\u{9fa0}', '\u{2e}', '\u{3012}', '\u{5344}',
+    '\u{5345}', '\u{20}', '\u{3099}', '\u{20}', '\u{309a}', '\u{3088}', '\u{308a}', '\u{30b3}',
+    '\u{30c8}', '\u{1100}', '\u{1101}', '\u{11aa}', '\u{1102}', '\u{11ac}', '\u{11ad}', '\u{1103}',
+    '\u{1104}', '\u{1105}', '\u{11b0}', '\u{11b1}', '\u{11b2}', '\u{11b3}', '\u{11b4}', '\u{11b5}',
+    '\u{111a}', '\u{1106}', '\u{1107}', '\u{1108}', '\u{1121}', '\u{1109}', '\u{110a}', '\u{110b}',
+    '\u{110c}', '\u{110d}', '\u{110e}', '\u{110f}', '\u{1110}', '\u{1111}', '\u{1112}', '\u{1161}',
+    '\u{1162}', '\u{1163}', '\u{1164}', '\u{1165}', '\u{1166}', '\u{1167}', '\u{1168}', '\u{1169}',
+    '\u{116a}', '\u{116b}', '\u{116c}', '\u{116d}', '\u{116e}', '\u{116f}', '\u{1170}', '\u{1171}',
+    '\u{1172}', '\u{1173}', '\u{1174}', '\u{1175}', '\u{1114}', '\u{1115}', '\u{11c7}', '\u{11c8}',
+    '\u{11cc}', '\u{11ce}', '\u{11d3}', '\u{11d7}', '\u{11d9}', '\u{111c}', '\u{11dd}', '\u{11df}',
+    '\u{111d}', '\u{111e}', '\u{1120}', '\u{1122}', '\u{1123}', '\u{1127}', '\u{1129}', '\u{112b}',
+    '\u{112c}', '\u{112d}', '\u{112e}', '\u{112f}', '\u{1132}', '\u{1136}', '\u{1140}', '\u{1147}',
+    '\u{114c}', '\u{11f1}', '\u{11f2}', '\u{1157}', '\u{1158}', '\u{1159}', '\u{1184}', '\u{1185}',
+    '\u{1188}', '\u{1191}', '\u{1192}', '\u{1194}', '\u{119e}', '\u{11a1}', '\u{4e09}', '\u{56db}',
+    '\u{4e0a}', '\u{4e2d}', '\u{4e0b}', '\u{7532}', '\u{4e19}', '\u{4e01}', '\u{5929}', '\u{5730}',
+    '\u{28}', '\u{1100}', '\u{29}', '\u{28}', '\u{1102}', '\u{29}', '\u{28}', '\u{1103}', '\u{29}',
+    '\u{28}', '\u{1105}', '\u{29}', '\u{28}', '\u{1106}', '\u{29}', '\u{28}', '\u{1107}', '\u{29}',
+    '\u{28}', '\u{1109}', '\u{29}', '\u{28}', '\u{110b}', '\u{29}', '\u{28}', '\u{110c}', '\u{29}',
+    '\u{28}', '\u{110e}', '\u{29}', '\u{28}', '\u{110f}', '\u{29}', '\u{28}', '\u{1110}', '\u{29}',
+    '\u{28}', '\u{1111}', '\u{29}', '\u{28}', '\u{1112}', '\u{29}', '\u{28}', '\u{ac00}', '\u{29}',
+    '\u{28}', '\u{b098}', '\u{29}', '\u{28}', '\u{b2e4}', '\u{29}', '\u{28}', '\u{b77c}', '\u{29}',
+    '\u{28}', '\u{b9c8}', '\u{29}', '\u{28}', '\u{bc14}', '\u{29}', '\u{28}', '\u{c0ac}', '\u{29}',
+    '\u{28}', '\u{c544}', '\u{29}', '\u{28}', '\u{c790}', '\u{29}', '\u{28}', '\u{cc28}', '\u{29}',
+    '\u{28}', '\u{ce74}', '\u{29}', '\u{28}', '\u{d0c0}', '\u{29}', '\u{28}', '\u{d30c}', '\u{29}',
+    '\u{28}', '\u{d558}', '\u{29}', '\u{28}', '\u{c8fc}', '\u{29}', '\u{28}', '\u{c624}',
+    '\u{c804}', '\u{29}', '\u{28}', '\u{c624}', '\u{d6c4}', '\u{29}', '\u{28}', '\u{4e00}',
+    '\u{29}', '\u{28}', '\u{4e8c}', '\u{29}', '\u{28}', '\u{4e09}', '\u{29}', '\u{28}', '\u{56db}',
+    '\u{29}', '\u{28}', '\u{4e94}', '\u{29}', '\u{28}', '\u{516d}', '\u{29}', '\u{28}', '\u{4e03}',
+    '\u{29}', '\u{28}', '\u{516b}', '\u{29}', '\u{28}', '\u{4e5d}', '\u{29}', '\u{28}', '\u{5341}',
+    '\u{29}', '\u{28}', '\u{6708}', '\u{29}', '\u{28}', '\u{706b}', '\u{29}', '\u{28}', '\u{6c34}',
+    '\u{29}', '\u{28}', '\u{6728}', '\u{29}', '\u{28}', '\u{91d1}', '\u{29}', '\u{28}', '\u{571f}',
+    '\u{29}', '\u{28}', '\u{65e5}', '\u{29}', '\u{28}', '\u{682a}', '\u{29}', '\u{28}', '\u{6709}',
+    '\u{29}', '\u{28}', '\u{793e}', '\u{29}', '\u{28}', '\u{540d}', '\u{29}', '\u{28}', '\u{7279}',
+    '\u{29}', '\u{28}', '\u{8ca1}', '\u{29}', '\u{28}', '\u{795d}', '\u{29}', '\u{28}', '\u{52b4}',
+    '\u{29}', '\u{28}', '\u{4ee3}', '\u{29}', '\u{28}', '\u{547c}', '\u{29}', '\u{28}', '\u{5b66}',
+    '\u{29}', '\u{28}', '\u{76e3}', '\u{29}', '\u{28}', '\u{4f01}', '\u{29}', '\u{28}', '\u{8cc7}',
+    '\u{29}', '\u{28}', '\u{5354}', '\u{29}', '\u{28}', '\u{796d}', '\u{29}', '\u{28}', '\u{4f11}',
+    '\u{29}', '\u{28}', '\u{81ea}', '\u{29}', '\u{28}', '\u{81f3}', '\u{29}', '\u{554f}',
+    '\u{5e7c}', '\u{7b8f}', '\u{70}', '\u{74}', '\u{65}', '\u{32}', '\u{32}', '\u{32}', '\u{34}',
+    '\u{32}', '\u{35}', '\u{32}', '\u{36}', '\u{32}', '\u{37}', '\u{32}', '\u{38}', '\u{32}',
+    '\u{39}', '\u{33}', '\u{30}', '\u{33}', '\u{33}', '\u{33}', '\u{34}', '\u{33}', '\u{35}',
+    '\u{cc38}', '\u{ace0}', '\u{c8fc}', '\u{c758}', '\u{c6b0}', '\u{79d8}', '\u{7537}', '\u{9069}',
+    '\u{512a}', '\u{5370}', '\u{6ce8}', '\u{9805}', '\u{5199}', '\u{6b63}', '\u{5de6}', '\u{53f3}',
+    '\u{533b}', '\u{5b97}', '\u{591c}', '\u{33}', '\u{36}', '\u{33}', '\u{37}', '\u{33}', '\u{38}',
+    '\u{33}', '\u{39}', '\u{34}', '\u{30}', '\u{34}', '\u{34}', '\u{34}', '\u{35}', '\u{34}',
+    '\u{36}', '\u{34}', '\u{37}', '\u{34}', '\u{38}', '\u{34}', '\u{39}', '\u{35}', '\u{30}',
+    '\u{31}', '\u{6708}', '\u{32}', '\u{6708}', '\u{33}', '\u{6708}', '\u{34}', '\u{6708}',
+    '\u{35}', '\u{6708}', '\u{36}', '\u{6708}', '\u{37}', '\u{6708}', '\u{38}', '\u{6708}',
+    '\u{39}', '\u{6708}', '\u{31}', '\u{30}', '\u{6708}', '\u{31}', '\u{31}', '\u{6708}', '\u{31}',
+    '\u{32}', '\u{6708}', '\u{68}', '\u{67}', '\u{65}', '\u{72}', '\u{67}', '\u{65}', '\u{76}',
+    '\u{6c}', '\u{74}', '\u{64}', '\u{30a2}', '\u{30a4}', '\u{30a6}', '\u{30a8}', '\u{30aa}',
+    '\u{30ab}', '\u{30ad}', '\u{30af}', '\u{30b1}', '\u{30b5}', '\u{30b7}', '\u{30b9}', '\u{30bb}',
+    '\u{30bd}', '\u{30bf}', '\u{30c1}', '\u{30c4}', '\u{30c6}', '\u{30ca}', '\u{30cb}', '\u{30cc}',
+    '\u{30cd}', '\u{30ce}', '\u{30cf}', '\u{30d2}', '\u{30d5}', '\u{30d8}', '\u{30db}', '\u{30de}',
+    '\u{30df}', '\u{30e0}', '\u{30e1}', '\u{30e2}', '\u{30e4}', '\u{30e6}', '\u{30e8}', '\u{30e9}',
+    '\u{30ea}', '\u{30eb}', '\u{30ec}', '\u{30ed}', '\u{30ef}', '\u{30f0}', '\u{30f1}', '\u{30f2}',
+    '\u{4ee4}', '\u{548c}', '\u{30a2}', '\u{30d1}', '\u{30fc}', '\u{30c8}', '\u{30a2}', '\u{30eb}',
+    '\u{30d5}', '\u{30a1}', '\u{30a2}', '\u{30f3}', '\u{30da}', '\u{30a2}', '\u{30a2}', '\u{30fc}',
+    '\u{30eb}', '\u{30a4}', '\u{30cb}', '\u{30f3}', '\u{30b0}', '\u{30a4}', '\u{30f3}', '\u{30c1}',
+    '\u{30a6}', '\u{30a9}', '\u{30f3}', '\u{30a8}', '\u{30b9}', '\u{30af}', '\u{30fc}', '\u{30c9}',
+    '\u{30a8}', '\u{30fc}', '\u{30ab}', '\u{30fc}', '\u{30aa}', '\u{30f3}', '\u{30b9}', '\u{30aa}',
+    '\u{30fc}', '\u{30e0}', '\u{30ab}', '\u{30a4}', '\u{30ea}', '\u{30ab}', '\u{30e9}', '\u{30c3}',
+    '\u{30c8}', '\u{30ab}', '\u{30ed}', '\u{30ea}', '\u{30fc}', '\u{30ac}', '\u{30ed}', '\u{30f3}',
+    '\u{30ac}', '\u{30f3}', '\u{30de}', '\u{30ae}', '\u{30ac}', '\u{30ae}', '\u{30cb}', '\u{30fc}',
+    '\u{30ad}', '\u{30e5}', '\u{30ea}', '\u{30fc}', '\u{30ae}', '\u{30eb}', '\u{30c0}', '\u{30fc}',
+    '\u{30ad}', '\u{30ed}', '\u{30ad}', '\u{30ed}', '\u{30b0}', '\u{30e9}', '\u{30e0}', '\u{30ad}',
+    '\u{30ed}', '\u{30e1}', '\u{30fc}', '\u{30c8}', '\u{30eb}', '\u{30ad}', '\u{30ed}', '\u{30ef}',
+    '\u{30c3}', '\u{30c8}', '\u{30b0}', '\u{30e9}', '\u{30e0}', '\u{30c8}', '\u{30f3}', '\u{30af}',
+    '\u{30eb}', '\u{30bc}', '\u{30a4}', '\u{30ed}', '\u{30af}', '\u{30ed}', '\u{30fc}', '\u{30cd}',
+    '\u{30b1}', '\u{30fc}', '\u{30b9}', '\u{30b3}', '\u{30eb}', '\u{30ca}', '\u{30b3}', '\u{30fc}',
+    '\u{30dd}', '\u{30b5}', '\u{30a4}', '\u{30af}', '\u{30eb}', '\u{30b5}', '\u{30f3}', '\u{30c1}',
+    '\u{30fc}', '\u{30e0}', '\u{30b7}', '\u{30ea}', '\u{30f3}', '\u{30b0}', '\u{30bb}', '\u{30f3}',
+    '\u{30c1}', '\u{30bb}', '\u{30f3}', '\u{30c8}', '\u{30c0}', '\u{30fc}', '\u{30b9}', '\u{30c7}',
+    '\u{30b7}', '\u{30c9}', '\u{30eb}', '\u{30ca}', '\u{30ce}', '\u{30ce}', '\u{30c3}', '\u{30c8}',
+    '\u{30cf}', '\u{30a4}', '\u{30c4}', '\u{30d1}', '\u{30fc}', '\u{30bb}', '\u{30f3}', '\u{30c8}',
+    '\u{30d1}', '\u{30fc}', '\u{30c4}', '\u{30d0}', '\u{30fc}', '\u{30ec}', '\u{30eb}', '\u{30d4}',
+    '\u{30a2}', '\u{30b9}', '\u{30c8}', '\u{30eb}', '\u{30d4}', '\u{30af}', '\u{30eb}', '\u{30d4}',
+    '\u{30b3}', '\u{30d3}', '\u{30eb}', '\u{30d5}', '\u{30a1}', '\u{30e9}', '\u{30c3}', '\u{30c9}',
+    '\u{30d5}', '\u{30a3}', '\u{30fc}', '\u{30c8}', '\u{30d6}', '\u{30c3}', '\u{30b7}', '\u{30a7}',
+    '\u{30eb}', '\u{30d5}', '\u{30e9}', '\u{30f3}', '\u{30d8}', '\u{30af}', '\u{30bf}', '\u{30fc}',
+    '\u{30eb}', '\u{30da}', '\u{30bd}', '\u{30da}', '\u{30cb}', '\u{30d2}', '\u{30d8}', '\u{30eb}',
+    '\u{30c4}', '\u{30da}', '\u{30f3}', '\u{30b9}', '\u{30da}', '\u{30fc}', '\u{30b8}', '\u{30d9}',
+    '\u{30fc}', '\u{30bf}', '\u{30dd}', '\u{30a4}', '\u{30f3}', '\u{30c8}', '\u{30dc}', '\u{30eb}',
+    '\u{30c8}', '\u{30db}', '\u{30f3}', '\u{30dd}', '\u{30f3}', '\u{30c9}', '\u{30db}', '\u{30fc}',
+    '\u{30eb}', '\u{30db}', '\u{30fc}', '\u{30f3}', '\u{30de}', '\u{30a4}', '\u{30af}', '\u{30ed}',
+    '\u{30de}', '\u{30a4}', '\u{30eb}', '\u{30de}', '\u{30c3}', '\u{30cf}', '\u{30de}', '\u{30eb}',
+    '\u{30af}', '\u{30de}', '\u{30f3}', '\u{30b7}', '\u{30e7}', '\u{30f3}', '\u{30df}', '\u{30af}',
+    '\u{30ed}', '\u{30f3}', '\u{30df}', '\u{30ea}', '\u{30df}', '\u{30ea}', '\u{30d0}', '\u{30fc}',
+    '\u{30eb}', '\u{30e1}', '\u{30ac}', '\u{30e1}', '\u{30ac}', '\u{30c8}', '\u{30f3}', '\u{30e4}',
+    '\u{30fc}', '\u{30c9}', '\u{30e4}', '\u{30fc}', '\u{30eb}', '\u{30e6}', '\u{30a2}', '\u{30f3}',
+    '\u{30ea}', '\u{30c3}', '\u{30c8}', '\u{30eb}', '\u{30ea}', '\u{30e9}', '\u{30eb}', '\u{30d4}',
+    '\u{30fc}', '\u{30eb}', '\u{30fc}', '\u{30d6}', '\u{30eb}', '\u{30ec}', '\u{30e0}', '\u{30ec}',
+    '\u{30f3}', '\u{30c8}', '\u{30b2}', '\u{30f3}', '\u{30}', '\u{70b9}', '\u{31}', '\u{70b9}',
+    '\u{32}', '\u{70b9}', '\u{33}', '\u{70b9}', '\u{34}', '\u{70b9}', '\u{35}', '\u{70b9}',
+    '\u{36}', '\u{70b9}', '\u{37}', '\u{70b9}', '\u{38}', '\u{70b9}', '\u{39}', '\u{70b9}',
+    '\u{31}', '\u{30}', '\u{70b9}', '\u{31}', '\u{31}', '\u{70b9}', '\u{31}', '\u{32}', '\u{70b9}',
+    '\u{31}', '\u{33}', '\u{70b9}', '\u{31}', '\u{34}', '\u{70b9}', '\u{31}', '\u{35}', '\u{70b9}',
+    '\u{31}', '\u{36}', '\u{70b9}', '\u{31}', '\u{37}', '\u{70b9}', '\u{31}', '\u{38}', '\u{70b9}',
+    '\u{31}', '\u{39}', '\u{70b9}', '\u{32}', '\u{30}', '\u{70b9}', '\u{32}', '\u{31}', '\u{70b9}',
+    '\u{32}', '\u{32}', '\u{70b9}', '\u{32}', '\u{33}', '\u{70b9}', '\u{32}', '\u{34}', '\u{70b9}',
+    '\u{68}', '\u{70}', '\u{61}', '\u{64}', '\u{61}', '\u{61}', '\u{75}', '\u{62}', '\u{61}',
+    '\u{72}', '\u{6f}', '\u{76}', '\u{70}', '\u{63}', '\u{64}', '\u{6d}', '\u{64}', '\u{6d}',
+    '\u{32}', '\u{64}', '\u{6d}', '\u{33}', '\u{69}', '\u{75}', '\u{5e73}', '\u{6210}', '\u{662d}',
+    '\u{548c}', '\u{5927}', '\u{6b63}', '\u{660e}', '\u{6cbb}', '\u{682a}', '\u{5f0f}', '\u{4f1a}',
+    '\u{793e}', '\u{6e}', '\u{61}', '\u{3bc}', '\u{61}', '\u{6d}', '\u{61}', '\u{6b}', '\u{61}',
+    '\u{6b}', '\u{62}', '\u{6d}', '\u{62}', '\u{67}', '\u{62}', '\u{63}', '\u{61}', '\u{6c}',
+    '\u{6b}', '\u{63}', '\u{61}', '\u{6c}', '\u{70}', '\u{66}', '\u{6e}', '\u{66}', '\u{3bc}',
+    '\u{66}', '\u{3bc}', '\u{67}', '\u{6d}', '\u{67}', '\u{6b}', '\u{67}', '\u{68}', '\u{7a}',
+    '\u{6b}', '\u{68}', '\u{7a}', '\u{6d}', '\u{68}', '\u{7a}', '\u{74}', '\u{68}', '\u{7a}',
+    '\u{3bc}', '\u{6c}', '\u{6d}', '\u{6c}', '\u{64}', '\u{6c}', '\u{66}', '\u{6d}', '\u{6e}',
+    '\u{6d}', '\u{3bc}', '\u{6d}', '\u{6d}', '\u{6d}', '\u{63}', '\u{6d}', '\u{6b}', '\u{6d}',
+    '\u{6d}', '\u{6d}', '\u{32}', '\u{63}', '\u{6d}', '\u{32}', '\u{6b}', '\u{6d}', '\u{32}',
+    '\u{6d}', '\u{6d}', '\u{33}', '\u{63}', '\u{6d}', '\u{33}', '\u{6b}', '\u{6d}', '\u{33}',
+    '\u{6d}', '\u{2215}', '\u{73}', '\u{6d}', '\u{2215}', '\u{73}', '\u{32}', '\u{6b}', '\u{70}',
+    '\u{61}', '\u{6d}', '\u{70}', '\u{61}', '\u{67}', '\u{70}', '\u{61}', '\u{72}', '\u{61}',
+    '\u{64}', '\u{72}', '\u{61}', '\u{64}', '\u{2215}', '\u{73}', '\u{72}', '\u{61}', '\u{64}',
+    '\u{2215}', '\u{73}', '\u{32}', '\u{70}', '\u{73}', '\u{6e}', '\u{73}', '\u{3bc}', '\u{73}',
+    '\u{6d}', '\u{73}', '\u{70}', '\u{76}', '\u{6e}', '\u{76}', '\u{3bc}', '\u{76}', '\u{6d}',
+    '\u{76}', '\u{6b}', '\u{76}', '\u{70}', '\u{77}', '\u{6e}', '\u{77}', '\u{3bc}', '\u{77}',
+    '\u{6d}', '\u{77}', '\u{6b}', '\u{77}', '\u{6b}', '\u{3c9}', '\u{6d}', '\u{3c9}', '\u{62}',
+    '\u{71}', '\u{63}', '\u{2215}', '\u{6b}', '\u{67}', '\u{64}', '\u{62}', '\u{67}', '\u{79}',
+    '\u{68}', '\u{61}', '\u{69}', '\u{6e}', '\u{6b}', '\u{6b}', '\u{6b}', '\u{74}', '\u{6c}',
+    '\u{6e}', '\u{6c}', '\u{6f}', '\u{67}', '\u{6c}', '\u{78}', '\u{6d}', '\u{69}', '\u{6c}',
+    '\u{6d}', '\u{6f}', '\u{6c}', '\u{70}', '\u{68}', '\u{70}', '\u{70}', '\u{6d}', '\u{70}',
+    '\u{72}', '\u{73}', '\u{76}', '\u{77}', '\u{62}', '\u{76}', '\u{2215}', '\u{6d}', '\u{61}',
+    '\u{2215}', '\u{6d}', '\u{31}', '\u{65e5}', '\u{32}', '\u{65e5}', '\u{33}', '\u{65e5}',
+    '\u{34}', '\u{65e5}', '\u{35}', '\u{65e5}', '\u{36}', '\u{65e5}', '\u{37}', '\u{65e5}',
+    '\u{38}', '\u{65e5}', '\u{39}', '\u{65e5}', '\u{31}', '\u{30}', '\u{65e5}', '\u{31}', '\u{31}',
+    '\u{65e5}', '\u{31}', '\u{32}', '\u{65e5}', '\u{31}', '\u{33}', '\u{65e5}', '\u{31}', '\u{34}',
+    '\u{65e5}', '\u{31}', '\u{35}', '\u{65e5}', '\u{31}', '\u{36}', '\u{65e5}', '\u{31}', '\u{37}',
+    '\u{65e5}', '\u{31}', '\u{38}', '\u{65e5}', '\u{31}', '\u{39}', '\u{65e5}', '\u{32}', '\u{30}',
+    '\u{65e5}', '\u{32}', '\u{31}', '\u{65e5}', '\u{32}', '\u{32}', '\u{65e5}', '\u{32}', '\u{33}',
+    '\u{65e5}', '\u{32}', '\u{34}', '\u{65e5}', '\u{32}', '\u{35}', '\u{65e5}', '\u{32}', '\u{36}',
+    '\u{65e5}', '\u{32}', '\u{37}', '\u{65e5}', '\u{32}', '\u{38}', '\u{65e5}', '\u{32}', '\u{39}',
+    '\u{65e5}', '\u{33}', '\u{30}', '\u{65e5}', '\u{33}', '\u{31}', '\u{65e5}', '\u{67}', '\u{61}',
+    '\u{6c}', '\u{a641}', '\u{a643}', '\u{a645}', '\u{a647}', '\u{a649}', '\u{a64d}', '\u{a64f}',
+    '\u{a651}', '\u{a653}', '\u{a655}', '\u{a657}', '\u{a659}', '\u{a65b}', '\u{a65d}', '\u{a65f}',
+    '\u{a661}', '\u{a663}', '\u{a665}', '\u{a667}', '\u{a669}', '\u{a66b}', '\u{a66d}', '\u{a681}',
+    '\u{a683}', '\u{a685}', '\u{a687}', '\u{a689}', '\u{a68b}', '\u{a68d}', '\u{a68f}', '\u{a691}',
+    '\u{a693}', '\u{a695}', '\u{a697}', '\u{a699}', '\u{a69b}', '\u{a723}', '\u{a725}', '\u{a727}',
+    '\u{a729}', '\u{a72b}', '\u{a72d}', '\u{a72f}', '\u{a733}', '\u{a735}', '\u{a737}', '\u{a739}',
+    '\u{a73b}', '\u{a73d}', '\u{a73f}', '\u{a741}', '\u{a743}', '\u{a745}', '\u{a747}', '\u{a749}',
+    '\u{a74b}', '\u{a74d}', '\u{a74f}', '\u{a751}', '\u{a753}', '\u{a755}', '\u{a757}', '\u{a759}',
+    '\u{a75b}', '\u{a75d}', '\u{a75f}', '\u{a761}', '\u{a763}', '\u{a765}', '\u{a767}', '\u{a769}',
+    '\u{a76b}', '\u{a76d}', '\u{a76f}', '\u{a77a}', '\u{a77c}', '\u{1d79}', '\u{a77f}', '\u{a781}',
+    '\u{a783}', '\u{a785}', '\u{a787}', '\u{a78c}', '\u{a791}', '\u{a793}', '\u{a797}', '\u{a799}',
+    '\u{a79b}', '\u{a79d}', '\u{a79f}', '\u{a7a1}', '\u{a7a3}', '\u{a7a5}', '\u{a7a7}', '\u{a7a9}',
+    '\u{26c}', '\u{29e}', '\u{287}', '\u{ab53}', '\u{a7b5}', '\u{a7b7}', '\u{a7b9}', '\u{a7bb}',
+    '\u{a7bd}', '\u{a7bf}', '\u{a7c3}', '\u{a794}', '\u{1d8e}', '\u{a7c8}', '\u{a7ca}', '\u{a7f6}',
+    '\u{ab37}', '\u{ab52}', '\u{28d}', '\u{13a0}', '\u{13a1}', '\u{13a2}', '\u{13a3}', '\u{13a4}',
+    '\u{13a5}', '\u{13a6}', '\u{13a7}', '\u{13a8}', '\u{13a9}', '\u{13aa}', '\u{13ab}', '\u{13ac}',
+    '\u{13ad}', '\u{13ae}', '\u{13af}', '\u{13b0}', '\u{13b1}', '\u{13b2}', '\u{13b3}', '\u{13b4}',
+    '\u{13b5}', '\u{13b6}', '\u{13b7}', '\u{13b8}', '\u{13b9}', '\u{13ba}', '\u{13bb}', '\u{13bc}',
+    '\u{13bd}', '\u{13be}', '\u{13bf}', '\u{13c0}', '\u{13c1}', '\u{13c2}', '\u{13c3}', '\u{13c4}',
+    '\u{13c5}', '\u{13c6}', '\u{13c7}', '\u{13c8}', '\u{13c9}', '\u{13ca}', '\u{13cb}', '\u{13cc}',
+    '\u{13cd}', '\u{13ce}', '\u{13cf}', '\u{13d0}', '\u{13d1}', '\u{13d2}', '\u{13d3}', '\u{13d4}',
+    '\u{13d5}', '\u{13d6}', '\u{13d7}', '\u{13d8}', '\u{13d9}', '\u{13da}', '\u{13db}', '\u{13dc}',
+    '\u{13dd}', '\u{13de}', '\u{13df}', '\u{13e0}', '\u{13e1}', '\u{13e2}', '\u{13e3}', '\u{13e4}',
+    '\u{13e5}', '\u{13e6}', '\u{13e7}', '\u{13e8}', '\u{13e9}', '\u{13ea}', '\u{13eb}', '\u{13ec}',
+    '\u{13ed}', '\u{13ee}', '\u{13ef}', '\u{8c48}', '\u{66f4}', '\u{8cc8}', '\u{6ed1}', '\u{4e32}',
+    '\u{53e5}', '\u{5951}', '\u{5587}', '\u{5948}', '\u{61f6}', '\u{7669}', '\u{7f85}', '\u{863f}',
+    '\u{87ba}', '\u{88f8}', '\u{908f}', '\u{6a02}', '\u{6d1b}', '\u{70d9}', '\u{73de}', '\u{843d}',
+    '\u{916a}', '\u{99f1}', '\u{4e82}', '\u{5375}', '\u{6b04}', '\u{721b}', '\u{862d}', '\u{9e1e}',
+    '\u{5d50}', '\u{6feb}', '\u{85cd}', '\u{8964}', '\u{62c9}', '\u{81d8}', '\u{881f}', '\u{5eca}',
+    '\u{6717}', '\u{6d6a}', '\u{72fc}', '\u{90ce}', '\u{4f86}', '\u{51b7}', '\u{52de}', '\u{64c4}',
+    '\u{6ad3}', '\u{7210}', '\u{76e7}', '\u{8606}', '\u{865c}', '\u{8def}', '\u{9732}', '\u{9b6f}',
+    '\u{9dfa}', '\u{788c}', '\u{797f}', '\u{7da0}', '\u{83c9}', '\u{9304}', '\u{8ad6}', '\u{58df}',
+    '\u{5f04}', '\u{7c60}', '\u{807e}', '\u{7262}', '\u{78ca}', '\u{8cc2}', '\u{96f7}', '\u{58d8}',
+    '\u{5c62}', '\u{6a13}', '\u{6dda}', '\u{6f0f}', '\u{7d2f}', '\u{7e37}', '\u{964b}', '\u{52d2}',
+    '\u{808b}', '\u{51dc}', '\u{51cc}', '\u{7a1c}', '\u{7dbe}', '\u{83f1}', '\u{9675}', '\u{8b80}',
+    '\u{62cf}', '\u{8afe}', '\u{4e39}', '\u{5be7}', '\u{6012}', '\u{7387}', '\u{7570}', '\u{5317}',
+    '\u{78fb}', '\u{4fbf}', '\u{5fa9}', '\u{4e0d}', '\u{6ccc}', '\u{6578}', '\u{7d22}', '\u{53c3}',
+    '\u{585e}', '\u{7701}', '\u{8449}', '\u{8aaa}', '\u{6bba}', '\u{6c88}', '\u{62fe}', '\u{82e5}',
+    '\u{63a0}', '\u{7565}', '\u{4eae}', '\u{5169}', '\u{51c9}', '\u{6881}', '\u{7ce7}', '\u{826f}',
+    '\u{8ad2}', '\u{91cf}', '\u{52f5}', '\u{5442}', '\u{5eec}', '\u{65c5}', '\u{6ffe}', '\u{792a}',
+    '\u{95ad}', '\u{9a6a}', '\u{9e97}', '\u{9ece}', '\u{66c6}', '\u{6b77}', '\u{8f62}', '\u{5e74}',
+    '\u{6190}', '\u{6200}', '\u{649a}', '\u{6f23}', '\u{7149}', '\u{7489}', '\u{79ca}', '\u{7df4}',
+    '\u{806f}', '\u{8f26}', '\u{84ee}', '\u{9023}', '\u{934a}', '\u{5217}', '\u{52a3}', '\u{54bd}',
+    '\u{70c8}', '\u{88c2}', '\u{5ec9}', '\u{5ff5}', '\u{637b}', '\u{6bae}', '\u{7c3e}', '\u{7375}',
+    '\u{56f9}', '\u{5dba}', '\u{601c}', '\u{73b2}', '\u{7469}', '\u{7f9a}', '\u{8046}', '\u{9234}',
+    '\u{96f6}', '\u{9748}', '\u{9818}', '\u{4f8b}', '\u{79ae}', '\u{91b4}', '\u{96b8}', '\u{60e1}',
+    '\u{4e86}', '\u{50da}', '\u{5bee}', '\u{5c3f}', '\u{6599}', '\u{71ce}', '\u{7642}', '\u{84fc}',
+    '\u{907c}', '\u{6688}', '\u{962e}', '\u{5289}', '\u{677b}', '\u{67f3}', '\u{6d41}', '\u{6e9c}',
+    '\u{7409}', '\u{7559}', '\u{786b}', '\u{7d10}', '\u{985e}', '\u{622e}', '\u{9678}', '\u{502b}',
+    '\u{5d19}', '\u{6dea}', '\u{8f2a}', '\u{5f8b}', '\u{6144}', '\u{6817}', '\u{9686}', '\u{5229}',
+    '\u{540f}', '\u{5c65}', '\u{6613}', '\u{674e}', '\u{68a8}', '\u{6ce5}', '\u{7406}', '\u{75e2}',
+    '\u{7f79}', '\u{88cf}', '\u{88e1}', '\u{96e2}', '\u{533f}', '\u{6eba}', '\u{541d}', '\u{71d0}',
+    '\u{7498}', '\u{85fa}', '\u{96a3}', '\u{9c57}', '\u{9e9f}', '\u{6797}', '\u{6dcb}', '\u{81e8}',
+    '\u{7b20}', '\u{7c92}', '\u{72c0}', '\u{7099}', '\u{8b58}', '\u{4ec0}', '\u{8336}', '\u{523a}',
+    '\u{5207}', '\u{5ea6}', '\u{62d3}', '\u{7cd6}', '\u{5b85}', '\u{6d1e}', '\u{66b4}', '\u{8f3b}',
+    '\u{964d}', '\u{5ed3}', '\u{5140}', '\u{55c0}', '\u{585a}', '\u{6674}', '\u{51de}', '\u{732a}',
+    '\u{76ca}', '\u{793c}', '\u{795e}', '\u{7965}', '\u{798f}', '\u{9756}', '\u{7cbe}', '\u{8612}',
+    '\u{8af8}', '\u{9038}', '\u{90fd}', '\u{98ef}', '\u{98fc}', '\u{9928}', '\u{9db4}', '\u{90de}',
+    '\u{96b7}', '\u{4fae}', '\u{50e7}', '\u{514d}', '\u{52c9}', '\u{52e4}', '\u{5351}', '\u{559d}',
+    '\u{5606}', '\u{5668}', '\u{5840}', '\u{58a8}', '\u{5c64}', '\u{6094}', '\u{6168}', '\u{618e}',
+    '\u{61f2}', '\u{654f}', '\u{65e2}', '\u{6691}', '\u{6885}', '\u{6d77}', '\u{6e1a}', '\u{6f22}',
+    '\u{716e}', '\u{722b}', '\u{7422}', '\u{7891}', '\u{7949}', '\u{7948}', '\u{7950}', '\u{7956}',
+    '\u{798d}', '\u{798e}', '\u{7a40}', '\u{7a81}', '\u{7bc0}', '\u{7e09}', '\u{7e41}', '\u{7f72}',
+    '\u{8005}', '\u{81ed}', '\u{8279}', '\u{8457}', '\u{8910}', '\u{8996}', '\u{8b01}', '\u{8b39}',
+    '\u{8cd3}', '\u{8d08}', '\u{8fb6}', '\u{96e3}', '\u{97ff}', '\u{983b}', '\u{6075}', '\u{242ee}',
+    '\u{8218}', '\u{4e26}', '\u{51b5}', '\u{5168}', '\u{4f80}', '\u{5145}', '\u{5180}', '\u{52c7}',
+    '\u{52fa}', '\u{5555}', '\u{5599}', '\u{55e2}', '\u{58b3}', '\u{5944}', '\u{5954}', '\u{5a62}',
+    '\u{5b28}', '\u{5ed2}', '\u{5ed9}', '\u{5f69}', '\u{5fad}', '\u{60d8}', '\u{614e}', '\u{6108}',
+    '\u{6160}', '\u{6234}', '\u{63c4}', '\u{641c}', '\u{6452}', '\u{6556}', '\u{671b}', '\u{6756}',
+    '\u{6edb}', '\u{6ecb}', '\u{701e}', '\u{77a7}', '\u{7235}', '\u{72af}', '\u{7471}', '\u{7506}',
+    '\u{753b}', '\u{761d}', '\u{761f}', '\u{76db}', '\u{76f4}', '\u{774a}', '\u{7740}', '\u{78cc}',
+    '\u{7ab1}', '\u{7c7b}', '\u{7d5b}', '\u{7f3e}', '\u{8352}', '\u{83ef}', '\u{8779}', '\u{8941}',
+    '\u{8986}', '\u{8abf}', '\u{8acb}', '\u{8aed}', '\u{8b8a}', '\u{8f38}', '\u{9072}', '\u{9199}',
+    '\u{9276}', '\u{967c}', '\u{97db}', '\u{980b}', '\u{9b12}', '\u{2284a}', '\u{22844}',
+    '\u{233d5}', '\u{3b9d}', '\u{4018}', '\u{4039}', '\u{25249}', '\u{25cd0}', '\u{27ed3}',
+    '\u{9f43}', '\u{9f8e}', '\u{66}', '\u{66}', '\u{66}', '\u{69}', '\u{66}', '\u{6c}', '\u{66}',
+    '\u{66}', '\u{6c}', '\u{574}', '\u{576}', '\u{574}', '\u{565}', '\u{574}', '\u{56b}', '\u{57e}',
+    '\u{576}', '\u{574}', '\u{56d}', '\u{5d9}', '\u{5b4}', '\u{5f2}', '\u{5b7}', '\u{5e2}',
+    '\u{5d4}', '\u{5db}', '\u{5dc}', '\u{5dd}', '\u{5e8}', '\u{5ea}', '\u{5e9}', '\u{5c1}',
+    '\u{5e9}', '\u{5c2}', '\u{5e9}', '\u{5bc}', '\u{5c1}', '\u{5e9}', '\u{5bc}', '\u{5c2}',
+    '\u{5d0}', '\u{5b7}', '\u{5d0}', '\u{5b8}', '\u{5d0}', '\u{5bc}', '\u{5d1}', '\u{5bc}',
+    '\u{5d2}', '\u{5bc}', '\u{5d3}', '\u{5bc}', '\u{5d4}', '\u{5bc}', '\u{5d5}', '\u{5bc}',
+    '\u{5d6}', '\u{5bc}', '\u{5d8}', '\u{5bc}', '\u{5d9}', '\u{5bc}', '\u{5da}', '\u{5bc}',
+    '\u{5db}', '\u{5bc}', '\u{5dc}', '\u{5bc}', '\u{5de}', '\u{5bc}', '\u{5e0}', '\u{5bc}',
+    '\u{5e1}', '\u{5bc}', '\u{5e3}', '\u{5bc}', '\u{5e4}', '\u{5bc}', '\u{5e6}', '\u{5bc}',
+    '\u{5e7}', '\u{5bc}', '\u{5e8}', '\u{5bc}', '\u{5ea}', '\u{5bc}', '\u{5d5}', '\u{5b9}',
+    '\u{5d1}', '\u{5bf}', '\u{5db}', '\u{5bf}', '\u{5e4}', '\u{5bf}', '\u{5d0}', '\u{5dc}',
+    '\u{671}', '\u{67b}', '\u{67e}', '\u{680}', '\u{67a}', '\u{67f}', '\u{679}', '\u{6a4}',
+    '\u{6a6}', '\u{684}', '\u{683}', '\u{686}', '\u{687}', '\u{68d}', '\u{68c}', '\u{68e}',
+    '\u{688}', '\u{698}', '\u{691}', '\u{6a9}', '\u{6af}', '\u{6b3}', '\u{6b1}', '\u{6ba}',
+    '\u{6bb}', '\u{6c0}', '\u{6c1}', '\u{6be}', '\u{6d2}', '\u{6d3}', '\u{6ad}', '\u{6c6}',
+    '\u{6c8}', '\u{6cb}', '\u{6c5}', '\u{6c9}', '\u{6d0}', '\u{649}', '\u{626}', '\u{627}',
+    '\u{626}', '\u{6d5}', '\u{626}', '\u{648}', '\u{626}', '\u{6c7}', '\u{626}', '\u{6c6}',
+    '\u{626}', '\u{6c8}', '\u{626}', '\u{6d0}', '\u{626}', '\u{649}', '\u{6cc}', '\u{626}',
+    '\u{62c}', '\u{626}', '\u{62d}', '\u{626}', '\u{645}', '\u{626}', '\u{64a}', '\u{628}',
+    '\u{62c}', '\u{628}', '\u{62d}', '\u{628}', '\u{62e}', '\u{628}', '\u{645}', '\u{628}',
+    '\u{649}', '\u{628}', '\u{64a}', '\u{62a}', '\u{62c}', '\u{62a}', '\u{62d}', '\u{62a}',
+    '\u{62e}', '\u{62a}', '\u{645}', '\u{62a}', '\u{649}', '\u{62a}', '\u{64a}', '\u{62b}',
+    '\u{62c}', '\u{62b}', '\u{645}', '\u{62b}', '\u{649}', '\u{62b}', '\u{64a}', '\u{62c}',
+    '\u{62d}', '\u{62c}', '\u{645}', '\u{62d}', '\u{645}', '\u{62e}', '\u{62c}', '\u{62e}',
+    '\u{62d}', '\u{62e}', '\u{645}', '\u{633}', '\u{62c}', '\u{633}', '\u{62d}', '\u{633}',
+    '\u{62e}', '\u{633}', '\u{645}', '\u{635}', '\u{62d}', '\u{635}', '\u{645}', '\u{636}',
+    '\u{62c}', '\u{636}', '\u{62d}', '\u{636}', '\u{62e}', '\u{636}', '\u{645}', '\u{637}',
+    '\u{62d}', '\u{637}', '\u{645}', '\u{638}', '\u{645}', '\u{639}', '\u{62c}', '\u{639}',
+    '\u{645}', '\u{63a}', '\u{62c}', '\u{63a}', '\u{645}', '\u{641}', '\u{62c}', '\u{641}',
+    '\u{62d}', '\u{641}', '\u{62e}', '\u{641}', '\u{645}', '\u{641}', '\u{649}', '\u{641}',
+    '\u{64a}', '\u{642}', '\u{62d}', '\u{642}', '\u{645}', '\u{642}', '\u{649}', '\u{642}',
+    '\u{64a}', '\u{643}', '\u{627}', '\u{643}', '\u{62c}', '\u{643}', '\u{62d}', '\u{643}',
+    '\u{62e}', '\u{643}', '\u{644}', '\u{643}', '\u{645}', '\u{643}', '\u{649}', '\u{643}',
+    '\u{64a}', '\u{644}', '\u{62c}', '\u{644}', '\u{62d}', '\u{644}', '\u{62e}', '\u{644}',
+    '\u{645}', '\u{644}', '\u{649}', '\u{644}', '\u{64a}', '\u{645}', '\u{62c}', '\u{645}',
+    '\u{645}', '\u{645}', '\u{649}', '\u{645}', '\u{64a}', '\u{646}', '\u{62c}', '\u{646}',
+    '\u{62d}', '\u{646}', '\u{62e}', '\u{646}', '\u{645}', '\u{646}', '\u{649}', '\u{646}',
+    '\u{64a}', '\u{647}', '\u{62c}', '\u{647}', '\u{645}', '\u{647}', '\u{649}', '\u{647}',
+    '\u{64a}', '\u{64a}', '\u{62d}', '\u{64a}', '\u{62e}', '\u{64a}', '\u{649}', '\u{630}',
+    '\u{670}', '\u{631}', '\u{670}', '\u{649}', '\u{670}', '\u{20}', '\u{64c}', '\u{651}', '\u{20}',
+    '\u{64d}', '\u{651}', '\u{20}', '\u{64e}', '\u{651}', '\u{20}', '\u{64f}', '\u{651}', '\u{20}',
+    '\u{650}', '\u{651}', '\u{20}', '\u{651}', '\u{670}', '\u{626}', '\u{631}', '\u{626}',
+    '\u{632}', '\u{626}', '\u{646}', '\u{628}', '\u{631}', '\u{628}', '\u{632}', '\u{628}',
+    '\u{646}', '\u{62a}', '\u{631}', '\u{62a}', '\u{632}', '\u{62a}', '\u{646}', '\u{62b}',
+    '\u{631}', '\u{62b}', '\u{632}', '\u{62b}', '\u{646}', '\u{645}', '\u{627}', '\u{646}',
+    '\u{631}', '\u{646}', '\u{632}', '\u{646}', '\u{646}', '\u{64a}', '\u{631}', '\u{64a}',
+    '\u{632}', '\u{626}', '\u{62e}', '\u{626}', '\u{647}', '\u{628}', '\u{647}', '\u{62a}',
+    '\u{647}', '\u{635}', '\u{62e}', '\u{644}', '\u{647}', '\u{646}', '\u{647}', '\u{647}',
+    '\u{670}', '\u{62b}', '\u{647}', '\u{633}', '\u{647}', '\u{634}', '\u{645}', '\u{634}',
+    '\u{647}', '\u{640}', '\u{64e}', '\u{651}', '\u{640}', '\u{64f}', '\u{651}', '\u{640}',
+    '\u{650}', '\u{651}', '\u{637}', '\u{649}', '\u{637}', '\u{64a}', '\u{639}', '\u{649}',
+    '\u{639}', '\u{64a}', '\u{63a}', '\u{649}', '\u{63a}', '\u{64a}', '\u{633}', '\u{649}',
+    '\u{633}', '\u{64a}', '\u{634}', '\u{649}', '\u{634}', '\u{64a}', '\u{62d}', '\u{649}',
+    '\u{62c}', '\u{649}', '\u{62c}', '\u{64a}', '\u{62e}', '\u{649}', '\u{635}', '\u{649}',
+    '\u{635}', '\u{64a}', '\u{636}', '\u{649}', '\u{636}', '\u{64a}', '\u{634}', '\u{62c}',
+    '\u{634}', '\u{62d}', '\u{634}', '\u{62e}', '\u{634}', '\u{631}', '\u{633}', '\u{631}',
+    '\u{635}', '\u{631}', '\u{636}', '\u{631}', '\u{627}', '\u{64b}', '\u{62a}', '\u{62c}',
+    '\u{645}', '\u{62a}', '\u{62d}', '\u{62c}', '\u{62a}', '\u{62d}', '\u{645}', '\u{62a}',
+    '\u{62e}', '\u{645}', '\u{62a}', '\u{645}', '\u{62c}', '\u{62a}', '\u{645}', '\u{62d}',
+    '\u{62a}', '\u{645}', '\u{62e}', '\u{62d}', '\u{645}', '\u{64a}', '\u{62d}', '\u{645}',
+    '\u{649}', '\u{633}', '\u{62d}', '\u{62c}', '\u{633}', '\u{62c}', '\u{62d}', '\u{633}',
+    '\u{62c}', '\u{649}', '\u{633}', '\u{645}', '\u{62d}', '\u{633}', '\u{645}', '\u{62c}',
+    '\u{633}', '\u{645}', '\u{645}', '\u{635}', '\u{62d}', '\u{62d}', '\u{635}', '\u{645}',
+    '\u{645}', '\u{634}', '\u{62d}', '\u{645}', '\u{634}', '\u{62c}', '\u{64a}', '\u{634}',
+    '\u{645}', '\u{62e}', '\u{634}', '\u{645}', '\u{645}', '\u{636}', '\u{62d}', '\u{649}',
+    '\u{636}', '\u{62e}', '\u{645}', '\u{637}', '\u{645}', '\u{62d}', '\u{637}', '\u{645}',
+    '\u{645}', '\u{637}', '\u{645}', '\u{64a}', '\u{639}', '\u{62c}', '\u{645}', '\u{639}',
+    '\u{645}', '\u{645}', '\u{639}', '\u{645}', '\u{649}', '\u{63a}', '\u{645}', '\u{645}',
+    '\u{63a}', '\u{645}', '\u{64a}', '\u{63a}', '\u{645}', '\u{649}', '\u{641}', '\u{62e}',
+    '\u{645}', '\u{642}', '\u{645}', '\u{62d}', '\u{642}', '\u{645}', '\u{645}', '\u{644}',
+    '\u{62d}', '\u{645}', '\u{644}', '\u{62d}', '\u{64a}', '\u{644}', '\u{62d}', '\u{649}',
+    '\u{644}', '\u{62c}', '\u{62c}', '\u{644}', '\u{62e}', '\u{645}', '\u{644}', '\u{645}',
+    '\u{62d}', '\u{645}', '\u{62d}', '\u{62c}', '\u{645}', '\u{62d}', '\u{64a}', '\u{645}',
+    '\u{62c}', '\u{62d}', '\u{645}', '\u{62e}', '\u{645}', '\u{645}', '\u{62c}', '\u{62e}',
+    '\u{647}', '\u{645}', '\u{62c}', '\u{647}', '\u{645}', '\u{645}', '\u{646}', '\u{62d}',
+    '\u{645}', '\u{646}', '\u{62d}', '\u{649}', '\u{646}', '\u{62c}', '\u{645}', '\u{646}',
+    '\u{62c}', '\u{649}', '\u{646}', '\u{645}', '\u{64a}', '\u{646}', '\u{645}', '\u{649}',
+    '\u{64a}', '\u{645}', '\u{645}', '\u{628}', '\u{62e}', '\u{64a}', '\u{62a}', '\u{62c}',
+    '\u{64a}', '\u{62a}', '\u{62c}', '\u{649}', '\u{62a}', '\u{62e}', '\u{64a}', '\u{62a}',
+    '\u{62e}', '\u{649}', '\u{62a}', '\u{645}', '\u{64a}', '\u{62a}', '\u{645}', '\u{649}',
+    '\u{62c}', '\u{645}', '\u{64a}', '\u{62c}', '\u{62d}', '\u{649}', '\u{62c}', '\u{645}',
+    '\u{649}', '\u{633}', '\u{62e}', '\u{649}', '\u{635}', '\u{62d}', '\u{64a}', '\u{634}',
+    '\u{62d}', '\u{64a}', '\u{636}', '\u{62d}', '\u{64a}', '\u{644}', '\u{62c}', '\u{64a}',
+    '\u{644}', '\u{645}', '\u{64a}', '\u{64a}', '\u{62c}', '\u{64a}', '\u{64a}', '\u{645}',
+    '\u{64a}', '\u{645}', '\u{645}', '\u{64a}', '\u{642}', '\u{645}', '\u{64a}', '\u{646}',
+    '\u{62d}', '\u{64a}', '\u{639}', '\u{645}', '\u{64a}', '\u{643}', '\u{645}', '\u{64a}',
+    '\u{646}', '\u{62c}', '\u{62d}', '\u{645}', '\u{62e}', '\u{64a}', '\u{644}', '\u{62c}',
+    '\u{645}', '\u{643}', '\u{645}', '\u{645}', '\u{62c}', '\u{62d}', '\u{64a}', '\u{62d}',
+    '\u{62c}', '\u{64a}', '\u{645}', '\u{62c}', '\u{64a}', '\u{641}', '\u{645}', '\u{64a}',
+    '\u{628}', '\u{62d}', '\u{64a}', '\u{633}', '\u{62e}', '\u{64a}', '\u{646}', '\u{62c}',
+    '\u{64a}', '\u{635}', '\u{644}', '\u{6d2}', '\u{642}', '\u{644}', '\u{6d2}', '\u{627}',
+    '\u{644}', '\u{644}', '\u{647}', '\u{627}', '\u{643}', '\u{628}', '\u{631}', '\u{645}',
+    '\u{62d}', '\u{645}', '\u{62f}', '\u{635}', '\u{644}', '\u{639}', '\u{645}', '\u{631}',
+    '\u{633}', '\u{648}', '\u{644}', '\u{639}', '\u{644}', '\u{64a}', '\u{647}', '\u{648}',
+    '\u{633}', '\u{644}', '\u{645}', '\u{635}', '\u{644}', '\u{649}', '\u{635}', '\u{644}',
+    '\u{649}', '\u{20}', '\u{627}', '\u{644}', '\u{644}', '\u{647}', '\u{20}', '\u{639}', '\u{644}',
+    '\u{64a}', '\u{647}', '\u{20}', '\u{648}', '\u{633}', '\u{644}', '\u{645}', '\u{62c}',
+    '\u{644}', '\u{20}', '\u{62c}', '\u{644}', '\u{627}', '\u{644}', '\u{647}', '\u{631}',
+    '\u{6cc}', '\u{627}', '\u{644}', '\u{2c}', '\u{3001}', '\u{3016}', '\u{3017}', '\u{2014}',
+    '\u{2013}', '\u{5f}', '\u{7b}', '\u{7d}', '\u{3014}', '\u{3015}', '\u{3010}', '\u{3011}',
+    '\u{300a}', '\u{300b}', '\u{300c}', '\u{300d}', '\u{300e}', '\u{300f}', '\u{5b}', '\u{5d}',
+    '\u{23}', '\u{26}', '\u{2a}', '\u{2d}', '\u{3c}', '\u{3e}', '\u{5c}', '\u{24}', '\u{25}',
+    '\u{40}', '\u{20}', '\u{64b}', '\u{640}', '\u{64b}', '\u{640}', '\u{651}', '\u{20}', '\u{652}',
+    '\u{640}', '\u{652}', '\u{621}', '\u{622}', '\u{623}', '\u{624}', '\u{625}', '\u{629}',
+    '\u{644}', '\u{622}', '\u{644}', '\u{623}', '\u{644}', '\u{625}', '\u{22}', '\u{27}', '\u{5e}',
+    '\u{7c}', '\u{7e}', '\u{2985}', '\u{2986}', '\u{30fb}', '\u{30a5}', '\u{30e3}', '\u{a2}',
+    '\u{a3}', '\u{ac}', '\u{a6}', '\u{a5}', '\u{20a9}', '\u{2502}', '\u{2190}', '\u{2191}',
+    '\u{2192}', '\u{2193}', '\u{25a0}', '\u{25cb}', '\u{10428}', '\u{10429}', '\u{1042a}',
+    '\u{1042b}', '\u{1042c}', '\u{1042d}', '\u{1042e}', '\u{1042f}', '\u{10430}', '\u{10431}',
+    '\u{10432}', '\u{10433}', '\u{10434}', '\u{10435}', '\u{10436}', '\u{10437}', '\u{10438}',
+    '\u{10439}', '\u{1043a}', '\u{1043b}', '\u{1043c}', '\u{1043d}', '\u{1043e}', '\u{1043f}',
+    '\u{10440}', '\u{10441}', '\u{10442}', '\u{10443}', '\u{10444}', '\u{10445}', '\u{10446}',
+    '\u{10447}', '\u{10448}', '\u{10449}', '\u{1044a}', '\u{1044b}', '\u{1044c}', '\u{1044d}',
+    '\u{1044e}', '\u{1044f}', '\u{104d8}', '\u{104d9}', '\u{104da}', '\u{104db}', '\u{104dc}',
+    '\u{104dd}', '\u{104de}', '\u{104df}', '\u{104e0}', '\u{104e1}', '\u{104e2}', '\u{104e3}',
+    '\u{104e4}', '\u{104e5}', '\u{104e6}', '\u{104e7}', '\u{104e8}', '\u{104e9}', '\u{104ea}',
+    '\u{104eb}', '\u{104ec}', '\u{104ed}', '\u{104ee}', '\u{104ef}', '\u{104f0}', '\u{104f1}',
+    '\u{104f2}', '\u{104f3}', '\u{104f4}', '\u{104f5}', '\u{104f6}', '\u{104f7}', '\u{104f8}',
+    '\u{104f9}', '\u{104fa}', '\u{104fb}', '\u{10cc0}', '\u{10cc1}', '\u{10cc2}', '\u{10cc3}',
+    '\u{10cc4}', '\u{10cc5}', '\u{10cc6}', '\u{10cc7}', '\u{10cc8}', '\u{10cc9}', '\u{10cca}',
+    '\u{10ccb}', '\u{10ccc}', '\u{10ccd}', '\u{10cce}', '\u{10ccf}', '\u{10cd0}', '\u{10cd1}',
+    '\u{10cd2}', '\u{10cd3}', '\u{10cd4}', '\u{10cd5}', '\u{10cd6}', '\u{10cd7}', '\u{10cd8}',
+    '\u{10cd9}', '\u{10cda}', '\u{10cdb}', '\u{10cdc}', '\u{10cdd}', '\u{10cde}', '\u{10cdf}',
+    '\u{10ce0}', '\u{10ce1}', '\u{10ce2}', '\u{10ce3}', '\u{10ce4}', '\u{10ce5}', '\u{10ce6}',
+    '\u{10ce7}', '\u{10ce8}', '\u{10ce9}', '\u{10cea}', '\u{10ceb}', '\u{10cec}', '\u{10ced}',
+    '\u{10cee}', '\u{10cef}', '\u{10cf0}', '\u{10cf1}', '\u{10cf2}', '\u{118c0}', '\u{118c1}',
+    '\u{118c2}', '\u{118c3}', '\u{118c4}', '\u{118c5}', '\u{118c6}', '\u{118c7}', '\u{118c8}',
+    '\u{118c9}', '\u{118ca}', '\u{118cb}', '\u{118cc}', '\u{118cd}', '\u{118ce}', '\u{118cf}',
+    '\u{118d0}', '\u{118d1}', '\u{118d2}', '\u{118d3}', '\u{118d4}', '\u{118d5}', '\u{118d6}',
+    '\u{118d7}', '\u{118d8}', '\u{118d9}', '\u{118da}', '\u{118db}', '\u{118dc}', '\u{118dd}',
+    '\u{118de}', '\u{118df}', '\u{16e60}', '\u{16e61}', '\u{16e62}', '\u{16e63}', '\u{16e64}',
+    '\u{16e65}', '\u{16e66}', '\u{16e67}', '\u{16e68}', '\u{16e69}', '\u{16e6a}', '\u{16e6b}',
+    '\u{16e6c}', '\u{16e6d}', '\u{16e6e}', '\u{16e6f}', '\u{16e70}', '\u{16e71}', '\u{16e72}',
+    '\u{16e73}', '\u{16e74}', '\u{16e75}', '\u{16e76}', '\u{16e77}', '\u{16e78}', '\u{16e79}',
+    '\u{16e7a}', '\u{16e7b}', '\u{16e7c}', '\u{16e7d}', '\u{16e7e}', '\u{16e7f}', '\u{1d157}',
+    '\u{1d165}', '\u{1d158}', '\u{1d165}', '\u{1d158}', '\u{1d165}', '\u{1d16e}', '\u{1d158}',
+    '\u{1d165}', '\u{1d16f}', '\u{1d158}', '\u{1d165}', '\u{1d170}', '\u{1d158}', '\u{1d165}',
+    '\u{1d171}', '\u{1d158}', '\u{1d165}', '\u{1d172}', '\u{1d1b9}', '\u{1d165}', '\u{1d1ba}',
+    '\u{1d165}', '\u{1d1b9}', '\u{1d165}', '\u{1d16e}', '\u{1d1ba}', '\u{1d165}', '\u{1d16e}',
+    '\u{1d1b9}', '\u{1d165}', '\u{1d16f}', '\u{1d1ba}', '\u{1d165}', '\u{1d16f}', '\u{131}',
+    '\u{237}', '\u{2207}', '\u{2202}', '\u{1e922}', '\u{1e923}', '\u{1e924}', '\u{1e925}',
+    '\u{1e926}', '\u{1e927}', '\u{1e928}', '\u{1e929}', '\u{1e92a}', '\u{1e92b}', '\u{1e92c}',
+    '\u{1e92d}', '\u{1e92e}', '\u{1e92f}', '\u{1e930}', '\u{1e931}', '\u{1e932}', '\u{1e933}',
+    '\u{1e934}', '\u{1e935}', '\u{1e936}', '\u{1e937}', '\u{1e938}', '\u{1e939}', '\u{1e93a}',
+    '\u{1e93b}', '\u{1e93c}', '\u{1e93d}', '\u{1e93e}', '\u{1e93f}', '\u{1e940}', '\u{1e941}',
+    '\u{1e942}', '\u{1e943}', '\u{66e}', '\u{6a1}', '\u{66f}', '\u{30}', '\u{2c}', '\u{31}',
+    '\u{2c}', '\u{32}', '\u{2c}', '\u{33}', '\u{2c}', '\u{34}', '\u{2c}', '\u{35}', '\u{2c}',
+    '\u{36}', '\u{2c}', '\u{37}', '\u{2c}', '\u{38}', '\u{2c}', '\u{39}', '\u{2c}', '\u{3014}',
+    '\u{73}', '\u{3015}', '\u{77}', '\u{7a}', '\u{68}', '\u{76}', '\u{73}', '\u{64}', '\u{70}',
+    '\u{70}', '\u{76}', '\u{77}', '\u{63}', '\u{6d}', '\u{72}', '\u{64}', '\u{6a}', '\u{307b}',
+    '\u{304b}', '\u{30b3}', '\u{30b3}', '\u{5b57}', '\u{53cc}', '\u{591a}', '\u{89e3}', '\u{4ea4}',
+    '\u{6620}', '\u{7121}', '\u{524d}', '\u{5f8c}', '\u{518d}', '\u{65b0}', '\u{521d}', '\u{7d42}',
+    '\u{8ca9}', '\u{58f0}', '\u{5439}', '\u{6f14}', '\u{6295}', '\u{6355}', '\u{904a}', '\u{6307}',
+    '\u{6253}', '\u{7981}', '\u{7a7a}', '\u{5408}', '\u{6e80}', '\u{7533}', '\u{5272}', '\u{55b6}',
+    '\u{914d}', '\u{3014}', '\u{672c}', '\u{3015}', '\u{3014}', '\u{4e09}', '\u{3015}', '\u{3014}',
+    '\u{4e8c}', '\u{3015}', '\u{3014}', '\u{5b89}', '\u{3015}', '\u{3014}', '\u{70b9}', '\u{3015}',
+    '\u{3014}', '\u{6253}', '\u{3015}', '\u{3014}', '\u{76d7}', '\u{3015}', '\u{3014}', '\u{52dd}',
+    '\u{3015}', '\u{3014}', '\u{6557}', '\u{3015}', '\u{5f97}', '\u{53ef}', '\u{4e3d}', '\u{4e38}',
+    '\u{4e41}', '\u{20122}', '\u{4f60}', '\u{4fbb}', '\u{5002}', '\u{507a}', '\u{5099}', '\u{50cf}',
+    '\u{349e}', '\u{2063a}', '\u{5154}', '\u{5164}', '\u{5177}', '\u{2051c}', '\u{34b9}',
+    '\u{5167}', '\u{2054b}', '\u{5197}', '\u{51a4}', '\u{4ecc}', '\u{51ac}', '\u{291df}',
+    '\u{5203}', '\u{34df}', '\u{523b}', '\u{5246}', '\u{5277}', '\u{3515}', '\u{5305}', '\u{5306}',
+    '\u{5349}', '\u{535a}', '\u{5373}', '\u{537d}', '\u{537f}', '\u{20a2c}', '\u{7070}', '\u{53ca}',
+    '\u{53df}', '\u{20b63}', '\u{53eb}', '\u{53f1}', '\u{5406}', '\u{549e}', '\u{5438}', '\u{5448}',
+    '\u{5468}', '\u{54a2}', '\u{54f6}', '\u{5510}', '\u{5553}', '\u{5563}', '\u{5584}', '\u{55ab}',
+    '\u{55b3}', '\u{55c2}', '\u{5716}', '\u{5717}', '\u{5651}', '\u{5674}', '\u{58ee}', '\u{57ce}',
+    '\u{57f4}', '\u{580d}', '\u{578b}', '\u{5832}', '\u{5831}', '\u{58ac}', '\u{214e4}', '\u{58f2}',
+    '\u{58f7}', '\u{5906}', '\u{5922}', '\u{5962}', '\u{216a8}', '\u{216ea}', '\u{59ec}',
+    '\u{5a1b}', '\u{5a27}', '\u{59d8}', '\u{5a66}', '\u{36ee}', '\u{5b08}', '\u{5b3e}', '\u{219c8}',
+    '\u{5bc3}', '\u{5bd8}', '\u{5bf3}', '\u{21b18}', '\u{5bff}', '\u{5c06}', '\u{3781}', '\u{5c60}',
+    '\u{5cc0}', '\u{5c8d}', '\u{21de4}', '\u{5d43}', '\u{21de6}', '\u{5d6e}', '\u{5d6b}',
+    '\u{5d7c}', '\u{5de1}', '\u{5de2}', '\u{382f}', '\u{5dfd}', '\u{5e28}', '\u{5e3d}', '\u{5e69}',
+    '\u{3862}', '\u{22183}', '\u{387c}', '\u{5eb0}', '\u{5eb3}', '\u{5eb6}', '\u{2a392}',
+    '\u{22331}', '\u{8201}', '\u{5f22}', '\u{38c7}', '\u{232b8}', '\u{261da}', '\u{5f62}',
+    '\u{5f6b}', '\u{38e3}', '\u{5f9a}', '\u{5fcd}', '\u{5fd7}', '\u{5ff9}', '\u{6081}', '\u{393a}',
+    '\u{391c}', '\u{226d4}', '\u{60c7}', '\u{6148}', '\u{614c}', '\u{617a}', '\u{61b2}', '\u{61a4}',
+    '\u{61af}', '\u{61de}', '\u{621b}', '\u{625d}', '\u{62b1}', '\u{62d4}', '\u{6350}', '\u{22b0c}',
+    '\u{633d}', '\u{62fc}', '\u{6368}', '\u{6383}', '\u{63e4}', '\u{22bf1}', '\u{6422}', '\u{63c5}',
+    '\u{63a9}', '\u{3a2e}', '\u{6469}', '\u{647e}', '\u{649d}', '\u{6477}', '\u{3a6c}', '\u{656c}',
+    '\u{2300a}', '\u{65e3}', '\u{66f8}', '\u{6649}', '\u{3b19}', '\u{3b08}', '\u{3ae4}', '\u{5192}',
+    '\u{5195}', '\u{6700}', '\u{669c}', '\u{80ad}', '\u{43d9}', '\u{6721}', '\u{675e}', '\u{6753}',
+    '\u{233c3}', '\u{3b49}', '\u{67fa}', '\u{6785}', '\u{6852}', '\u{2346d}', '\u{688e}',
+    '\u{681f}', '\u{6914}', '\u{6942}', '\u{69a3}', '\u{69ea}', '\u{6aa8}', '\u{236a3}', '\u{6adb}',
+    '\u{3c18}', '\u{6b21}', '\u{238a7}', '\u{6b54}', '\u{3c4e}', '\u{6b72}', '\u{6b9f}', '\u{6bbb}',
+    '\u{23a8d}', '\u{21d0b}', '\u{23afa}', '\u{6c4e}', '\u{23cbc}', '\u{6cbf}', '\u{6ccd}',
+    '\u{6c67}', '\u{6d16}', '\u{6d3e}', '\u{6d69}', '\u{6d78}', '\u{6d85}', '\u{23d1e}', '\u{6d34}',
+    '\u{6e2f}', '\u{6e6e}', '\u{3d33}', '\u{6ec7}', '\u{23ed1}', '\u{6df9}', '\u{6f6e}',
+    '\u{23f5e}', '\u{23f8e}', '\u{6fc6}', '\u{7039}', '\u{701b}', '\u{3d96}', '\u{704a}',
+    '\u{707d}', '\u{7077}', '\u{70ad}', '\u{20525}', '\u{7145}', '\u{24263}', '\u{719c}',
+    '\u{7228}', '\u{7250}', '\u{24608}', '\u{7280}', '\u{7295}', '\u{24735}', '\u{24814}',
+    '\u{737a}', '\u{738b}', '\u{3eac}', '\u{73a5}', '\u{3eb8}', '\u{7447}', '\u{745c}', '\u{7485}',
+    '\u{74ca}', '\u{3f1b}', '\u{7524}', '\u{24c36}', '\u{753e}', '\u{24c92}', '\u{2219f}',
+    '\u{7610}', '\u{24fa1}', '\u{24fb8}', '\u{25044}', '\u{3ffc}', '\u{4008}', '\u{250f3}',
+    '\u{250f2}', '\u{25119}', '\u{25133}', '\u{771e}', '\u{771f}', '\u{778b}', '\u{4046}',
+    '\u{4096}', '\u{2541d}', '\u{784e}', '\u{40e3}', '\u{25626}', '\u{2569a}', '\u{256c5}',
+    '\u{79eb}', '\u{412f}', '\u{7a4a}', '\u{7a4f}', '\u{2597c}', '\u{25aa7}', '\u{4202}',
+    '\u{25bab}', '\u{7bc6}', '\u{7bc9}', '\u{4227}', '\u{25c80}', '\u{7cd2}', '\u{42a0}',
+    '\u{7ce8}', '\u{7ce3}', '\u{7d00}', '\u{25f86}', '\u{7d63}', '\u{4301}', '\u{7dc7}', '\u{7e02}',
+    '\u{7e45}', '\u{4334}', '\u{26228}', '\u{26247}', '\u{4359}', '\u{262d9}', '\u{7f7a}',
+    '\u{2633e}', '\u{7f95}', '\u{7ffa}', '\u{264da}', '\u{26523}', '\u{8060}', '\u{265a8}',
+    '\u{8070}', '\u{2335f}', '\u{43d5}', '\u{80b2}', '\u{8103}', '\u{440b}', '\u{813e}', '\u{5ab5}',
+    '\u{267a7}', '\u{267b5}', '\u{23393}', '\u{2339c}', '\u{8204}', '\u{8f9e}', '\u{446b}',
+    '\u{8291}', '\u{828b}', '\u{829d}', '\u{52b3}', '\u{82b1}', '\u{82b3}', '\u{82bd}', '\u{82e6}',
+    '\u{26b3c}', '\u{831d}', '\u{8363}', '\u{83ad}', '\u{8323}', '\u{83bd}', '\u{83e7}', '\u{8353}',
+    '\u{83ca}', '\u{83cc}', '\u{83dc}', '\u{26c36}', '\u{26d6b}', '\u{26cd5}', '\u{452b}',
+    '\u{84f1}', '\u{84f3}', '\u{8516}', '\u{273ca}', '\u{8564}', '\u{26f2c}', '\u{455d}',
+    '\u{4561}', '\u{26fb1}', '\u{270d2}', '\u{456b}', '\u{8650}', '\u{8667}', '\u{8669}',
+    '\u{86a9}', '\u{8688}', '\u{870e}', '\u{86e2}', '\u{8728}', '\u{876b}', '\u{8786}', '\u{87e1}',
+    '\u{8801}', '\u{45f9}', '\u{8860}', '\u{27667}', '\u{88d7}', '\u{88de}', '\u{4635}', '\u{88fa}',
+    '\u{34bb}', '\u{278ae}', '\u{27966}', '\u{46be}', '\u{46c7}', '\u{8aa0}', '\u{27ca8}',
+    '\u{8cab}', '\u{8cc1}', '\u{8d1b}', '\u{8d77}', '\u{27f2f}', '\u{20804}', '\u{8dcb}',
+    '\u{8dbc}', '\u{8df0}', '\u{208de}', '\u{8ed4}', '\u{285d2}', '\u{285ed}', '\u{9094}',
+    '\u{90f1}', '\u{9111}', '\u{2872e}', '\u{911b}', '\u{9238}', '\u{92d7}', '\u{92d8}', '\u{927c}',
+    '\u{93f9}', '\u{9415}', '\u{28bfa}', '\u{958b}', '\u{4995}', '\u{95b7}', '\u{28d77}',
+    '\u{49e6}', '\u{96c3}', '\u{5db2}', '\u{9723}', '\u{29145}', '\u{2921a}', '\u{4a6e}',
+    '\u{4a76}', '\u{97e0}', '\u{2940a}', '\u{4ab2}', '\u{29496}', '\u{9829}', '\u{295b6}',
+    '\u{98e2}', '\u{4b33}', '\u{9929}', '\u{99a7}', '\u{99c2}', '\u{99fe}', '\u{4bce}', '\u{29b30}',
+    '\u{9c40}', '\u{9cfd}', '\u{4cce}', '\u{4ced}', '\u{9d67}', '\u{2a0ce}', '\u{4cf8}',
+    '\u{2a105}', '\u{2a20e}', '\u{2a291}', '\u{4d56}', '\u{9efe}', '\u{9f05}', '\u{9f0f}',
+    '\u{9f16}', '\u{2a600}',
+];
+/// Code point, offset & length of the full decomposition in DECOMPOSED
+pub(super) static DECOMPOSITIONS: &[(u32, u16, u8)] = &[
+    (0xc0, 0, 2), (0xc1, 2, 2), (0xc2, 4, 2), (0xc3, 6, 2), (0xc4, 8, 2), (0xc5, 10, 2),
+    (0xc7, 12, 2), (0xc8, 14, 2), (0xc9, 16, 2), (0xca, 18, 2), (0xcb, 20, 2), (0xcc, 22, 2),
+    (0xcd, 24, 2), (0xce, 26, 2), (0xcf, 28, 2), (0xd1, 30, 2), (0xd2, 32, 2), (0xd3, 34, 2),
+    (0xd4, 36, 2), (0xd5, 38, 2), (0xd6, 40, 2), (0xd9, 42, 2), (0xda, 44, 2), (0xdb, 46, 2),
+    (0xdc, 48, 2), (0xdd, 50, 2), (0xe0, 52, 2), (0xe1, 54, 2), (0xe2, 56, 2), (0xe3, 58, 2),
+    (0xe4, 60, 2), (0xe5, 62, 2), (0xe7, 64, 2), (0xe8, 66, 2), (0xe9, 68, 2), (0xea, 70, 2),
+    (0xeb, 72, 2), (0xec, 74, 2), (0xed, 76, 2), (0xee, 78, 2), (0xef, 80, 2), (0xf1, 82, 2),
+    (0xf2, 84, 2), (0xf3, 86, 2), (0xf4, 88, 2), (0xf5, 90, 2), (0xf6, 92, 2), (0xf9, 94, 2),
+    (0xfa, 96, 2), (0xfb, 98, 2), (0xfc, 100, 2), (0xfd, 102, 2), (0xff, 104, 2), (0x100, 106, 2),
+    (0x101, 108, 2), (0x102, 110, 2), (0x103, 112, 2), (0x104, 114, 2), (0x105, 116, 2),
+    (0x106, 118, 2), (0x107, 120, 2), (0x108, 122, 2), (0x109, 124, 2), (0x10a, 126, 2),
+    (0x10b, 128, 2), (0x10c, 130, 2), (0x10d, 132, 2), (0x10e, 134, 2), (0x10f, 136, 2),
+    (0x112, 138, 2), (0x113, 140, 2), (0x114, 142, 2), (0x115, 144, 2), (0x116, 146, 2),
+    (0x117, 148, 2), (0x118, 150, 2), (0x119, 152, 2), (0x11a, 154, 2), (0x11b, 156, 2),
+    (0x11c, 158, 2), (0x11d, 160, 2), (0x11e, 162, 2), (0x11f, 164, 2), (0x120, 166, 2),
+    (0x121, 168, 2), (0x122, 170, 2), (0x123, 172, 2), (0x124, 174, 2), (0x125, 176, 2),
+    (0x128, 178, 2), (0x129, 180, 2), (0x12a, 182, 2), (0x12b, 184, 2), (0x12c, 186, 2),
+    (0x12d, 188, 2), (0x12e, 190, 2), (0x12f, 192, 2), (0x130, 194, 2), (0x134, 196, 2),
+    (0x135, 198, 2), (0x136, 200, 2), (0x137, 202, 2), (0x139, 204, 2), (0x13a, 206, 2),
+    (0x13b, 208, 2), (0x13c, 210, 2), (0x13d, 212, 2), (0x13e, 214, 2), (0x143, 216, 2),
+    (0x144, 218, 2), (0x145, 220, 2), (0x146, 222, 2), (0x147, 224, 2), (0x148, 226, 2),
+    (0x14c, 228, 2), (0x14d, 230, 2), (0x14e, 232, 2), (0x14f, 234, 2), (0x150, 236, 2),
+    (0x151, 238, 2), (0x154, 240, 2), (0x155, 242, 2), (0x156, 244, 2), (0x157, 246, 2),
+    (0x158, 248, 2), (0x159, 250, 2), (0x15a, 252, 2), (0x15b, 254, 2), (0x15c, 256, 2),
+    (0x15d, 258, 2), (0x15e, 260, 2), (0x15f, 262, 2), (0x160, 264, 2), (0x161, 266, 2),
+    (0x162, 268, 2), (0x163, 270, 2), (0x164, 272, 2), (0x165, 274, 2), (0x168, 276, 2),
+    (0x169, 278, 2), (0x16a, 280, 2), (0x16b, 282, 2), (0x16c, 284, 2), (0x16d, 286, 2),
+    (0x16e, 288, 2), (0x16f, 290, 2), (0x170, 292, 2), (0x171, 294, 2), (0x172, 296, 2),
+    (0x173, 298, 2), (0x174, 300, 2), (0x175, 302, 2), (0x176, 304, 2), (0x177, 306, 2),
+    (0x178, 308, 2), (0x179, 310, 2), (0x17a, 312, 2), (0x17b, 314, 2), (0x17c, 316, 2),
+    (0x17d, 318, 2), (0x17e, 320, 2), (0x1a0, 322, 2), (0x1a1, 324, 2), (0x1af, 326, 2),
+    (0x1b0, 328, 2), (0x1cd, 330, 2), (0x1ce, 332, 2), (0x1cf, 334, 2), (0x1d0, 336, 2),
+    (0x1d1, 338, 2), (0x1d2, 340, 2), (0x1d3, 342, 2), (0x1d4, 344, 2), (0x1d5, 346, 3),
+    (0x1d6, 349, 3), (0x1d7, 352, 3), (0x1d8, 355, 3), (0x1d9, 358, 3), (0x1da, 361, 3),
+    (0x1db, 364, 3), (0x1dc, 367, 3), (0x1de, 370, 3), (0x1df, 373, 3), (0x1e0, 376, 3),
+    (0x1e1, 379, 3), (0x1e2, 382, 2), (0x1e3, 384, 2), (0x1e6, 386, 2), (0x1e7, 388, 2),
+    (0x1e8, 390, 2), (0x1e9, 392, 2), (0x1ea, 394, 2), (0x1eb, 396, 2), (0x1ec, 398, 3),
+    (0x1ed, 401, 3), (0x1ee, 404, 2), (0x1ef, 406, 2), (0x1f0, 408, 2), (0x1f4, 410, 2),
+    (0x1f5, 412, 2), (0x1f8, 414, 2), (0x1f9, 416, 2), (0x1fa, 418, 3), (0x1fb, 421, 3),
+    (0x1fc, 424, 2), (0x1fd, 426, 2), (0x1fe, 428, 2), (0x1ff, 430, 2), (0x200, 432, 2),
+    (0x201, 434, 2), (0x202, 436, 2), (0x203, 438, 2), (0x204, 440, 2), (0x205, 442, 2),
+    (0x206, 444, 2), (0x207, 446, 2), (0x208, 448, 2), (0x209, 450, 2), (0x20a, 452, 2),
+    (0x20b, 454, 2), (0x20c, 456, 2), (0x20d, 458, 2), (0x20e, 460, 2), (0x20f, 462, 2),
+    (0x210, 464, 2), (0x211, 466, 2), (0x212, 468, 2), (0x213, 470, 2), (0x214, 472, 2),
+    (0x215, 474, 2), (0x216, 476, 2), (0x217, 478, 2), (0x218, 480, 2), (0x219, 482, 2),
+    (0x21a, 484, 2), (0x21b, 486, 2), (0x21e, 488, 2), (0x21f, 490, 2), (0x226, 492, 2),
+    (0x227, 494, 2), (0x228, 496, 2), (0x229, 498, 2), (0x22a, 500, 3), (0x22b, 503, 3),
+    (0x22c, 506, 3), (0x22d, 509, 3), (0x22e, 512, 2), (0x22f, 514, 2), (0x230, 516, 3),
+    (0x231, 519, 3), (0x232, 522, 2), (0x233, 524, 2), (0x340, 526, 1), (0x341, 527, 1),
+    (0x343, 528, 1), (0x344, 529, 2), (0x374, 531, 1), (0x37e, 532, 1), (0x385, 533, 2),
+    (0x386, 535, 2), (0x387, 537, 1), (0x388, 538, 2), (0x389, 540, 2), (0x38a, 542, 2),
+    (0x38c, 544, 2), (0x38e, 546, 2), (0x38f, 548, 2), (0x390, 550, 3), (0x3aa, 553, 2),
+    (0x3ab, 555, 2), (0x3ac, 557, 2), (0x3ad, 559, 2), (0x3ae, 561, 2), (0x3af, 563, 2),
+    (0x3b0, 565, 3), (0x3ca, 568, 2), (0x3cb, 570, 2), (0x3cc, 572, 2), (0x3cd, 574, 2),
+    (0x3ce, 576, 2), (0x3d3, 578, 2), (0x3d4, 580, 2), (0x400, 582, 2), (0x401, 584, 2),
+    (0x403, 586, 2), (0x407, 588, 2), (0x40c, 590, 2), (0x40d, 592, 2), (0x40e, 594, 2),
+    (0x419, 596, 2), (0x439, 598, 2), (0x450, 600, 2), (0x451, 602, 2), (0x453, 604, 2),
+    (0x457, 606, 2), (0x45c, 608, 2), (0x45d, 610, 2), (0x45e, 612, 2), (0x476, 614, 2),
+    (0x477, 616, 2), (0x4c1, 618, 2), (0x4c2, 620, 2), (0x4d0, 622, 2), (0x4d1, 624, 2),
+    (0x4d2, 626, 2), (0x4d3, 628, 2), (0x4d6, 630, 2), (0x4d7, 632, 2), (0x4da, 634, 2),
+    (0x4db, 636, 2), (0x4dc, 638, 2), (0x4dd, 640, 2), (0x4de, 642, 2), (0x4df, 644, 2),
+    (0x4e2, 646, 2), (0x4e3, 648, 2), (0x4e4, 650, 2), (0x4e5, 652, 2), (0x4e6, 654, 2),
+    (0x4e7, 656, 2), (0x4ea, 658, 2), (0x4eb, 660, 2), (0x4ec, 662, 2), (0x4ed, 664, 2),
+    (0x4ee, 666, 2), (0x4ef, 668, 2), (0x4f0, 670, 2), (0x4f1, 672, 2), (0x4f2, 674, 2),
+    (0x4f3, 676, 2), (0x4f4, 678, 2), (0x4f5, 680, 2), (0x4f8, 682, 2), (0x4f9, 684, 2),
+    (0x622, 686, 2), (0x623, 688, 2), (0x624, 690, 2), (0x625, 692, 2), (0x626, 694, 2),
+    (0x6c0, 696, 2), (0x6c2, 698, 2), (0x6d3, 700, 2), (0x929, 702, 2), (0x931, 704, 2),
+    (0x934, 706, 2), (0x958, 708, 2), (0x959, 710, 2), (0x95a, 712, 2), (0x95b, 714, 2),
+    (0x95c, 716, 2), (0x95d, 718, 2), (0x95e, 720, 2), (0x95f, 722, 2), (0x9cb, 724, 2),
+    (0x9cc, 726, 2), (0x9dc, 728, 2), (0x9dd, 730, 2), (0x9df, 732, 2), (0xa33, 734, 2),
+    (0xa36, 736, 2), (0xa59, 738, 2), (0xa5a, 740, 2), (0xa5b, 742, 2), (0xa5e, 744, 2),
+    (0xb48, 746, 2), (0xb4b, 748, 2), (0xb4c, 750, 2), (0xb5c, 752, 2), (0xb5d, 754, 2),
+    (0xb94, 756, 2), (0xbca, 758, 2), (0xbcb, 760, 2), (0xbcc, 762, 2), (0xc48, 764, 2),
+    (0xcc0, 766, 2), (0xcc7, 768, 2), (0xcc8, 770, 2), (0xcca, 772, 2), (0xccb, 774, 3),
+    (0xd4a, 777, 2), (0xd4b, 779, 2), (0xd4c, 781, 2), (0xdda, 783, 2), (0xddc, 785, 2),
+    (0xddd, 787, 3), (0xdde, 790, 2), (0xf43, 792, 2), (0xf4d, 794, 2), (0xf52, 796, 2),
+    (0xf57, 798, 2), (0xf5c, 800, 2), (0xf69, 802, 2), (0xf73, 804, 2), (0xf75, 806, 2),
+    (0xf76, 808, 2), (0xf78, 810, 2), (0xf81, 812, 2), (0xf93, 814, 2), (0xf9d, 816, 2),
+    (0xfa2, 818, 2), (0xfa7, 820, 2), (0xfac, 822, 2), (0xfb9, 824, 2), (0x1026, 826, 2),
+    (0x1b06, 828, 2), (0x1b08, 830, 2), (0x1b0a, 832, 2), (0x1b0c, 834, 2), (0x1b0e, 836, 2),
+    (0x1b12, 838, 2), (0x1b3b, 840, 2), (0x1b3d, 842, 2), (0x1b40, 844, 2), (0x1b41, 846, 2),
+    (0x1b43, 848, 2), (0x1e00, 850, 2), (0x1e01, 852, 2), (0x1e02, 854, 2), (0x1e03, 856, 2),
+    (0x1e04, 858, 2), (0x1e05, 860, 2), (0x1e06, 862, 2), (0x1e07, 864, 2), (0x1e08, 866, 3),
+    (0x1e09, 869, 3), (0x1e0a, 872, 2), (0x1e0b, 874, 2), (0x1e0c, 876, 2), (0x1e0d, 878, 2),
+    (0x1e0e, 880, 2), (0x1e0f, 882, 2), (0x1e10, 884, 2), (0x1e11, 886, 2), (0x1e12, 888, 2),
+    (0x1e13, 890, 2), (0x1e14, 892, 3), (0x1e15, 895, 3), (0x1e16, 898, 3), (0x1e17, 901, 3),
+    (0x1e18, 904, 2), (0x1e19, 906, 2), (0x1e1a, 908, 2), (0x1e1b, 910, 2), (0x1e1c, 912, 3),
+    (0x1e1d, 915, 3), (0x1e1e, 918, 2), (0x1e1f, 920, 2), (0x1e20, 922, 2), (0x1e21, 924, 2),
+    (0x1e22, 926, 2), (0x1e23, 928, 2), (0x1e24, 930, 2), (0x1e25, 932, 2), (0x1e26, 934, 2),
+    (0x1e27, 936, 2), (0x1e28, 938, 2), (0x1e29, 940, 2), (0x1e2a, 942, 2), (0x1e2b, 944, 2),
+    (0x1e2c, 946, 2), (0x1e2d, 948, 2), (0x1e2e, 950, 3), (0x1e2f, 953, 3), (0x1e30, 956, 2),
+    (0x1e31, 958, 2), (0x1e32, 960, 2), (0x1e33, 962, 2), (0x1e34, 964, 2), (0x1e35, 966, 2),
+    (0x1e36, 968, 2), (0x1e37, 970, 2), (0x1e38, 972, 3), (0x1e39, 975, 3), (0x1e3a, 978, 2),
+    (0x1e3b, 980, 2), (0x1e3c, 982, 2), (0x1e3d, 984, 2), (0x1e3e, 986, 2), (0x1e3f, 988, 2),
+    (0x1e40, 990, 2), (0x1e41, 992, 2), (0x1e42, 994, 2), (0x1e43, 996, 2), (0x1e44, 998, 2),
+    (0x1e45, 1000, 2), (0x1e46, 1002, 2), (0x1e47, 1004, 2), (0x1e48, 1006, 2), (0x1e49, 1008, 2),
+    (0x1e4a, 1010, 2), (0x1e4b, 1012, 2), (0x1e4c, 1014, 3), (0x1e4d, 1017, 3), (0x1e4e, 1020, 3),
+    (0x1e4f, 1023, 3), (0x1e50, 1026, 3), (0x1e51, 1029, 3), (0x1e52, 1032, 3), (0x1e53, 1035, 3),
+    (0x1e54, 1038, 2), (0x1e55, 1040, 2), (0x1e56, 1042, 2), (0x1e57, 1044, 2), (0x1e58, 1046, 2),
+    (0x1e59, 1048, 2), (0x1e5a, 1050, 2), (0x1e5b, 1052, 2), (0x1e5c, 1054, 3), (0x1e5d, 1057, 3),
+    (0x1e5e, 1060, 2), (0x1e5f, 1062, 2), (0x1e60, 1064, 2), (0x1e61, 1066, 2), (0x1e62, 1068, 2),
+    (0x1e63, 1070, 2), (0x1e64, 1072, 3), (0x1e65, 1075, 3), (0x1e66, 1078, 3), (0x1e67, 1081, 3),
+    (0x1e68, 1084, 3), (0x1e69, 1087, 3), (0x1e6a, 1090, 2), (0x1e6b, 1092, 2), (0x1e6c, 1094, 2),
+    (0x1e6d, 1096, 2), (0x1e6e, 1098, 2), (0x1e6f, 1100, 2), (0x1e70, 1102, 2), (0x1e71, 1104, 2),
+    (0x1e72, 1106, 2), (0x1e73, 1108, 2), (0x1e74, 1110, 2), (0x1e75, 1112, 2), (0x1e76, 1114, 2),
+    (0x1e77, 1116, 2), (0x1e78, 1118, 3), (0x1e79, 1121, 3), (0x1e7a, 1124, 3), (0x1e7b, 1127, 3),
+    (0x1e7c, 1130, 2), (0x1e7d, 1132, 2), (0x1e7e, 1134, 2), (0x1e7f, 1136, 2), (0x1e80, 1138, 2),
+    (0x1e81, 1140, 2), (0x1e82, 1142, 2), (0x1e83, 1144, 2), (0x1e84, 1146, 2), (0x1e85, 1148, 2),
+    (0x1e86, 1150, 2), (0x1e87, 1152, 2), (0x1e88, 1154, 2), (0x1e89, 1156, 2), (0x1e8a, 1158, 2),
+    (0x1e8b, 1160, 2), (0x1e8c, 1162, 2), (0x1e8d, 1164, 2), (0x1e8e, 1166, 2), (0x1e8f, 1168, 2),
+    (0x1e90, 1170, 2), (0x1e91, 1172, 2), (0x1e92, 1174, 2), (0x1e93, 1176, 2), (0x1e94, 1178, 2),
+    (0x1e95, 1180, 2), (0x1e96, 1182, 2), (0x1e97, 1184, 2), (0x1e98, 1186, 2), (0x1e99, 1188, 2),
+    (0x1e9b, 1190, 2), (0x1ea0, 1192, 2), (0x1ea1, 1194, 2), (0x1ea2, 1196, 2), (0x1ea3, 1198, 2),
+    (0x1ea4, 1200, 3), (0x1ea5, 1203, 3), (0x1ea6, 1206, 3), (0x1ea7, 1209, 3), (0x1ea8, 1212, 3),
+    (0x1ea9, 1215, 3), (0x1eaa, 1218, 3), (0x1eab, 1221, 3), (0x1eac, 1224, 3), (0x1ead, 1227, 3),
+    (0x1eae, 1230, 3), (0x1eaf, 1233, 3), (0x1eb0, 1236, 3), (0x1eb1, 1239, 3), (0x1eb2, 1242, 3),
+    (0x1eb3, 1245, 3), (0x1eb4, 1248, 3), (0x1eb5, 1251, 3), (0x1eb6, 1254, 3), (0x1eb7, 1257, 3),
+    (0x1eb8, 1260, 2), (0x1eb9, 1262, 2), (0x1eba, 1264, 2), (0x1ebb, 1266, 2), (0x1ebc, 1268, 2),
+    (0x1ebd, 1270, 2), (0x1ebe, 1272, 3), (0x1ebf, 1275, 3), (0x1ec0, 1278, 3), (0x1ec1, 1281, 3),
+    (0x1ec2, 1284, 3), (0x1ec3, 1287, 3), (0x1ec4, 1290, 3), (0x1ec5, 1293, 3), (0x1ec6, 1296, 3),
+    (0x1ec7, 1299, 3), (0x1ec8, 1302, 2), (0x1ec9, 1304, 2), (0x1eca, 1306, 2), (0x1ecb, 1308, 2),
+    (0x1ecc, 1310, 2), (0x1ecd, 1312, 2), (0x1ece, 1314, 2), (0x1ecf, 1316, 2), (0x1ed0, 1318, 3),
+    (0x1ed1, 1321, 3), (0x1ed2, 1324, 3), (0x1ed3, 1327, 3), (0x1ed4, 1330, 3), (0x1ed5, 1333, 3),
+    (0x1ed6, 1336, 3), (0x1ed7, 1339, 3), (0x1ed8, 1342, 3), (0x1ed9, 1345, 3), (0x1eda, 1348, 3),
+    (0x1edb, 1351, 3), (0x1edc, 1354, 3), (0x1edd, 1357, 3), (0x1ede, 1360, 3), (0x1edf, 1363, 3),
+    (0x1ee0, 1366, 3), (0x1ee1, 1369, 3), (0x1ee2, 1372, 3), (0x1ee3, 1375, 3), (0x1ee4, 1378, 2),
+    (0x1ee5, 1380, 2), (0x1ee6, 1382, 2), (0x1ee7, 1384, 2), (0x1ee8, 1386, 3), (0x1ee9, 1389, 3),
+    (0x1eea, 1392, 3), (0x1eeb, 1395, 3), (0x1eec, 1398, 3), (0x1eed, 1401, 3), (0x1eee, 1404, 3),
+    (0x1eef, 1407, 3), (0x1ef0, 1410, 3), (0x1ef1, 1413, 3), (0x1ef2, 1416, 2), (0x1ef3, 1418, 2),
+    (0x1ef4, 1420, 2), (0x1ef5, 1422, 2), (0x1ef6, 1424, 2), (0x1ef7, 1426, 2), (0x1ef8, 1428, 2),
+    (0x1ef9, 1430, 2), (0x1f00, 1432, 2), (0x1f01, 1434, 2), (0x1f02, 1436, 3), (0x1f03, 1439, 3),
+    (0x1f04, 1442, 3), (0x1f05, 1445, 3), (0x1f06, 1448, 3), (0x1f07, 1451, 3), (0x1f08, 1454, 2),
+    (0x1f09, 1456, 2), (0x1f0a, 1458, 3), (0x1f0b, 1461, 3), (0x1f0c, 1464, 3), (0x1f0d, 1467, 3),
+    (0x1f0e, 1470, 3), (0x1f0f, 1473, 3), (0x1f10, 1476, 2), (0x1f11, 1478, 2), (0x1f12, 1480, 3),
+    (0x1f13, 1483, 3), (0x1f14, 1486, 3), (0x1f15, 1489, 3), (0x1f18, 1492, 2), (0x1f19, 1494, 2),
+    (0x1f1a, 1496, 3), (0x1f1b, 1499, 3), (0x1f1c, 1502, 3), (0x1f1d, 1505, 3), (0x1f20, 1508, 2),
+    (0x1f21, 1510, 2), (0x1f22, 1512, 3), (0x1f23, 1515, 3), (0x1f24, 1518, 3), (0x1f25, 1521, 3),
+    (0x1f26, 1524, 3), (0x1f27, 1527, 3), (0x1f28, 1530, 2), (0x1f29, 1532, 2), (0x1f2a, 1534, 3),
+    (0x1f2b, 1537, 3), (0x1f2c, 1540, 3), (0x1f2d, 1543, 3), (0x1f2e, 1546, 3), (0x1f2f, 1549, 3),
+    (0x1f30, 1552, 2), (0x1f31, 1554, 2), (0x1f32, 1556, 3), (0x1f33, 1559, 3), (0x1f34, 1562, 3),
+    (0x1f35, 1565, 3), (0x1f36, 1568, 3), (0x1f37, 1571, 3), (0x1f38, 1574, 2), (0x1f39, 1576, 2),
+    (0x1f3a, 1578, 3), (0x1f3b, 1581, 3), (0x1f3c, 1584, 3), (0x1f3d, 1587, 3), (0x1f3e, 1590, 3),
+    (0x1f3f, 1593, 3), (0x1f40, 1596, 2), (0x1f41, 1598, 2), (0x1f42, 1600, 3), (0x1f43, 1603, 3),
+    (0x1f44, 1606, 3), (0x1f45, 1609, 3), (0x1f48, 1612, 2), (0x1f49, 1614, 2), (0x1f4a, 1616, 3),
+    (0x1f4b, 1619, 3), (0x1f4c, 1622, 3), (0x1f4d, 1625, 3), (0x1f50, 1628, 2), (0x1f51, 1630, 2),
+    (0x1f52, 1632, 3), (0x1f53, 1635, 3), (0x1f54, 1638, 3), (0x1f55, 1641, 3), (0x1f56, 1644, 3),
+    (0x1f57, 1647, 3), (0x1f59, 1650, 2), (0x1f5b, 1652, 3), (0x1f5d, 1655, 3), (0x1f5f, 1658, 3),
+    (0x1f60, 1661, 2), (0x1f61, 1663, 2), (0x1f62, 1665, 3), (0x1f63, 1668, 3), (0x1f64, 1671, 3),
+    (0x1f65, 1674, 3), (0x1f66, 1677, 3), (0x1f67, 1680, 3), (0x1f68, 1683, 2), (0x1f69, 1685, 2),
+    (0x1f6a, 1687, 3), (0x1f6b, 1690, 3), (0x1f6c, 1693, 3), (0x1f6d, 1696, 3), (0x1f6e, 1699, 3),
+    (0x1f6f, 1702, 3), (0x1f70, 1705, 2), (0x1f71, 1707, 2), (0x1f72, 1709, 2), (0x1f73, 1711, 2),
+    (0x1f74, 1713, 2), (0x1f75, 1715, 2), (0x1f76, 1717, 2), (0x1f77, 1719, 2), (0x1f78, 1721, 2),
+    (0x1f79, 1723, 2), (0x1f7a, 1725, 2), (0x1f7b, 1727, 2), (0x1f7c, 1729, 2), (0x1f7d, 1731, 2),
+    (0x1f80, 1733, 3), (0x1f81, 1736, 3), (0x1f82, 1739, 4), (0x1f83, 1743, 4), (0x1f84, 1747, 4),
+    (0x1f85, 1751, 4), (0x1f86, 1755, 4), (0x1f87, 1759, 4), (0x1f88, 1763, 3), (0x1f89, 1766, 3),
+    (0x1f8a, 1769, 4), (0x1f8b, 1773, 4), (0x1f8c, 1777, 4), (0x1f8d, 1781, 4), (0x1f8e, 1785, 4),
+    (0x1f8f, 1789, 4), (0x1f90, 1793, 3), (0x1f91, 1796, 3), (0x1f92, 1799, 4), (0x1f93, 1803, 4),
+    (0x1f94, 1807, 4), (0x1f95, 1811, 4), (0x1f96, 1815, 4), (0x1f97, 1819, 4), (0x1f98, 1823, 3),
+    (0x1f99, 1826, 3), (0x1f9a, 1829, 4), (0x1f9b, 1833, 4), (0x1f9c, 1837, 4), (0x1f9d, 1841, 4),
+    (0x1f9e, 1845, 4), (0x1f9f, 1849, 4), (0x1fa0, 1853, 3), (0x1fa1, 1856, 3), (0x1fa2, 1859, 4),
+    (0x1fa3, 1863, 4), (0x1fa4, 1867, 4), (0x1fa5, 1871, 4), (0x1fa6, 1875, 4), (0x1fa7, 1879, 4),
+    (0x1fa8, 1883, 3), (0x1fa9, 1886, 3), (0x1faa, 1889, 4), (0x1fab, 1893, 4), (0x1fac, 1897, 4),
+    (0x1fad, 1901, 4), (0x1fae, 1905, 4), (0x1faf, 1909, 4), (0x1fb0, 1913, 2), (0x1fb1, 1915, 2),
+    (0x1fb2, 1917, 3), (0x1fb3, 1920, 2), (0x1fb4, 1922, 3), (0x1fb6, 1925, 2), (0x1fb7, 1927, 3),
+    (0x1fb8, 1930, 2), (0x1fb9, 1932, 2), (0x1fba, 1934, 2), (0x1fbb, 1936, 2), (0x1fbc, 1938, 2),
+    (0x1fbe, 1940, 1), (0x1fc1, 1941, 2), (0x1fc2, 1943, 3), (0x1fc3, 1946, 2), (0x1fc4, 1948, 3),
+    (0x1fc6, 1951, 2), (0x1fc7, 1953, 3), (0x1fc8, 1956, 2), (0x1fc9, 1958, 2), (0x1fca, 1960, 2),
+    (0x1fcb, 1962, 2), (0x1fcc, 1964, 2), (0x1fcd, 1966, 2), (0x1fce, 1968, 2), (0x1fcf, 1970, 2),
+    (0x1fd0, 1972, 2), (0x1fd1, 1974, 2), (0x1fd2, 1976, 3), (0x1fd3, 1979, 3), (0x1fd6, 1982, 2),
+    (0x1fd7, 1984, 3), (0x1fd8, 1987, 2), (0x1fd9, 1989, 2), (0x1fda, 1991, 2), (0x1fdb, 1993, 2),
+    (0x1fdd, 1995, 2), (0x1fde, 1997, 2), (0x1fdf, 1999, 2), (0x1fe0, 2001, 2), (0x1fe1, 2003, 2),
+    (0x1fe2, 2005, 3), (0x1fe3, 2008, 3), (0x1fe4, 2011, 2), (0x1fe5, 2013, 2), (0x1fe6, 2015, 2),
+    (0x1fe7, 2017, 3), (0x1fe8, 2020, 2), (0x1fe9, 2022, 2), (0x1fea, 2024, 2), (0x1feb, 2026, 2),
+    (0x1fec, 2028, 2), (0x1fed, 2030, 2), (0x1fee, 2032, 2), (0x1fef, 2034, 1), (0x1ff2, 2035, 3),
+    (0x1ff3, 2038, 2), (0x1ff4, 2040, 3), (0x1ff6, 2043, 2), (0x1ff7, 2045, 3), (0x1ff8, 2048, 2),
+    (0x1ff9, 2050, 2), (0x1ffa, 2052, 2), (0x1ffb, 2054, 2), (0x1ffc, 2056, 2), (0x1ffd, 2058, 1),
+    (0x2000, 2059, 1), (0x2001, 2060, 1), (0x2126, 2061, 1), (0x212a, 2062, 1), (0x212b, 2063, 2),
+    (0x219a, 2065, 2), (0x219b, 2067, 2), (0x21ae, 2069, 2), (0x21cd, 2071, 2), (0x21ce, 2073, 2),
+    (0x21cf, 2075, 2), (0x2204, 2077, 2), (0x2209, 2079, 2), (0x220c, 2081, 2), (0x2224, 2083, 2),
+    (0x2226, 2085, 2), (0x2241, 2087, 2), (0x2244, 2089, 2), (0x2247, 2091, 2), (0x2249, 2093, 2),
+    (0x2260, 2095, 2), (0x2262, 2097, 2), (0x226d, 2099, 2), (0x226e, 2101, 2), (0x226f, 2103, 2),
+    (0x2270, 2105, 2), (0x2271, 2107, 2), (0x2274, 2109, 2), (0x2275, 2111, 2), (0x2278, 2113, 2),
+    (0x2279, 2115, 2), (0x2280, 2117, 2), (0x2281, 2119, 2), (0x2284, 2121, 2), (0x2285, 2123, 2),
+    (0x2288, 2125, 2), (0x2289, 2127, 2), (0x22ac, 2129, 2), (0x22ad, 2131, 2), (0x22ae, 2133, 2),
+    (0x22af, 2135, 2), (0x22e0, 2137, 2), (0x22e1, 2139, 2), (0x22e2, 2141, 2), (0x22e3, 2143, 2),
+    (0x22ea, 2145, 2), (0x22eb, 2147, 2), (0x22ec, 2149, 2), (0x22ed, 2151, 2), (0x2329, 2153, 1),
+    (0x232a, 2154, 1), (0x2adc, 2155, 2), (0x304c, 2157, 2), (0x304e, 2159, 2), (0x3050, 2161, 2),
+    (0x3052, 2163, 2), (0x3054, 2165, 2), (0x3056, 2167, 2), (0x3058, 2169, 2), (0x305a, 2171, 2),
+    (0x305c, 2173, 2), (0x305e, 2175, 2), (0x3060, 2177, 2), (0x3062, 2179, 2), (0x3065, 2181, 2),
+    (0x3067, 2183, 2), (0x3069, 2185, 2), (0x3070, 2187, 2), (0x3071, 2189, 2), (0x3073, 2191, 2),
+    (0x3074, 2193, 2), (0x3076, 2195, 2), (0x3077, 2197, 2), (0x3079, 2199, 2), (0x307a, 2201, 2),
+    (0x307c, 2203, 2), (0x307d, 2205, 2), (0x3094, 2207, 2), (0x309e, 2209, 2), (0x30ac, 2211, 2),
+    (0x30ae, 2213, 2), (0x30b0, 2215, 2), (0x30b2, 2217, 2), (0x30b4, 2219, 2), (0x30b6, 2221, 2),
+    (0x30b8, 2223, 2), (0x30ba, 2225, 2), (0x30bc, 2227, 2), (0x30be, 2229, 2), (0x30c0, 2231, 2),
+    (0x30c2, 2233, 2), (0x30c5, 2235, 2), (0x30c7, 2237, 2), (0x30c9, 2239, 2), (0x30d0, 2241, 2),
+    (0x30d1, 2243, 2), (0x30d3, 2245, 2), (0x30d4, 2247, 2), (0x30d6, 2249, 2), (0x30d7, 2251, 2),
+    (0x30d9, 2253, 2), (0x30da, 2255, 2), (0x30dc, 2257, 2), (0x30dd, 2259, 2), (0x30f4, 2261, 2),
+    (0x30f7, 2263, 2), (0x30f8, 2265, 2), (0x30f9, 2267, 2), (0x30fa, 2269, 2), (0x30fe, 2271, 2),
+    (0xf900, 2273, 1), (0xf901, 2274, 1), (0xf902, 2275, 1), (0xf903, 2276, 1), (0xf904, 2277, 1),
+    (0xf905, 2278, 1), (0xf906, 2279, 1), (0xf907, 2280, 1), (0xf908, 2281, 1), (0xf909, 2282, 1),
+    (0xf90a, 2283, 1), (0xf90b, 2284, 1), (0xf90c, 2285, 1), (0xf90d, 2286, 1), (0xf90e, 2287, 1),
+    (0xf90f, 2288, 1), (0xf910, 2289, 1), (0xf911, 2290, 1), (0xf912, 2291, 1), (0xf913, 2292, 1),
+    (0xf914, 2293, 1), (0xf915, 2294, 1), (0xf916, 2295, 1), (0xf917, 2296, 1), (0xf918, 2297, 1),
+    (0xf919, 2298, 1), (0xf91a, 2299, 1), (0xf91b, 2300, 1), (0xf91c, 2301, 1), (0xf91d, 2302, 1),
+    (0xf91e, 2303, 1), (0xf91f, 2304, 1), (0xf920, 2305, 1), (0xf921, 2306, 1), (0xf922, 2307, 1),
+    (0xf923, 2308, 1), (0xf924, 2309, 1), (0xf925, 2310, 1), (0xf926, 2311, 1), (0xf927, 2312, 1),
+    (0xf928, 2313, 1), (0xf929, 2314, 1), (0xf92a, 2315, 1), (0xf92b, 2316, 1), (0xf92c, 2317, 1),
+    (0xf92d, 2318, 1), (0xf92e, 2319, 1), (0xf92f, 2320, 1), (0xf930, 2321, 1), (0xf931, 2322, 1),
+    (0xf932, 2323, 1), (0xf933, 2324, 1), (0xf934, 2325, 1), (0xf935, 2326, 1), (0xf936, 2327, 1),
+    (0xf937, 2328, 1), (0xf938, 2329, 1), (0xf939, 2330, 1), (0xf93a, 2331, 1), (0xf93b, 2332, 1),
+    (0xf93c, 2333, 1), (0xf93d, 2334, 1), (0xf93e, 2335, 1), (0xf93f, 2336, 1), (0xf940, 2337, 1),
+    (0xf941, 2338, 1), (0xf942, 2339, 1), (0xf943, 2340, 1), (0xf944, 2341, 1), (0xf945, 2342, 1),
+    (0xf946, 2343, 1), (0xf947, 2344, 1), (0xf948, 2345, 1), (0xf949, 2346, 1), (0xf94a, 2347, 1),
+    (0xf94b, 2348, 1), (0xf94c, 2349, 1), (0xf94d, 2350, 1), (0xf94e, 2351, 1), (0xf94f, 2352, 1),
+    (0xf950, 2353, 1), (0xf951, 2354, 1), (0xf952, 2355, 1), (0xf953, 2356, 1), (0xf954, 2357, 1),
+    (0xf955, 2358, 1), (0xf956, 2359, 1), (0xf957, 2360, 1), (0xf958, 2361, 1), (0xf959, 2362, 1),
+    (0xf95a, 2363, 1), (0xf95b, 2364, 1), (0xf95c, 2365, 1), (0xf95d, 2366, 1), (0xf95e, 2367, 1),
+    (0xf95f, 2368, 1), (0xf960, 2369, 1), (0xf961, 2370, 1), (0xf962, 2371, 1), (0xf963, 2372, 1),
+    (0xf964, 2373, 1), (0xf965, 2374, 1), (0xf966, 2375, 1), (0xf967, 2376, 1), (0xf968, 2377, 1),
+    (0xf969, 2378, 1), (0xf96a, 2379, 1), (0xf96b, 2380, 1), (0xf96c, 2381, 1), (0xf96d, 2382, 1),
+    (0xf96e, 2383, 1), (0xf96f, 2384, 1), (0xf970, 2385, 1), (0xf971, 2386, 1), (0xf972, 2387, 1),
+    (0xf973, 2388, 1), (0xf974, 2389, 1), (0xf975, 2390, 1), (0xf976, 2391, 1), (0xf977, 2392, 1),
+    (0xf978, 2393, 1), (0xf979, 2394, 1), (0xf97a, 2395, 1), (0xf97b, 2396, 1), (0xf97c, 2397, 1),
+    (0xf97d, 2398, 1), (0xf97e, 2399, 1), (0xf97f, 2400, 1), (0xf980, 2401, 1), (0xf981, 2402, 1),
+    (0xf982, 2403, 1), (0xf983, 2404, 1), (0xf984, 2405, 1), (0xf985, 2406, 1), (0xf986, 2407, 1),
+    (0xf987, 2408, 1), (0xf988, 2409, 1), (0xf989, 2410, 1), (0xf98a, 2411, 1), (0xf98b, 2412, 1),
+    (0xf98c, 2413, 1), (0xf98d, 2414, 1), (0xf98e, 2415, 1), (0xf98f, 2416, 1), (0xf990, 2417, 1),
+    (0xf991, 2418, 1), (0xf992, 2419, 1), (0xf993, 2420, 1), (0xf994, 2421, 1), (0xf995, 2422, 1),
+    (0xf996, 2423, 1), (0xf997, 2424, 1), (0xf998, 2425, 1), (0xf999, 2426, 1), (0xf99a, 2427, 1),
+    (0xf99b, 2428, 1), (0xf99c, 2429, 1), (0xf99d, 2430, 1), (0xf99e, 2431, 1), (0xf99f, 2432, 1),
+    (0xf9a0, 2433, 1), (0xf9a1, 2434, 1), (0xf9a2, 2435, 1), (0xf9a3, 2436, 1), (0xf9a4, 2437, 1),
+    (0xf9a5, 2438, 1), (0xf9a6, 2439, 1), (0xf9a7, 2440, 1), (0xf9a8, 2441, 1), (0xf9a9, 2442, 1),
+    (0xf9aa, 2443, 1), (0xf9ab, 2444, 1), (0xf9ac, 2445, 1), (0xf9ad, 2446, 1), (0xf9ae, 2447, 1),
+    (0xf9af, 2448, 1), (0xf9b0, 2449, 1), (0xf9b1, 2450, 1), (0xf9b2, 2451, 1), (0xf9b3, 2452, 1),
+    (0xf9b4, 2453, 1), (0xf9b5, 2454, 1), (0xf9b6, 2455, 1), (0xf9b7, 2456, 1), (0xf9b8, 2457, 1),
+    (0xf9b9, 2458, 1), (0xf9ba, 2459, 1), (0xf9bb, 2460, 1), (0xf9bc, 2461, 1), (0xf9bd, 2462, 1),
+    (0xf9be, 2463, 1), (0xf9bf, 2464, 1), (0xf9c0, 2465, 1), (0xf9c1, 2466, 1), (0xf9c2, 2467, 1),
+    (0xf9c3, 2468, 1), (0xf9c4, 2469, 1), (0xf9c5, 2470, 1), (0xf9c6, 2471, 1), (0xf9c7, 2472, 1),
+    (0xf9c8, 2473, 1), (0xf9c9, 2474, 1), (0xf9ca, 2475, 1), (0xf9cb, 2476, 1), (0xf9cc, 2477, 1),
+    (0xf9cd, 2478, 1), (0xf9ce, 2479, 1), (0xf9cf, 2480, 1), (0xf9d0, 2481, 1), (0xf9d1, 2482, 1),
+    (0xf9d2, 2483, 1), (0xf9d3, 2484, 1), (0xf9d4, 2485, 1), (0xf9d5, 2486, 1), (0xf9d6, 2487, 1),
+    (0xf9d7, 2488, 1), (0xf9d8, 2489, 1), (0xf9d9, 2490, 1), (0xf9da, 2491, 1), (0xf9db, 2492, 1),
+    (0xf9dc, 2493, 1), (0xf9dd, 2494, 1), (0xf9de, 2495, 1), (0xf9df, 2496, 1), (0xf9e0, 2497, 1),
+    (0xf9e1, 2498, 1), (0xf9e2, 2499, 1), (0xf9e3, 2500, 1), (0xf9e4, 2501, 1), (0xf9e5, 2502, 1),
+    (0xf9e6, 2503, 1), (0xf9e7, 2504, 1), (0xf9e8, 2505, 1), (0xf9e9, 2506, 1), (0xf9ea, 2507, 1),
+    (0xf9eb, 2508, 1), (0xf9ec, 2509, 1), (0xf9ed, 2510, 1), (0xf9ee, 2511, 1), (0xf9ef, 2512, 1),
+    (0xf9f0, 2513, 1), (0xf9f1, 2514, 1), (0xf9f2, 2515, 1), (0xf9f3, 2516, 1), (0xf9f4, 2517, 1),
+    (0xf9f5, 2518, 1), (0xf9f6, 2519, 1), (0xf9f7, 2520, 1), (0xf9f8, 2521, 1), (0xf9f9, 2522, 1),
+    (0xf9fa, 2523, 1), (0xf9fb, 2524, 1), (0xf9fc, 2525, 1), (0xf9fd, 2526, 1), (0xf9fe, 2527, 1),
+    (0xf9ff, 2528, 1), (0xfa00, 2529, 1), (0xfa01, 2530, 1), (0xfa02, 2531, 1), (0xfa03, 2532, 1),
+    (0xfa04, 2533, 1), (0xfa05, 2534, 1), (0xfa06, 2535, 1), (0xfa07, 2536, 1), (0xfa08, 2537, 1),
+    (0xfa09, 2538, 1), (0xfa0a, 2539, 1), (0xfa0b, 2540, 1), (0xfa0c, 2541, 1), (0xfa0d, 2542, 1),
+    (0xfa10, 2543, 1), (0xfa12, 2544, 1), (0xfa15, 2545, 1), (0xfa16, 2546, 1), (0xfa17, 2547, 1),
+    (0xfa18, 2548, 1), (0xfa19, 2549, 1), (0xfa1a, 2550, 1), (0xfa1b, 2551, 1), (0xfa1c, 2552, 1),
+    (0xfa1d, 2553, 1), (0xfa1e, 2554, 1), (0xfa20, 2555, 1), (0xfa22, 2556, 1), (0xfa25, 2557, 1),
+    (0xfa26, 2558, 1), (0xfa2a, 2559, 1), (0xfa2b, 2560, 1), (0xfa2c, 2561, 1), (0xfa2d, 2562, 1),
+    (0xfa2e, 2563, 1), (0xfa2f, 2564, 1), (0xfa30, 2565, 1), (0xfa31, 2566, 1), (0xfa32, 2567, 1),
+    (0xfa33, 2568, 1), (0xfa34, 2569, 1), (0xfa35, 2570, 1), (0xfa36, 2571, 1), (0xfa37, 2572, 1),
+    (0xfa38, 2573, 1), (0xfa39, 2574, 1), (0xfa3a, 2575, 1), (0xfa3b, 2576, 1), (0xfa3c, 2577, 1),
+    (0xfa3d, 2578, 1), (0xfa3e, 2579, 1), (0xfa3f, 2580, 1), (0xfa40, 2581, 1), (0xfa41, 2582, 1),
+    (0xfa42, 2583, 1), (0xfa43, 2584, 1), (0xfa44, 2585, 1), (0xfa45, 2586, 1), (0xfa46, 2587, 1),
+    (0xfa47, 2588, 1), (0xfa48, 2589, 1), (0xfa49, 2590, 1), (0xfa4a, 2591, 1), (0xfa4b, 2592, 1),
+    (0xfa4c, 2593, 1), (0xfa4d, 2594, 1), (0xfa4e, 2595, 1), (0xfa4f, 2596, 1), (0xfa50, 2597, 1),
+    (0xfa51, 2598, 1), (0xfa52, 2599, 1), (0xfa53, 2600, 1), (0xfa54, 2601, 1), (0xfa55, 2602, 1),
+    (0xfa56, 2603, 1), (0xfa57, 2604, 1), (0xfa58, 2605, 1), (0xfa59, 2606, 1), (0xfa5a, 2607, 1),
+    (0xfa5b, 2608, 1), (0xfa5c, 2609, 1), (0xfa5d, 2610, 1), (0xfa5e, 2611, 1), (0xfa5f, 2612, 1),
+    (0xfa60, 2613, 1), (0xfa61, 2614, 1), (0xfa62, 2615, 1), (0xfa63, 2616, 1), (0xfa64, 2617, 1),
+    (0xfa65, 2618, 1), (0xfa66, 2619, 1), (0xfa67, 2620, 1), (0xfa68, 2621, 1), (0xfa69, 2622, 1),
+    (0xfa6a, 2623, 1), (0xfa6b, 2624, 1), (0xfa6c, 2625, 1), (0xfa6d, 2626, 1), (0xfa70, 2627, 1),
+    (0xfa71, 2628, 1), (0xfa72, 2629, 1), (0xfa73, 2630, 1), (0xfa74, 2631, 1), (0xfa75, 2632, 1),
+    (0xfa76, 2633, 1), (0xfa77, 2634, 1), (0xfa78, 2635, 1), (0xfa79, 2636, 1), (0xfa7a, 2637, 1),
+    (0xfa7b, 2638, 1), (0xfa7c, 2639, 1), (0xfa7d, 2640, 1), (0xfa7e, 2641, 1), (0xfa7f, 2642, 1),
+    (0xfa80, 2643, 1), (0xfa81, 2644, 1), (0xfa82, 2645, 1), (0xfa83, 2646, 1), (0xfa84, 2647, 1),
+    (0xfa85, 2648, 1), (0xfa86, 2649, 1), (0xfa87, 2650, 1), (0xfa88, 2651, 1), (0xfa89, 2652, 1),
+    (0xfa8a, 2653, 1), (0xfa8b, 2654, 1), (0xfa8c, 2655, 1), (0xfa8d, 2656, 1), (0xfa8e, 2657, 1),
+    (0xfa8f, 2658, 1), (0xfa90, 2659, 1), (0xfa91, 2660, 1), (0xfa92, 2661, 1), (0xfa93, 2662, 1),
+    (0xfa94, 2663, 1), (0xfa95, 2664, 1), (0xfa96, 2665, 1), (0xfa97, 2666, 1), (0xfa98, 2667, 1),
+    (0xfa99, 2668, 1), (0xfa9a, 2669, 1), (0xfa9b, 2670, 1), (0xfa9c, 2671, 1), (0xfa9d, 2672, 1),
+    (0xfa9e, 2673, 1), (0xfa9f, 2674, 1), (0xfaa0, 2675, 1), (0xfaa1, 2676, 1), (0xfaa2, 2677, 1),
+    (0xfaa3, 2678, 1), (0xfaa4, 2679, 1), (0xfaa5, 2680, 1), (0xfaa6, 2681, 1), (0xfaa7, 2682, 1),
+    (0xfaa8, 2683, 1), (0xfaa9, 2684, 1), (0xfaaa, 2685, 1), (0xfaab, 2686, 1), (0xfaac, 2687, 1),
+    (0xfaad, 2688, 1), (0xfaae, 2689, 1), (0xfaaf, 2690, 1), (0xfab0, 2691, 1), (0xfab1, 2692, 1),
+    (0xfab2, 2693, 1), (0xfab3, 2694, 1), (0xfab4, 2695, 1), (0xfab5, 2696, 1), (0xfab6, 2697, 1),
+    (0xfab7, 2698, 1), (0xfab8, 2699, 1), (0xfab9, 2700, 1), (0xfaba, 2701, 1), (0xfabb, 2702, 1),
+    (0xfabc, 2703, 1), (0xfabd, 2704, 1), (0xfabe, 2705, 1), (0xfabf, 2706, 1), (0xfac0, 2707, 1),
+    (0xfac1, 2708, 1), (0xfac2, 2709, 1), (0xfac3, 2710, 1), (0xfac4, 2711, 1), (0xfac5, 2712, 1),
+    (0xfac6, 2713, 1), (0xfac7, 2714, 1), (0xfac8, 2715, 1), (0xfac9, 2716, 1), (0xfaca, 2717, 1),
+    (0xfacb, 2718, 1), (0xfacc, 2719, 1), (0xfacd, 2720, 1), (0xface, 2721, 1), (0xfacf, 2722, 1),
+    (0xfad0, 2723, 1), (0xfad1, 2724, 1), (0xfad2, 2725, 1), (0xfad3, 2726, 1), (0xfad4, 2727, 1),
+    (0xfad5, 2728, 1), (0xfad6, 2729, 1), (0xfad7, 2730, 1), (0xfad8, 2731, 1), (0xfad9, 2732, 1),
+    (0xfb1d, 2733, 2), (0xfb1f, 2735, 2), (0xfb2a, 2737, 2), (0xfb2b, 2739, 2), (0xfb2c, 2741, 3),
+    (0xfb2d, 2744, 3), (0xfb2e, 2747, 2), (0xfb2f, 2749, 2), (0xfb30, 2751, 2), (0xfb31, 2753, 2),
+    (0xfb32, 2755, 2), (0xfb33, 2757, 2), (0xfb34, 2759, 2), (0xfb35, 2761, 2), (0xfb36, 2763, 2),
+    (0xfb38, 2765, 2), (0xfb39, 2767, 2), (0xfb3a, 2769, 2), (0xfb3b, 2771, 2), (0xfb3c, 2773, 2),
+    (0xfb3e, 2775, 2), (0xfb40, 2777, 2), (0xfb41, 2779, 2), (0xfb43, 2781, 2), (0xfb44, 2783, 2),
+    (0xfb46, 2785, 2), (0xfb47, 2787, 2), (0xfb48, 2789, 2), (0xfb49, 2791, 2), (0xfb4a, 2793, 2),
+    (0xfb4b, 2795, 2), (0xfb4c, 2797, 2), (0xfb4d, 2799, 2), (0xfb4e, 2801, 2), (0x1109a, 2803, 2),
+    (0x1109c, 2805, 2), (0x110ab, 2807, 2), (0x1112e, 2809, 2), (0x1112f, 2811, 2),
+    (0x1134b, 2813, 2), (0x1134c, 2815, 2), (0x114bb, 2817, 2), (0x114bc, 2819, 2),
+    (0x114be, 2821, 2), (0x115ba, 2823, 2), (0x115bb, 2825, 2), (0x11938, 2827, 2),
+    (0x1d15e, 2829, 2), (0x1d15f, 2831, 2), (0x1d160, 2833, 3), (0x1d161, 2836, 3),
+    (0x1d162, 2839, 3), (0x1d163, 2842, 3), (0x1d164, 2845, 3), (0x1d1bb, 2848, 2),
+    (0x1d1bc, 2850, 2), (0x1d1bd, 2852, 3), (0x1d1be, 2855, 3), (0x1d1bf, 2858, 3),
+    (0x1d1c0, 2861, 3), (0x2f800, 2864, 1), (0x2f801, 2865, 1), (0x2f802, 2866, 1),
+    (0x2f803, 2867, 1), (0x2f804, 2868, 1), (0x2f805, 2869, 1), (0x2f806, 2870, 1),
+    (0x2f807, 2871, 1), (0x2f808, 2872, 1), (0x2f809, 2873, 1), (0x2f80a, 2874, 1),
+    (0x2f80b, 2875, 1), (0x2f80c, 2876, 1), (0x2f80d, 2877, 1), (0x2f80e, 2878, 1),
+    (0x2f80f, 2879, 1), (0x2f810, 2880, 1), (0x2f811, 2881, 1), (0x2f812, 2882, 1),
+    (0x2f813, 2883, 1), (0x2f814, 2884, 1), (0x2f815, 2885, 1), (0x2f816, 2886, 1),
+    (0x2f817, 2887, 1), (0x2f818, 2888, 1), (0x2f819, 2889, 1), (0x2f81a, 2890, 1),
+    (0x2f81b, 2891, 1), (0x2f81c, 2892, 1), (0x2f81d, 2893, 1), (0x2f81e, 2894, 1),
+    (0x2f81f, 2895, 1), (0x2f820, 2896, 1), (0x2f821, 2897, 1), (0x2f822, 2898, 1),
+    (0x2f823, 2899, 1), (0x2f824, 2900, 1), (0x2f825, 2901, 1), (0x2f826, 2902, 1),
+    (0x2f827, 2903, 1), (0x2f828, 2904, 1), (0x2f829, 2905, 1), (0x2f82a, 2906, 1),
+    (0x2f82b, 2907, 1), (0x2f82c, 2908, 1), (0x2f82d, 2909, 1), (0x2f82e, 2910, 1),
+    (0x2f82f, 2911, 1), (0x2f830, 2912, 1), (0x2f831, 2913, 1), (0x2f832, 2914, 1),
+    (0x2f833, 2915, 1), (0x2f834, 2916, 1), (0x2f835, 2917, 1), (0x2f836, 2918, 1),
+    (0x2f837, 2919, 1), (0x2f838, 2920, 1), (0x2f839, 2921, 1), (0x2f83a, 2922, 1),
+    (0x2f83b, 2923, 1), (0x2f83c, 2924, 1), (0x2f83d, 2925, 1), (0x2f83e, 2926, 1),
+    (0x2f83f, 2927, 1), (0x2f840, 2928, 1), (0x2f841, 2929, 1), (0x2f842, 2930, 1),
+    (0x2f843, 2931, 1), (0x2f844, 2932, 1), (0x2f845, 2933, 1), (0x2f846, 2934, 1),
+    (0x2f847, 2935, 1), (0x2f848, 2936, 1), (0x2f849, 2937, 1), (0x2f84a, 2938, 1),
+    (0x2f84b, 2939, 1), (0x2f84c, 2940, 1), (0x2f84d, 2941, 1), (0x2f84e, 2942, 1),
+    (0x2f84f, 2943, 1), (0x2f850, 2944, 1), (0x2f851, 2945, 1), (0x2f852, 2946, 1),
+    (0x2f853, 2947, 1), (0x2f854, 2948, 1), (0x2f855, 2949, 1), (0x2f856, 2950, 1),
+    (0x2f857, 2951, 1), (0x2f858, 2952, 1), (0x2f859, 2953, 1), (0x2f85a, 2954, 1),
+    (0x2f85b, 2955, 1), (0x2f85c, 2956, 1), (0x2f85d, 2957, 1), (0x2f85e, 2958, 1),
+    (0x2f85f, 2959, 1), (0x2f860, 2960, 1), (0x2f861, 2961, 1), (0x2f862, 2962, 1),
+    (0x2f863, 2963, 1), (0x2f864, 2964, 1), (0x2f865, 2965, 1), (0x2f866, 2966, 1),
+    (0x2f867, 2967, 1), (0x2f868, 2968, 1), (0x2f869, 2969, 1), (0x2f86a, 2970, 1),
+    (0x2f86b, 2971, 1), (0x2f86c, 2972, 1), (0x2f86d, 2973, 1), (0x2f86e, 2974, 1),
+    (0x2f86f, 2975, 1), (0x2f870, 2976, 1), (0x2f871, 2977, 1), (0x2f872, 2978, 1),
+    (0x2f873, 2979, 1), (0x2f874, 2980, 1), (0x2f875, 2981, 1), (0x2f876, 2982, 1),
+    (0x2f877, 2983, 1), (0x2f878, 2984, 1), (0x2f879, 2985, 1), (0x2f87a, 2986, 1),
+    (0x2f87b, 2987, 1), (0x2f87c, 2988, 1), (0x2f87d, 2989, 1), (0x2f87e, 2990, 1),
+    (0x2f87f, 2991, 1), (0x2f880, 2992, 1), (0x2f881, 2993, 1), (0x2f882, 2994, 1),
+    (0x2f883, 2995, 1), (0x2f884, 2996, 1), (0x2f885, 2997, 1), (0x2f886, 2998, 1),
+    (0x2f887, 2999, 1), (0x2f888, 3000, 1), (0x2f889, 3001, 1), (0x2f88a, 3002, 1),
+    (0x2f88b, 3003, 1), (0x2f88c, 3004, 1), (0x2f88d, 3005, 1), (0x2f88e, 3006, 1),
+    (0x2f88f, 3007, 1), (0x2f890, 3008, 1), (0x2f891, 3009, 1), (0x2f892, 3010, 1),
+    (0x2f893, 3011, 1), (0x2f894, 3012, 1), (0x2f895, 3013, 1), (0x2f896, 3014, 1),
+    (0x2f897, 3015, 1), (0x2f898, 3016, 1), (0x2f899, 3017, 1), (0x2f89a, 3018, 1),
+    (0x2f89b, 3019, 1), (0x2f89c, 3020, 1), (0x2f89d, 3021, 1), (0x2f89e, 3022, 1),
+    (0x2f89f, 3023, 1), (0x2f8a0, 3024, 1), (0x2f8a1, 3025, 1), (0x2f8a2, 3026, 1),
+    (0x2f8a3, 3027, 1), (0x2f8a4, 3028, 1), (0x2f8a5, 3029, 1), (0x2f8a6, 3030, 1),
+    (0x2f8a7, 3031, 1), (0x2f8a8, 3032, 1), (0x2f8a9, 3033, 1), (0x2f8aa, 3034, 1),
+    (0x2f8ab, 3035, 1), (0x2f8ac, 3036, 1), (0x2f8ad, 3037, 1), (0x2f8ae, 3038, 1),
+    (0x2f8af, 3039, 1), (0x2f8b0, 3040, 1), (0x2f8b1, 3041, 1), (0x2f8b2, 3042, 1),
+    (0x2f8b3, 3043, 1), (0x2f8b4, 3044, 1), (0x2f8b5, 3045, 1), (0x2f8b6, 3046, 1),
+    (0x2f8b7, 3047, 1), (0x2f8b8, 3048, 1), (0x2f8b9, 3049, 1), (0x2f8ba, 3050, 1),
+    (0x2f8bb, 3051, 1), (0x2f8bc, 3052, 1), (0x2f8bd, 3053, 1), (0x2f8be, 3054, 1),
+    (0x2f8bf, 3055, 1), (0x2f8c0, 3056, 1), (0x2f8c1, 3057, 1), (0x2f8c2, 3058, 1),
+    (0x2f8c3, 3059, 1), (0x2f8c4, 3060, 1), (0x2f8c5, 3061, 1), (0x2f8c6, 3062, 1),
+    (0x2f8c7, 3063, 1), (0x2f8c8, 3064, 1), (0x2f8c9, 3065, 1), (0x2f8ca, 3066, 1),
+    (0x2f8cb, 3067, 1), (0x2f8cc, 3068, 1), (0x2f8cd, 3069, 1), (0x2f8ce, 3070, 1),
+    (0x2f8cf, 3071, 1), (0x2f8d0, 3072, 1), (0x2f8d1, 3073, 1), (0x2f8d2, 3074, 1),
+    (0x2f8d3, 3075, 1), (0x2f8d4, 3076, 1), (0x2f8d5, 3077, 1), (0x2f8d6, 3078, 1),
+    (0x2f8d7, 3079, 1), (0x2f8d8, 3080, 1), (0x2f8d9, 3081, 1), (0x2f8da, 3082, 1),
+    (0x2f8db, 3083, 1), (0x2f8dc, 3084, 1), (0x2f8dd, 3085, 1), (0x2f8de, 3086, 1),
+    (0x2f8df, 3087, 1), (0x2f8e0, 3088, 1), (0x2f8e1, 3089, 1), (0x2f8e2, 3090, 1),
+    (0x2f8e3, 3091, 1), (0x2f8e4, 3092, 1), (0x2f8e5, 3093, 1), (0x2f8e6, 3094, 1),
+    (0x2f8e7, 3095, 1), (0x2f8e8, 3096, 1), (0x2f8e9, 3097, 1), (0x2f8ea, 3098, 1),
+    (0x2f8eb, 3099, 1), (0x2f8ec, 3100, 1), (0x2f8ed, 3101, 1), (0x2f8ee, 3102, 1),
+    (0x2f8ef, 3103, 1), (0x2f8f0, 3104, 1), (0x2f8f1, 3105, 1), (0x2f8f2, 3106, 1),
+    (0x2f8f3, 3107, 1), (0x2f8f4, 3108, 1), (0x2f8f5, 3109, 1), (0x2f8f6, 3110, 1),
+    (0x2f8f7, 3111, 1), (0x2f8f8, 3112, 1), (0x2f8f9, 3113, 1), (0x2f8fa, 3114, 1),
+    (0x2f8fb, 3115, 1), (0x2f8fc, 3116, 1), (0x2f8fd, 3117, 1), (0x2f8fe, 3118, 1),
+    (0x2f8ff, 3119, 1), (0x2f900, 3120, 1), (0x2f901, 3121, 1), (0x2f902, 3122, 1),
+    (0x2f903, 3123, 1), (0x2f904, 3124, 1), (0x2f905, 3125, 1), (0x2f906, 3126, 1),
+    (0x2f907, 3127, 1), (0x2f908, 3128, 1), (0x2f909, 3129, 1), (0x2f90a, 3130, 1),
+    (0x2f90b, 3131, 1), (0x2f90c, 3132, 1), (0x2f90d, 3133, 1), (0x2f90e, 3134, 1),
+    (0x2f90f, 3135, 1), (0x2f910, 3136, 1), (0x2f911, 3137, 1), (0x2f912, 3138, 1),
+    (0x2f913, 3139, 1), (0x2f914, 3140, 1), (0x2f915, 3141, 1), (0x2f916, 3142, 1),
+    (0x2f917, 3143, 1), (0x2f918, 3144, 1), (0x2f919, 3145, 1), (0x2f91a, 3146, 1),
+    (0x2f91b, 3147, 1), (0x2f91c, 3148, 1), (0x2f91d, 3149, 1), (0x2f91e, 3150, 1),
+    (0x2f91f, 3151, 1), (0x2f920, 3152, 1), (0x2f921, 3153, 1), (0x2f922, 3154, 1),
+    (0x2f923, 3155, 1), (0x2f924, 3156, 1), (0x2f925, 3157, 1), (0x2f926, 3158, 1),
+    (0x2f927, 3159, 1), (0x2f928, 3160, 1), (0x2f929, 3161, 1), (0x2f92a, 3162, 1),
+    (0x2f92b, 3163, 1), (0x2f92c, 3164, 1), (0x2f92d, 3165, 1), (0x2f92e, 3166, 1),
+    (0x2f92f, 3167, 1), (0x2f930, 3168, 1), (0x2f931, 3169, 1), (0x2f932, 3170, 1),
+    (0x2f933, 3171, 1), (0x2f934, 3172, 1), (0x2f935, 3173, 1), (0x2f936, 3174, 1),
+    (0x2f937, 3175, 1), (0x2f938, 3176, 1), (0x2f939, 3177, 1), (0x2f93a, 3178, 1),
+    (0x2f93b, 3179, 1), (0x2f93c, 3180, 1), (0x2f93d, 3181, 1), (0x2f93e, 3182, 1),
+    (0x2f93f, 3183, 1), (0x2f940, 3184, 1), (0x2f941, 3185, 1), (0x2f942, 3186, 1),
+    (0x2f943, 3187, 1), (0x2f944, 3188, 1), (0x2f945, 3189, 1), (0x2f946, 3190, 1),
+    (0x2f947, 3191, 1), (0x2f948, 3192, 1), (0x2f949, 3193, 1), (0x2f94a, 3194, 1),
+    (0x2f94b, 3195, 1), (0x2f94c, 3196, 1), (0x2f94d, 3197, 1), (0x2f94e, 3198, 1),
+    (0x2f94f, 3199, 1), (0x2f950, 3200, 1), (0x2f951, 3201, 1), (0x2f952, 3202, 1),
+    (0x2f953, 3203, 1), (0x2f954, 3204, 1), (0x2f955, 3205, 1), (0x2f956, 3206, 1),
+    (0x2f957, 3207, 1), (0x2f958, 3208, 1), (0x2f959, 3209, 1), (0x2f95a, 3210, 1),
+    (0x2f95b, 3211, 1), (0x2f95c, 3212, 1), (0x2f95d, 3213, 1), (0x2f95e, 3214, 1),
+    (0x2f95f, 3215, 1), (0x2f960, 3216, 1), (0x2f961, 3217, 1), (0x2f962, 3218, 1),
+    (0x2f963, 3219, 1), (0x2f964, 3220, 1), (0x2f965, 3221, 1), (0x2f966, 3222, 1),
+    (0x2f967, 3223, 1), (0x2f968, 3224, 1), (0x2f969, 3225, 1), (0x2f96a, 3226, 1),
+    (0x2f96b, 3227, 1), (0x2f96c, 3228, 1), (0x2f96d, 3229, 1), (0x2f96e, 3230, 1),
+    (0x2f96f, 3231, 1), (0x2f970, 3232, 1), (0x2f971, 3233, 1), (0x2f972, 3234, 1),
+    (0x2f973, 3235, 1), (0x2f974, 3236, 1), (0x2f975, 3237, 1), (0x2f976, 3238, 1),
+    (0x2f977, 3239, 1), (0x2f978, 3240, 1), (0x2f979, 3241, 1), (0x2f97a, 3242, 1),
+    (0x2f97b, 3243, 1), (0x2f97c, 3244, 1), (0x2f97d, 3245, 1), (0x2f97e, 3246, 1),
+    (0x2f97f, 3247, 1), (0x2f980, 3248, 1), (0x2f981, 3249, 1), (0x2f982, 3250, 1),
+    (0x2f983, 3251, 1), (0x2f984, 3252, 1), (0x2f985, 3253, 1), (0x2f986, 3254, 1),
+    (0x2f987, 3255, 1), (0x2f988, 3256, 1), (0x2f989, 3257, 1), (0x2f98a, 3258, 1),
+    (0x2f98b, 3259, 1), (0x2f98c, 3260, 1), (0x2f98d, 3261, 1), (0x2f98e, 3262, 1),
+    (0x2f98f, 3263, 1), (0x2f990, 3264, 1), (0x2f991, 3265, 1), (0x2f992, 3266, 1),
+    (0x2f993, 3267, 1), (0x2f994, 3268, 1), (0x2f995, 3269, 1), (0x2f996, 3270, 1),
+    (0x2f997, 3271, 1), (0x2f998, 3272, 1), (0x2f999, 3273, 1), (0x2f99a, 3274, 1),
+    (0x2f99b, 3275, 1), (0x2f99c, 3276, 1), (0x2f99d, 3277, 1), (0x2f99e, 3278, 1),
+    (0x2f99f, 3279, 1), (0x2f9a0, 3280, 1), (0x2f9a1, 3281, 1), (0x2f9a2, 3282, 1),
+    (0x2f9a3, 3283, 1), (0x2f9a4, 3284, 1), (0x2f9a5, 3285, 1), (0x2f9a6, 3286, 1),
+    (0x2f9a7, 3287, 1), (0x2f9a8, 3288, 1), (0x2f9a9, 3289, 1), (0x2f9aa, 3290, 1),
+    (0x2f9ab, 3291, 1), (0x2f9ac, 3292, 1), (0x2f9ad, 3293, 1), (0x2f9ae, 3294, 1),
+    (0x2f9af, 3295, 1), (0x2f9b0, 3296, 1), (0x2f9b1, 3297, 1), (0x2f9b2, 3298, 1),
+    (0x2f9b3, 3299, 1), (0x2f9b4, 3300, 1), (0x2f9b5, 3301, 1), (0x2f9b6, 3302, 1),
+    (0x2f9b7, 3303, 1), (0x2f9b8, 3304, 1), (0x2f9b9, 3305, 1), (0x2f9ba, 3306, 1),
+    (0x2f9bb, 3307, 1), (0x2f9bc, 3308, 1), (0x2f9bd, 3309, 1), (0x2f9be, 3310, 1),
+    (0x2f9bf, 3311, 1), (0x2f9c0, 3312, 1), (0x2f9c1, 3313, 1), (0x2f9c2, 3314, 1),
+    (0x2f9c3, 3315, 1), (0x2f9c4, 3316, 1), (0x2f9c5, 3317, 1), (0x2f9c6, 3318, 1),
+    (0x2f9c7, 3319, 1), (0x2f9c8, 3320, 1), (0x2f9c9, 3321, 1), (0x2f9ca, 3322, 1),
+    (0x2f9cb, 3323, 1), (0x2f9cc, 3324, 1), (0x2f9cd, 3325, 1), (0x2f9ce, 3326, 1),
+    (0x2f9cf, 3327, 1), (0x2f9d0, 3328, 1), (0x2f9d1, 3329, 1), (0x2f9d2, 3330, 1),
+    (0x2f9d3, 3331, 1), (0x2f9d4, 3332, 1), (0x2f9d5, 3333, 1), (0x2f9d6, 3334, 1),
+    (0x2f9d7, 3335, 1), (0x2f9d8, 3336, 1), (0x2f9d9, 3337, 1), (0x2f9da, 3338, 1),
+    (0x2f9db, 3339, 1), (0x2f9dc, 3340, 1), (0x2f9dd, 3341, 1), (0x2f9de, 3342, 1),
+    (0x2f9df, 3343, 1), (0x2f9e0, 3344, 1), (0x2f9e1, 3345, 1), (0x2f9e2, 3346, 1),
+    (0x2f9e3, 3347, 1), (0x2f9e4, 3348, 1), (0x2f9e5, 3349, 1), (0x2f9e6, 3350, 1),
+    (0x2f9e7, 3351, 1), (0x2f9e8, 3352, 1), (0x2f9e9, 3353, 1), (0x2f9ea, 3354, 1),
+    (0x2f9eb, 3355, 1), (0x2f9ec, 3356, 1), (0x2f9ed, 3357, 1), (0x2f9ee, 3358, 1),
+    (0x2f9ef, 3359, 1), (0x2f9f0, 3360, 1), (0x2f9f1, 3361, 1), (0x2f9f2, 3362, 1),
+    (0x2f9f3, 3363, 1), (0x2f9f4, 3364, 1), (0x2f9f5, 3365, 1), (0x2f9f6, 3366, 1),
+    (0x2f9f7, 3367, 1), (0x2f9f8, 3368, 1), (0x2f9f9, 3369, 1), (0x2f9fa, 3370, 1),
+    (0x2f9fb, 3371, 1), (0x2f9fc, 3372, 1), (0x2f9fd, 3373, 1), (0x2f9fe, 3374, 1),
+    (0x2f9ff, 3375, 1), (0x2fa00, 3376, 1), (0x2fa01, 3377, 1), (0x2fa02, 3378, 1),
+    (0x2fa03, 3379, 1), (0x2fa04, 3380, 1), (0x2fa05, 3381, 1), (0x2fa06, 3382, 1),
+    (0x2fa07, 3383, 1), (0x2fa08, 3384, 1), (0x2fa09, 3385, 1), (0x2fa0a, 3386, 1),
+    (0x2fa0b, 3387, 1), (0x2fa0c, 3388, 1), (0x2fa0d, 3389, 1), (0x2fa0e, 3390, 1),
+    (0x2fa0f, 3391, 1), (0x2fa10, 3392, 1), (0x2fa11, 3393, 1), (0x2fa12, 3394, 1),
+    (0x2fa13, 3395, 1), (0x2fa14, 3396, 1), (0x2fa15, 3397, 1), (0x2fa16, 3398, 1),
+    (0x2fa17, 3399, 1), (0x2fa18, 3400, 1), (0x2fa19, 3401, 1), (0x2fa1a, 3402, 1),
+    (0x2fa1b, 3403, 1), (0x2fa1c, 3404, 1), (0x2fa1d, 3405, 1),
+];
+/// Full canonical decompositions
+pub(super) static DECOMPOSED: &[char] = &[
+    '\u{41}', '\u{300}', '\u{41}', '\u{301}', '\u{41}', '\u{302}', '\u{41}', '\u{303}', '\u{41}',
+    '\u{308}', '\u{41}', '\u{30a}', '\u{43}', '\u{327}', '\u{45}', '\u{300}', '\u{45}', '\u{301}',
+    '\u{45}', '\u{302}', '\u{45}', '\u{308}', '\u{49}', '\u{300}', '\u{49}', '\u{301}', '\u{49}',
+    '\u{302}', '\u{49}', '\u{308}', '\u{4e}', '\u{303}', '\u{4f}', '\u{300}', '\u{4f}', '\u{301}',
+    '\u{4f}', '\u{302}', '\u{4f}', '\u{303}', '\u{4f}', '\u{308}', '\u{55}', '\u{300}', '\u{55}',
+    '\u{301}', '\u{55}', '\u{302}', '\u{55}', '\u{308}', '\u{59}', '\u{301}', '\u{61}', '\u{300}',
+    '\u{61}', '\u{301}', '\u{61}', '\u{302}', '\u{61}', '\u{303}', '\u{61}', '\u{308}', '\u{61}',
+    '\u{30a}', '\u{63}', '\u{327}', '\u{65}', '\u{300}', '\u{65}', '\u{301}', '\u{65}', '\u{302}',
+    '\u{65}', '\u{308}', '\u{69}', '\u{300}', '\u{69}', '\u{301}', '\u{69}', '\u{302}', '\u{69}',
+    '\u{308}', '\u{6e}', '\u{303}', '\u{6f}', '\u{300}', '\u{6f}', '\u{301}', '\u{6f}', '\u{302}',
+    '\u{6f}', '\u{303}', '\u{6f}', '\u{308}', '\u{75}', '\u{300}', '\u{75}', '\u{301}', '\u{75}',
+    '\u{302}', '\u{75}', '\u{308}', '\u{79}', '\u{301}', '\u{79}', '\u{308}', '\u{41}', '\u{304}',
+    '\u{61}', '\u{304}', '\u{41}', '\u{306}', '\u{61}', '\u{306}', '\u{41}', '\u{328}', '\u{61}',
+    '\u{328}', '\u{43}', '\u{301}', '\u{63}', '\u{301}', '\u{43}', '\u{302}', '\u{63}', '\u{302}',
+    '\u{43}', '\u{307}', '\u{63}', '\u{307}', '\u{43}', '\u{30c}', '\u{63}', '\u{30c}', '\u{44}',
+    '\u{30c}', '\u{64}', '\u{30c}', '\u{45}', '\u{304}', '\u{65}', '\u{304}', '\u{45}', '\u{306}',
+    '\u{65}', '\u{306}', '\u{45}', '\u{307}', '\u{65}', '\u{307}', '\u{45}', '\u{328}', '\u{65}',
+    '\u{328}', '\u{45}', '\u{30c}', '\u{65}', '\u{30c}', '\u{47}', '\u{302}', '\u{67}', '\u{302}',
+    '\u{47}', '\u{306}', '\u{67}', '\u{306}', '\u{47}', '\u{307}', '\u{67}', '\u{307}', '\u{47}',
+    '\u{327}', '\u{67}', '\u{327}', '\u{48}', '\u{302}', '\u{68}', '\u{302}', '\u{49}', '\u{303}',
+    '\u{69}', '\u{303}', '\u{49}', '\u{304}', '\u{69}', '\u{304}', '\u{49}', '\u{306}', '\u{69}',
+    '\u{306}', '\u{49}', '\u{328}', '\u{69}', '\u{328}', '\u{49}', '\u{307}', '\u{4a}', '\u{302}',
+    '\u{6a}', '\u{302}', '\u{4b}', '\u{327}', '\u{6b}', '\u{327}', '\u{4c}', '\u{301}', '\u{6c}',
+    '\u{301}', '\u{4c}', '\u{327}', '\u{6c}', '\u{327}', '\u{4c}', '\u{30c}', '\u{6c}', '\u{30c}',
+    '\u{4e}', '\u{301}', '\u{6e}', '\u{301}', '\u{4e}', '\u{327}', '\u{6e}', '\u{327}', '\u{4e}',
+    '\u{30c}', '\u{6e}', '\u{30c}', '\u{4f}', '\u{304}', '\u{6f}', '\u{304}', '\u{4f}', '\u{306}',
+    '\u{6f}', '\u{306}', '\u{4f}', '\u{30b}', '\u{6f}', '\u{30b}', '\u{52}', '\u{301}', '\u{72}',
+    '\u{301}', '\u{52}', '\u{327}', '\u{72}', '\u{327}', '\u{52}', '\u{30c}', '\u{72}', '\u{30c}',
+    '\u{53}', '\u{301}', '\u{73}', '\u{301}', '\u{53}', '\u{302}', '\u{73}', '\u{302}', '\u{53}',
+    '\u{327}', '\u{73}', '\u{327}', '\u{53}', '\u{30c}', '\u{73}', '\u{30c}', '\u{54}', '\u{327}',
+    '\u{74}', '\u{327}', '\u{54}', '\u{30c}', '\u{74}', '\u{30c}', '\u{55}', '\u{303}', '\u{75}',
+    '\u{303}', '\u{55}', '\u{304}', '\u{75}', '\u{304}', '\u{55}', '\u{306}', '\u{75}', '\u{306}',
+    '\u{55}', '\u{30a}', '\u{75}', '\u{30a}', '\u{55}', '\u{30b}', '\u{75}', '\u{30b}', '\u{55}',
+    '\u{328}', '\u{75}', '\u{328}', '\u{57}', '\u{302}', '\u{77}', '\u{302}', '\u{59}', '\u{302}',
+    '\u{79}', '\u{302}', '\u{59}', '\u{308}', '\u{5a}', '\u{301}', '\u{7a}', '\u{301}', '\u{5a}',
+    '\u{307}', '\u{7a}', '\u{307}', '\u{5a}', '\u{30c}', '\u{7a}', '\u{30c}', '\u{4f}', '\u{31b}',
+    '\u{6f}', '\u{31b}', '\u{55}', '\u{31b}', '\u{75}', '\u{31b}', '\u{41}', '\u{30c}', '\u{61}',
+    '\u{30c}', '\u{49}', '\u{30c}', '\u{69}', '\u{30c}', '\u{4f}', '\u{30c}', '\u{6f}', '\u{30c}',
+    '\u{55}', '\u{30c}', '\u{75}', '\u{30c}', '\u{55}', '\u{308}', '\u{304}', '\u{75}', '\u{308}',
+    '\u{304}', '\u{55}', '\u{308}', '\u{301}', '\u{75}', '\u{308}', '\u{301}', '\u{55}', '\u{308}',
+    '\u{30c}', '\u{75}', '\u{308}', '\u{30c}', '\u{55}', '\u{308}', '\u{300}', '\u{75}', '\u{308}',
+    '\u{300}', '\u{41}', '\u{308}', '\u{304}', '\u{61}', '\u{308}', '\u{304}', '\u{41}', '\u{307}',
+    '\u{304}', '\u{61}', '\u{307}', '\u{304}', '\u{c6}', '\u{304}', '\u{e6}', '\u{304}', '\u{47}',
+    '\u{30c}', '\u{67}', '\u{30c}', '\u{4b}', '\u{30c}', '\u{6b}', '\u{30c}', '\u{4f}', '\u{328}',
+    '\u{6f}', '\u{328}', '\u{4f}', '\u{328}', '\u{304}', '\u{6f}', '\u{328}', '\u{304}', '\u{1b7}',
+    '\u{30c}', '\u{292}', '\u{30c}', '\u{6a}', '\u{30c}', '\u{47}', '\u{301}', '\u{67}', '\u{301}',
+    '\u{4e}', '\u{300}', '\u{6e}', '\u{300}', '\u{41}', '\u{30a}', '\u{301}', '\u{61}', '\u{30a}',
+    '\u{301}', '\u{c6}', '\u{301}', '\u{e6}', '\u{301}', '\u{d8}', '\u{301}', '\u{f8}', '\u{301}',
+    '\u{41}', '\u{30f}', '\u{61}', '\u{30f}', '\u{41}', '\u{311}', '\u{61}', '\u{311}', '\u{45}',
+    '\u{30f}', '\u{65}', '\u{30f}', '\u{45}', '\u{311}', '\u{65}', '\u{311}', '\u{49}', '\u{30f}',
+    '\u{69}', '\u{30f}', '\u{49}', '\u{311}', '\u{69}', '\u{311}', '\u{4f}', '\u{30f}', '\u{6f}',
+    '\u{30f}', '\u{4f}', '\u{311}', '\u{6f}', '\u{311}', '\u{52}', '\u{30f}', '\u{72}', '\u{30f}',
+    '\u{52}', '\u{311}', '\u{72}', '\u{311}', '\u{55}', '\u{30f}', '\u{75}', '\u{30f}', '\u{55}',
+    '\u{311}', '\u{75}', '\u{311}', '\u{53}', '\u{326}', '\u{73}', '\u{326}', '\u{54}', '\u{326}',
+    '\u{74}', '\u{326}', '\u{48}', '\u{30c}', '\u{68}', '\u{30c}', '\u{41}', '\u{307}', '\u{61}',
+    '\u{307}', '\u{45}', '\u{327}', '\u{65}', '\u{327}', '\u{4f}', '\u{308}', '\u{304}', '\u{6f}',
+    '\u{308}', '\u{304}', '\u{4f}', '\u{303}', '\u{304}', '\u{6f}', '\u{303}', '\u{304}', '\u{4f}',
+    '\u{307}', '\u{6f}', '\u{307}', '\u{4f}', '\u{307}', '\u{304}', '\u{6f}', '\u{307}', '\u{304}',
+    '\u{59}', '\u{304}', '\u{79}', '\u{304}', '\u{300}', '\u{301}', '\u{313}', '\u{308}', '\u{301}',
+    '\u{2b9}', '\u{3b}', '\u{a8}', '\u{301}', '\u{391}', '\u{301}', '\u{b7}', '\u{395}', '\u{301}',
+    '\u{397}', '\u{301}', '\u{399}', '\u{301}', '\u{39f}', '\u{301}', '\u{3a5}', '\u{301}',
+    '\u{3a9}', '\u{301}', '\u{3b9}', '\u{308}', '\u{301}', '\u{399}', '\u{308}', '\u{3a5}',
+    '\u{308}', '\u{3b1}', '\u{301}', '\u{3b5}', '\u{301}', '\u{3b7}', '\u{301}', '\u{3b9}',
+    '\u{301}', '\u{3c5}', '\u{308}', '\u{301}', '\u{3b9}', '\u{308}', '\u{3c5}', '\u{308}',
+    '\u{3bf}', '\u{301}', '\u{3c5}', '\u{301}', '\u{3c9}', '\u{301}', '\u{3d2}', '\u{301}',
+    '\u{3d2}', '\u{308}', '\u{415}', '\u{300}', '\u{415}', '\u{308}', '\u{413}', '\u{301}',
+    '\u{406}', '\u{308}', '\u{41a}', '\u{301}', '\u{418}', '\u{300}', '\u{423}', '\u{306}',
+    '\u{418}', '\u{306}', '\u{438}', '\u{306}', '\u{435}', '\u{300}', '\u{435}', '\u{308}',
+    '\u{433}', '\u{301}', '\u{456}', '\u{308}', '\u{43a}', '\u{301}', '\u{438}', '\u{300}',
+    '\u{443}', '\u{306}', '\u{474}', '\u{30f}', '\u{475}', '\u{30f}', '\u{416}', '\u{306}',
+    '\u{436}', '\u{306}', '\u{410}', '\u{306}', '\u{430}', '\u{306}', '\u{410}', '\u{308}',
+    '\u{430}', '\u{308}', '\u{415}', '\u{306}', '\u{435}', '\u{306}', '\u{4d8}', '\u{308}',
+    '\u{4d9}', '\u{308}', '\u{416}', '\u{308}', '\u{436}', '\u{308}', '\u{417}', '\u{308}',
+    '\u{437}', '\u{308}', '\u{418}', '\u{304}', '\u{438}', '\u{304}', '\u{418}', '\u{308}',
+    '\u{438}', '\u{308}', '\u{41e}', '\u{308}', '\u{43e}', '\u{308}', '\u{4e8}', '\u{308}',
+    '\u{4e9}', '\u{308}', '\u{42d}', '\u{308}', '\u{44d}', '\u{308}', '\u{423}', '\u{304}',
+    '\u{443}', '\u{304}', '\u{423}', '\u{308}', '\u{443}', '\u{308}', '\u{423}', '\u{30b}',
+    '\u{443}', '\u{30b}', '\u{427}', '\u{308}', '\u{447}', '\u{308}', '\u{42b}', '\u{308}',
+    '\u{44b}', '\u{308}', '\u{627}', '\u{653}', '\u{627}', '\u{654}', '\u{648}', '\u{654}',
+    '\u{627}', '\u{655}', '\u{64a}', '\u{654}', '\u{6d5}', '\u{654}', '\u{6c1}', '\u{654}',
+    '\u{6d2}', '\u{654}', '\u{928}', '\u{93c}', '\u{930}', '\u{93c}', '\u{933}', '\u{93c}',
+    '\u{915}', '\u{93c}', '\u{916}', '\u{93c}', '\u{917}', '\u{93c}', '\u{91c}', '\u{93c}',
+    '\u{921}', '\u{93c}', '\u{922}', '\u{93c}', '\u{92b}', '\u{93c}', '\u{92f}', '\u{93c}',
+    '\u{9c7}', '\u{9be}', '\u{9c7}', '\u{9d7}', '\u{9a1}', '\u{9bc}', '\u{9a2}', '\u{9bc}',
+    '\u{9af}', '\u{9bc}', '\u{a32}', '\u{a3c}', '\u{a38}', '\u{a3c}', '\u{a16}', '\u{a3c}',
+    '\u{a17}', '\u{a3c}', '\u{a1c}', '\u{a3c}', '\u{a2b}', '\u{a3c}', '\u{b47}', '\u{b56}',
+    '\u{b47}', '\u{b3e}', '\u{b47}', '\u{b57}', '\u{b21}', '\u{b3c}', '\u{b22}', '\u{b3c}',
+    '\u{b92}', '\u{bd7}', '\u{bc6}', '\u{bbe}', '\u{bc7}', '\u{bbe}', '\u{bc6}', '\u{bd7}',
+    '\u{c46}', '\u{c56}', '\u{cbf}', '\u{cd5}', '\u{cc6}', '\u{cd5}', '\u{cc6}', '\u{cd6}',
+    '\u{cc6}', '\u{cc2}', '\u{cc6}', '\u{cc2}', '\u{cd5}', '\u{d46}', '\u{d3e}', '\u{d47}',
+    '\u{d3e}', '\u{d46}', '\u{d57}', '\u{dd9}', '\u{dca}', '\u{dd9}', '\u{dcf}', '\u{dd9}',
+    '\u{dcf}', '\u{dca}', '\u{dd9}', '\u{ddf}', '\u{f42}', '\u{fb7}', '\u{f4c}', '\u{fb7}',
+    '\u{f51}', '\u{fb7}', '\u{f56}', '\u{fb7}', '\u{f5b}', '\u{fb7}', '\u{f40}', '\u{fb5}',
+    '\u{f71}', '\u{f72}', '\u{f71}', '\u{f74}', '\u{fb2}', '\u{f80}', '\u{fb3}', '\u{f80}',
+    '\u{f71}', '\u{f80}', '\u{f92}', '\u{fb7}', '\u{f9c}', '\u{fb7}', '\u{fa1}', '\u{fb7}',
+    '\u{fa6}', '\u{fb7}', '\u{fab}', '\u{fb7}', '\u{f90}', '\u{fb5}', '\u{1025}', '\u{102e}',
+    '\u{1b05}', '\u{1b35}', '\u{1b07}', '\u{1b35}', '\u{1b09}', '\u{1b35}', '\u{1b0b}', '\u{1b35}',
+    '\u{1b0d}', '\u{1b35}', '\u{1b11}', '\u{1b35}', '\u{1b3a}', '\u{1b35}', '\u{1b3c}', '\u{1b35}',
+    '\u{1b3e}', '\u{1b35}', '\u{1b3f}', '\u{1b35}', '\u{1b42}', '\u{1b35}', '\u{41}', '\u{325}',
+    '\u{61}', '\u{325}', '\u{42}', '\u{307}', '\u{62}', '\u{307}', '\u{42}', '\u{323}', '\u{62}',
+    '\u{323}', '\u{42}', '\u{331}', '\u{62}', '\u{331}', '\u{43}', '\u{327}', '\u{301}', '\u{63}',
+    '\u{327}', '\u{301}', '\u{44}', '\u{307}', '\u{64}', '\u{307}', '\u{44}', '\u{323}', '\u{64}',
+    '\u{323}', '\u{44}', '\u{331}', '\u{64}', '\u{331}', '\u{44}', '\u{327}', '\u{64}', '\u{327}',
+    '\u{44}', '\u{32d}', '\u{64}', '\u{32d}', '\u{45}', '\u{304}', '\u{300}', '\u{65}', '\u{304}',
+    '\u{300}', '\u{45}', '\u{304}', '\u{301}', '\u{65}', '\u{304}', '\u{301}', '\u{45}', '\u{32d}',
+    '\u{65}', '\u{32d}', '\u{45}', '\u{330}', '\u{65}', '\u{330}', '\u{45}', '\u{327}', '\u{306}',
+    '\u{65}', '\u{327}', '\u{306}', '\u{46}', '\u{307}', '\u{66}', '\u{307}', '\u{47}', '\u{304}',
+    '\u{67}', '\u{304}', '\u{48}', '\u{307}', '\u{68}', '\u{307}', '\u{48}', '\u{323}', '\u{68}',
+    '\u{323}', '\u{48}', '\u{308}', '\u{68}', '\u{308}', '\u{48}', '\u{327}', '\u{68}', '\u{327}',
+    '\u{48}', '\u{32e}', '\u{68}', '\u{32e}', '\u{49}', '\u{330}', '\u{69}', '\u{330}', '\u{49}',
+    '\u{308}', '\u{301}', '\u{69}', '\u{308}', '\u{301}', '\u{4b}', '\u{301}', '\u{6b}', '\u{301}',
+    '\u{4b}', '\u{323}', '\u{6b}', '\u{323}', '\u{4b}', '\u{331}', '\u{6b}', '\u{331}', '\u{4c}',
+    '\u{323}', '\u{6c}', '\u{323}', '\u{4c}', '\u{323}', '\u{304}', '\u{6c}', '\u{323}', '\u{304}',
+    '\u{4c}', '\u{331}', '\u{6c}', '\u{331}', '\u{4c}', '\u{32d}', '\u{6c}', '\u{32d}', '\u{4d}',
+    '\u{301}', '\u{6d}', '\u{301}', '\u{4d}', '\u{307}', '\u{6d}', '\u{307}', '\u{4d}', '\u{323}',
+    '\u{6d}', '\u{323}', '\u{4e}', '\u{307}', '\u{6e}', '\u{307}', '\u{4e}', '\u{323}', '\u{6e}',
+    '\u{323}', '\u{4e}', '\u{331}', '\u{6e}', '\u{331}', '\u{4e}', '\u{32d}', '\u{6e}', '\u{32d}',
+    '\u{4f}', '\u{303}', '\u{301}', '\u{6f}', '\u{303}', '\u{301}', '\u{4f}', '\u{303}', '\u{308}',
+    '\u{6f}', '\u{303}', '\u{308}', '\u{4f}', '\u{304}', '\u{300}', '\u{6f}', '\u{304}', '\u{300}',
+    '\u{4f}', '\u{304}', '\u{301}', '\u{6f}', '\u{304}', '\u{301}', '\u{50}', '\u{301}', '\u{70}',
+    '\u{301}', '\u{50}', '\u{307}', '\u{70}', '\u{307}', '\u{52}', '\u{307}', '\u{72}', '\u{307}',
+    '\u{52}', '\u{323}', '\u{72}', '\u{323}', '\u{52}', '\u{323}', '\u{304}', '\u{72}', '\u{323}',
+    '\u{304}', '\u{52}', '\u{331}', '\u{72}', '\u{331}', '\u{53}', '\u{307}', '\u{73}', '\u{307}',
+    '\u{53}', '\u{323}', '\u{73}', '\u{323}', '\u{53}', '\u{301}', '\u{307}', '\u{73}', '\u{301}',
+    '\u{307}', '\u{53}', '\u{30c}', '\u{307}', '\u{73}', '\u{30c}', '\u{307}', '\u{53}', '\u{323}',
+    '\u{307}', '\u{73}', '\u{323}', '\u{307}', '\u{54}', '\u{307}', '\u{74}', '\u{307}', '\u{54}',
+    '\u{323}', '\u{74}', '\u{323}', '\u{54}', '\u{331}', '\u{74}', '\u{331}', '\u{54}', '\u{32d}',
+    '\u{74}', '\u{32d}', '\u{55}', '\u{324}', '\u{75}', '\u{324}', '\u{55}', '\u{330}', '\u{75}',
+    '\u{330}', '\u{55}', '\u{32d}', '\u{75}', '\u{32d}', '\u{55}', '\u{303}', '\u{301}', '\u{75}',
+    '\u{303}', '\u{301}', '\u{55}', '\u{304}', '\u{308}', '\u{75}', '\u{304}', '\u{308}', '\u{56}',
+    '\u{303}', '\u{76}', '\u{303}', '\u{56}', '\u{323}', '\u{76}', '\u{323}', '\u{57}', '\u{300}',
+    '\u{77}', '\u{300}', '\u{57}', '\u{301}', '\u{77}', '\u{301}', '\u{57}', '\u{308}', '\u{77}',
+    '\u{308}', '\u{57}', '\u{307}', '\u{77}', '\u{307}', '\u{57}', '\u{323}', '\u{77}', '\u{323}',
+    '\u{58}', '\u{307}', '\u{78}', '\u{307}', '\u{58}', '\u{308}', '\u{78}', '\u{308}', '\u{59}',
+    '\u{307}', '\u{79}', '\u{307}', '\u{5a}', '\u{302}', '\u{7a}', '\u{302}', '\u{5a}', '\u{323}',
+    '\u{7a}', '\u{323}', '\u{5a}', '\u{331}', '\u{7a}', '\u{331}', '\u{68}', '\u{331}', '\u{74}',
+    '\u{308}', '\u{77}', '\u{30a}', '\u{79}', '\u{30a}', '\u{17f}', '\u{307}', '\u{41}', '\u{323}',
+    '\u{61}', '\u{323}', '\u{41}', '\u{309}', '\u{61}', '\u{309}', '\u{41}', '\u{302}', '\u{301}',
+    '\u{61}', '\u{302}', '\u{301}', '\u{41}', '\u{302}', '\u{300}', '\u{61}', '\u{302}', '\u{300}',
+    '\u{41}', '\u{302}', '\u{309}', '\u{61}', '\u{302}', '\u{309}', '\u{41}', '\u{302}', '\u{303}',
+    '\u{61}', '\u{302}', '\u{303}', '\u{41}', '\u{323}', '\u{302}', '\u{61}', '\u{323}', '\u{302}',
+    '\u{41}', '\u{306}', '\u{301}', '\u{61}', '\u{306}', '\u{301}', '\u{41}', '\u{306}', '\u{300}',
+    '\u{61}', '\u{306}', '\u{300}', '\u{41}', '\u{306}', '\u{309}', '\u{61}', '\u{306}', '\u{309}',
+    '\u{41}', '\u{306}', '\u{303}', '\u{61}', '\u{306}', '\u{303}', '\u{41}', '\u{323}', '\u{306}',
+    '\u{61}', '\u{323}', '\u{306}', '\u{45}', '\u{323}', '\u{65}', '\u{323}', '\u{45}', '\u{309}',
+    '\u{65}', '\u{309}', '\u{45}', '\u{303}', '\u{65}', '\u{303}', '\u{45}', '\u{302}', '\u{301}',
+    '\u{65}', '\u{302}', '\u{301}', '\u{45}', '\u{302}', '\u{300}', '\u{65}', '\u{302}', '\u{300}',
+    '\u{45}', '\u{302}', '\u{309}', '\u{65}', '\u{302}', '\u{309}', '\u{45}', '\u{302}', '\u{303}',
+    '\u{65}', '\u{302}', '\u{303}', '\u{45}', '\u{323}', '\u{302}', '\u{65}', '\u{323}', '\u{302}',
+    '\u{49}', '\u{309}', '\u{69}', '\u{309}', '\u{49}', '\u{323}', '\u{69}', '\u{323}', '\u{4f}',
+    '\u{323}', '\u{6f}', '\u{323}', '\u{4f}', '\u{309}', '\u{6f}', '\u{309}', '\u{4f}', '\u{302}',
+    '\u{301}', '\u{6f}', '\u{302}', '\u{301}', '\u{4f}', '\u{302}', '\u{300}', '\u{6f}', '\u{302}',
+    '\u{300}', '\u{4f}', '\u{302}', '\u{309}', '\u{6f}', '\u{302}', '\u{309}', '\u{4f}', '\u{302}',
+    '\u{303}', '\u{6f}', '\u{302}', '\u{303}', '\u{4f}', '\u{323}', '\u{302}', '\u{6f}', '\u{323}',
+    '\u{302}', '\u{4f}', '\u{31b}', '\u{301}', '\u{6f}', '\u{31b}', '\u{301}', '\u{4f}', '\u{31b}',
+    '\u{300}', '\u{6f}', '\u{31b}', '\u{300}', '\u{4f}', '\u{31b}', '\u{309}', '\u{6f}', '\u{31b}',
+    '\u{309}', '\u{4f}', '\u{31b}', '\u{303}', '\u{6f}', '\u{31b}', '\u{303}', '\u{4f}', '\u{31b}',
+    '\u{323}', '\u{6f}', '\u{31b}', '\u{323}', '\u{55}', '\u{323}', '\u{75}', '\u{323}', '\u{55}',
+    '\u{309}', '\u{75}', '\u{309}', '\u{55}', '\u{31b}', '\u{301}', '\u{75}', '\u{31b}', '\u{301}',
+    '\u{55}', '\u{31b}', '\u{300}', '\u{75}', '\u{31b}', '\u{300}', '\u{55}', '\u{31b}', '\u{309}',
+    '\u{75}', '\u{31b}', '\u{309}', '\u{55}', '\u{31b}', '\u{303}', '\u{75}', '\u{31b}', '\u{303}',
+    '\u{55}', '\u{31b}', '\u{323}', '\u{75}', '\u{31b}', '\u{323}', '\u{59}', '\u{300}', '\u{79}',
+    '\u{300}', '\u{59}', '\u{323}', '\u{79}', '\u{323}', '\u{59}', '\u{309}', '\u{79}', '\u{309}',
+    '\u{59}', '\u{303}', '\u{79}', '\u{303}', '\u{3b1}', '\u{313}', '\u{3b1}', '\u{314}', '\u{3b1}',
+    '\u{313}', '\u{300}', '\u{3b1}', '\u{314}', '\u{300}', '\u{3b1}', '\u{313}', '\u{301}',
+    '\u{3b1}', '\u{314}', '\u{301}', '\u{3b1}', '\u{313}', '\u{342}', '\u{3b1}', '\u{314}',
+    '\u{342}', '\u{391}', '\u{313}', '\u{391}', '\u{314}', '\u{391}', '\u{313}', '\u{300}',
+    '\u{391}', '\u{314}', '\u{300}', '\u{391}', '\u{313}', '\u{301}', '\u{391}', '\u{314}',
+    '\u{301}', '\u{391}', '\u{313}', '\u{342}', '\u{391}', '\u{314}', '\u{342}', '\u{3b5}',
+    '\u{313}', '\u{3b5}', '\u{314}', '\u{3b5}', '\u{313}', '\u{300}', '\u{3b5}', '\u{314}',
+    '\u{300}', '\u{3b5}', '\u{313}', '\u{301}', '\u{3b5}', '\u{314}', '\u{301}', '\u{395}',
+    '\u{313}', '\u{395}', '\u{314}', '\u{395}', '\u{313}', '\u{300}', '\u{395}', '\u{314}',
+    '\u{300}', '\u{395}', '\u{313}', '\u{301}', '\u{395}', '\u{314}', '\u{301}', '\u{3b7}',
+    '\u{313}', '\u{3b7}', '\u{314}', '\u{3b7}', '\u{313}', '\u{300}', '\u{3b7}', '\u{314}',
+    '\u{300}', '\u{3b7}', '\u{313}', '\u{301}', '\u{3b7}', '\u{314}', '\u{301}', '\u{3b7}',
+    '\u{313}', '\u{342}', '\u{3b7}', '\u{314}', '\u{342}', '\u{397}', '\u{313}', '\u{397}',
+    '\u{314}', '\u{397}', '\u{313}', '\u{300}', '\u{397}', '\u{314}', '\u{300}', '\u{397}',
+    '\u{313}', '\u{301}', '\u{397}', '\u{314}', '\u{301}', '\u{397}', '\u{313}', '\u{342}',
+    '\u{397}', '\u{314}', '\u{342}', '\u{3b9}', '\u{313}', '\u{3b9}', '\u{314}', '\u{3b9}',
+    '\u{313}', '\u{300}', '\u{3b9}', '\u{314}', '\u{300}', '\u{3b9}', '\u{313}', '\u{301}',
+    '\u{3b9}', '\u{314}', '\u{301}', '\u{3b9}', '\u{313}', '\u{342}', '\u{3b9}', '\u{314}',
+    '\u{342}', '\u{399}', '\u{313}', '\u{399}', '\u{314}', '\u{399}', '\u{313}', '\u{300}',
+    '\u{399}', '\u{314}', '\u{300}', '\u{399}', '\u{313}', '\u{301}', '\u{399}', '\u{314}',
+    '\u{301}', '\u{399}', '\u{313}', '\u{342}', '\u{399}', '\u{314}', '\u{342}', '\u{3bf}',
+    '\u{313}', '\u{3bf}', '\u{314}', '\u{3bf}', '\u{313}', '\u{300}', '\u{3bf}', '\u{314}',
+    '\u{300}', '\u{3bf}', '\u{313}', '\u{301}', '\u{3bf}', '\u{314}', '\u{301}', '\u{39f}',
+    '\u{313}', '\u{39f}', '\u{314}', '\u{39f}', '\u{313}', '\u{300}', '\u{39f}', '\u{314}',
+    '\u{300}', '\u{39f}', '\u{313}', '\u{301}', '\u{39f}', '\u{314}', '\u{301}', '\u{3c5}',
+    '\u{313}', '\u{3c5}', '\u{314}', '\u{3c5}', '\u{313}', '\u{300}', '\u{3c5}', '\u{314}',
+    '\u{300}', '\u{3c5}', '\u{313}', '\u{301}', '\u{3c5}', '\u{314}', '\u{301}', '\u{3c5}',
+    '\u{313}', '\u{342}', '\u{3c5}', '\u{314}', '\u{342}', '\u{3a5}', '\u{314}', '\u{3a5}',
+    '\u{314}', '\u{300}', '\u{3a5}', '\u{314}', '\u{301}', '\u{3a5}', '\u{314}', '\u{342}',
+    '\u{3c9}', '\u{313}', '\u{3c9}', '\u{314}', '\u{3c9}', '\u{313}', '\u{300}', '\u{3c9}',
+    '\u{314}', '\u{300}', '\u{3c9}', '\u{313}', '\u{301}', '\u{3c9}', '\u{314}', '\u{301}',
+    '\u{3c9}', '\u{313}', '\u{342}', '\u{3c9}', '\u{314}', '\u{342}', '\u{3a9}', '\u{313}',
+    '\u{3a9}', '\u{314}', '\u{3a9}', '\u{313}', '\u{300}', '\u{3a9}', '\u{314}', '\u{300}',
+    '\u{3a9}', '\u{313}', '\u{301}', '\u{3a9}', '\u{314}', '\u{301}', '\u{3a9}', '\u{313}',
+    '\u{342}', '\u{3a9}', '\u{314}', '\u{342}', '\u{3b1}', '\u{300}', '\u{3b1}', '\u{301}',
+    '\u{3b5}', '\u{300}', '\u{3b5}', '\u{301}', '\u{3b7}', '\u{300}', '\u{3b7}', '\u{301}',
+    '\u{3b9}', '\u{300}', '\u{3b9}', '\u{301}', '\u{3bf}', '\u{300}', '\u{3bf}', '\u{301}',
+    '\u{3c5}', '\u{300}', '\u{3c5}', '\u{301}', '\u{3c9}', '\u{300}', '\u{3c9}', '\u{301}',
+    '\u{3b1}', '\u{313}', '\u{345}', '\u{3b1}', '\u{314}', '\u{345}', '\u{3b1}', '\u{313}',
+    '\u{300}', '\u{345}', '\u{3b1}', '\u{314}', '\u{300}', '\u{345}', '\u{3b1}', '\u{313}',
+    '\u{301}', '\u{345}', '\u{3b1}', '\u{314}', '\u{301}', '\u{345}', '\u{3b1}', '\u{313}',
+    '\u{342}', '\u{345}', '\u{3b1}', '\u{314}', '\u{342}', '\u{345}', '\u{391}', '\u{313}',
+    '\u{345}', '\u{391}', '\u{314}', '\u{345}', '\u{391}', '\u{313}', '\u{300}', '\u{345}',
+    '\u{391}', '\u{314}', '\u{300}', '\u{345}', '\u{391}', '\u{313}', '\u{301}', '\u{345}',
+    '\u{391}', '\u{314}', '\u{301}', '\u{345}', '\u{391}', '\u{313}', '\u{342}', '\u{345}',
+    '\u{391}', '\u{314}', '\u{342}', '\u{345}', '\u{3b7}', '\u{313}', '\u{345}', '\u{3b7}',
+    '\u{314}', '\u{345}', '\u{3b7}', '\u{313}', '\u{300}', '\u{345}', '\u{3b7}', '\u{314}',
+    '\u{300}', '\u{345}', '\u{3b7}', '\u{313}', '\u{301}', '\u{345}', '\u{3b7}', '\u{314}',
+    '\u{301}', '\u{345}', '\u{3b7}', '\u{313}', '\u{342}', '\u{345}', '\u{3b7}', '\u{314}',
+    '\u{342}', '\u{345}', '\u{397}', '\u{313}', '\u{345}', '\u{397}', '\u{314}', '\u{345}',
+    '\u{397}', '\u{313}', '\u{300}', '\u{345}', '\u{397}', '\u{314}', '\u{300}', '\u{345}',
+    '\u{397}', '\u{313}', '\u{301}', '\u{345}', '\u{397}', '\u{314}', '\u{301}', '\u{345}',
+    '\u{397}', '\u{313}', '\u{342}', '\u{345}', '\u{397}', '\u{314}', '\u{342}', '\u{345}',
+    '\u{3c9}', '\u{313}', '\u{345}', '\u{3c9}', '\u{314}', '\u{345}', '\u{3c9}', '\u{313}',
+    '\u{300}', '\u{345}', '\u{3c9}', '\u{314}', '\u{300}', '\u{345}', '\u{3c9}', '\u{313}',
+    '\u{301}', '\u{345}', '\u{3c9}', '\u{314}', '\u{301}', '\u{345}', '\u{3c9}', '\u{313}',
+    '\u{342}', '\u{345}', '\u{3c9}', '\u{314}', '\u{342}', '\u{345}', '\u{3a9}', '\u{313}',
+    '\u{345}', '\u{3a9}', '\u{314}', '\u{345}', '\u{3a9}', '\u{313}', '\u{300}', '\u{345}',
+    '\u{3a9}', '\u{314}', '\u{300}', '\u{345}', '\u{3a9}', '\u{313}', '\u{301}', '\u{345}',
+    '\u{3a9}', '\u{314}', '\u{301}', '\u{345}', '\u{3a9}', '\u{313}', '\u{342}', '\u{345}',
+    '\u{3a9}', '\u{314}', '\u{342}', '\u{345}', '\u{3b1}', '\u{306}', '\u{3b1}', '\u{304}',
+    '\u{3b1}', '\u{300}', '\u{345}', '\u{3b1}', '\u{345}', '\u{3b1}', '\u{301}', '\u{345}',
+    '\u{3b1}', '\u{342}', '\u{3b1}', '\u{342}', '\u{345}', '\u{391}', '\u{306}', '\u{391}',
+    '\u{304}', '\u{391}', '\u{300}', '\u{391}', '\u{301}', '\u{391}', '\u{345}', '\u{3b9}',
+    '\u{a8}', '\u{342}', '\u{3b7}', '\u{300}', '\u{345}', '\u{3b7}', '\u{345}', '\u{3b7}',
+    '\u{301}', '\u{345}', '\u{3b7}', '\u{342}', '\u{3b7}', '\u{342}', '\u{345}', '\u{395}',
+    '\u{300}', '\u{395}', '\u{301}', '\u{397}', '\u{300}', '\u{397}', '\u{301}', '\u{397}',
+    '\u{345}', '\u{1fbf}', '\u{300}', '\u{1fbf}', '\u{301}', '\u{1fbf}', '\u{342}', '\u{3b9}',
+    '\u{306}', '\u{3b9}', '\u{304}', '\u{3b9}', '\u{308}', '\u{300}', '\u{3b9}', '\u{308}',
+    '\u{301}', '\u{3b9}', '\u{342}', '\u{3b9}', '\u{308}', '\u{342}', '\u{399}', '\u{306}',
+    '\u{399}', '\u{304}', '\u{399}', '\u{300}', '\u{399}', '\u{301}', '\u{1ffe}', '\u{300}',
+    '\u{1ffe}', '\u{301}', '\u{1ffe}', '\u{342}', '\u{3c5}', '\u{306}', '\u{3c5}', '\u{304}',
+    '\u{3c5}', '\u{308}', '\u{300}', '\u{3c5}', '\u{308}', '\u{301}', '\u{3c1}', '\u{313}',
+    '\u{3c1}', '\u{314}', '\u{3c5}', '\u{342}', '\u{3c5}', '\u{308}', '\u{342}', '\u{3a5}',
+    '\u{306}', '\u{3a5}', '\u{304}', '\u{3a5}', '\u{300}', '\u{3a5}', '\u{301}', '\u{3a1}',
+    '\u{314}', '\u{a8}', '\u{300}', '\u{a8}', '\u{301}', '\u{60}', '\u{3c9}', '\u{300}', '\u{345}',
+    '\u{3c9}', '\u{345}', '\u{3c9}', '\u{301}', '\u{345}', '\u{3c9}', '\u{342}', '\u{3c9}',
+    '\u{342}', '\u{345}', '\u{39f}', '\u{300}', '\u{39f}', '\u{301}', '\u{3a9}', '\u{300}',
+    '\u{3a9}', '\u{301}', '\u{3a9}', '\u{345}', '\u{b4}', '\u{2002}', '\u{2003}', '\u{3a9}',
+    '\u{4b}', '\u{41}', '\u{30a}', '\u{2190}', '\u{338}', '\u{2192}', '\u{338}', '\u{2194}',
+    '\u{338}', '\u{21d0}', '\u{338}', '\u{21d4}', '\u{338}', '\u{21d2}', '\u{338}', '\u{2203}',
+    '\u{338}', '\u{2208}', '\u{338}', '\u{220b}', '\u{338}', '\u{2223}', '\u{338}', '\u{2225}',
+    '\u{338}', '\u{223c}', '\u{338}', '\u{2243}', '\u{338}', '\u{2245}', '\u{338}', '\u{2248}',
+    '\u{338}', '\u{3d}', '\u{338}', '\u{2261}', '\u{338}', '\u{224d}', '\u{338}', '\u{3c}',
+    '\u{338}', '\u{3e}', '\u{338}', '\u{2264}', '\u{338}', '\u{2265}', '\u{338}', '\u{2272}',
+    '\u{338}', '\u{2273}', '\u{338}', '\u{2276}', '\u{338}', '\u{2277}', '\u{338}', '\u{227a}',
+    '\u{338}', '\u{227b}', '\u{338}', '\u{2282}', '\u{338}', '\u{2283}', '\u{338}', '\u{2286}',
+    '\u{338}', '\u{2287}', '\u{338}', '\u{22a2}', '\u{338}', '\u{22a8}', '\u{338}', '\u{22a9}',
+    '\u{338}', '\u{22ab}', '\u{338}', '\u{227c}', '\u{338}', '\u{227d}', '\u{338}', '\u{2291}',
+    '\u{338}', '\u{2292}', '\u{338}', '\u{22b2}', '\u{338}', '\u{22b3}', '\u{338}', '\u{22b4}',
+    '\u{338}', '\u{22b5}', '\u{338}', '\u{3008}', '\u{3009}', '\u{2add}', '\u{338}', '\u{304b}',
+    '\u{3099}', '\u{304d}', '\u{3099}', '\u{304f}', '\u{3099}', '\u{3051}', '\u{3099}', '\u{3053}',
+    '\u{3099}', '\u{3055}', '\u{3099}', '\u{3057}', '\u{3099}', '\u{3059}', '\u{3099}', '\u{305b}',
+    '\u{3099}', '\u{305d}', '\u{3099}', '\u{305f}', '\u{3099}', '\u{3061}', '\u{3099}', '\u{3064}',
+    '\u{3099}', '\u{3066}', '\u{3099}', '\u{3068}', '\u{3099}', '\u{306f}', '\u{3099}', '\u{306f}',
+    '\u{309a}', '\u{3072}', '\u{3099}', '\u{3072}', '\u{309a}', '\u{3075}', '\u{3099}', '\u{3075}',
+    '\u{309a}', '\u{3078}', '\u{3099}', '\u{3078}', '\u{309a}', '\u{307b}', '\u{3099}', '\u{307b}',
+    '\u{309a}', '\u{3046}', '\u{3099}', '\u{309d}', '\u{3099}', '\u{30ab}', '\u{3099}', '\u{30ad}',
+    '\u{3099}', '\u{30af}', '\u{3099}', '\u{30b1}', '\u{3099}', '\u{30b3}', '\u{3099}', '\u{30b5}',
+    '\u{3099}', '\u{30b7}', '\u{3099}', '\u{30b9}', '\u{3099}', '\u{30bb}', '\u{3099}', '\u{30bd}',
+    '\u{3099}', '\u{30bf}', '\u{3099}', '\u{30c1}', '\u{3099}', '\u{30c4}', '\u{3099}', '\u{30c6}',
+    '\u{3099}', '\u{30c8}', '\u{3099}', '\u{30cf}', '\u{3099}', '\u{30cf}', '\u{309a}', '\u{30d2}',
+    '\u{3099}', '\u{30d2}', '\u{309a}', '\u{30d5}', '\u{3099}', '\u{30d5}', '\u{309a}', '\u{30d8}',
+    '\u{3099}', '\u{30d8}', '\u{309a}', '\u{30db}', '\u{3099}', '\u{30db}', '\u{309a}', '\u{30a6}',
+    '\u{3099}', '\u{30ef}', '\u{3099}', '\u{30f0}', '\u{3099}', '\u{30f1}', '\u{3099}', '\u{30f2}',
+    '\u{3099}', '\u{30fd}', '\u{3099}', '\u{8c48}', '\u{66f4}', '\u{8eca}', '\u{8cc8}', '\u{6ed1}',
+    '\u{4e32}', '\u{53e5}', '\u{9f9c}', '\u{9f9c}', '\u{5951}', '\u{91d1}', '\u{5587}', '\u{5948}',
+    '\u{61f6}', '\u{7669}', '\u{7f85}', '\u{863f}', '\u{87ba}', '\u{88f8}', '\u{908f}', '\u{6a02}',
+    '\u{6d1b}', '\u{70d9}', '\u{73de}', '\u{843d}', '\u{916a}', '\u{99f1}', '\u{4e82}', '\u{5375}',
+    '\u{6b04}', '\u{721b}', '\u{862d}', '\u{9e1e}', '\u{5d50}', '\u{6feb}', '\u{85cd}', '\u{8964}',
+    '\u{62c9}', '\u{81d8}', '\u{881f}', '\u{5eca}', '\u{6717}', '\u{6d6a}', '\u{72fc}', '\u{90ce}',
+    '\u{4f86}', '\u{51b7}', '\u{52de}', '\u{64c4}', '\u{6ad3}', '\u{7210}', '\u{76e7}', '\u{8001}',
+    '\u{8606}', '\u{865c}', '\u{8def}', '\u{9732}', '\u{9b6f}', '\u{9dfa}', '\u{788c}', '\u{797f}',
+    '\u{7da0}', '\u{83c9}', '\u{9304}', '\u{9e7f}', '\u{8ad6}', '\u{58df}', '\u{5f04}', '\u{7c60}',
+    '\u{807e}', '\u{7262}', '\u{78ca}', '\u{8cc2}', '\u{96f7}', '\u{58d8}', '\u{5c62}', '\u{6a13}',
+    '\u{6dda}', '\u{6f0f}', '\u{7d2f}', '\u{7e37}', '\u{964b}', '\u{52d2}', '\u{808b}', '\u{51dc}',
+    '\u{51cc}', '\u{7a1c}', '\u{7dbe}', '\u{83f1}', '\u{9675}', '\u{8b80}', '\u{62cf}', '\u{6a02}',
+    '\u{8afe}', '\u{4e39}', '\u{5be7}', '\u{6012}', '\u{7387}', '\u{7570}', '\u{5317}', '\u{78fb}',
+    '\u{4fbf}', '\u{5fa9}', '\u{4e0d}', '\u{6ccc}', '\u{6578}', '\u{7d22}', '\u{53c3}', '\u{585e}',
+    '\u{7701}', '\u{8449}', '\u{8aaa}', '\u{6bba}', '\u{8fb0}', '\u{6c88}', '\u{62fe}', '\u{82e5}',
+    '\u{63a0}', '\u{7565}', '\u{4eae}', '\u{5169}', '\u{51c9}', '\u{6881}', '\u{7ce7}', '\u{826f}',
+    '\u{8ad2}', '\u{91cf}', '\u{52f5}', '\u{5442}', '\u{5973}', '\u{5eec}', '\u{65c5}', '\u{6ffe}',
+    '\u{792a}', '\u{95ad}', '\u{9a6a}', '\u{9e97}', '\u{9ece}', '\u{529b}', '\u{66c6}', '\u{6b77}',
+    '\u{8f62}', '\u{5e74}', '\u{6190}', '\u{6200}', '\u{649a}', '\u{6f23}', '\u{7149}', '\u{7489}',
+    '\u{79ca}', '\u{7df4}', '\u{806f}', '\u{8f26}', '\u{84ee}', '\u{9023}', '\u{934a}', '\u{5217}',
+    '\u{52a3}', '\u{54bd}', '\u{70c8}', '\u{88c2}', '\u{8aaa}', '\u{5ec9}', '\u{5ff5}', '\u{637b}',
+    '\u{6bae}', '\u{7c3e}', '\u{7375}', '\u{4ee4}', '\u{56f9}', '\u{5be7}', '\u{5dba}', '\u{601c}',
+    '\u{73b2}', '\u{7469}', '\u{7f9a}', '\u{8046}', '\u{9234}', '\u{96f6}', '\u{9748}', '\u{9818}',
+    '\u{4f8b}', '\u{79ae}', '\u{91b4}', '\u{96b8}', '\u{60e1}', '\u{4e86}', '\u{50da}', '\u{5bee}',
+    '\u{5c3f}', '\u{6599}', '\u{6a02}', '\u{71ce}', '\u{7642}', '\u{84fc}', '\u{907c}', '\u{9f8d}',
+    '\u{6688}', '\u{962e}', '\u{5289}', '\u{677b}', '\u{67f3}', '\u{6d41}', '\u{6e9c}', '\u{7409}',
+    '\u{7559}', '\u{786b}', '\u{7d10}', '\u{985e}', '\u{516d}', '\u{622e}', '\u{9678}', '\u{502b}',
+    '\u{5d19}', '\u{6dea}', '\u{8f2a}', '\u{5f8b}', '\u{6144}', '\u{6817}', '\u{7387}', '\u{9686}',
+    '\u{5229}', '\u{540f}', '\u{5c65}', '\u{6613}', '\u{674e}', '\u{68a8}', '\u{6ce5}', '\u{7406}',
+    '\u{75e2}', '\u{7f79}', '\u{88cf}', '\u{88e1}', '\u{91cc}', '\u{96e2}', '\u{533f}', '\u{6eba}',
+    '\u{541d}', '\u{71d0}', '\u{7498}', '\u{85fa}', '\u{96a3}', '\u{9c57}', '\u{9e9f}', '\u{6797}',
+    '\u{6dcb}', '\u{81e8}', '\u{7acb}', '\u{7b20}', '\u{7c92}', '\u{72c0}', '\u{7099}', '\u{8b58}',
+    '\u{4ec0}', '\u{8336}', '\u{523a}', '\u{5207}', '\u{5ea6}', '\u{62d3}', '\u{7cd6}', '\u{5b85}',
+    '\u{6d1e}', '\u{66b4}', '\u{8f3b}', '\u{884c}', '\u{964d}', '\u{898b}', '\u{5ed3}', '\u{5140}',
+    '\u{55c0}', '\u{585a}', '\u{6674}', '\u{51de}', '\u{732a}', '\u{76ca}', '\u{793c}', '\u{795e}',
+    '\u{7965}', '\u{798f}', '\u{9756}', '\u{7cbe}', '\u{7fbd}', '\u{8612}', '\u{8af8}', '\u{9038}',
+    '\u{90fd}', '\u{98ef}', '\u{98fc}', '\u{9928}', '\u{9db4}', '\u{90de}', '\u{96b7}', '\u{4fae}',
+    '\u{50e7}', '\u{514d}', '\u{52c9}', '\u{52e4}', '\u{5351}', '\u{559d}', '\u{5606}', '\u{5668}',
+    '\u{5840}', '\u{58a8}', '\u{5c64}', '\u{5c6e}', '\u{6094}', '\u{6168}', '\u{618e}', '\u{61f2}',
+    '\u{654f}', '\u{65e2}', '\u{6691}', '\u{6885}', '\u{6d77}', '\u{6e1a}', '\u{6f22}', '\u{716e}',
+    '\u{722b}', '\u{7422}', '\u{7891}', '\u{793e}', '\u{7949}', '\u{7948}', '\u{7950}', '\u{7956}',
+    '\u{795d}', '\u{798d}', '\u{798e}', '\u{7a40}', '\u{7a81}', '\u{7bc0}', '\u{7df4}', '\u{7e09}',
+    '\u{7e41}', '\u{7f72}', '\u{8005}', '\u{81ed}', '\u{8279}', '\u{8279}', '\u{8457}', '\u{8910}',
+    '\u{8996}', '\u{8b01}', '\u{8b39}', '\u{8cd3}', '\u{8d08}', '\u{8fb6}', '\u{9038}', '\u{96e3}',
+    '\u{97ff}', '\u{983b}', '\u{6075}', '\u{242ee}', '\u{8218}', '\u{4e26}', '\u{51b5}', '\u{5168}',
+    '\u{4f80}', '\u{5145}', '\u{5180}', '\u{52c7}', '\u{52fa}', '\u{559d}', '\u{5555}', '\u{5599}',
+    '\u{55e2}', '\u{585a}', '\u{58b3}', '\u{5944}', '\u{5954}', '\u{5a62}', '\u{5b28}', '\u{5ed2}',
+    '\u{5ed9}', '\u{5f69}', '\u{5fad}', '\u{60d8}', '\u{614e}', '\u{6108}', '\u{618e}', '\u{6160}',
+    '\u{61f2}', '\u{6234}', '\u{63c4}', '\u{641c}', '\u{6452}', '\u{6556}', '\u{6674}', '\u{6717}',
+    '\u{671b}', '\u{6756}', '\u{6b79}', '\u{6bba}', '\u{6d41}', '\u{6edb}', '\u{6ecb}', '\u{6f22}',
+    '\u{701e}', '\u{716e}', '\u{77a7}', '\u{7235}', '\u{72af}', '\u{732a}', '\u{7471}', '\u{7506}',
+    '\u{753b}', '\u{761d}', '\u{761f}', '\u{76ca}', '\u{76db}', '\u{76f4}', '\u{774a}', '\u{7740}',
+    '\u{78cc}', '\u{7ab1}', '\u{7bc0}', '\u{7c7b}', '\u{7d5b}', '\u{7df4}', '\u{7f3e}', '\u{8005}',
+    '\u{8352}', '\u{83ef}', '\u{8779}', '\u{8941}', '\u{8986}', '\u{8996}', '\u{8abf}', '\u{8af8}',
+    '\u{8acb}', '\u{8b01}', '\u{8afe}', '\u{8aed}', '\u{8b39}', '\u{8b8a}', '\u{8d08}', '\u{8f38}',
+    '\u{9072}', '\u{9199}', '\u{9276}', '\u{967c}', '\u{96e3}', '\u{9756}', '\u{97db}', '\u{97ff}',
+    '\u{980b}', '\u{983b}', '\u{9b12}', '\u{9f9c}', '\u{2284a}', '\u{22844}', '\u{233d5}',
+    '\u{3b9d}', '\u{4018}', '\u{4039}', '\u{25249}', '\u{25cd0}', '\u{27ed3}', '\u{9f43}',
+    '\u{9f8e}', '\u{5d9}', '\u{5b4}', '\u{5f2}', '\u{5b7}', '\u{5e9}', '\u{5c1}', '\u{5e9}',
+    '\u{5c2}', '\u{5e9}', '\u{5bc}', '\u{5c1}', '\u{5e9}', '\u{5bc}', '\u{5c2}', '\u{5d0}',
+    '\u{5b7}', '\u{5d0}', '\u{5b8}', '\u{5d0}', '\u{5bc}', '\u{5d1}', '\u{5bc}', '\u{5d2}',
+    '\u{5bc}', '\u{5d3}', '\u{5bc}', '\u{5d4}', '\u{5bc}', '\u{5d5}', '\u{5bc}', '\u{5d6}',
+    '\u{5bc}', '\u{5d8}', '\u{5bc}', '\u{5d9}', '\u{5bc}', '\u{5da}', '\u{5bc}', '\u{5db}',
+    '\u{5bc}', '\u{5dc}', '\u{5bc}', '\u{5de}', '\u{5bc}', '\u{5e0}', '\u{5bc}', '\u{5e1}',
+    '\u{5bc}', '\u{5e3}', '\u{5bc}', '\u{5e4}', '\u{5bc}', '\u{5e6}', '\u{5bc}', '\u{5e7}',
+    '\u{5bc}', '\u{5e8}', '\u{5bc}', '\u{5e9}', '\u{5bc}', '\u{5ea}', '\u{5bc}', '\u{5d5}',
+    '\u{5b9}', '\u{5d1}', '\u{5bf}', '\u{5db}', '\u{5bf}', '\u{5e4}', '\u{5bf}', '\u{11099}',
+    '\u{110ba}', '\u{1109b}', '\u{110ba}', '\u{110a5}', '\u{110ba}', '\u{11131}', '\u{11127}',
+    '\u{11132}', '\u{11127}', '\u{11347}', '\u{1133e}', '\u{11347}', '\u{11357}', '\u{114b9}',
+    '\u{114ba}', '\u{114b9}', '\u{114b0}', '\u{114b9}', '\u{114bd}', '\u{115b8}', '\u{115af}',
+    '\u{115b9}', '\u{115af}', '\u{11935}', '\u{11930}', '\u{1d157}', '\u{1d165}', '\u{1d158}',
+    '\u{1d165}', '\u{1d158}', '\u{1d165}', '\u{1d16e}', '\u{1d158}', '\u{1d165}', '\u{1d16f}',
+    '\u{1d158}', '\u{1d165}', '\u{1d170}', '\u{1d158}', '\u{1d165}', '\u{1d171}', '\u{1d158}',
+    '\u{1d165}', '\u{1d172}', '\u{1d1b9}', '\u{1d165}', '\u{1d1ba}', '\u{1d165}', '\u{1d1b9}',
+    '\u{1d165}', '\u{1d16e}', '\u{1d1ba}', '\u{1d165}', '\u{1d16e}', '\u{1d1b9}', '\u{1d165}',
+    '\u{1d16f}', '\u{1d1ba}', '\u{1d165}', '\u{1d16f}', '\u{4e3d}', '\u{4e38}', '\u{4e41}',
+    '\u{20122}', '\u{4f60}', '\u{4fae}', '\u{4fbb}', '\u{5002}', '\u{507a}', '\u{5099}', '\u{50e7}',
+    '\u{50cf}', '\u{349e}', '\u{2063a}', '\u{514d}', '\u{5154}', '\u{5164}', '\u{5177}',
+    '\u{2051c}', '\u{34b9}', '\u{5167}', '\u{518d}', '\u{2054b}', '\u{5197}', '\u{51a4}',
+    '\u{4ecc}', '\u{51ac}', '\u{51b5}', '\u{291df}', '\u{51f5}', '\u{5203}', '\u{34df}', '\u{523b}',
+    '\u{5246}', '\u{5272}', '\u{5277}', '\u{3515}', '\u{52c7}', '\u{52c9}', '\u{52e4}', '\u{52fa}',
+    '\u{5305}', '\u{5306}', '\u{5317}', '\u{5349}', '\u{5351}', '\u{535a}', '\u{5373}', '\u{537d}',
+    '\u{537f}', '\u{537f}', '\u{537f}', '\u{20a2c}', '\u{7070}', '\u{53ca}', '\u{53df}',
+    '\u{20b63}', '\u{53eb}', '\u{53f1}', '\u{5406}', '\u{549e}', '\u{5438}', '\u{5448}', '\u{5468}',
+    '\u{54a2}', '\u{54f6}', '\u{5510}', '\u{5553}', '\u{5563}', '\u{5584}', '\u{5584}', '\u{5599}',
+    '\u{55ab}', '\u{55b3}', '\u{55c2}', '\u{5716}', '\u{5606}', '\u{5717}', '\u{5651}', '\u{5674}',
+    '\u{5207}', '\u{58ee}', '\u{57ce}', '\u{57f4}', '\u{580d}', '\u{578b}', '\u{5832}', '\u{5831}',
+    '\u{58ac}', '\u{214e4}', '\u{58f2}', '\u{58f7}', '\u{5906}', '\u{591a}', '\u{5922}', '\u{5962}',
+    '\u{216a8}', '\u{216ea}', '\u{59ec}', '\u{5a1b}', '\u{5a27}', '\u{59d8}', '\u{5a66}',
+    '\u{36ee}', '\u{36fc}', '\u{5b08}', '\u{5b3e}', '\u{5b3e}', '\u{219c8}', '\u{5bc3}', '\u{5bd8}',
+    '\u{5be7}', '\u{5bf3}', '\u{21b18}', '\u{5bff}', '\u{5c06}', '\u{5f53}', '\u{5c22}', '\u{3781}',
+    '\u{5c60}', '\u{5c6e}', '\u{5cc0}', '\u{5c8d}', '\u{21de4}', '\u{5d43}', '\u{21de6}',
+    '\u{5d6e}', '\u{5d6b}', '\u{5d7c}', '\u{5de1}', '\u{5de2}', '\u{382f}', '\u{5dfd}', '\u{5e28}',
+    '\u{5e3d}', '\u{5e69}', '\u{3862}', '\u{22183}', '\u{387c}', '\u{5eb0}', '\u{5eb3}', '\u{5eb6}',
+    '\u{5eca}', '\u{2a392}', '\u{5efe}', '\u{22331}', '\u{22331}', '\u{8201}', '\u{5f22}',
+    '\u{5f22}', '\u{38c7}', '\u{232b8}', '\u{261da}', '\u{5f62}', '\u{5f6b}', '\u{38e3}',
+    '\u{5f9a}', '\u{5fcd}', '\u{5fd7}', '\u{5ff9}', '\u{6081}', '\u{393a}', '\u{391c}', '\u{6094}',
+    '\u{226d4}', '\u{60c7}', '\u{6148}', '\u{614c}', '\u{614e}', '\u{614c}', '\u{617a}', '\u{618e}',
+    '\u{61b2}', '\u{61a4}', '\u{61af}', '\u{61de}', '\u{61f2}', '\u{61f6}', '\u{6210}', '\u{621b}',
+    '\u{625d}', '\u{62b1}', '\u{62d4}', '\u{6350}', '\u{22b0c}', '\u{633d}', '\u{62fc}', '\u{6368}',
+    '\u{6383}', '\u{63e4}', '\u{22bf1}', '\u{6422}', '\u{63c5}', '\u{63a9}', '\u{3a2e}', '\u{6469}',
+    '\u{647e}', '\u{649d}', '\u{6477}', '\u{3a6c}', '\u{654f}', '\u{656c}', '\u{2300a}', '\u{65e3}',
+    '\u{66f8}', '\u{6649}', '\u{3b19}', '\u{6691}', '\u{3b08}', '\u{3ae4}', '\u{5192}', '\u{5195}',
+    '\u{6700}', '\u{669c}', '\u{80ad}', '\u{43d9}', '\u{6717}', '\u{671b}', '\u{6721}', '\u{675e}',
+    '\u{6753}', '\u{233c3}', '\u{3b49}', '\u{67fa}', '\u{6785}', '\u{6852}', '\u{6885}',
+    '\u{2346d}', '\u{688e}', '\u{681f}', '\u{6914}', '\u{3b9d}', '\u{6942}', '\u{69a3}', '\u{69ea}',
+    '\u{6aa8}', '\u{236a3}', '\u{6adb}', '\u{3c18}', '\u{6b21}', '\u{238a7}', '\u{6b54}',
+    '\u{3c4e}', '\u{6b72}', '\u{6b9f}', '\u{6bba}', '\u{6bbb}', '\u{23a8d}', '\u{21d0b}',
+    '\u{23afa}', '\u{6c4e}', '\u{23cbc}', '\u{6cbf}', '\u{6ccd}', '\u{6c67}', '\u{6d16}',
+    '\u{6d3e}', '\u{6d77}', '\u{6d41}', '\u{6d69}', '\u{6d78}', '\u{6d85}', '\u{23d1e}', '\u{6d34}',
+    '\u{6e2f}', '\u{6e6e}', '\u{3d33}', '\u{6ecb}', '\u{6ec7}', '\u{23ed1}', '\u{6df9}', '\u{6f6e}',
+    '\u{23f5e}', '\u{23f8e}', '\u{6fc6}', '\u{7039}', '\u{701e}', '\u{701b}', '\u{3d96}',
+    '\u{704a}', '\u{707d}', '\u{7077}', '\u{70ad}', '\u{20525}', '\u{7145}', '\u{24263}',
+    '\u{719c}', '\u{243ab}', '\u{7228}', '\u{7235}', '\u{7250}', '\u{24608}', '\u{7280}',
+    '\u{7295}', '\u{24735}', '\u{24814}', '\u{737a}', '\u{738b}', '\u{3eac}', '\u{73a5}',
+    '\u{3eb8}', '\u{3eb8}', '\u{7447}', '\u{745c}', '\u{7471}', '\u{7485}', '\u{74ca}', '\u{3f1b}',
+    '\u{7524}', '\u{24c36}', '\u{753e}', '\u{24c92}', '\u{7570}', '\u{2219f}', '\u{7610}',
+    '\u{24fa1}', '\u{24fb8}', '\u{25044}', '\u{3ffc}', '\u{4008}', '\u{76f4}', '\u{250f3}',
+    '\u{250f2}', '\u{25119}', '\u{25133}', '\u{771e}', '\u{771f}', '\u{771f}', '\u{774a}',
+    '\u{4039}', '\u{778b}', '\u{4046}', '\u{4096}', '\u{2541d}', '\u{784e}', '\u{788c}', '\u{78cc}',
+    '\u{40e3}', '\u{25626}', '\u{7956}', '\u{2569a}', '\u{256c5}', '\u{798f}', '\u{79eb}',
+    '\u{412f}', '\u{7a40}', '\u{7a4a}', '\u{7a4f}', '\u{2597c}', '\u{25aa7}', '\u{25aa7}',
+    '\u{7aee}', '\u{4202}', '\u{25bab}', '\u{7bc6}', '\u{7bc9}', '\u{4227}', '\u{25c80}',
+    '\u{7cd2}', '\u{42a0}', '\u{7ce8}', '\u{7ce3}', '\u{7d00}', '\u{25f86}', '\u{7d63}', '\u{4301}',
+    '\u{7dc7}', '\u{7e02}', '\u{7e45}', '\u{4334}', '\u{26228}', '\u{26247}', '\u{4359}',
+    '\u{262d9}', '\u{7f7a}', '\u{2633e}', '\u{7f95}', '\u{7ffa}', '\u{8005}', '\u{264da}',
+    '\u{26523}', '\u{8060}', '\u{265a8}', '\u{8070}', '\u{2335f}', '\u{43d5}', '\u{80b2}',
+    '\u{8103}', '\u{440b}', '\u{813e}', '\u{5ab5}', '\u{267a7}', '\u{267b5}', '\u{23393}',
+    '\u{2339c}', '\u{8201}', '\u{8204}', '\u{8f9e}', '\u{446b}', '\u{8291}', '\u{828b}', '\u{829d}',
+    '\u{52b3}', '\u{82b1}', '\u{82b3}', '\u{82bd}', '\u{82e6}', '\u{26b3c}', '\u{82e5}', '\u{831d}',
+    '\u{8363}', '\u{83ad}', '\u{8323}', '\u{83bd}', '\u{83e7}', '\u{8457}', '\u{8353}', '\u{83ca}',
+    '\u{83cc}', '\u{83dc}', '\u{26c36}', '\u{26d6b}', '\u{26cd5}', '\u{452b}', '\u{84f1}',
+    '\u{84f3}', '\u{8516}', '\u{273ca}', '\u{8564}', '\u{26f2c}', '\u{455d}', '\u{4561}',
+    '\u{26fb1}', '\u{270d2}', '\u{456b}', '\u{8650}', '\u{865c}', '\u{8667}', '\u{8669}',
+    '\u{86a9}', '\u{8688}', '\u{870e}', '\u{86e2}', '\u{8779}', '\u{8728}', '\u{876b}', '\u{8786}',
+    '\u{45d7}', '\u{87e1}', '\u{8801}', '\u{45f9}', '\u{8860}', '\u{8863}', '\u{27667}', '\u{88d7}',
+    '\u{88de}', '\u{4635}', '\u{88fa}', '\u{34bb}', '\u{278ae}', '\u{27966}', '\u{46be}',
+    '\u{46c7}', '\u{8aa0}', '\u{8aed}', '\u{8b8a}', '\u{8c55}', '\u{27ca8}', '\u{8cab}', '\u{8cc1}',
+    '\u{8d1b}', '\u{8d77}', '\u{27f2f}', '\u{20804}', '\u{8dcb}', '\u{8dbc}', '\u{8df0}',
+    '\u{208de}', '\u{8ed4}', '\u{8f38}', '\u{285d2}', '\u{285ed}', '\u{9094}', '\u{90f1}',
+    '\u{9111}', '\u{2872e}', '\u{911b}', '\u{9238}', '\u{92d7}', '\u{92d8}', '\u{927c}', '\u{93f9}',
+    '\u{9415}', '\u{28bfa}', '\u{958b}', '\u{4995}', '\u{95b7}', '\u{28d77}', '\u{49e6}',
+    '\u{96c3}', '\u{5db2}', '\u{9723}', '\u{29145}', '\u{2921a}', '\u{4a6e}', '\u{4a76}',
+    '\u{97e0}', '\u{2940a}', '\u{4ab2}', '\u{29496}', '\u{980b}', '\u{980b}', '\u{9829}',
+    '\u{295b6}', '\u{98e2}', '\u{4b33}', '\u{9929}', '\u{99a7}', '\u{99c2}', '\u{99fe}', '\u{4bce}',
+    '\u{29b30}', '\u{9b12}', '\u{9c40}', '\u{9cfd}', '\u{4cce}', '\u{4ced}', '\u{9d67}',
+    '\u{2a0ce}', '\u{4cf8}', '\u{2a105}', '\u{2a20e}', '\u{2a291}', '\u{9ebb}', '\u{4d56}',
+    '\u{9ef9}', '\u{9efe}', '\u{9f05}', '\u{9f0f}', '\u{9f16}', '\u{9f3b}', '\u{2a600}',
+];
+/// Primary composites by the starter & the combining code point
+pub(super) static COMPOSITIONS: &[(u32, u32, char)] = &[
+    (0x3c, 0x338, '\u{226e}'), (0x3d, 0x338, '\u{2260}'), (0x3e, 0x338, '\u{226f}'),
+    (0x41, 0x300, '\u{c0}'), (0x41, 0x301, '\u{c1}'), (0x41, 0x302, '\u{c2}'),
+    (0x41, 0x303, '\u{c3}'), (0x41, 0x304, '\u{100}'), (0x41, 0x306, '\u{102}'),
+    (0x41, 0x307, '\u{226}'), (0x41, 0x308, '\u{c4}'), (0x41, 0x309, '\u{1ea2}'),
+    (0x41, 0x30a, '\u{c5}'), (0x41, 0x30c, '\u{1cd}'), (0x41, 0x30f, '\u{200}'),
+    (0x41, 0x311, '\u{202}'), (0x41, 0x323, '\u{1ea0}'), (0x41, 0x325, '\u{1e00}'),
+    (0x41, 0x328, '\u{104}'), (0x42, 0x307, '\u{1e02}'), (0x42, 0x323, '\u{1e04}'),
+    (0x42, 0x331, '\u{1e06}'), (0x43, 0x301, '\u{106}'), (0x43, 0x302, '\u{108}'),
+    (0x43, 0x307, '\u{10a}'), (0x43, 0x30c, '\u{10c}'), (0x43, 0x327, '\u{c7}'),
+    (0x44, 0x307, '\u{1e0a}'), (0x44, 0x30c, '\u{10e}'), (0x44, 0x323, '\u{1e0c}'),
+    (0x44, 0x327, '\u{1e10}'), (0x44, 0x32d, '\u{1e12}'), (0x44, 0x331, '\u{1e0e}'),
+    (0x45, 0x300, '\u{c8}'), (0x45, 0x301, '\u{c9}'), (0x45, 0x302, '\u{ca}'),
+    (0x45, 0x303, '\u{1ebc}'), (0x45, 0x304, '\u{112}'), (0x45, 0x306, '\u{114}'),
+    (0x45, 0x307, '\u{116}'), (0x45, 0x308, '\u{cb}'), (0x45, 0x309, '\u{1eba}'),
+    (0x45, 0x30c, '\u{11a}'), (0x45, 0x30f, '\u{204}'), (0x45, 0x311, '\u{206}'),
+    (0x45, 0x323, '\u{1eb8}'), (0x45, 0x327, '\u{228}'), (0x45, 0x328, '\u{118}'),
+    (0x45, 0x32d, '\u{1e18}'), (0x45, 0x330, '\u{1e1a}'), (0x46, 0x307, '\u{1e1e}'),
+    (0x47, 0x301, '\u{1f4}'), (0x47, 0x302, '\u{11c}'), (0x47, 0x304, '\u{1e20}'),
+    (0x47, 0x306, '\u{11e}'), (0x47, 0x307, '\u{120}'), (0x47, 0x30c, '\u{1e6}'),
+    (0x47, 0x327, '\u{122}'), (0x48, 0x302, '\u{124}'), (0x48, 0x307, '\u{1e22}'),
+    (0x48, 0x308, '\u{1e26}'), (0x48, 0x30c, '\u{21e}'), (0x48, 0x323, '\u{1e24}'),
+    (0x48, 0x327, '\u{1e28}'), (0x48, 0x32e, '\u{1e2a}'), (0x49, 0x300, '\u{cc}'),
+    (0x49, 0x301, '\u{cd}'), (0x49, 0x302, '\u{ce}'), (0x49, 0x303, '\u{128}'),
+    (0x49, 0x304, '\u{12a}'), (0x49, 0x306, '\u{12c}'), (0x49, 0x307, '\u{130}'),
+    (0x49, 0x308, '\u{cf}'), (0x49, 0x309, '\u{1ec8}'), (0x49, 0x30c, '\u{1cf}'),
+    (0x49, 0x30f, '\u{208}'), (0x49, 0x311, '\u{20a}'), (0x49, 0x323, '\u{1eca}'),
+    (0x49, 0x328, '\u{12e}'), (0x49, 0x330, '\u{1e2c}'), (0x4a, 0x302, '\u{134}'),
+    (0x4b, 0x301, '\u{1e30}'), (0x4b, 0x30c, '\u{1e8}'), (0x4b, 0x323, '\u{1e32}'),
+    (0x4b, 0x327, '\u{136}'), (0x4b, 0x331, '\u{1e34}'), (0x4c, 0x301, '\u{139}'),
+    (0x4c, 0x30c, '\u{13d}'), (0x4c, 0x323, '\u{1e36}'), (0x4c, 0x327, '\u{13b}'),
+    (0x4c, 0x32d, '\u{1e3c}'), (0x4c, 0x331, '\u{1e3a}'), (0x4d, 0x301, '\u{1e3e}'),
+    (0x4d, 0x307, '\u{1e40}'), (0x4d, 0x323, '\u{1e42}'), (0x4e, 0x300, '\u{1f8}'),
+    (0x4e, 0x301, '\u{143}'), (0x4e, 0x303, '\u{d1}'), (0x4e, 0x307, '\u{1e44}'),
+    (0x4e, 0x30c, '\u{147}'), (0x4e, 0x323, '\u{1e46}'), (0x4e, 0x327, '\u{145}'),
+    (0x4e, 0x32d, '\u{1e4a}'), (0x4e, 0x331, '\u{1e48}'), (0x4f, 0x300, '\u{d2}'),
+    (0x4f, 0x301, '\u{d3}'), (0x4f, 0x302, '\u{d4}'), (0x4f, 0x303, '\u{d5}'),
+    (0x4f, 0x304, '\u{14c}'), (0x4f, 0x306, '\u{14e}'), (0x4f, 0x307, '\u{22e}'),
+    (0x4f, 0x308, '\u{d6}'), (0x4f, 0x309, '\u{1ece}'), (0x4f, 0x30b, '\u{150}'),
+    (0x4f, 0x30c, '\u{1d1}'), (0x4f, 0x30f, '\u{20c}'), (0x4f, 0x311, '\u{20e}'),
+    (0x4f, 0x31b, '\u{1a0}'), (0x4f, 0x323, '\u{1ecc}'), (0x4f, 0x328, '\u{1ea}'),
+    (0x50, 0x301, '\u{1e54}'), (0x50, 0x307, '\u{1e56}'), (0x52, 0x301, '\u{154}'),
+    (0x52, 0x307, '\u{1e58}'), (0x52, 0x30c, '\u{158}'), (0x52, 0x30f, '\u{210}'),
+    (0x52, 0x311, '\u{212}'), (0x52, 0x323, '\u{1e5a}'), (0x52, 0x327, '\u{156}'),
+    (0x52, 0x331, '\u{1e5e}'), (0x53, 0x301, '\u{15a}'), (0x53, 0x302, '\u{15c}'),
+    (0x53, 0x307, '\u{1e60}'), (0x53, 0x30c, '\u{160}'), (0x53, 0x323, '\u{1e62}'),
+    (0x53, 0x326, '\u{218}'), (0x53, 0x327, '\u{15e}'), (0x54, 0x307, '\u{1e6a}'),
+    (0x54, 0x30c, '\u{164}'), (0x54, 0x323, '\u{1e6c}'), (0x54, 0x326, '\u{21a}'),
+    (0x54, 0x327, '\u{162}'), (0x54, 0x32d, '\u{1e70}'), (0x54, 0x331, '\u{1e6e}'),
+    (0x55, 0x300, '\u{d9}'), (0x55, 0x301, '\u{da}'), (0x55, 0x302, '\u{db}'),
+    (0x55, 0x303, '\u{168}'), (0x55, 0x304, '\u{16a}'), (0x55, 0x306, '\u{16c}'),
+    (0x55, 0x308, '\u{dc}'), (0x55, 0x309, '\u{1ee6}'), (0x55, 0x30a, '\u{16e}'),
+    (0x55, 0x30b, '\u{170}'), (0x55, 0x30c, '\u{1d3}'), (0x55, 0x30f, '\u{214}'),
+    (0x55, 0x311, '\u{216}'), (0x55, 0x31b, '\u{1af}'), (0x55, 0x323, '\u{1ee4}'),
+    (0x55, 0x324, '\u{1e72}'), (0x55, 0x328, '\u{172}'), (0x55, 0x32d, '\u{1e76}'),
+    (0x55, 0x330, '\u{1e74}'), (0x56, 0x303, '\u{1e7c}'), (0x56, 0x323, '\u{1e7e}'),
+    (0x57, 0x300, '\u{1e80}'), (0x57, 0x301, '\u{1e82}'), (0x57, 0x302, '\u{174}'),
+    (0x57, 0x307, '\u{1e86}'), (0x57, 0x308, '\u{1e84}'), (0x57, 0x323, '\u{1e88}'),
+    (0x58, 0x307, '\u{1e8a}'), (0x58, 0x308, '\u{1e8c}'), (0x59, 0x300, '\u{1ef2}'),
+    (0x59, 0x301, '\u{dd}'), (0x59, 0x302, '\u{176}'), (0x59, 0x303, '\u{1ef8}'),
+    (0x59, 0x304, '\u{232}'), (0x59, 0x307, '\u{1e8e}'), (0x59, 0x308, '\u{178}'),
+    (0x59, 0x309, '\u{1ef6}'), (0x59, 0x323, '\u{1ef4}'), (0x5a, 0x301, '\u{179}'),
+    (0x5a, 0x302, '\u{1e90}'), (0x5a, 0x307, '\u{17b}'), (0x5a, 0x30c, '\u{17d}'),
+    (0x5a, 0x323, '\u{1e92}'), (0x5a, 0x331, '\u{1e94}'), (0x61, 0x300, '\u{e0}'),
+    (0x61, 0x301, '\u{e1}'), (0x61, 0x302, '\u{e2}'), (0x61, 0x303, '\u{e3}'),
+    (0x61, 0x304, '\u{101}'), (0x61, 0x306, '\u{103}'), (0x61, 0x307, '\u{227}'),
+    (0x61, 0x308, '\u{e4}'), (0x61, 0x309, '\u{1ea3}'), (0x61, 0x30a, '\u{e5}'),
+    (0x61, 0x30c, '\u{1ce}'), (0x61, 0x30f, '\u{201}'), (0x61, 0x311, '\u{203}'),
+    (0x61, 0x323, '\u{1ea1}'), (0x61, 0x325, '\u{1e01}'), (0x61, 0x328, '\u{105}'),
+    (0x62, 0x307, '\u{1e03}'), (0x62, 0x323, '\u{1e05}'), (0x62, 0x331, '\u{1e07}'),
+    (0x63, 0x301, '\u{107}'), (0x63, 0x302, '\u{109}'), (0x63, 0x307, '\u{10b}'),
+    (0x63, 0x30c, '\u{10d}'), (0x63, 0x327, '\u{e7}'), (0x64, 0x307, '\u{1e0b}'),
+    (0x64, 0x30c, '\u{10f}'), (0x64, 0x323, '\u{1e0d}'), (0x64, 0x327, '\u{1e11}'),
+    (0x64, 0x32d, '\u{1e13}'), (0x64, 0x331, '\u{1e0f}'), (0x65, 0x300, '\u{e8}'),
+    (0x65, 0x301, '\u{e9}'), (0x65, 0x302, '\u{ea}'), (0x65, 0x303, '\u{1ebd}'),
+    (0x65, 0x304, '\u{113}'), (0x65, 0x306, '\u{115}'), (0x65, 0x307, '\u{117}'),
+    (0x65, 0x308, '\u{eb}'), (0x65, 0x309, '\u{1ebb}'), (0x65, 0x30c, '\u{11b}'),
+    (0x65, 0x30f, '\u{205}'), (0x65, 0x311, '\u{207}'), (0x65, 0x323, '\u{1eb9}'),
+    (0x65, 0x327, '\u{229}'), (0x65, 0x328, '\u{119}'), (0x65, 0x32d, '\u{1e19}'),
+    (0x65, 0x330, '\u{1e1b}'), (0x66, 0x307, '\u{1e1f}'), (0x67, 0x301, '\u{1f5}'),
+    (0x67, 0x302, '\u{11d}'), (0x67, 0x304, '\u{1e21}'), (0x67, 0x306, '\u{11f}'),
+    (0x67, 0x307, '\u{121}'), (0x67, 0x30c, '\u{1e7}'), (0x67, 0x327, '\u{123}'),
+    (0x68, 0x302, '\u{125}'), (0x68, 0x307, '\u{1e23}'), (0x68, 0x308, '\u{1e27}'),
+    (0x68, 0x30c, '\u{21f}'), (0x68, 0x323, '\u{1e25}'), (0x68, 0x327, '\u{1e29}'),
+    (0x68, 0x32e, '\u{1e2b}'), (0x68, 0x331, '\u{1e96}'), (0x69, 0x300, '\u{ec}'),
+    (0x69, 0x301, '\u{ed}'), (0x69, 0x302, '\u{ee}'), (0x69, 0x303, '\u{129}'),
+    (0x69, 0x304, '\u{12b}'), (0x69, 0x306, '\u{12d}'), (0x69, 0x308, '\u{ef}'),
+    (0x69, 0x309, '\u{1ec9}'), (0x69, 0x30c, '\u{1d0}'), (0x69, 0x30f, '\u{209}'),
+    (0x69, 0x311, '\u{20b}'), (0x69, 0x323, '\u{1ecb}'), (0x69, 0x328, '\u{12f}'),
+    (0x69, 0x330, '\u{1e2d}'), (0x6a, 0x302, '\u{135}'), (0x6a, 0x30c, '\u{1f0}'),
+    (0x6b, 0x301, '\u{1e31}'), (0x6b, 0x30c, '\u{1e9}'), (0x6b, 0x323, '\u{1e33}'),
+    (0x6b, 0x327, '\u{137}'), (0x6b, 0x331, '\u{1e35}'), (0x6c, 0x301, '\u{13a}'),
+    (0x6c, 0x30c, '\u{13e}'), (0x6c, 0x323, '\u{1e37}'), (0x6c, 0x327, '\u{13c}'),
+    (0x6c, 0x32d, '\u{1e3d}'), (0x6c, 0x331, '\u{1e3b}'), (0x6d, 0x301, '\u{1e3f}'),
+    (0x6d, 0x307, '\u{1e41}'), (0x6d, 0x323, '\u{1e43}'), (0x6e, 0x300, '\u{1f9}'),
+    (0x6e, 0x301, '\u{144}'), (0x6e, 0x303, '\u{f1}'), (0x6e, 0x307, '\u{1e45}'),
+    (0x6e, 0x30c, '\u{148}'), (0x6e, 0x323, '\u{1e47}'), (0x6e, 0x327, '\u{146}'),
+    (0x6e, 0x32d, '\u{1e4b}'), (0x6e, 0x331, '\u{1e49}'), (0x6f, 0x300, '\u{f2}'),
+    (0x6f, 0x301, '\u{f3}'), (0x6f, 0x302, '\u{f4}'), (0x6f, 0x303, '\u{f5}'),
+    (0x6f, 0x304, '\u{14d}'), (0x6f, 0x306, '\u{14f}'), (0x6f, 0x307, '\u{22f}'),
+    (0x6f, 0x308, '\u{f6}'), (0x6f, 0x309, '\u{1ecf}'), (0x6f, 0x30b, '\u{151}'),
+    (0x6f, 0x30c, '\u{1d2}'), (0x6f, 0x30f, '\u{20d}'), (0x6f, 0x311, '\u{20f}'),
+    (0x6f, 0x31b, '\u{1a1}'), (0x6f, 0x323, '\u{1ecd}'), (0x6f, 0x328, '\u{1eb}'),
+    (0x70, 0x301, '\u{1e55}'), (0x70, 0x307, '\u{1e57}'), (0x72, 0x301, '\u{155}'),
+    (0x72, 0x307, '\u{1e59}'), (0x72, 0x30c, '\u{159}'), (0x72, 0x30f, '\u{211}'),
+    (0x72, 0x311, '\u{213}'), (0x72, 0x323, '\u{1e5b}'), (0x72, 0x327, '\u{157}'),
+    (0x72, 0x331, '\u{1e5f}'), (0x73, 0x301, '\u{15b}'), (0x73, 0x302, '\u{15d}'),
+    (0x73, 0x307, '\u{1e61}'), (0x73, 0x30c, '\u{161}'), (0x73, 0x323, '\u{1e63}'),
+    (0x73, 0x326, '\u{219}'), (0x73, 0x327, '\u{15f}'), (0x74, 0x307, '\u{1e6b}'),
+    (0x74, 0x308, '\u{1e97}'), (0x74, 0x30c, '\u{165}'), (0x74, 0x323, '\u{1e6d}'),
+    (0x74, 0x326, '\u{21b}'), (0x74, 0x327, '\u{163}'), (0x74, 0x32d, '\u{1e71}'),
+    (0x74, 0x331, '\u{1e6f}'), (0x75, 0x300, '\u{f9}'), (0x75, 0x301, '\u{fa}'),
+    (0x75, 0x302, '\u{fb}'), (0x75, 0x303, '\u{169}'), (0x75, 0x304, '\u{16b}'),
+    (0x75, 0x306, '\u{16d}'), (0x75, 0x308, '\u{fc}'), (0x75, 0x309, '\u{1ee7}'),
+    (0x75, 0x30a, '\u{16f}'), (0x75, 0x30b, '\u{171}'), (0x75, 0x30c, '\u{1d4}'),
+    (0x75, 0x30f, '\u{215}'), (0x75, 0x311, '\u{217}'), (0x75, 0x31b, '\u{1b0}'),
+    (0x75, 0x323, '\u{1ee5}'), (0x75, 0x324, '\u{1e73}'), (0x75, 0x328, '\u{173}'),
+    (0x75, 0x32d, '\u{1e77}'), (0x75, 0x330, '\u{1e75}'), (0x76, 0x303, '\u{1e7d}'),
+    (0x76, 0x323, '\u{1e7f}'), (0x77, 0x300, '\u{1e81}'), (0x77, 0x301, '\u{1e83}'),
+    (0x77, 0x302, '\u{175}'), (0x77, 0x307, '\u{1e87}'), (0x77, 0x308, '\u{1e85}'),
+    (0x77, 0x30a, '\u{1e98}'), (0x77, 0x323, '\u{1e89}'), (0x78, 0x307, '\u{1e8b}'),
+    (0x78, 0x308, '\u{1e8d}'), (0x79, 0x300, '\u{1ef3}'), (0x79, 0x301, '\u{fd}'),
+    (0x79, 0x302, '\u{177}'), (0x79, 0x303, '\u{1ef9}'), (0x79, 0x304, '\u{233}'),
+    (0x79, 0x307, '\u{1e8f}'), (0x79, 0x308, '\u{ff}'), (0x79, 0x309, '\u{1ef7}'),
+    (0x79, 0x30a, '\u{1e99}'), (0x79, 0x323, '\u{1ef5}'), (0x7a, 0x301, '\u{17a}'),
+    (0x7a, 0x302, '\u{1e91}'), (0x7a, 0x307, '\u{17c}'), (0x7a, 0x30c, '\u{17e}'),
+    (0x7a, 0x323, '\u{1e93}'), (0x7a, 0x331, '\u{1e95}'), (0xa8, 0x300, '\u{1fed}'),
+    (0xa8, 0x301, '\u{385}'), (0xa8, 0x342, '\u{1fc1}'), (0xc2, 0x300, '\u{1ea6}'),
+    (0xc2, 0x301, '\u{1ea4}'), (0xc2, 0x303, '\u{1eaa}'), (0xc2, 0x309, '\u{1ea8}'),
+    (0xc4, 0x304, '\u{1de}'), (0xc5, 0x301, '\u{1fa}'), (0xc6, 0x301, '\u{1fc}'),
+    (0xc6, 0x304, '\u{1e2}'), (0xc7, 0x301, '\u{1e08}'), (0xca, 0x300, '\u{1ec0}'),
+    (0xca, 0x301, '\u{1ebe}'), (0xca, 0x303, '\u{1ec4}'), (0xca, 0x309, '\u{1ec2}'),
+    (0xcf, 0x301, '\u{1e2e}'), (0xd4, 0x300, '\u{1ed2}'), (0xd4, 0x301, '\u{1ed0}'),
+    (0xd4, 0x303, '\u{1ed6}'), (0xd4, 0x309, '\u{1ed4}'), (0xd5, 0x301, '\u{1e4c}'),
+    (0xd5, 0x304, '\u{22c}'), (0xd5, 0x308, '\u{1e4e}'), (0xd6, 0x304, '\u{22a}'),
+    (0xd8, 0x301, '\u{1fe}'), (0xdc, 0x300, '\u{1db}'), (0xdc, 0x301, '\u{1d7}'),
+    (0xdc, 0x304, '\u{1d5}'), (0xdc, 0x30c, '\u{1d9}'), (0xe2, 0x300, '\u{1ea7}'),
+    (0xe2, 0x301, '\u{1ea5}'), (0xe2, 0x303, '\u{1eab}'), (0xe2, 0x309, '\u{1ea9}'),
+    (0xe4, 0x304, '\u{1df}'), (0xe5, 0x301, '\u{1fb}'), (0xe6, 0x301, '\u{1fd}'),
+    (0xe6, 0x304, '\u{1e3}'), (0xe7, 0x301, '\u{1e09}'), (0xea, 0x300, '\u{1ec1}'),
+    (0xea, 0x301, '\u{1ebf}'), (0xea, 0x303, '\u{1ec5}'), (0xea, 0x309, '\u{1ec3}'),
+    (0xef, 0x301, '\u{1e2f}'), (0xf4, 0x300, '\u{1ed3}'), (0xf4, 0x301, '\u{1ed1}'),
+    (0xf4, 0x303, '\u{1ed7}'), (0xf4, 0x309, '\u{1ed5}'), (0xf5, 0x301, '\u{1e4d}'),
+    (0xf5, 0x304, '\u{22d}'), (0xf5, 0x308, '\u{1e4f}'), (0xf6, 0x304, '\u{22b}'),
+    (0xf8, 0x301, '\u{1ff}'), (0xfc, 0x300, '\u{1dc}'), (0xfc, 0x301, '\u{1d8}'),
+    (0xfc, 0x304, '\u{1d6}'), (0xfc, 0x30c, '\u{1da}'), (0x102, 0x300, '\u{1eb0}'),
+    (0x102, 0x301, '\u{1eae}'), (0x102, 0x303, '\u{1eb4}'), (0x102, 0x309, '\u{1eb2}'),
+    (0x103, 0x300, '\u{1eb1}'), (0x103, 0x301, '\u{1eaf}'), (0x103, 0x303, '\u{1eb5}'),
+    (0x103, 0x309, '\u{1eb3}'), (0x112, 0x300, '\u{1e14}'), (0x112, 0x301, '\u{1e16}'),
+    (0x113, 0x300, '\u{1e15}'), (0x113, 0x301, '\u{1e17}'), (0x14c, 0x300, '\u{1e50}'),
+    (0x14c, 0x301, '\u{1e52}'), (0x14d, 0x300, '\u{1e51}'), (0x14d, 0x301, '\u{1e53}'),
+    (0x15a, 0x307, '\u{1e64}'), (0x15b, 0x307, '\u{1e65}'), (0x160, 0x307, '\u{1e66}'),
+    (0x161, 0x307, '\u{1e67}'), (0x168, 0x301, '\u{1e78}'), (0x169, 0x301, '\u{1e79}'),
+    (0x16a, 0x308, '\u{1e7a}'), (0x16b, 0x308, '\u{1e7b}'), (0x17f, 0x307, '\u{1e9b}'),
+    (0x1a0, 0x300, '\u{1edc}'), (0x1a0, 0x301, '\u{1eda}'), (0x1a0, 0x303, '\u{1ee0}'),
+    (0x1a0, 0x309, '\u{1ede}'), (0x1a0, 0x323, '\u{1ee2}'), (0x1a1, 0x300, '\u{1edd}'),
+    (0x1a1, 0x301, '\u{1edb}'), (0x1a1, 0x303, '\u{1ee1}'), (0x1a1, 0x309, '\u{1edf}'),
+    (0x1a1, 0x323, '\u{1ee3}'), (0x1af, 0x300, '\u{1eea}'), (0x1af, 0x301, '\u{1ee8}'),
+    (0x1af, 0x303, '\u{1eee}'), (0x1af, 0x309, '\u{1eec}'), (0x1af, 0x323, '\u{1ef0}'),
+    (0x1b0, 0x300, '\u{1eeb}'), (0x1b0, 0x301, '\u{1ee9}'), (0x1b0, 0x303, '\u{1eef}'),
+    (0x1b0, 0x309, '\u{1eed}'), (0x1b0, 0x323, '\u{1ef1}'), (0x1b7, 0x30c, '\u{1ee}'),
+    (0x1ea, 0x304, '\u{1ec}'), (0x1eb, 0x304, '\u{1ed}'), (0x226, 0x304, '\u{1e0}'),
+    (0x227, 0x304, '\u{1e1}'), (0x228, 0x306, '\u{1e1c}'), (0x229, 0x306, '\u{1e1d}'),
+    (0x22e, 0x304, '\u{230}'), (0x22f, 0x304, '\u{231}'), (0x292, 0x30c, '\u{1ef}'),
+    (0x391, 0x300, '\u{1fba}'), (0x391, 0x301, '\u{386}'), (0x391, 0x304, '\u{1fb9}'),
+    (0x391, 0x306, '\u{1fb8}'), (0x391, 0x313, '\u{1f08}'), (0x391, 0x314, '\u{1f09}'),
+    (0x391, 0x345, '\u{1fbc}'), (0x395, 0x300, '\u{1fc8}'), (0x395, 0x301, '\u{388}'),
+    (0x395, 0x313, '\u{1f18}'), (0x395, 0x314, '\u{1f19}'), (0x397, 0x300, '\u{1fca}'),
+    (0x397, 0x301, '\u{389}'), (0x397, 0x313, '\u{1f28}'), (0x397, 0x314, '\u{1f29}'),
+    (0x397, 0x345, '\u{1fcc}'), (0x399, 0x300, '\u{1fda}'), (0x399, 0x301, '\u{38a}'),
+    (0x399, 0x304, '\u{1fd9}'), (0x399, 0x306, '\u{1fd8}'), (0x399, 0x308, '\u{3aa}'),
+    (0x399, 0x313, '\u{1f38}'), (0x399, 0x314, '\u{1f39}'), (0x39f, 0x300, '\u{1ff8}'),
+    (0x39f, 0x301, '\u{38c}'), (0x39f, 0x313, '\u{1f48}'), (0x39f, 0x314, '\u{1f49}'),
+    (0x3a1, 0x314, '\u{1fec}'), (0x3a5, 0x300, '\u{1fea}'), (0x3a5, 0x301, '\u{38e}'),
+    (0x3a5, 0x304, '\u{1fe9}'), (0x3a5, 0x306, '\u{1fe8}'), (0x3a5, 0x308, '\u{3ab}'),
+    (0x3a5, 0x314, '\u{1f59}'), (0x3a9, 0x300, '\u{1ffa}'), (0x3a9, 0x301, '\u{38f}'),
+    (0x3a9, 0x313, '\u{1f68}'), (0x3a9, 0x314, '\u{1f69}'), (0x3a9, 0x345, '\u{1ffc}'),
+    (0x3ac, 0x345, '\u{1fb4}'), (0x3ae, 0x345, '\u{1fc4}'), (0x3b1, 0x300, '\u{1f70}'),
+    (0x3b1, 0x301, '\u{3ac}'), (0x3b1, 0x304, '\u{1fb1}'), (0x3b1, 0x306, '\u{1fb0}'),
+    (0x3b1, 0x313, '\u{1f00}'), (0x3b1, 0x314, '\u{1f01}'), (0x3b1, 0x342, '\u{1fb6}'),
+    (0x3b1, 0x345, '\u{1fb3}'), (0x3b5, 0x300, '\u{1f72}'), (0x3b5, 0x301, '\u{3ad}'),
+    (0x3b5, 0x313, '\u{1f10}'), (0x3b5, 0x314, '\u{1f11}'), (0x3b7, 0x300, '\u{1f74}'),
+    (0x3b7, 0x301, '\u{3ae}'), (0x3b7, 0x313, '\u{1f20}'), (0x3b7, 0x314, '\u{1f21}'),
+    (0x3b7, 0x342, '\u{1fc6}'), (0x3b7, 0x345, '\u{1fc3}'), (0x3b9, 0x300, '\u{1f76}'),
+    (0x3b9, 0x301, '\u{3af}'), (0x3b9, 0x304, '\u{1fd1}'), (0x3b9, 0x306, '\u{1fd0}'),
+    (0x3b9, 0x308, '\u{3ca}'), (0x3b9, 0x313, '\u{1f30}'), (0x3b9, 0x314, '\u{1f31}'),
+    (0x3b9, 0x342, '\u{1fd6}'), (0x3bf, 0x300, '\u{1f78}'), (0x3bf, 0x301, '\u{3cc}'),
+    (0x3bf, 0x313, '\u{1f40}'), (0x3bf, 0x314, '\u{1f41}'), (0x3c1, 0x313, '\u{1fe4}'),
+    (0x3c1, 0x314, '\u{1fe5}'), (0x3c5, 0x300, '\u{1f7a}'), (0x3c5, 0x301, '\u{3cd}'),
+    (0x3c5, 0x304, '\u{1fe1}'), (0x3c5, 0x306, '\u{1fe0}'), (0x3c5, 0x308, '\u{3cb}'),
+    (0x3c5, 0x313, '\u{1f50}'), (0x3c5, 0x314, '\u{1f51}'), (0x3c5, 0x342, '\u{1fe6}'),
+    (0x3c9, 0x300, '\u{1f7c}'), (0x3c9, 0x301, '\u{3ce}'), (0x3c9, 0x313, '\u{1f60}'),
+    (0x3c9, 0x314, '\u{1f61}'), (0x3c9, 0x342, '\u{1ff6}'), (0x3c9, 0x345, '\u{1ff3}'),
+    (0x3ca, 0x300, '\u{1fd2}'), (0x3ca, 0x301, '\u{390}'), (0x3ca, 0x342, '\u{1fd7}'),
+    (0x3cb, 0x300, '\u{1fe2}'), (0x3cb, 0x301, '\u{3b0}'), (0x3cb, 0x342, '\u{1fe7}'),
+    (0x3ce, 0x345, '\u{1ff4}'), (0x3d2, 0x301, '\u{3d3}'), (0x3d2, 0x308, '\u{3d4}'),
+    (0x406, 0x308, '\u{407}'), (0x410, 0x306, '\u{4d0}'), (0x410, 0x308, '\u{4d2}'),
+    (0x413, 0x301, '\u{403}'), (0x415, 0x300, '\u{400}'), (0x415, 0x306, '\u{4d6}'),
+    (0x415, 0x308, '\u{401}'), (0x416, 0x306, '\u{4c1}'), (0x416, 0x308, '\u{4dc}'),
+    (0x417, 0x308, '\u{4de}'), (0x418, 0x300, '\u{40d}'), (0x418, 0x304, '\u{4e2}'),
+    (0x418, 0x306, '\u{419}'), (0x418, 0x308, '\u{4e4}'), (0x41a, 0x301, '\u{40c}'),
+    (0x41e, 0x308, '\u{4e6}'), (0x423, 0x304, '\u{4ee}'), (0x423, 0x306, '\u{40e}'),
+    (0x423, 0x308, '\u{4f0}'), (0x423, 0x30b, '\u{4f2}'), (0x427, 0x308, '\u{4f4}'),
+    (0x42b, 0x308, '\u{4f8}'), (0x42d, 0x308, '\u{4ec}'), (0x430, 0x306, '\u{4d1}'),
+    (0x430, 0x308, '\u{4d3}'), (0x433, 0x301, '\u{453}'), (0x435, 0x300, '\u{450}'),
+    (0x435, 0x306, '\u{4d7}'), (0x435, 0x308, '\u{451}'), (0x436, 0x306, '\u{4c2}'),
+    (0x436, 0x308, '\u{4dd}'), (0x437, 0x308, '\u{4df}'), (0x438, 0x300, '\u{45d}'),
+    (0x438, 0x304, '\u{4e3}'), (0x438, 0x306, '\u{439}'), (0x438, 0x308, '\u{4e5}'),
+    (0x43a, 0x301, '\u{45c}'), (0x43e, 0x308, '\u{4e7}'), (0x443, 0x304, '\u{4ef}'),
+    (0x443, 0x306, '\u{45e}'), (0x443, 0x308, '\u{4f1}'), (0x443, 0x30b, '\u{4f3}'),
+    (0x447, 0x308, '\u{4f5}'), (0x44b, 0x308, '\u{4f9}'), (0x44d, 0x308, '\u{4ed}'),
+    (0x456, 0x308, '\u{457}'), (0x474, 0x30f, '\u{476}'), (0x475, 0x30f, '\u{477}'),
+    (0x4d8, 0x308, '\u{4da}'), (0x4d9, 0x308, '\u{4db}'), (0x4e8, 0x308, '\u{4ea}'),
+    (0x4e9, 0x308, '\u{4eb}'), (0x627, 0x653, '\u{622}'), (0x627, 0x654, '\u{623}'),
+    (0x627, 0x655, '\u{625}'), (0x648, 0x654, '\u{624}'), (0x64a, 0x654, '\u{626}'),
+    (0x6c1, 0x654, '\u{6c2}'), (0x6d2, 0x654, '\u{6d3}'), (0x6d5, 0x654, '\u{6c0}'),
+    (0x928, 0x93c, '\u{929}'), (0x930, 0x93c, '\u{931}'), (0x933, 0x93c, '\u{934}'),
+    (0x9c7, 0x9be, '\u{9cb}'), (0x9c7, 0x9d7, '\u{9cc}'), (0xb47, 0xb3e, '\u{b4b}'),
+    (0xb47, 0xb56, '\u{b48}'), (0xb47, 0xb57, '\u{b4c}'), (0xb92, 0xbd7, '\u{b94}'),
+    (0xbc6, 0xbbe, '\u{bca}'), (0xbc6, 0xbd7, '\u{bcc}'), (0xbc7, 0xbbe, '\u{bcb}'),
+    (0xc46, 0xc56, '\u{c48}'), (0xcbf, 0xcd5, '\u{cc0}'), (0xcc6, 0xcc2, '\u{cca}'),
+    (0xcc6, 0xcd5, '\u{cc7}'), (0xcc6, 0xcd6, '\u{cc8}'), (0xcca, 0xcd5, '\u{ccb}'),
+    (0xd46, 0xd3e, '\u{d4a}'), (0xd46, 0xd57, '\u{d4c}'), (0xd47, 0xd3e, '\u{d4b}'),
+    (0xdd9, 0xdca, '\u{dda}'), (0xdd9, 0xdcf, '\u{ddc}'), (0xdd9, 0xddf, '\u{dde}'),
+    (0xddc, 0xdca, '\u{ddd}'), (0x1025, 0x102e, '\u{1026}'), (0x1b05, 0x1b35, '\u{1b06}'),
+    (0x1b07, 0x1b35, '\u{1b08}'), (0x1b09, 0x1b35, '\u{1b0a}'), (0x1b0b, 0x1b35, '\u{1b0c}'),
+    (0x1b0d, 0x1b35, '\u{1b0e}'), (0x1b11, 0x1b35, '\u{1b12}'), (0x1b3a, 0x1b35, '\u{1b3b}'),
+    (0x1b3c, 0x1b35, '\u{1b3d}'), (0x1b3e, 0x1b35, '\u{1b40}'), (0x1b3f, 0x1b35, '\u{1b41}'),
+    (0x1b42, 0x1b35, '\u{1b43}'), (0x1e36, 0x304, '\u{1e38}'), (0x1e37, 0x304, '\u{1e39}'),
+    (0x1e5a, 0x304, '\u{1e5c}'), (0x1e5b, 0x304, '\u{1e5d}'), (0x1e62, 0x307, '\u{1e68}'),
+    (0x1e63, 0x307, '\u{1e69}'), (0x1ea0, 0x302, '\u{1eac}'), (0x1ea0, 0x306, '\u{1eb6}'),
+    (0x1ea1, 0x302, '\u{1ead}'), (0x1ea1, 0x306, '\u{1eb7}'), (0x1eb8, 0x302, '\u{1ec6}'),
+    (0x1eb9, 0x302, '\u{1ec7}'), (0x1ecc, 0x302, '\u{1ed8}'), (0x1ecd, 0x302, '\u{1ed9}'),
+    (0x1f00, 0x300, '\u{1f02}'), (0x1f00, 0x301, '\u{1f04}'), (0x1f00, 0x342, '\u{1f06}'),
+    (0x1f00, 0x345, '\u{1f80}'), (0x1f01, 0x300, '\u{1f03}'), (0x1f01, 0x301, '\u{1f05}'),
+    (0x1f01, 0x342, '\u{1f07}'), (0x1f01, 0x345, '\u{1f81}'), (0x1f02, 0x345, '\u{1f82}'),
+    (0x1f03, 0x345, '\u{1f83}'), (0x1f04, 0x345, '\u{1f84}'), (0x1f05, 0x345, '\u{1f85}'),
+    (0x1f06, 0x345, '\u{1f86}'), (0x1f07, 0x345, '\u{1f87}'), (0x1f08, 0x300, '\u{1f0a}'),
+    (0x1f08, 0x301, '\u{1f0c}'), (0x1f08, 0x342, '\u{1f0e}'), (0x1f08, 0x345, '\u{1f88}'),
+    (0x1f09, 0x300, '\u{1f0b}'), (0x1f09, 0x301, '\u{1f0d}'), (0x1f09, 0x342, '\u{1f0f}'),
+    (0x1f09, 0x345, '\u{1f89}'), (0x1f0a, 0x345, '\u{1f8a}'), (0x1f0b, 0x345, '\u{1f8b}'),
+    (0x1f0c, 0x345, '\u{1f8c}'), (0x1f0d, 0x345, '\u{1f8d}'), (0x1f0e, 0x345, '\u{1f8e}'),
+    (0x1f0f, 0x345, '\u{1f8f}'), (0x1f10, 0x300, '\u{1f12}'), (0x1f10, 0x301, '\u{1f14}'),
+    (0x1f11, 0x300, '\u{1f13}'), (0x1f11, 0x301, '\u{1f15}'), (0x1f18, 0x300, '\u{1f1a}'),
+    (0x1f18, 0x301, '\u{1f1c}'), (0x1f19, 0x300, '\u{1f1b}'), (0x1f19, 0x301, '\u{1f1d}'),
+    (0x1f20, 0x300, '\u{1f22}'), (0x1f20, 0x301, '\u{1f24}'), (0x1f20, 0x342, '\u{1f26}'),
+    (0x1f20, 0x345, '\u{1f90}'), (0x1f21, 0x300, '\u{1f23}'), (0x1f21, 0x301, '\u{1f25}'),
+    (0x1f21, 0x342, '\u{1f27}'), (0x1f21, 0x345, '\u{1f91}'), (0x1f22, 0x345, '\u{1f92}'),
+    (0x1f23, 0x345, '\u{1f93}'), (0x1f24, 0x345, '\u{1f94}'), (0x1f25, 0x345, '\u{1f95}'),
+    (0x1f26, 0x345, '\u{1f96}'), (0x1f27, 0x345, '\u{1f97}'), (0x1f28, 0x300, '\u{1f2a}'),
+    (0x1f28, 0x301, '\u{1f2c}'), (0x1f28, 0x342, '\u{1f2e}'), (0x1f28, 0x345, '\u{1f98}'),
+    (0x1f29, 0x300, '\u{1f2b}'), (0x1f29, 0x301, '\u{1f2d}'), (0x1f29, 0x342, '\u{1f2f}'),
+    (0x1f29, 0x345, '\u{1f99}'), (0x1f2a, 0x345, '\u{1f9a}'), (0x1f2b, 0x345, '\u{1f9b}'),
+    (0x1f2c, 0x345, '\u{1f9c}'), (0x1f2d, 0x345, '\u{1f9d}'), (0x1f2e, 0x345, '\u{1f9e}'),
+    (0x1f2f, 0x345, '\u{1f9f}'), (0x1f30, 0x300, '\u{1f32}'), (0x1f30, 0x301, '\u{1f34}'),
+    (0x1f30, 0x342, '\u{1f36}'), (0x1f31, 0x300, '\u{1f33}'), (0x1f31, 0x301, '\u{1f35}'),
+    (0x1f31, 0x342, '\u{1f37}'), (0x1f38, 0x300, '\u{1f3a}'), (0x1f38, 0x301, '\u{1f3c}'),
+    (0x1f38, 0x342, '\u{1f3e}'), (0x1f39, 0x300, '\u{1f3b}'), (0x1f39, 0x301, '\u{1f3d}'),
+    (0x1f39, 0x342, '\u{1f3f}'), (0x1f40, 0x300, '\u{1f42}'), (0x1f40, 0x301, '\u{1f44}'),
+    (0x1f41, 0x300, '\u{1f43}'), (0x1f41, 0x301, '\u{1f45}'), (0x1f48, 0x300, '\u{1f4a}'),
+    (0x1f48, 0x301, '\u{1f4c}'), (0x1f49, 0x300, '\u{1f4b}'), (0x1f49, 0x301, '\u{1f4d}'),
+    (0x1f50, 0x300, '\u{1f52}'), (0x1f50, 0x301, '\u{1f54}'), (0x1f50, 0x342, '\u{1f56}'),
+    (0x1f51, 0x300, '\u{1f53}'), (0x1f51, 0x301, '\u{1f55}'), (0x1f51, 0x342, '\u{1f57}'),
+    (0x1f59, 0x300, '\u{1f5b}'), (0x1f59, 0x301, '\u{1f5d}'), (0x1f59, 0x342, '\u{1f5f}'),
+    (0x1f60, 0x300, '\u{1f62}'), (0x1f60, 0x301, '\u{1f64}'), (0x1f60, 0x342, '\u{1f66}'),
+    (0x1f60, 0x345, '\u{1fa0}'), (0x1f61, 0x300, '\u{1f63}'), (0x1f61, 0x301, '\u{1f65}'),
+    (0x1f61, 0x342, '\u{1f67}'), (0x1f61, 0x345, '\u{1fa1}'), (0x1f62, 0x345, '\u{1fa2}'),
+    (0x1f63, 0x345, '\u{1fa3}'), (0x1f64, 0x345, '\u{1fa4}'), (0x1f65, 0x345, '\u{1fa5}'),
+    (0x1f66, 0x345, '\u{1fa6}'), (0x1f67, 0x345, '\u{1fa7}'), (0x1f68, 0x300, '\u{1f6a}'),
+    (0x1f68, 0x301, '\u{1f6c}'), (0x1f68, 0x342, '\u{1f6e}'), (0x1f68, 0x345, '\u{1fa8}'),
+    (0x1f69, 0x300, '\u{1f6b}'), (0x1f69, 0x301, '\u{1f6d}'), (0x1f69, 0x342, '\u{1f6f}'),
+    (0x1f69, 0x345, '\u{1fa9}'), (0x1f6a, 0x345, '\u{1faa}'), (0x1f6b, 0x345, '\u{1fab}'),
+    (0x1f6c, 0x345, '\u{1fac}'), (0x1f6d, 0x345, '\u{1fad}'), (0x1f6e, 0x345, '\u{1fae}'),
+    (0x1f6f, 0x345, '\u{1faf}'), (0x1f70, 0x345, '\u{1fb2}'), (0x1f74, 0x345, '\u{1fc2}'),
+    (0x1f7c, 0x345, '\u{1ff2}'), (0x1fb6, 0x345, '\u{1fb7}'), (0x1fbf, 0x300, '\u{1fcd}'),
+    (0x1fbf, 0x301, '\u{1fce}'), (0x1fbf, 0x342, '\u{1fcf}'), (0x1fc6, 0x345, '\u{1fc7}'),
+    (0x1ff6, 0x345, '\u{1ff7}'), (0x1ffe, 0x300, '\u{1fdd}'), (0x1ffe, 0x301, '\u{1fde}'),
+    (0x1ffe, 0x342, '\u{1fdf}'), (0x2190, 0x338, '\u{219a}'), (0x2192, 0x338, '\u{219b}'),
+    (0x2194, 0x338, '\u{21ae}'), (0x21d0, 0x338, '\u{21cd}'), (0x21d2, 0x338, '\u{21cf}'),
+    (0x21d4, 0x338, '\u{21ce}'), (0x2203, 0x338, '\u{2204}'), (0x2208, 0x338, '\u{2209}'),
+    (0x220b, 0x338, '\u{220c}'), (0x2223, 0x338, '\u{2224}'), (0x2225, 0x338, '\u{2226}'),
+    (0x223c, 0x338, '\u{2241}'), (0x2243, 0x338, '\u{2244}'), (0x2245, 0x338, '\u{2247}'),
+    (0x2248, 0x338, '\u{2249}'), (0x224d, 0x338, '\u{226d}'), (0x2261, 0x338, '\u{2262}'),
+    (0x2264, 0x338, '\u{2270}'), (0x2265, 0x338, '\u{2271}'), (0x2272, 0x338, '\u{2274}'),
+    (0x2273, 0x338, '\u{2275}'), (0x2276, 0x338, '\u{2278}'), (0x2277, 0x338, '\u{2279}'),
+    (0x227a, 0x338, '\u{2280}'), (0x227b, 0x338, '\u{2281}'), (0x227c, 0x338, '\u{22e0}'),
+    (0x227d, 0x338, '\u{22e1}'), (0x2282, 0x338, '\u{2284}'), (0x2283, 0x338, '\u{2285}'),
+    (0x2286, 0x338, '\u{2288}'), (0x2287, 0x338, '\u{2289}'), (0x2291, 0x338, '\u{22e2}'),
+    (0x2292, 0x338, '\u{22e3}'), (0x22a2, 0x338, '\u{22ac}'), (0x22a8, 0x338, '\u{22ad}'),
+    (0x22a9, 0x338, '\u{22ae}'), (0x22ab, 0x338, '\u{22af}'), (0x22b2, 0x338, '\u{22ea}'),
+    (0x22b3, 0x338, '\u{22eb}'), (0x22b4, 0x338, '\u{22ec}'), (0x22b5, 0x338, '\u{22ed}'),
+    (0x3046, 0x3099, '\u{3094}'), (0x304b, 0x3099, '\u{304c}'), (0x304d, 0x3099, '\u{304e}'),
+    (0x304f, 0x3099, '\u{3050}'), (0x3051, 0x3099, '\u{3052}'), (0x3053, 0x3099, '\u{3054}'),
+    (0x3055, 0x3099, '\u{3056}'), (0x3057, 0x3099, '\u{3058}'), (0x3059, 0x3099, '\u{305a}'),
+    (0x305b, 0x3099, '\u{305c}'), (0x305d, 0x3099, '\u{305e}'), (0x305f, 0x3099, '\u{3060}'),
+    (0x3061, 0x3099, '\u{3062}'), (0x3064, 0x3099, '\u{3065}'), (0x3066, 0x3099, '\u{3067}'),
+    (0x3068, 0x3099, '\u{3069}'), (0x306f, 0x3099, '\u{3070}'), (0x306f, 0x309a, '\u{3071}'),
+    (0x3072, 0x3099, '\u{3073}'), (0x3072, 0x309a, '\u{3074}'), (0x3075, 0x3099, '\u{3076}'),
+    (0x3075, 0x309a, '\u{3077}'), (0x3078, 0x3099, '\u{3079}'), (0x3078, 0x309a, '\u{307a}'),
+    (0x307b, 0x3099, '\u{307c}'), (0x307b, 0x309a, '\u{307d}'), (0x309d, 0x3099, '\u{309e}'),
+    (0x30a6, 0x3099, '\u{30f4}'), (0x30ab, 0x3099, '\u{30ac}'), (0x30ad, 0x3099, '\u{30ae}'),
+    (0x30af, 0x3099, '\u{30b0}'), (0x30b1, 0x3099, '\u{30b2}'), (0x30b3, 0x3099, '\u{30b4}'),
+    (0x30b5, 0x3099, '\u{30b6}'), (0x30b7, 0x3099, '\u{30b8}'), (0x30b9, 0x3099, '\u{30ba}'),
+    (0x30bb, 0x3099, '\u{30bc}'), (0x30bd, 0x3099, '\u{30be}'), (0x30bf, 0x3099, '\u{30c0}'),
+    (0x30c1, 0x3099, '\u{30c2}'), (0x30c4, 0x3099, '\u{30c5}'), (0x30c6, 0x3099, '\u{30c7}'),
+    (0x30c8, 0x3099, '\u{30c9}'), (0x30cf, 0x3099, '\u{30d0}'), (0x30cf, 0x309a, '\u{30d1}'),
+    (0x30d2, 0x3099, '\u{30d3}'), (0x30d2, 0x309a, '\u{30d4}'), (0x30d5, 0x3099, '\u{30d6}'),
+    (0x30d5, 0x309a, '\u{30d7}'), (0x30d8, 0x3099, '\u{30d9}'), (0x30d8, 0x309a, '\u{30da}'),
+    (0x30db, 0x3099, '\u{30dc}'), (0x30db, 0x309a, '\u{30dd}'), (0x30ef, 0x3099, '\u{30f7}'),
+    (0x30f0, 0x3099, '\u{30f8}'), (0x30f1, 0x3099, '\u{30f9}'), (0x30f2, 0x3099, '\u{30fa}'),
+    (0x30fd, 0x3099, '\u{30fe}'), (0x11099, 0x110ba, '\u{1109a}'), (0x1109b, 0x110ba, '\u{1109c}'),
+    (0x110a5, 0x110ba, '\u{110ab}'), (0x11131, 0x11127, '\u{1112e}'),
+    (0x11132, 0x11127, '\u{1112f}'), (0x11347, 0x1133e, '\u{1134b}'),
+    (0x11347, 0x11357, '\u{1134c}'), (0x114b9, 0x114b0, '\u{114bc}'),
+    (0x114b9, 0x114ba, '\u{114bb}'), (0x114b9, 0x114bd, '\u{114be}'),
+    (0x115b8, 0x115af, '\u{115ba}'), (0x115b9, 0x115af, '\u{115bb}'),
+    (0x11935, 0x11930, '\u{11938}'),
+];
+/// Canonical_Combining_Class of the non-zero ranges
+pub(super) static COMBINING_CLASS: &[(u32, u32, u8)] = &[
+    (0x300, 0x314, 230), (0x315, 0x315, 232), (0x316, 0x319, 220), (0x31a, 0x31a, 232),
+    (0x31b, 0x31b, 216), (0x31c, 0x320, 220), (0x321, 0x322, 202), (0x323, 0x326, 220),
+    (0x327, 0x328, 202), (0x329, 0x333, 220), (0x334, 0x338, 1), (0x339, 0x33c, 220),
+    (0x33d, 0x344, 230), (0x345, 0x345, 240), (0x346, 0x346, 230), (0x347, 0x349, 220),
+    (0x34a, 0x34c, 230), (0x34d, 0x34e, 220), (0x350, 0x352, 230), (0x353, 0x356, 220),
+    (0x357, 0x357, 230), (0x358, 0x358, 232), (0x359, 0x35a, 220), (0x35b, 0x35b, 230),
+    (0x35c, 0x35c, 233), (0x35d, 0x35e, 234), (0x35f, 0x35f, 233), (0x360, 0x361, 234),
+    (0x362, 0x362, 233), (0x363, 0x36f, 230), (0x483, 0x487, 230), (0x591, 0x591, 220),
+    (0x592, 0x595, 230), (0x596, 0x596, 220), (0x597, 0x599, 230), (0x59a, 0x59a, 222),
+    (0x59b, 0x59b, 220), (0x59c, 0x5a1, 230), (0x5a2, 0x5a7, 220), (0x5a8, 0x5a9, 230),
+    (0x5aa, 0x5aa, 220), (0x5ab, 0x5ac, 230), (0x5ad, 0x5ad, 222), (0x5ae, 0x5ae, 228),
+    (0x5af, 0x5af, 230), (0x5b0, 0x5b0, 10), (0x5b1, 0x5b1, 11), (0x5b2, 0x5b2, 12),
+    (0x5b3, 0x5b3, 13), (0x5b4, 0x5b4, 14), (0x5b5, 0x5b5, 15), (0x5b6, 0x5b6, 16),
+    (0x5b7, 0x5b7, 17), (0x5b8, 0x5b8, 18), (0x5b9, 0x5ba, 19), (0x5bb, 0x5bb, 20),
+    (0x5bc, 0x5bc, 21), (0x5bd, 0x5bd, 22), (0x5bf, 0x5bf, 23), (0x5c1, 0x5c1, 24),
+    (0x5c2, 0x5c2, 25), (0x5c4, 0x5c4, 230), (0x5c5, 0x5c5, 220), (0x5c7, 0x5c7, 18),
+    (0x610, 0x617, 230), (0x618, 0x618, 30), (0x619, 0x619, 31), (0x61a, 0x61a, 32),
+    (0x64b, 0x64b, 27), (0x64c, 0x64c, 28), (0x64d, 0x64d, 29), (0x64e, 0x64e, 30),
+    (0x64f, 0x64f, 31), (0x650, 0x650, 32), (0x651, 0x651, 33), (0x652, 0x652, 34),
+    (0x653, 0x654, 230), (0x655, 0x656, 220), (0x657, 0x65b, 230), (0x65c, 0x65c, 220),
+    (0x65d, 0x65e, 230), (0x65f, 0x65f, 220), (0x670, 0x670, 35), (0x6d6, 0x6dc, 230),
+    (0x6df, 0x6e2, 230), (0x6e3, 0x6e3, 220), (0x6e4, 0x6e4, 230), (0x6e7, 0x6e8, 230),
+    (0x6ea, 0x6ea, 220), (0x6eb, 0x6ec, 230), (0x6ed, 0x6ed, 220), (0x711, 0x711, 36),
+    (0x730, 0x730, 230), (0x731, 0x731, 220), (0x732, 0x733, 230), (0x734, 0x734, 220),
+    (0x735, 0x736, 230), (0x737, 0x739, 220), (0x73a, 0x73a, 230), (0x73b, 0x73c, 220),
+    (0x73d, 0x73d, 230), (0x73e, 0x73e, 220), (0x73f, 0x741, 230), (0x742, 0x742, 220),
+    (0x743, 0x743, 230), (0x744, 0x744, 220), (0x745, 0x745, 230), (0x746, 0x746, 220),
+    (0x747, 0x747, 230), (0x748, 0x748, 220), (0x749, 0x74a, 230), (0x7eb, 0x7f1, 230),
+    (0x7f2, 0x7f2, 220), (0x7f3, 0x7f3, 230), (0x7fd, 0x7fd, 220), (0x816, 0x819, 230),
+    (0x81b, 0x823, 230), (0x825, 0x827, 230), (0x829, 0x82d, 230), (0x859, 0x85b, 220),
+    (0x898, 0x898, 230), (0x899, 0x89b, 220), (0x89c, 0x89f, 230), (0x8ca, 0x8ce, 230),
+    (0x8cf, 0x8d3, 220), (0x8d4, 0x8e1, 230), (0x8e3, 0x8e3, 220), (0x8e4, 0x8e5, 230),
+    (0x8e6, 0x8e6, 220), (0x8e7, 0x8e8, 230), (0x8e9, 0x8e9, 220), (0x8ea, 0x8ec, 230),
+    (0x8ed, 0x8ef, 220), (0x8f0, 0x8f0, 27), (0x8f1, 0x8f1, 28), (0x8f2, 0x8f2, 29),
+    (0x8f3, 0x8f5, 230), (0x8f6, 0x8f6, 220), (0x8f7, 0x8f8, 230), (0x8f9, 0x8fa, 220),
+    (0x8fb, 0x8ff, 230), (0x93c, 0x93c, 7), (0x94d, 0x94d, 9), (0x951, 0x951, 230),
+    (0x952, 0x952, 220), (0x953, 0x954, 230), (0x9bc, 0x9bc, 7), (0x9cd, 0x9cd, 9),
+    (0x9fe, 0x9fe, 230), (0xa3c, 0xa3c, 7), (0xa4d, 0xa4d, 9), (0xabc, 0xabc, 7), (0xacd, 0xacd, 9),
+    (0xb3c, 0xb3c, 7), (0xb4d, 0xb4d, 9), (0xbcd, 0xbcd, 9), (0xc3c, 0xc3c, 7), (0xc4d, 0xc4d, 9),
+    (0xc55, 0xc55, 84), (0xc56, 0xc56, 91), (0xcbc, 0xcbc, 7), (0xccd, 0xccd, 9), (0xd3b, 0xd3c, 9),
+    (0xd4d, 0xd4d, 9), (0xdca, 0xdca, 9), (0xe38, 0xe39, 103), (0xe3a, 0xe3a, 9),
+    (0xe48, 0xe4b, 107), (0xeb8, 0xeb9, 118), (0xeba, 0xeba, 9), (0xec8, 0xecb, 122),
+    (0xf18, 0xf19, 220), (0xf35, 0xf35, 220), (0xf37, 0xf37, 220), (0xf39, 0xf39, 216),
+    (0xf71, 0xf71, 129), (0xf72, 0xf72, 130), (0xf74, 0xf74, 132), (0xf7a, 0xf7d, 130),
+    (0xf80, 0xf80, 130), (0xf82, 0xf83, 230), (0xf84, 0xf84, 9), (0xf86, 0xf87, 230),
+    (0xfc6, 0xfc6, 220), (0x1037, 0x1037, 7), (0x1039, 0x103a, 9), (0x108d, 0x108d, 220),
+    (0x135d, 0x135f, 230), (0x1714, 0x1715, 9), (0x1734, 0x1734, 9), (0x17d2, 0x17d2, 9),
+    (0x17dd, 0x17dd, 230), (0x18a9, 0x18a9, 228), (0x1939, 0x1939, 222), (0x193a, 0x193a, 230),
+    (0x193b, 0x193b, 220), (0x1a17, 0x1a17, 230), (0x1a18, 0x1a18, 220), (0x1a60, 0x1a60, 9),
+    (0x1a75, 0x1a7c, 230), (0x1a7f, 0x1a7f, 220), (0x1ab0, 0x1ab4, 230), (0x1ab5, 0x1aba, 220),
+    (0x1abb, 0x1abc, 230), (0x1abd, 0x1abd, 220), (0x1abf, 0x1ac0, 220), (0x1ac1, 0x1ac2, 230),
+    (0x1ac3, 0x1ac4, 220), (0x1ac5, 0x1ac9, 230), (0x1aca, 0x1aca, 220), (0x1acb, 0x1ace, 230),
+    (0x1b34, 0x1b34, 7), (0x1b44, 0x1b44, 9), (0x1b6b, 0x1b6b, 230), (0x1b6c, 0x1b6c, 220),
+    (0x1b6d, 0x1b73, 230), (0x1baa, 0x1bab, 9), (0x1be6, 0x1be6, 7), (0x1bf2, 0x1bf3, 9),
+    (0x1c37, 0x1c37, 7), (0x1cd0, 0x1cd2, 230), (0x1cd4, 0x1cd4, 1), (0x1cd5, 0x1cd9, 220),
+    (0x1cda, 0x1cdb, 230), (0x1cdc, 0x1cdf, 220), (0x1ce0, 0x1ce0, 230), (0x1ce2, 0x1ce8, 1),
+    (0x1ced, 0x1ced, 220), (0x1cf4, 0x1cf4, 230), (0x1cf8, 0x1cf9, 230), (0x1dc0, 0x1dc1, 230),
+    (0x1dc2, 0x1dc2, 220), (0x1dc3, 0x1dc9, 230), (0x1dca, 0x1dca, 220), (0x1dcb, 0x1dcc, 230),
+    (0x1dcd, 0x1dcd, 234), (0x1dce, 0x1dce, 214), (0x1dcf, 0x1dcf, 220), (0x1dd0, 0x1dd0, 202),
+    (0x1dd1, 0x1df5, 230), (0x1df6, 0x1df6, 232), (0x1df7, 0x1df8, 228), (0x1df9, 0x1df9, 220),
+    (0x1dfa, 0x1dfa, 218), (0x1dfb, 0x1dfb, 230), (0x1dfc, 0x1dfc, 233), (0x1dfd, 0x1dfd, 220),
+    (0x1dfe, 0x1dfe, 230), (0x1dff, 0x1dff, 220), (0x20d0, 0x20d1, 230), (0x20d2, 0x20d3, 1),
+    (0x20d4, 0x20d7, 230), (0x20d8, 0x20da, 1), (0x20db, 0x20dc, 230), (0x20e1, 0x20e1, 230),
+    (0x20e5, 0x20e6, 1), (0x20e7, 0x20e7, 230), (0x20e8, 0x20e8, 220), (0x20e9, 0x20e9, 230),
+    (0x20ea, 0x20eb, 1), (0x20ec, 0x20ef, 220), (0x20f0, 0x20f0, 230), (0x2cef, 0x2cf1, 230),
+    (0x2d7f, 0x2d7f, 9), (0x2de0, 0x2dff, 230), (0x302a, 0x302a, 218), (0x302b, 0x302b, 228),
+    (0x302c, 0x302c, 232), (0x302d, 0x302d, 222), (0x302e, 0x302f, 224), (0x3099, 0x309a, 8),
+    (0xa66f, 0xa66f, 230), (0xa674, 0xa67d, 230), (0xa69e, 0xa69f, 230), (0xa6f0, 0xa6f1, 230),
+    (0xa806, 0xa806, 9), (0xa82c, 0xa82c, 9), (0xa8c4, 0xa8c4, 9), (0xa8e0, 0xa8f1, 230),
+    (0xa92b, 0xa92d, 220), (0xa953, 0xa953, 9), (0xa9b3, 0xa9b3, 7), (0xa9c0, 0xa9c0, 9),
+    (0xaab0, 0xaab0, 230), (0xaab2, 0xaab3, 230), (0xaab4, 0xaab4, 220), (0xaab7, 0xaab8, 230),
+    (0xaabe, 0xaabf, 230), (0xaac1, 0xaac1, 230), (0xaaf6, 0xaaf6, 9), (0xabed, 0xabed, 9),
+    (0xfb1e, 0xfb1e, 26), (0xfe20, 0xfe26, 230), (0xfe27, 0xfe2d, 220), (0xfe2e, 0xfe2f, 230),
+    (0x101fd, 0x101fd, 220), (0x102e0, 0x102e0, 220), (0x10376, 0x1037a, 230),
+    (0x10a0d, 0x10a0d, 220), (0x10a0f, 0x10a0f, 230), (0x10a38, 0x10a38, 230),
+    (0x10a39, 0x10a39, 1), (0x10a3a, 0x10a3a, 220), (0x10a3f, 0x10a3f, 9), (0x10ae5, 0x10ae5, 230),
+    (0x10ae6, 0x10ae6, 220), (0x10d24, 0x10d27, 230), (0x10eab, 0x10eac, 230),
+    (0x10f46, 0x10f47, 220), (0x10f48, 0x10f4a, 230), (0x10f4b, 0x10f4b, 220),
+    (0x10f4c, 0x10f4c, 230), (0x10f4d, 0x10f50, 220), (0x10f82, 0x10f82, 230),
+    (0x10f83, 0x10f83, 220), (0x10f84, 0x10f84, 230), (0x10f85, 0x10f85, 220),
+    (0x11046, 0x11046, 9), (0x11070, 0x11070, 9), (0x1107f, 0x1107f, 9), (0x110b9, 0x110b9, 9),
+    (0x110ba, 0x110ba, 7), (0x11100, 0x11102, 230), (0x11133, 0x11134, 9), (0x11173, 0x11173, 7),
+    (0x111c0, 0x111c0, 9), (0x111ca, 0x111ca, 7), (0x11235, 0x11235, 9), (0x11236, 0x11236, 7),
+    (0x112e9, 0x112e9, 7), (0x112ea, 0x112ea, 9), (0x1133b, 0x1133c, 7), (0x1134d, 0x1134d, 9),
+    (0x11366, 0x1136c, 230), (0x11370, 0x11374, 230), (0x11442, 0x11442, 9), (0x11446, 0x11446, 7),
+    (0x1145e, 0x1145e, 230), (0x114c2, 0x114c2, 9), (0x114c3, 0x114c3, 7), (0x115bf, 0x115bf, 9),
+    (0x115c0, 0x115c0, 7), (0x1163f, 0x1163f, 9), (0x116b6, 0x116b6, 9), (0x116b7, 0x116b7, 7),
+    (0x1172b, 0x1172b, 9), (0x11839, 0x11839, 9), (0x1183a, 0x1183a, 7), (0x1193d, 0x1193e, 9),
+    (0x11943, 0x11943, 7), (0x119e0, 0x119e0, 9), (0x11a34, 0x11a34, 9), (0x11a47, 0x11a47, 9),
+    (0x11a99, 0x11a99, 9), (0x11c3f, 0x11c3f, 9), (0x11d42, 0x11d42, 7), (0x11d44, 0x11d45, 9),
+    (0x11d97, 0x11d97, 9), (0x16af0, 0x16af4, 1), (0x16b30, 0x16b36, 230), (0x16ff0, 0x16ff1, 6),
+    (0x1bc9e, 0x1bc9e, 1), (0x1d165, 0x1d166, 216), (0x1d167, 0x1d169, 1), (0x1d16d, 0x1d16d, 226),
+    (0x1d16e, 0x1d172, 216), (0x1d17b, 0x1d182, 220), (0x1d185, 0x1d189, 230),
+    (0x1d18a, 0x1d18b, 220), (0x1d1aa, 0x1d1ad, 230), (0x1d242, 0x1d244, 230),
+    (0x1e000, 0x1e006, 230), (0x1e008, 0x1e018, 230), (0x1e01b, 0x1e021, 230),
+    (0x1e023, 0x1e024, 230), (0x1e026, 0x1e02a, 230), (0x1e130, 0x1e136, 230),
+    (0x1e2ae, 0x1e2ae, 230), (0x1e2ec, 0x1e2ef, 230), (0x1e8d0, 0x1e8d6, 220),
+    (0x1e944, 0x1e949, 230), (0x1e94a, 0x1e94a, 7),
+];
+/// Bidi_Class ranges other than L
+pub(super) static BIDI_CLASS: &[(u32, u32, BidiClass)] = &[
+    (0x0, 0x8, BidiClass::BoundaryNeutral), (0x9, 0xd, BidiClass::Other),
+    (0xe, 0x1b, BidiClass::BoundaryNeutral), (0x1c, 0x20, BidiClass::Other),
+    (0x21, 0x22, BidiClass::OtherNeutral), (0x23, 0x25, BidiClass::EuropeanTerminator),
+    (0x26, 0x2a, BidiClass::OtherNeutral), (0x2b, 0x2b, BidiClass::EuropeanSeparator),
+    (0x2c, 0x2c, BidiClass::CommonSeparator), (0x2d, 0x2d, BidiClass::EuropeanSeparator),
+    (0x2e, 0x2f, BidiClass::CommonSeparator), (0x30, 0x39, BidiClass::EuropeanNumber),
+    (0x3a, 0x3a, BidiClass::CommonSeparator), (0x3b, 0x40, BidiClass::OtherNeutral),
+    (0x5b, 0x60, BidiClass::OtherNeutral), (0x7b, 0x7e, BidiClass::OtherNeutral),
+    (0x7f, 0x84, BidiClass::BoundaryNeutral), (0x85, 0x85, BidiClass::Other),
+    (0x86, 0x9f, BidiClass::BoundaryNeutral), (0xa0, 0xa0, BidiClass::CommonSeparator),
+    (0xa1, 0xa1, BidiClass::OtherNeutral), (0xa2, 0xa5, BidiClass::EuropeanTerminator),
+    (0xa6, 0xa9, BidiClass::OtherNeutral), (0xab, 0xac, BidiClass::OtherNeutral),
+    (0xad, 0xad, BidiClass::BoundaryNeutral), (0xae, 0xaf, BidiClass::OtherNeutral),
+    (0xb0, 0xb1, BidiClass::EuropeanTerminator), (0xb2, 0xb3, BidiClass::EuropeanNumber),
+    (0xb4, 0xb4, BidiClass::OtherNeutral), (0xb6, 0xb8, BidiClass::OtherNeutral),
+    (0xb9, 0xb9, BidiClass::EuropeanNumber), (0xbb, 0xbf, BidiClass::OtherNeutral),
+    (0xd7, 0xd7, BidiClass::OtherNeutral), (0xf7, 0xf7, BidiClass::OtherNeutral),
+    (0x2b9, 0x2ba, BidiClass::OtherNeutral), (0x2c2, 0x2cf, BidiClass::OtherNeutral),
+    (0x2d2, 0x2df, BidiClass::OtherNeutral), (0x2e5, 0x2ed, BidiClass::OtherNeutral),
+    (0x2ef, 0x2ff, BidiClass::OtherNeutral), (0x300, 0x36f, BidiClass::NonspacingMark),
+    (0x374, 0x375, BidiClass::OtherNeutral), (0x37e, 0x37e, BidiClass::OtherNeutral),
+    (0x384, 0x385, BidiClass::OtherNeutral), (0x387, 0x387, BidiClass::OtherNeutral),
+    (0x3f6, 0x3f6, BidiClass::OtherNeutral), (0x483, 0x489, BidiClass::NonspacingMark),
+    (0x58a, 0x58a, BidiClass::OtherNeutral), (0x58d, 0x58e, BidiClass::OtherNeutral),
+    (0x58f, 0x58f, BidiClass::EuropeanTerminator), (0x591, 0x5bd, BidiClass::NonspacingMark),
+    (0x5be, 0x5be, BidiClass::RightToLeft), (0x5bf, 0x5bf, BidiClass::NonspacingMark),
+    (0x5c0, 0x5c0, BidiClass::RightToLeft), (0x5c1, 0x5c2, BidiClass::NonspacingMark),
+    (0x5c3, 0x5c3, BidiClass::RightToLeft), (0x5c4, 0x5c5, BidiClass::NonspacingMark),
+    (0x5c6, 0x5c6, BidiClass::RightToLeft), (0x5c7, 0x5c7, BidiClass::NonspacingMark),
+    (0x5d0, 0x5ea, BidiClass::RightToLeft), (0x5ef, 0x5f4, BidiClass::RightToLeft),
+    (0x600, 0x605, BidiClass::ArabicNumber), (0x606, 0x607, BidiClass::OtherNeutral),
+    (0x608, 0x608, BidiClass::ArabicLetter), (0x609, 0x60a, BidiClass::EuropeanTerminator),
+    (0x60b, 0x60b, BidiClass::ArabicLetter), (0x60c, 0x60c, BidiClass::CommonSeparator),
+    (0x60d, 0x60d, BidiClass::ArabicLetter), (0x60e, 0x60f, BidiClass::OtherNeutral),
+    (0x610, 0x61a, BidiClass::NonspacingMark), (0x61b, 0x64a, BidiClass::ArabicLetter),
+    (0x64b, 0x65f, BidiClass::NonspacingMark), (0x660, 0x669, BidiClass::ArabicNumber),
+    (0x66a, 0x66a, BidiClass::EuropeanTerminator), (0x66b, 0x66c, BidiClass::ArabicNumber),
+    (0x66d, 0x66f, BidiClass::ArabicLetter), (0x670, 0x670, BidiClass::NonspacingMark),
+    (0x671, 0x6d5, BidiClass::ArabicLetter), (0x6d6, 0x6dc, BidiClass::NonspacingMark),
+    (0x6dd, 0x6dd, BidiClass::ArabicNumber), (0x6de, 0x6de, BidiClass::OtherNeutral),
+    (0x6df, 0x6e4, BidiClass::NonspacingMark), (0x6e5, 0x6e6, BidiClass::ArabicLetter),
+    (0x6e7, 0x6e8, BidiClass::NonspacingMark), (0x6e9, 0x6e9, BidiClass::OtherNeutral),
+    (0x6ea, 0x6ed, BidiClass::NonspacingMark), (0x6ee, 0x6ef, BidiClass::ArabicLetter),
+    (0x6f0, 0x6f9, BidiClass::EuropeanNumber), (0x6fa, 0x70d, BidiClass::ArabicLetter),
+    (0x70f, 0x710, BidiClass::ArabicLetter), (0x711, 0x711, BidiClass::NonspacingMark),
+    (0x712, 0x72f, BidiClass::ArabicLetter), (0x730, 0x74a, BidiClass::NonspacingMark),
+    (0x74d, 0x7a5, BidiClass::ArabicLetter), (0x7a6, 0x7b0, BidiClass::NonspacingMark),
+    (0x7b1, 0x7b1, BidiClass::ArabicLetter), (0x7c0, 0x7ea, BidiClass::RightToLeft),
+    (0x7eb, 0x7f3, BidiClass::NonspacingMark), (0x7f4, 0x7f5, BidiClass::RightToLeft),
+    (0x7f6, 0x7f9, BidiClass::OtherNeutral), (0x7fa, 0x7fa, BidiClass::RightToLeft),
+    (0x7fd, 0x7fd, BidiClass::NonspacingMark), (0x7fe, 0x815, BidiClass::RightToLeft),
+    (0x816, 0x819, BidiClass::NonspacingMark), (0x81a, 0x81a, BidiClass::RightToLeft),
+    (0x81b, 0x823, BidiClass::NonspacingMark), (0x824, 0x824, BidiClass::RightToLeft),
+    (0x825, 0x827, BidiClass::NonspacingMark), (0x828, 0x828, BidiClass::RightToLeft),
+    (0x829, 0x82d, BidiClass::NonspacingMark), (0x830, 0x83e, BidiClass::RightToLeft),
+    (0x840, 0x858, BidiClass::RightToLeft), (0x859, 0x85b, BidiClass::NonspacingMark),
+    (0x85e, 0x85e, BidiClass::RightToLeft), (0x860, 0x86a, BidiClass::ArabicLetter),
+    (0x870, 0x88e, BidiClass::ArabicLetter), (0x890, 0x891, BidiClass::ArabicNumber),
+    (0x898, 0x89f, BidiClass::NonspacingMark), (0x8a0, 0x8c9, BidiClass::ArabicLetter),
+    (0x8ca, 0x8e1, BidiClass::NonspacingMark), (0x8e2, 0x8e2, BidiClass::ArabicNumber),
+    (0x8e3, 0x902, BidiClass::NonspacingMark), (0x93a, 0x93a, BidiClass::NonspacingMark),
+    (0x93c, 0x93c, BidiClass::NonspacingMark), (0x941, 0x948, BidiClass::NonspacingMark),
+    (0x94d, 0x94d, BidiClass::NonspacingMark), (0x951, 0x957, BidiClass::NonspacingMark),
+    (0x962, 0x963, BidiClass::NonspacingMark), (0x981, 0x981, BidiClass::NonspacingMark),
+    (0x9bc, 0x9bc, BidiClass::NonspacingMark), (0x9c1, 0x9c4, BidiClass::NonspacingMark),
+    (0x9cd, 0x9cd, BidiClass::NonspacingMark), (0x9e2, 0x9e3, BidiClass::NonspacingMark),
+    (0x9f2, 0x9f3, BidiClass::EuropeanTerminator), (0x9fb, 0x9fb, BidiClass::EuropeanTerminator),
+    (0x9fe, 0x9fe, BidiClass::NonspacingMark), (0xa01, 0xa02, BidiClass::NonspacingMark),
+    (0xa3c, 0xa3c, BidiClass::NonspacingMark), (0xa41, 0xa42, BidiClass::NonspacingMark),
+    (0xa47, 0xa48, BidiClass::NonspacingMark), (0xa4b, 0xa4d, BidiClass::NonspacingMark),
+    (0xa51, 0xa51, BidiClass::NonspacingMark), (0xa70, 0xa71, BidiClass::NonspacingMark),
+    (0xa75, 0xa75, BidiClass::NonspacingMark), (0xa81, 0xa82, BidiClass::NonspacingMark),
+    (0xabc, 0xabc, BidiClass::NonspacingMark), (0xac1, 0xac5, BidiClass::NonspacingMark),
+    (0xac7, 0xac8, BidiClass::NonspacingMark), (0xacd, 0xacd, BidiClass::NonspacingMark),
+    (0xae2, 0xae3, BidiClass::NonspacingMark), (0xaf1, 0xaf1, BidiClass::EuropeanTerminator),
+    (0xafa, 0xaff, BidiClass::NonspacingMark), (0xb01, 0xb01, BidiClass::NonspacingMark),
+    (0xb3c, 0xb3c, BidiClass::NonspacingMark), (0xb3f, 0xb3f, BidiClass::NonspacingMark),
+    (0xb41, 0xb44, BidiClass::NonspacingMark), (0xb4d, 0xb4d, BidiClass::NonspacingMark),
+    (0xb55, 0xb56, BidiClass::NonspacingMark), (0xb62, 0xb63, BidiClass::NonspacingMark),
+    (0xb82, 0xb82, BidiClass::NonspacingMark), (0xbc0, 0xbc0, BidiClass::NonspacingMark),
+    (0xbcd, 0xbcd, BidiClass::NonspacingMark), (0xbf3, 0xbf8, BidiClass::OtherNeutral),
+    (0xbf9, 0xbf9, BidiClass::EuropeanTerminator), (0xbfa, 0xbfa, BidiClass::OtherNeutral),
+    (0xc00, 0xc00, BidiClass::NonspacingMark), (0xc04, 0xc04, BidiClass::NonspacingMark),
+    (0xc3c, 0xc3c, BidiClass::NonspacingMark), (0xc3e, 0xc40, BidiClass::NonspacingMark),
+    (0xc46, 0xc48, BidiClass::NonspacingMark), (0xc4a, 0xc4d, BidiClass::NonspacingMark),
+    (0xc55, 0xc56, BidiClass::NonspacingMark), (0xc62, 0xc63, BidiClass::NonspacingMark),
+    (0xc78, 0xc7e, BidiClass::OtherNeutral), (0xc81, 0xc81, BidiClass::NonspacingMark),
+    (0xcbc, 0xcbc, BidiClass::NonspacingMark), (0xccc, 0xccd, BidiClass::NonspacingMark),
+    (0xce2, 0xce3, BidiClass::NonspacingMark), (0xd00, 0xd01, BidiClass::NonspacingMark),
+    (0xd3b, 0xd3c, BidiClass::NonspacingMark), (0xd41, 0xd44, BidiClass::NonspacingMark),
+    (0xd4d, 0xd4d, BidiClass::NonspacingMark), (0xd62, 0xd63, BidiClass::NonspacingMark),
+    (0xd81, 0xd81, BidiClass::NonspacingMark), (0xdca, 0xdca, BidiClass::NonspacingMark),
+    (0xdd2, 0xdd4, BidiClass::NonspacingMark), (0xdd6, 0xdd6, BidiClass::NonspacingMark),
+    (0xe31, 0xe31, BidiClass::NonspacingMark), (0xe34, 0xe3a, BidiClass::NonspacingMark),
+    (0xe3f, 0xe3f, BidiClass::EuropeanTerminator), (0xe47, 0xe4e, BidiClass::NonspacingMark),
+    (0xeb1, 0xeb1, BidiClass::NonspacingMark), (0xeb4, 0xebc, BidiClass::NonspacingMark),
+    (0xec8, 0xecd, BidiClass::NonspacingMark), (0xf18, 0xf19, BidiClass::NonspacingMark),
+    (0xf35, 0xf35, BidiClass::NonspacingMark), (0xf37, 0xf37, BidiClass::NonspacingMark),
+    (0xf39, 0xf39, BidiClass::NonspacingMark), (0xf3a, 0xf3d, BidiClass::OtherNeutral),
+    (0xf71, 0xf7e, BidiClass::NonspacingMark), (0xf80, 0xf84, BidiClass::NonspacingMark),
+    (0xf86, 0xf87, BidiClass::NonspacingMark), (0xf8d, 0xf97, BidiClass::NonspacingMark),
+    (0xf99, 0xfbc, BidiClass::NonspacingMark), (0xfc6, 0xfc6, BidiClass::NonspacingMark),
+    (0x102d, 0x1030, BidiClass::NonspacingMark), (0x1032, 0x1037, BidiClass::NonspacingMark),
+    (0x1039, 0x103a, BidiClass::NonspacingMark), (0x103d, 0x103e, BidiClass::NonspacingMark),
+    (0x1058, 0x1059, BidiClass::NonspacingMark), (0x105e, 0x1060, BidiClass::NonspacingMark),
+    (0x1071, 0x1074, BidiClass::NonspacingMark), (0x1082, 0x1082, BidiClass::NonspacingMark),
+    (0x1085, 0x1086, BidiClass::NonspacingMark), (0x108d, 0x108d, BidiClass::NonspacingMark),
+    (0x109d, 0x109d, BidiClass::NonspacingMark), (0x135d, 0x135f, BidiClass::NonspacingMark),
+    (0x1390, 0x1399, BidiClass::OtherNeutral), (0x1400, 0x1400, BidiClass::OtherNeutral),
+    (0x1680, 0x1680, BidiClass::Other), (0x169b, 0x169c, BidiClass::OtherNeutral),
+    (0x1712, 0x1714, BidiClass::NonspacingMark), (0x1732, 0x1733, BidiClass::NonspacingMark),
+    (0x1752, 0x1753, BidiClass::NonspacingMark), (0x1772, 0x1773, BidiClass::NonspacingMark),
+    (0x17b4, 0x17b5, BidiClass::NonspacingMark), (0x17b7, 0x17bd, BidiClass::NonspacingMark),
+    (0x17c6, 0x17c6, BidiClass::NonspacingMark), (0x17c9, 0x17d3, BidiClass::NonspacingMark),
+    (0x17db, 0x17db, BidiClass::EuropeanTerminator), (0x17dd, 0x17dd, BidiClass::NonspacingMark),
+    (0x17f0, 0x17f9, BidiClass::OtherNeutral), (0x1800, 0x180a, BidiClass::OtherNeutral),
+    (0x180b, 0x180d, BidiClass::NonspacingMark), (0x180e, 0x180e, BidiClass::BoundaryNeutral),
+    (0x180f, 0x180f, BidiClass::NonspacingMark), (0x1885, 0x1886, BidiClass::NonspacingMark),
+    (0x18a9, 0x18a9, BidiClass::NonspacingMark), (0x1920, 0x1922, BidiClass::NonspacingMark),
+    (0x1927, 0x1928, BidiClass::NonspacingMark), (0x1932, 0x1932, BidiClass::NonspacingMark),
+    (0x1939, 0x193b, BidiClass::NonspacingMark), (0x1940, 0x1940, BidiClass::OtherNeutral),
+    (0x1944, 0x1945, BidiClass::OtherNeutral), (0x19de, 0x19ff, BidiClass::OtherNeutral),
+    (0x1a17, 0x1a18, BidiClass::NonspacingMark), (0x1a1b, 0x1a1b, BidiClass::NonspacingMark),
+    (0x1a56, 0x1a56, BidiClass::NonspacingMark), (0x1a58, 0x1a5e, BidiClass::NonspacingMark),
+    (0x1a60, 0x1a60, BidiClass::NonspacingMark), (0x1a62, 0x1a62, BidiClass::NonspacingMark),
+    (0x1a65, 0x1a6c, BidiClass::NonspacingMark), (0x1a73, 0x1a7c, BidiClass::NonspacingMark),
+    (0x1a7f, 0x1a7f, BidiClass::NonspacingMark), (0x1ab0, 0x1ace, BidiClass::NonspacingMark),
+    (0x1b00, 0x1b03, BidiClass::NonspacingMark), (0x1b34, 0x1b34, BidiClass::NonspacingMark),
+    (0x1b36, 0x1b3a, BidiClass::NonspacingMark), (0x1b3c, 0x1b3c, BidiClass::NonspacingMark),
+    (0x1b42, 0x1b42, BidiClass::NonspacingMark), (0x1b6b, 0x1b73, BidiClass::NonspacingMark),
+    (0x1b80, 0x1b81, BidiClass::NonspacingMark), (0x1ba2, 0x1ba5, BidiClass::NonspacingMark),
+    (0x1ba8, 0x1ba9, BidiClass::NonspacingMark), (0x1bab, 0x1bad, BidiClass::NonspacingMark),
+    (0x1be6, 0x1be6, BidiClass::NonspacingMark), (0x1be8, 0x1be9, BidiClass::NonspacingMark),
+    (0x1bed, 0x1bed, BidiClass::NonspacingMark), (0x1bef, 0x1bf1, BidiClass::NonspacingMark),
+    (0x1c2c, 0x1c33, BidiClass::NonspacingMark), (0x1c36, 0x1c37, BidiClass::NonspacingMark),
+    (0x1cd0, 0x1cd2, BidiClass::NonspacingMark), (0x1cd4, 0x1ce0, BidiClass::NonspacingMark),
+    (0x1ce2, 0x1ce8, BidiClass::NonspacingMark), (0x1ced, 0x1ced, BidiClass::NonspacingMark),
+    (0x1cf4, 0x1cf4, BidiClass::NonspacingMark), (0x1cf8, 0x1cf9, BidiClass::NonspacingMark),
+    (0x1dc0, 0x1dff, BidiClass::NonspacingMark), (0x1fbd, 0x1fbd, BidiClass::OtherNeutral),
+    (0x1fbf, 0x1fc1, BidiClass::OtherNeutral), (0x1fcd, 0x1fcf, BidiClass::OtherNeutral),
+    (0x1fdd, 0x1fdf, BidiClass::OtherNeutral), (0x1fed, 0x1fef, BidiClass::OtherNeutral),
+    (0x1ffd, 0x1ffe, BidiClass::OtherNeutral), (0x2000, 0x200a, BidiClass::Other),
+    (0x200b, 0x200d, BidiClass::BoundaryNeutral), (0x200f, 0x200f, BidiClass::RightToLeft),
+    (0x2010, 0x2027, BidiClass::OtherNeutral), (0x2028, 0x202e, BidiClass::Other),
+    (0x202f, 0x202f, BidiClass::CommonSeparator), (0x2030, 0x2034, BidiClass::EuropeanTerminator),
+    (0x2035, 0x2043, BidiClass::OtherNeutral), (0x2044, 0x2044, BidiClass::CommonSeparator),
+    (0x2045, 0x205e, BidiClass::OtherNeutral), (0x205f, 0x205f, BidiClass::Other),
+    (0x2060, 0x2064, BidiClass::BoundaryNeutral), (0x2066, 0x2069, BidiClass::Other),
+    (0x206a, 0x206f, BidiClass::BoundaryNeutral), (0x2070, 0x2070, BidiClass::EuropeanNumber),
+    (0x2074, 0x2079, BidiClass::EuropeanNumber), (0x207a, 0x207b, BidiClass::EuropeanSeparator),
+    (0x207c, 0x207e, BidiClass::OtherNeutral), (0x2080, 0x2089, BidiClass::EuropeanNumber),
+    (0x208a, 0x208b, BidiClass::EuropeanSeparator), (0x208c, 0x208e, BidiClass::OtherNeutral),
+    (0x20a0, 0x20c0, BidiClass::EuropeanTerminator), (0x20d0, 0x20f0, BidiClass::NonspacingMark),
+    (0x2100, 0x2101, BidiClass::OtherNeutral), (0x2103, 0x2106, BidiClass::OtherNeutral),
+    (0x2108, 0x2109, BidiClass::OtherNeutral), (0x2114, 0x2114, BidiClass::OtherNeutral),
+    (0x2116, 0x2118, BidiClass::OtherNeutral), (0x211e, 0x2123, BidiClass::OtherNeutral),
+    (0x2125, 0x2125, BidiClass::OtherNeutral), (0x2127, 0x2127, BidiClass::OtherNeutral),
+    (0x2129, 0x2129, BidiClass::OtherNeutral), (0x212e, 0x212e, BidiClass::EuropeanTerminator),
+    (0x213a, 0x213b, BidiClass::OtherNeutral), (0x2140, 0x2144, BidiClass::OtherNeutral),
+    (0x214a, 0x214d, BidiClass::OtherNeutral), (0x2150, 0x215f, BidiClass::OtherNeutral),
+    (0x2189, 0x218b, BidiClass::OtherNeutral), (0x2190, 0x2211, BidiClass::OtherNeutral),
+    (0x2212, 0x2212, BidiClass::EuropeanSeparator), (0x2213, 0x2213, BidiClass::EuropeanTerminator),
+    (0x2214, 0x2335, BidiClass::OtherNeutral), (0x237b, 0x2394, BidiClass::OtherNeutral),
+    (0x2396, 0x2426, BidiClass::OtherNeutral), (0x2440, 0x244a, BidiClass::OtherNeutral),
+    (0x2460, 0x2487, BidiClass::OtherNeutral), (0x2488, 0x249b, BidiClass::EuropeanNumber),
+    (0x24ea, 0x26ab, BidiClass::OtherNeutral), (0x26ad, 0x27ff, BidiClass::OtherNeutral),
+    (0x2900, 0x2b73, BidiClass::OtherNeutral), (0x2b76, 0x2b95, BidiClass::OtherNeutral),
+    (0x2b97, 0x2bff, BidiClass::OtherNeutral), (0x2ce5, 0x2cea, BidiClass::OtherNeutral),
+    (0x2cef, 0x2cf1, BidiClass::NonspacingMark), (0x2cf9, 0x2cff, BidiClass::OtherNeutral),
+    (0x2d7f, 0x2d7f, BidiClass::NonspacingMark), (0x2de0, 0x2dff, BidiClass::NonspacingMark),
+    (0x2e00, 0x2e5d, BidiClass::OtherNeutral), (0x2e80, 0x2e99, BidiClass::OtherNeutral),
+    (0x2e9b, 0x2ef3, BidiClass::OtherNeutral), (0x2f00, 0x2fd5, BidiClass::OtherNeutral),
+    (0x2ff0, 0x2ffb, BidiClass::OtherNeutral), (0x3000, 0x3000, BidiClass::Other),
+    (0x3001, 0x3004, BidiClass::OtherNeutral), (0x3008, 0x3020, BidiClass::OtherNeutral),
+    (0x302a, 0x302d, BidiClass::NonspacingMark), (0x3030, 0x3030, BidiClass::OtherNeutral),
+    (0x3036, 0x3037, BidiClass::OtherNeutral), (0x303d, 0x303f, BidiClass::OtherNeutral),
+    (0x3099, 0x309a, BidiClass::NonspacingMark), (0x309b, 0x309c, BidiClass::OtherNeutral),
+    (0x30a0, 0x30a0, BidiClass::OtherNeutral), (0x30fb, 0x30fb, BidiClass::OtherNeutral),
+    (0x31c0, 0x31e3, BidiClass::OtherNeutral), (0x321d, 0x321e, BidiClass::OtherNeutral),
+    (0x3250, 0x325f, BidiClass::OtherNeutral), (0x327c, 0x327e, BidiClass::OtherNeutral),
+    (0x32b1, 0x32bf, BidiClass::OtherNeutral), (0x32cc, 0x32cf, BidiClass::OtherNeutral),
+    (0x3377, 0x337a, BidiClass::OtherNeutral), (0x33de, 0x33df, BidiClass::OtherNeutral),
+    (0x33ff, 0x33ff, BidiClass::OtherNeutral), (0x4dc0, 0x4dff, BidiClass::OtherNeutral),
+    (0xa490, 0xa4c6, BidiClass::OtherNeutral), (0xa60d, 0xa60f, BidiClass::OtherNeutral),
+    (0xa66f, 0xa672, BidiClass::NonspacingMark), (0xa673, 0xa673, BidiClass::OtherNeutral),
+    (0xa674, 0xa67d, BidiClass::NonspacingMark), (0xa67e, 0xa67f, BidiClass::OtherNeutral),
+    (0xa69e, 0xa69f, BidiClass::NonspacingMark), (0xa6f0, 0xa6f1, BidiClass::NonspacingMark),
+    (0xa700, 0xa721, BidiClass::OtherNeutral), (0xa788, 0xa788, BidiClass::OtherNeutral),
+    (0xa802, 0xa802, BidiClass::NonspacingMark), (0xa806, 0xa806, BidiClass::NonspacingMark),
+    (0xa80b, 0xa80b, BidiClass::NonspacingMark), (0xa825, 0xa826, BidiClass::NonspacingMark),
+    (0xa828, 0xa82b, BidiClass::OtherNeutral), (0xa82c, 0xa82c, BidiClass::NonspacingMark),
+    (0xa838, 0xa839, BidiClass::EuropeanTerminator), (0xa874, 0xa877, BidiClass::OtherNeutral),
+    (0xa8c4, 0xa8c5, BidiClass::NonspacingMark), (0xa8e0, 0xa8f1, BidiClass::NonspacingMark),
+    (0xa8ff, 0xa8ff, BidiClass::NonspacingMark), (0xa926, 0xa92d, BidiClass::NonspacingMark),
+    (0xa947, 0xa951, BidiClass::NonspacingMark), (0xa980, 0xa982, BidiClass::NonspacingMark),
+    (0xa9b3, 0xa9b3, BidiClass::NonspacingMark), (0xa9b6, 0xa9b9, BidiClass::NonspacingMark),
+    (0xa9bc, 0xa9bd, BidiClass::NonspacingMark), (0xa9e5, 0xa9e5, BidiClass::NonspacingMark),
+    (0xaa29, 0xaa2e, BidiClass::NonspacingMark), (0xaa31, 0xaa32, BidiClass::NonspacingMark),
+    (0xaa35, 0xaa36, BidiClass::NonspacingMark), (0xaa43, 0xaa43, BidiClass::NonspacingMark),
+    (0xaa4c, 0xaa4c, BidiClass::NonspacingMark), (0xaa7c, 0xaa7c, BidiClass::NonspacingMark),
+    (0xaab0, 0xaab0, BidiClass::NonspacingMark), (0xaab2, 0xaab4, BidiClass::NonspacingMark),
+    (0xaab7, 0xaab8, BidiClass::NonspacingMark), (0xaabe, 0xaabf, BidiClass::NonspacingMark),
+    (0xaac1, 0xaac1, BidiClass::NonspacingMark), (0xaaec, 0xaaed, BidiClass::NonspacingMark),
+    (0xaaf6, 0xaaf6, BidiClass::NonspacingMark), (0xab6a, 0xab6b, BidiClass::OtherNeutral),
+    (0xabe5, 0xabe5, BidiClass::NonspacingMark), (0xabe8, 0xabe8, BidiClass::NonspacingMark),
+    (0xabed, 0xabed, BidiClass::NonspacingMark), (0xfb1d, 0xfb1d, BidiClass::RightToLeft),
+    (0xfb1e, 0xfb1e, BidiClass::NonspacingMark), (0xfb1f, 0xfb28, BidiClass::RightToLeft),
+    (0xfb29, 0xfb29, BidiClass::EuropeanSeparator), (0xfb2a, 0xfb36, BidiClass::RightToLeft),
+    (0xfb38, 0xfb3c, BidiClass::RightToLeft), (0xfb3e, 0xfb3e, BidiClass::RightToLeft),
+    (0xfb40, 0xfb41, BidiClass::RightToLeft), (0xfb43, 0xfb44, BidiClass::RightToLeft),
+    (0xfb46, 0xfb4f, BidiClass::RightToLeft), (0xfb50, 0xfbc2, BidiClass::ArabicLetter),
+    (0xfbd3, 0xfd3d, BidiClass::ArabicLetter), (0xfd3e, 0xfd4f, BidiClass::OtherNeutral),
+    (0xfd50, 0xfd8f, BidiClass::ArabicLetter), (0xfd92, 0xfdc7, BidiClass::ArabicLetter),
+    (0xfdcf, 0xfdcf, BidiClass::OtherNeutral), (0xfdf0, 0xfdfc, BidiClass::ArabicLetter),
+    (0xfdfd, 0xfdff, BidiClass::OtherNeutral), (0xfe00, 0xfe0f, BidiClass::NonspacingMark),
+    (0xfe10, 0xfe19, BidiClass::OtherNeutral), (0xfe20, 0xfe2f, BidiClass::NonspacingMark),
+    (0xfe30, 0xfe4f, BidiClass::OtherNeutral), (0xfe50, 0xfe50, BidiClass::CommonSeparator),
+    (0xfe51, 0xfe51, BidiClass::OtherNeutral), (0xfe52, 0xfe52, BidiClass::CommonSeparator),
+    (0xfe54, 0xfe54, BidiClass::OtherNeutral), (0xfe55, 0xfe55, BidiClass::CommonSeparator),
+    (0xfe56, 0xfe5e, BidiClass::OtherNeutral), (0xfe5f, 0xfe5f, BidiClass::EuropeanTerminator),
+    (0xfe60, 0xfe61, BidiClass::OtherNeutral), (0xfe62, 0xfe63, BidiClass::EuropeanSeparator),
+    (0xfe64, 0xfe66, BidiClass::OtherNeutral), (0xfe68, 0xfe68, BidiClass::OtherNeutral),
+    (0xfe69, 0xfe6a, BidiClass::EuropeanTerminator), (0xfe6b, 0xfe6b, BidiClass::OtherNeutral),
+    (0xfe70, 0xfe74, BidiClass::ArabicLetter), (0xfe76, 0xfefc, BidiClass::ArabicLetter),
+    (0xfeff, 0xfeff, BidiClass::BoundaryNeutral), (0xff01, 0xff02, BidiClass::OtherNeutral),
+    (0xff03, 0xff05, BidiClass::EuropeanTerminator), (0xff06, 0xff0a, BidiClass::OtherNeutral),
+    (0xff0b, 0xff0b, BidiClass::EuropeanSeparator), (0xff0c, 0xff0c, BidiClass::CommonSeparator),
+    (0xff0d, 0xff0d, BidiClass::EuropeanSeparator), (0xff0e, 0xff0f, BidiClass::CommonSeparator),
+    (0xff10, 0xff19, BidiClass::EuropeanNumber), (0xff1a, 0xff1a, BidiClass::CommonSeparator),
+    (0xff1b, 0xff20, BidiClass::OtherNeutral), (0xff3b, 0xff40, BidiClass::OtherNeutral),
+    (0xff5b, 0xff65, BidiClass::OtherNeutral), (0xffe0, 0xffe1, BidiClass::EuropeanTerminator),
+    (0xffe2, 0xffe4, BidiClass::OtherNeutral), (0xffe5, 0xffe6, BidiClass::EuropeanTerminator),
+    (0xffe8, 0xffee, BidiClass::OtherNeutral), (0xfff9, 0xfffd, BidiClass::OtherNeutral),
+    (0x10101, 0x10101, BidiClass::OtherNeutral), (0x10140, 0x1018c, BidiClass::OtherNeutral),
+    (0x10190, 0x1019c, BidiClass::OtherNeutral), (0x101a0, 0x101a0, BidiClass::OtherNeutral),
+    (0x101fd, 0x101fd, BidiClass::NonspacingMark), (0x102e0, 0x102e0, BidiClass::NonspacingMark),
+    (0x102e1, 0x102fb, BidiClass::EuropeanNumber), (0x10376, 0x1037a, BidiClass::NonspacingMark),
+    (0x10800, 0x10805, BidiClass::RightToLeft), (0x10808, 0x10808, BidiClass::RightToLeft),
+    (0x1080a, 0x10835, BidiClass::RightToLeft), (0x10837, 0x10838, BidiClass::RightToLeft),
+    (0x1083c, 0x1083c, BidiClass::RightToLeft), (0x1083f, 0x10855, BidiClass::RightToLeft),
+    (0x10857, 0x1089e, BidiClass::RightToLeft), (0x108a7, 0x108af, BidiClass::RightToLeft),
+    (0x108e0, 0x108f2, BidiClass::RightToLeft), (0x108f4, 0x108f5, BidiClass::RightToLeft),
+    (0x108fb, 0x1091b, BidiClass::RightToLeft), (0x1091f, 0x1091f, BidiClass::OtherNeutral),
+    (0x10920, 0x10939, BidiClass::RightToLeft), (0x1093f, 0x1093f, BidiClass::RightToLeft),
+    (0x10980, 0x109b7, BidiClass::RightToLeft), (0x109bc, 0x109cf, BidiClass::RightToLeft),
+    (0x109d2, 0x10a00, BidiClass::RightToLeft), (0x10a01, 0x10a03, BidiClass::NonspacingMark),
+    (0x10a05, 0x10a06, BidiClass::NonspacingMark), (0x10a0c, 0x10a0f, BidiClass::NonspacingMark),
+    (0x10a10, 0x10a13, BidiClass::RightToLeft), (0x10a15, 0x10a17, BidiClass::RightToLeft),
+    (0x10a19, 0x10a35, BidiClass::RightToLeft), (0x10a38, 0x10a3a, BidiClass::NonspacingMark),
+    (0x10a3f, 0x10a3f, BidiClass::NonspacingMark), (0x10a40, 0x10a48, BidiClass::RightToLeft),
+    (0x10a50, 0x10a58, BidiClass::RightToLeft), (0x10a60, 0x10a9f, BidiClass::RightToLeft),
+    (0x10ac0, 0x10ae4, BidiClass::RightToLeft), (0x10ae5, 0x10ae6, BidiClass::NonspacingMark),
+    (0x10aeb, 0x10af6, BidiClass::RightToLeft), (0x10b00, 0x10b35, BidiClass::RightToLeft),
+    (0x10b39, 0x10b3f, BidiClass::OtherNeutral), (0x10b40, 0x10b55, BidiClass::RightToLeft),
+    (0x10b58, 0x10b72, BidiClass::RightToLeft), (0x10b78, 0x10b91, BidiClass::RightToLeft),
+    (0x10b99, 0x10b9c, BidiClass::RightToLeft), (0x10ba9, 0x10baf, BidiClass::RightToLeft),
+    (0x10c00, 0x10c48, BidiClass::RightToLeft), (0x10c80, 0x10cb2, BidiClass::RightToLeft),
+    (0x10cc0, 0x10cf2, BidiClass::RightToLeft), (0x10cfa, 0x10cff, BidiClass::RightToLeft),
+    (0x10d00, 0x10d23, BidiClass::ArabicLetter), (0x10d24, 0x10d27, BidiClass::NonspacingMark),
+    (0x10d30, 0x10d39, BidiClass::ArabicNumber), (0x10e60, 0x10e7e, BidiClass::ArabicNumber),
+    (0x10e80, 0x10ea9, BidiClass::RightToLeft), (0x10eab, 0x10eac, BidiClass::NonspacingMark),
+    (0x10ead, 0x10ead, BidiClass::RightToLeft), (0x10eb0, 0x10eb1, BidiClass::RightToLeft),
+    (0x10f00, 0x10f27, BidiClass::RightToLeft), (0x10f30, 0x10f45, BidiClass::ArabicLetter),
+    (0x10f46, 0x10f50, BidiClass::NonspacingMark), (0x10f51, 0x10f59, BidiClass::ArabicLetter),
+    (0x10f70, 0x10f81, BidiClass::RightToLeft), (0x10f82, 0x10f85, BidiClass::NonspacingMark),
+    (0x10f86, 0x10f89, BidiClass::RightToLeft), (0x10fb0, 0x10fcb, BidiClass::RightToLeft),
+    (0x10fe0, 0x10ff6, BidiClass::RightToLeft), (0x11001, 0x11001, BidiClass::NonspacingMark),
+    (0x11038, 0x11046, BidiClass::NonspacingMark), (0x11052, 0x11065, BidiClass::OtherNeutral),
+    (0x11070, 0x11070, BidiClass::NonspacingMark), (0x11073, 0x11074, BidiClass::NonspacingMark),
+    (0x1107f, 0x11081, BidiClass::NonspacingMark), (0x110b3, 0x110b6, BidiClass::NonspacingMark),
+    (0x110b9, 0x110ba, BidiClass::NonspacingMark), (0x110c2, 0x110c2, BidiClass::NonspacingMark),
+    (0x11100, 0x11102, BidiClass::NonspacingMark), (0x11127, 0x1112b, BidiClass::NonspacingMark),
+    (0x1112d, 0x11134, BidiClass::NonspacingMark), (0x11173, 0x11173, BidiClass::NonspacingMark),
+    (0x11180, 0x11181, BidiClass::NonspacingMark), (0x111b6, 0x111be, BidiClass::NonspacingMark),
+    (0x111c9, 0x111cc, BidiClass::NonspacingMark), (0x111cf, 0x111cf, BidiClass::NonspacingMark),
+    (0x1122f, 0x11231, BidiClass::NonspacingMark), (0x11234, 0x11234, BidiClass::NonspacingMark),
+    (0x11236, 0x11237, BidiClass::NonspacingMark), (0x1123e, 0x1123e, BidiClass::NonspacingMark),
+    (0x112df, 0x112df, BidiClass::NonspacingMark), (0x112e3, 0x112ea, BidiClass::NonspacingMark),
+    (0x11300, 0x11301, BidiClass::NonspacingMark), (0x1133b, 0x1133c, BidiClass::NonspacingMark),
+    (0x11340, 0x11340, BidiClass::NonspacingMark), (0x11366, 0x1136c, BidiClass::NonspacingMark),
+    (0x11370, 0x11374, BidiClass::NonspacingMark), (0x11438, 0x1143f, BidiClass::NonspacingMark),
+    (0x11442, 0x11444, BidiClass::NonspacingMark), (0x11446, 0x11446, BidiClass::NonspacingMark),
+    (0x1145e, 0x1145e, BidiClass::NonspacingMark), (0x114b3, 0x114b8, BidiClass::NonspacingMark),
+    (0x114ba, 0x114ba, BidiClass::NonspacingMark), (0x114bf, 0x114c0, BidiClass::NonspacingMark),
+    (0x114c2, 0x114c3, BidiClass::NonspacingMark), (0x115b2, 0x115b5, BidiClass::NonspacingMark),
+    (0x115bc, 0x115bd, BidiClass::NonspacingMark), (0x115bf, 0x115c0, BidiClass::NonspacingMark),
+    (0x115dc, 0x115dd, BidiClass::NonspacingMark), (0x11633, 0x1163a, BidiClass::NonspacingMark),
+    (0x1163d, 0x1163d, BidiClass::NonspacingMark), (0x1163f, 0x11640, BidiClass::NonspacingMark),
+    (0x11660, 0x1166c, BidiClass::OtherNeutral), (0x116ab, 0x116ab, BidiClass::NonspacingMark),
+    (0x116ad, 0x116ad, BidiClass::NonspacingMark), (0x116b0, 0x116b5, BidiClass::NonspacingMark),
+    (0x116b7, 0x116b7, BidiClass::NonspacingMark), (0x1171d, 0x1171f, BidiClass::NonspacingMark),
+    (0x11722, 0x11725, BidiClass::NonspacingMark), (0x11727, 0x1172b, BidiClass::NonspacingMark),
+    (0x1182f, 0x11837, BidiClass::NonspacingMark), (0x11839, 0x1183a, BidiClass::NonspacingMark),
+    (0x1193b, 0x1193c, BidiClass::NonspacingMark), (0x1193e, 0x1193e, BidiClass::NonspacingMark),
+    (0x11943, 0x11943, BidiClass::NonspacingMark), (0x119d4, 0x119d7, BidiClass::NonspacingMark),
+    (0x119da, 0x119db, BidiClass::NonspacingMark), (0x119e0, 0x119e0, BidiClass::NonspacingMark),
+    (0x11a01, 0x11a06, BidiClass::NonspacingMark), (0x11a09, 0x11a0a, BidiClass::NonspacingMark),
+    (0x11a33, 0x11a38, BidiClass::NonspacingMark), (0x11a3b, 0x11a3e, BidiClass::NonspacingMark),
+    (0x11a47, 0x11a47, BidiClass::NonspacingMark), (0x11a51, 0x11a56, BidiClass::NonspacingMark),
+    (0x11a59, 0x11a5b, BidiClass::NonspacingMark), (0x11a8a, 0x11a96, BidiClass::NonspacingMark),
+    (0x11a98, 0x11a99, BidiClass::NonspacingMark), (0x11c30, 0x11c36, BidiClass::NonspacingMark),
+    (0x11c38, 0x11c3d, BidiClass::NonspacingMark), (0x11c92, 0x11ca7, BidiClass::NonspacingMark),
+    (0x11caa, 0x11cb0, BidiClass::NonspacingMark), (0x11cb2, 0x11cb3, BidiClass::NonspacingMark),
+    (0x11cb5, 0x11cb6, BidiClass::NonspacingMark), (0x11d31, 0x11d36, BidiClass::NonspacingMark),
+    (0x11d3a, 0x11d3a, BidiClass::NonspacingMark), (0x11d3c, 0x11d3d, BidiClass::NonspacingMark),
+    (0x11d3f, 0x11d45, BidiClass::NonspacingMark), (0x11d47, 0x11d47, BidiClass::NonspacingMark),
+    (0x11d90, 0x11d91, BidiClass::NonspacingMark), (0x11d95, 0x11d95, BidiClass::NonspacingMark),
+    (0x11d97, 0x11d97, BidiClass::NonspacingMark), (0x11ef3, 0x11ef4, BidiClass::NonspacingMark),
+    (0x11fd5, 0x11fdc, BidiClass::OtherNeutral), (0x11fdd, 0x11fe0, BidiClass::EuropeanTerminator),
+    (0x11fe1, 0x11ff1, BidiClass::OtherNeutral), (0x16af0, 0x16af4, BidiClass::NonspacingMark),
+    (0x16b30, 0x16b36, BidiClass::NonspacingMark), (0x16f4f, 0x16f4f, BidiClass::NonspacingMark),
+    (0x16f8f, 0x16f92, BidiClass::NonspacingMark), (0x16fe2, 0x16fe2, BidiClass::OtherNeutral),
+    (0x16fe4, 0x16fe4, BidiClass::NonspacingMark), (0x1bc9d, 0x1bc9e, BidiClass::NonspacingMark),
+    (0x1bca0, 0x1bca3, BidiClass::BoundaryNeutral), (0x1cf00, 0x1cf2d, BidiClass::NonspacingMark),
+    (0x1cf30, 0x1cf46, BidiClass::NonspacingMark), (0x1d167, 0x1d169, BidiClass::NonspacingMark),
+    (0x1d173, 0x1d17a, BidiClass::BoundaryNeutral), (0x1d17b, 0x1d182, BidiClass::NonspacingMark),
+    (0x1d185, 0x1d18b, BidiClass::NonspacingMark), (0x1d1aa, 0x1d1ad, BidiClass::NonspacingMark),
+    (0x1d1e9, 0x1d1ea, BidiClass::OtherNeutral), (0x1d200, 0x1d241, BidiClass::OtherNeutral),
+    (0x1d242, 0x1d244, BidiClass::NonspacingMark), (0x1d245, 0x1d245, BidiClass::OtherNeutral),
+    (0x1d300, 0x1d356, BidiClass::OtherNeutral), (0x1d6db, 0x1d6db, BidiClass::OtherNeutral),
+    (0x1d715, 0x1d715, BidiClass::OtherNeutral), (0x1d74f, 0x1d74f, BidiClass::OtherNeutral),
+    (0x1d789, 0x1d789, BidiClass::OtherNeutral), (0x1d7c3, 0x1d7c3, BidiClass::OtherNeutral),
+    (0x1d7ce, 0x1d7ff, BidiClass::EuropeanNumber), (0x1da00, 0x1da36, BidiClass::NonspacingMark),
+    (0x1da3b, 0x1da6c, BidiClass::NonspacingMark), (0x1da75, 0x1da75, BidiClass::NonspacingMark),
+    (0x1da84, 0x1da84, BidiClass::NonspacingMark), (0x1da9b, 0x1da9f, BidiClass::NonspacingMark),
+    (0x1daa1, 0x1daaf, BidiClass::NonspacingMark), (0x1e000, 0x1e006, BidiClass::NonspacingMark),
+    (0x1e008, 0x1e018, BidiClass::NonspacingMark), (0x1e01b, 0x1e021, BidiClass::NonspacingMark),
+    (0x1e023, 0x1e024, BidiClass::NonspacingMark), (0x1e026, 0x1e02a, BidiClass::NonspacingMark),
+    (0x1e130, 0x1e136, BidiClass::NonspacingMark), (0x1e2ae, 0x1e2ae, BidiClass::NonspacingMark),
+    (0x1e2ec, 0x1e2ef, BidiClass::NonspacingMark),
+    (0x1e2ff, 0x1e2ff, BidiClass::EuropeanTerminator), (0x1e800, 0x1e8c4, BidiClass::RightToLeft),
+    (0x1e8c7, 0x1e8cf, BidiClass::RightToLeft), (0x1e8d0, 0x1e8d6, BidiClass::NonspacingMark),
+    (0x1e900, 0x1e943, BidiClass::RightToLeft), (0x1e944, 0x1e94a, BidiClass::NonspacingMark),
+    (0x1e94b, 0x1e94b, BidiClass::RightToLeft), (0x1e950, 0x1e959, BidiClass::RightToLeft),
+    (0x1e95e, 0x1e95f, BidiClass::RightToLeft), (0x1ec71, 0x1ecb4, BidiClass::ArabicLetter),
+    (0x1ed01, 0x1ed3d, BidiClass::ArabicLetter), (0x1ee00, 0x1ee03, BidiClass::ArabicLetter),
+    (0x1ee05, 0x1ee1f, BidiClass::ArabicLetter), (0x1ee21, 0x1ee22, BidiClass::ArabicLetter),
+    (0x1ee24, 0x1ee24, BidiClass::ArabicLetter), (0x1ee27, 0x1ee27, BidiClass::ArabicLetter),
+    (0x1ee29, 0x1ee32, BidiClass::ArabicLetter), (0x1ee34, 0x1ee37, BidiClass::ArabicLetter),
+    (0x1ee39, 0x1ee39, BidiClass::ArabicLetter), (0x1ee3b, 0x1ee3b, BidiClass::ArabicLetter),
+    (0x1ee42, 0x1ee42, BidiClass::ArabicLetter), (0x1ee47, 0x1ee47, BidiClass::ArabicLetter),
+    (0x1ee49, 0x1ee49, BidiClass::ArabicLetter), (0x1ee4b, 0x1ee4b, BidiClass::ArabicLetter),
+    (0x1ee4d, 0x1ee4f, BidiClass::ArabicLetter), (0x1ee51, 0x1ee52, BidiClass::ArabicLetter),
+    (0x1ee54, 0x1ee54, BidiClass::ArabicLetter), (0x1ee57, 0x1ee57, BidiClass::ArabicLetter),
+    (0x1ee59, 0x1ee59, BidiClass::ArabicLetter), (0x1ee5b, 0x1ee5b, BidiClass::ArabicLetter),
+    (0x1ee5d, 0x1ee5d, BidiClass::ArabicLetter), (0x1ee5f, 0x1ee5f, BidiClass::ArabicLetter),
+    (0x1ee61, 0x1ee62, BidiClass::ArabicLetter), (0x1ee64, 0x1ee64, BidiClass::ArabicLetter),
+    (0x1ee67, 0x1ee6a, BidiClass::ArabicLetter), (0x1ee6c, 0x1ee72, BidiClass::ArabicLetter),
+    (0x1ee74, 0x1ee77, BidiClass::ArabicLetter), (0x1ee79, 0x1ee7c, BidiClass::ArabicLetter),
+    (0x1ee7e, 0x1ee7e, BidiClass::ArabicLetter), (0x1ee80, 0x1ee89, BidiClass::ArabicLetter),
+    (0x1ee8b, 0x1ee9b, BidiClass::ArabicLetter), (0x1eea1, 0x1eea3, BidiClass::ArabicLetter),
+    (0x1eea5, 0x1eea9, BidiClass::ArabicLetter), (0x1eeab, 0x1eebb, BidiClass::ArabicLetter),
+    (0x1eef0, 0x1eef1, BidiClass::OtherNeutral), (0x1f000, 0x1f02b, BidiClass::OtherNeutral),
+    (0x1f030, 0x1f093, BidiClass::OtherNeutral), (0x1f0a0, 0x1f0ae, BidiClass::OtherNeutral),
+    (0x1f0b1, 0x1f0bf, BidiClass::OtherNeutral), (0x1f0c1, 0x1f0cf, BidiClass::OtherNeutral),
+    (0x1f0d1, 0x1f0f5, BidiClass::OtherNeutral), (0x1f100, 0x1f10a, BidiClass::EuropeanNumber),
+    (0x1f10b, 0x1f10f, BidiClass::OtherNeutral), (0x1f12f, 0x1f12f, BidiClass::OtherNeutral),
+    (0x1f16a, 0x1f16f, BidiClass::OtherNeutral), (0x1f1ad, 0x1f1ad, BidiClass::OtherNeutral),
+    (0x1f260, 0x1f265, BidiClass::OtherNeutral), (0x1f300, 0x1f6d7, BidiClass::OtherNeutral),
+    (0x1f6dd, 0x1f6ec, BidiClass::OtherNeutral), (0x1f6f0, 0x1f6fc, BidiClass::OtherNeutral),
+    (0x1f700, 0x1f773, BidiClass::OtherNeutral), (0x1f780, 0x1f7d8, BidiClass::OtherNeutral),
+    (0x1f7e0, 0x1f7eb, BidiClass::OtherNeutral), (0x1f7f0, 0x1f7f0, BidiClass::OtherNeutral),
+    (0x1f800, 0x1f80b, BidiClass::OtherNeutral), (0x1f810, 0x1f847, BidiClass::OtherNeutral),
+    (0x1f850, 0x1f859, BidiClass::OtherNeutral), (0x1f860, 0x1f887, BidiClass::OtherNeutral),
+    (0x1f890, 0x1f8ad, BidiClass::OtherNeutral), (0x1f8b0, 0x1f8b1, BidiClass::OtherNeutral),
+    (0x1f900, 0x1fa53, BidiClass::OtherNeutral), (0x1fa60, 0x1fa6d, BidiClass::OtherNeutral),
+    (0x1fa70, 0x1fa74, BidiClass::OtherNeutral), (0x1fa78, 0x1fa7c, BidiClass::OtherNeutral),
+    (0x1fa80, 0x1fa86, BidiClass::OtherNeutral), (0x1fa90, 0x1faac, BidiClass::OtherNeutral),
+    (0x1fab0, 0x1faba, BidiClass::OtherNeutral), (0x1fac0, 0x1fac5, BidiClass::OtherNeutral),
+    (0x1fad0, 0x1fad9, BidiClass::OtherNeutral), (0x1fae0, 0x1fae7, BidiClass::OtherNeutral),
+    (0x1faf0, 0x1faf6, BidiClass::OtherNeutral), (0x1fb00, 0x1fb92, BidiClass::OtherNeutral),
+    (0x1fb94, 0x1fbca, BidiClass::OtherNeutral), (0x1fbf0, 0x1fbf9, BidiClass::EuropeanNumber),
+    (0xe0001, 0xe0001, BidiClass::BoundaryNeutral), (0xe0020, 0xe007f, BidiClass::BoundaryNeutral),
+    (0xe0100, 0xe01ef, BidiClass::NonspacingMark),
+];
+/// General_Category Mark (Mn, Mc & Me) ranges
+pub(super) static MARKS: &[(u32, u32)] = &[
+    (0x300, 0x36f), (0x483, 0x489), (0x591, 0x5bd), (0x5bf, 0x5bf), (0x5c1, 0x5c2), (0x5c4, 0x5c5),
+    (0x5c7, 0x5c7), (0x610, 0x61a), (0x64b, 0x65f), (0x670, 0x670), (0x6d6, 0x6dc), (0x6df, 0x6e4),
+    (0x6e7, 0x6e8), (0x6ea, 0x6ed), (0x711, 0x711), (0x730, 0x74a), (0x7a6, 0x7b0), (0x7eb, 0x7f3),
+    (0x7fd, 0x7fd), (0x816, 0x819), (0x81b, 0x823), (0x825, 0x827), (0x829, 0x82d), (0x859, 0x85b),
+    (0x898, 0x89f), (0x8ca, 0x8e1), (0x8e3, 0x903), (0x93a, 0x93c), (0x93e, 0x94f), (0x951, 0x957),
+    (0x962, 0x963), (0x981, 0x983), (0x9bc, 0x9bc), (0x9be, 0x9c4), (0x9c7, 0x9c8), (0x9cb, 0x9cd),
+    (0x9d7, 0x9d7), (0x9e2, 0x9e3), (0x9fe, 0x9fe), (0xa01, 0xa03), (0xa3c, 0xa3c), (0xa3e, 0xa42),
+    (0xa47, 0xa48), (0xa4b, 0xa4d), (0xa51, 0xa51), (0xa70, 0xa71), (0xa75, 0xa75), (0xa81, 0xa83),
+    (0xabc, 0xabc), (0xabe, 0xac5), (0xac7, 0xac9), (0xacb, 0xacd), (0xae2, 0xae3), (0xafa, 0xaff),
+    (0xb01, 0xb03), (0xb3c, 0xb3c), (0xb3e, 0xb44), (0xb47, 0xb48), (0xb4b, 0xb4d), (0xb55, 0xb57),
+    (0xb62, 0xb63), (0xb82, 0xb82), (0xbbe, 0xbc2), (0xbc6, 0xbc8), (0xbca, 0xbcd), (0xbd7, 0xbd7),
+    (0xc00, 0xc04), (0xc3c, 0xc3c), (0xc3e, 0xc44), (0xc46, 0xc48), (0xc4a, 0xc4d), (0xc55, 0xc56),
+    (0xc62, 0xc63), (0xc81, 0xc83), (0xcbc, 0xcbc), (0xcbe, 0xcc4), (0xcc6, 0xcc8), (0xcca, 0xccd),
+    (0xcd5, 0xcd6), (0xce2, 0xce3), (0xd00, 0xd03), (0xd3b, 0xd3c), (0xd3e, 0xd44), (0xd46, 0xd48),
+    (0xd4a, 0xd4d), (0xd57, 0xd57), (0xd62, 0xd63), (0xd81, 0xd83), (0xdca, 0xdca), (0xdcf, 0xdd4),
+    (0xdd6, 0xdd6), (0xdd8, 0xddf), (0xdf2, 0xdf3), (0xe31, 0xe31), (0xe34, 0xe3a), (0xe47, 0xe4e),
+    (0xeb1, 0xeb1), (0xeb4, 0xebc), (0xec8, 0xecd), (0xf18, 0xf19), (0xf35, 0xf35), (0xf37, 0xf37),
+    (0xf39, 0xf39), (0xf3e, 0xf3f), (0xf71, 0xf84), (0xf86, 0xf87), (0xf8d, 0xf97), (0xf99, 0xfbc),
+    (0xfc6, 0xfc6), (0x102b, 0x103e), (0x1056, 0x1059), (0x105e, 0x1060), (0x1062, 0x1064),
+    (0x1067, 0x106d), (0x1071, 0x1074), (0x1082, 0x108d), (0x108f, 0x108f), (0x109a, 0x109d),
+    (0x135d, 0x135f), (0x1712, 0x1715), (0x1732, 0x1734), (0x1752, 0x1753), (0x1772, 0x1773),
+    (0x17b4, 0x17d3), (0x17dd, 0x17dd), (0x180b, 0x180d), (0x180f, 0x180f), (0x1885, 0x1886),
+    (0x18a9, 0x18a9), (0x1920, 0x192b), (0x1930, 0x193b), (0x1a17, 0x1a1b), (0x1a55, 0x1a5e),
+    (0x1a60, 0x1a7c), (0x1a7f, 0x1a7f), (0x1ab0, 0x1ace), (0x1b00, 0x1b04), (0x1b34, 0x1b44),
+    (0x1b6b, 0x1b73), (0x1b80, 0x1b82), (0x1ba1, 0x1bad), (0x1be6, 0x1bf3), (0x1c24, 0x1c37),
+    (0x1cd0, 0x1cd2), (0x1cd4, 0x1ce8), (0x1ced, 0x1ced), (0x1cf4, 0x1cf4), (0x1cf7, 0x1cf9),
+    (0x1dc0, 0x1dff), (0x20d0, 0x20f0), (0x2cef, 0x2cf1), (0x2d7f, 0x2d7f), (0x2de0, 0x2dff),
+    (0x302a, 0x302f), (0x3099, 0x309a), (0xa66f, 0xa672), (0xa674, 0xa67d), (0xa69e, 0xa69f),
+    (0xa6f0, 0xa6f1), (0xa802, 0xa802), (0xa806, 0xa806), (0xa80b, 0xa80b), (0xa823, 0xa827),
+    (0xa82c, 0xa82c), (0xa880, 0xa881), (0xa8b4, 0xa8c5), (0xa8e0, 0xa8f1), (0xa8ff, 0xa8ff),
+    (0xa926, 0xa92d), (0xa947, 0xa953), (0xa980, 0xa983), (0xa9b3, 0xa9c0), (0xa9e5, 0xa9e5),
+    (0xaa29, 0xaa36), (0xaa43, 0xaa43), (0xaa4c, 0xaa4d), (0xaa7b, 0xaa7d), (0xaab0, 0xaab0),
+    (0xaab2, 0xaab4), (0xaab7, 0xaab8), (0xaabe, 0xaabf), (0xaac1, 0xaac1), (0xaaeb, 0xaaef),
+    (0xaaf5, 0xaaf6), (0xabe3, 0xabea), (0xabec, 0xabed), (0xfb1e, 0xfb1e), (0xfe00, 0xfe0f),
+    (0xfe20, 0xfe2f), (0x101fd, 0x101fd), (0x102e0, 0x102e0), (0x10376, 0x1037a),
+    (0x10a01, 0x10a03), (0x10a05, 0x10a06), (0x10a0c, 0x10a0f), (0x10a38, 0x10a3a),
+    (0x10a3f, 0x10a3f), (0x10ae5, 0x10ae6), (0x10d24, 0x10d27), (0x10eab, 0x10eac),
+    (0x10f46, 0x10f50), (0x10f82, 0x10f85), (0x11000, 0x11002), (0x11038, 0x11046),
+    (0x11070, 0x11070), (0x11073, 0x11074), (0x1107f, 0x11082), (0x110b0, 0x110ba),
+    (0x110c2, 0x110c2), (0x11100, 0x11102), (0x11127, 0x11134), (0x11145, 0x11146),
+    (0x11173, 0x11173), (0x11180, 0x11182), (0x111b3, 0x111c0), (0x111c9, 0x111cc),
+    (0x111ce, 0x111cf), (0x1122c, 0x11237), (0x1123e, 0x1123e), (0x112df, 0x112ea),
+    (0x11300, 0x11303), (0x1133b, 0x1133c), (0x1133e, 0x11344), (0x11347, 0x11348),
+    (0x1134b, 0x1134d), (0x11357, 0x11357), (0x11362, 0x11363), (0x11366, 0x1136c),
+    (0x11370, 0x11374), (0x11435, 0x11446), (0x1145e, 0x1145e), (0x114b0, 0x114c3),
+    (0x115af, 0x115b5), (0x115b8, 0x115c0), (0x115dc, 0x115dd), (0x11630, 0x11640),
+    (0x116ab, 0x116b7), (0x1171d, 0x1172b), (0x1182c, 0x1183a), (0x11930, 0x11935),
+    (0x11937, 0x11938), (0x1193b, 0x1193e), (0x11940, 0x11940), (0x11942, 0x11943),
+    (0x119d1, 0x119d7), (0x119da, 0x119e0), (0x119e4, 0x119e4), (0x11a01, 0x11a0a),
+    (0x11a33, 0x11a39), (0x11a3b, 0x11a3e), (0x11a47, 0x11a47), (0x11a51, 0x11a5b),
+    (0x11a8a, 0x11a99), (0x11c2f, 0x11c36), (0x11c38, 0x11c3f), (0x11c92, 0x11ca7),
+    (0x11ca9, 0x11cb6), (0x11d31, 0x11d36), (0x11d3a, 0x11d3a), (0x11d3c, 0x11d3d),
+    (0x11d3f, 0x11d45), (0x11d47, 0x11d47), (0x11d8a, 0x11d8e), (0x11d90, 0x11d91),
+    (0x11d93, 0x11d97), (0x11ef3, 0x11ef6), (0x16af0, 0x16af4), (0x16b30, 0x16b36),
+    (0x16f4f, 0x16f4f), (0x16f51, 0x16f87), (0x16f8f, 0x16f92), (0x16fe4, 0x16fe4),
+    (0x16ff0, 0x16ff1), (0x1bc9d, 0x1bc9e), (0x1cf00, 0x1cf2d), (0x1cf30, 0x1cf46),
+    (0x1d165, 0x1d169), (0x1d16d, 0x1d172), (0x1d17b, 0x1d182), (0x1d185, 0x1d18b),
+    (0x1d1aa, 0x1d1ad), (0x1d242, 0x1d244), (0x1da00, 0x1da36), (0x1da3b, 0x1da6c),
+    (0x1da75, 0x1da75), (0x1da84, 0x1da84), (0x1da9b, 0x1da9f), (0x1daa1, 0x1daaf),
+    (0x1e000, 0x1e006), (0x1e008, 0x1e018), (0x1e01b, 0x1e021), (0x1e023, 0x1e024),
+    (0x1e026, 0x1e02a), (0x1e130, 0x1e136), (0x1e2ae, 0x1e2ae), (0x1e2ec, 0x1e2ef),
+    (0x1e8d0, 0x1e8d6), (0x1e944, 0x1e94a), (0xe0100, 0xe01ef),
+];
+/// Joining_Type ranges other than U & C
+pub(super) static JOINING_TYPE: &[(u32, u32, JoiningType)] = &[
+    (0xad, 0xad, JoiningType::Transparent), (0x300, 0x36f, JoiningType::Transparent),
+    (0x483, 0x489, JoiningType::Transparent), (0x591, 0x5bd, JoiningType::Transparent),
+    (0x5bf, 0x5bf, JoiningType::Transparent), (0x5c1, 0x5c2, JoiningType::Transparent),
+    (0x5c4, 0x5c5, JoiningType::Transparent), (0x5c7, 0x5c7, JoiningType::Transparent),
+    (0x610, 0x61a, JoiningType::Transparent), (0x61c, 0x61c, JoiningType::Transparent),
+    (0x620, 0x620, JoiningType::Dual), (0x622, 0x625, JoiningType::Right),
+    (0x626, 0x626, JoiningType::Dual), (0x627, 0x627, JoiningType::Right),
+    (0x628, 0x628, JoiningType::Dual), (0x629, 0x629, JoiningType::Right),
+    (0x62a, 0x62e, JoiningType::Dual), (0x62f, 0x632, JoiningType::Right),
+    (0x633, 0x63f, JoiningType::Dual), (0x641, 0x647, JoiningType::Dual),
+    (0x648, 0x648, JoiningType::Right), (0x649, 0x64a, JoiningType::Dual),
+    (0x64b, 0x65f, JoiningType::Transparent), (0x66e, 0x66f, JoiningType::Dual),
+    (0x670, 0x670, JoiningType::Transparent), (0x671, 0x673, JoiningType::Right),
+    (0x675, 0x677, JoiningType::Right), (0x678, 0x687, JoiningType::Dual),
+    (0x688, 0x699, JoiningType::Right), (0x69a, 0x6bf, JoiningType::Dual),
+    (0x6c0, 0x6c0, JoiningType::Right), (0x6c1, 0x6c2, JoiningType::Dual),
+    (0x6c3, 0x6cb, JoiningType::Right), (0x6cc, 0x6cc, JoiningType::Dual),
+    (0x6cd, 0x6cd, JoiningType::Right), (0x6ce, 0x6ce, JoiningType::Dual),
+    (0x6cf, 0x6cf, JoiningType::Right), (0x6d0, 0x6d1, JoiningType::Dual),
+    (0x6d2, 0x6d3, JoiningType::Right), (0x6d5, 0x6d5, JoiningType::Right),
+    (0x6d6, 0x6dc, JoiningType::Transparent), (0x6df, 0x6e4, JoiningType::Transparent),
+    (0x6e7, 0x6e8, JoiningType::Transparent), (0x6ea, 0x6ed, JoiningType::Transparent),
+    (0x6ee, 0x6ef, JoiningType::Right), (0x6fa, 0x6fc, JoiningType::Dual),
+    (0x6ff, 0x6ff, JoiningType::Dual), (0x70f, 0x70f, JoiningType::Transparent),
+    (0x710, 0x710, JoiningType::Right), (0x711, 0x711, JoiningType::Transparent),
+    (0x712, 0x714, JoiningType::Dual), (0x715, 0x719, JoiningType::Right),
+    (0x71a, 0x71d, JoiningType::Dual), (0x71e, 0x71e, JoiningType::Right),
+    (0x71f, 0x727, JoiningType::Dual), (0x728, 0x728, JoiningType::Right),
+    (0x729, 0x729, JoiningType::Dual), (0x72a, 0x72a, JoiningType::Right),
+    (0x72b, 0x72b, JoiningType::Dual), (0x72c, 0x72c, JoiningType::Right),
+    (0x72d, 0x72e, JoiningType::Dual), (0x72f, 0x72f, JoiningType::Right),
+    (0x730, 0x74a, JoiningType::Transparent), (0x74d, 0x74d, JoiningType::Right),
+    (0x74e, 0x758, JoiningType::Dual), (0x759, 0x75b, JoiningType::Right),
+    (0x75c, 0x76a, JoiningType::Dual), (0x76b, 0x76c, JoiningType::Right),
+    (0x76d, 0x770, JoiningType::Dual), (0x771, 0x771, JoiningType::Right),
+    (0x772, 0x772, JoiningType::Dual), (0x773, 0x774, JoiningType::Right),
+    (0x775, 0x777, JoiningType::Dual), (0x778, 0x779, JoiningType::Right),
+    (0x77a, 0x77f, JoiningType::Dual), (0x7a6, 0x7b0, JoiningType::Transparent),
+    (0x7ca, 0x7ea, JoiningType::Dual), (0x7eb, 0x7f3, JoiningType::Transparent),
+    (0x7fd, 0x7fd, JoiningType::Transparent), (0x816, 0x819, JoiningType::Transparent),
+    (0x81b, 0x823, JoiningType::Transparent), (0x825, 0x827, JoiningType::Transparent),
+    (0x829, 0x82d, JoiningType::Transparent), (0x840, 0x840, JoiningType::Right),
+    (0x841, 0x845, JoiningType::Dual), (0x846, 0x847, JoiningType::Right),
+    (0x848, 0x848, JoiningType::Dual), (0x849, 0x849, JoiningType::Right),
+    (0x84a, 0x853, JoiningType::Dual), (0x854, 0x854, JoiningType::Right),
+    (0x855, 0x855, JoiningType::Dual), (0x856, 0x858, JoiningType::Right),
+    (0x859, 0x85b, JoiningType::Transparent), (0x860, 0x860, JoiningType::Dual),
+    (0x862, 0x865, JoiningType::Dual), (0x867, 0x867, JoiningType::Right),
+    (0x868, 0x868, JoiningType::Dual), (0x869, 0x86a, JoiningType::Right),
+    (0x870, 0x882, JoiningType::Right), (0x886, 0x886, JoiningType::Dual),
+    (0x889, 0x88d, JoiningType::Dual), (0x88e, 0x88e, JoiningType::Right),
+    (0x898, 0x89f, JoiningType::Transparent), (0x8a0, 0x8a9, JoiningType::Dual),
+    (0x8aa, 0x8ac, JoiningType::Right), (0x8ae, 0x8ae, JoiningType::Right),
+    (0x8af, 0x8b0, JoiningType::Dual), (0x8b1, 0x8b2, JoiningType::Right),
+    (0x8b3, 0x8b8, JoiningType::Dual), (0x8b9, 0x8b9, JoiningType::Right),
+    (0x8ba, 0x8c8, JoiningType::Dual), (0x8ca, 0x8e1, JoiningType::Transparent),
+    (0x8e3, 0x902, JoiningType::Transparent), (0x93a, 0x93a, JoiningType::Transparent),
+    (0x93c, 0x93c, JoiningType::Transparent), (0x941, 0x948, JoiningType::Transparent),
+    (0x94d, 0x94d, JoiningType::Transparent), (0x951, 0x957, JoiningType::Transparent),
+    (0x962, 0x963, JoiningType::Transparent), (0x981, 0x981, JoiningType::Transparent),
+    (0x9bc, 0x9bc, JoiningType::Transparent), (0x9c1, 0x9c4, JoiningType::Transparent),
+    (0x9cd, 0x9cd, JoiningType::Transparent), (0x9e2, 0x9e3, JoiningType::Transparent),
+    (0x9fe, 0x9fe, JoiningType::Transparent), (0xa01, 0xa02, JoiningType::Transparent),
+    (0xa3c, 0xa3c, JoiningType::Transparent), (0xa41, 0xa42, JoiningType::Transparent),
+    (0xa47, 0xa48, JoiningType::Transparent), (0xa4b, 0xa4d, JoiningType::Transparent),
+    (0xa51, 0xa51, JoiningType::Transparent), (0xa70, 0xa71, JoiningType::Transparent),
+    (0xa75, 0xa75, JoiningType::Transparent), (0xa81, 0xa82, JoiningType::Transparent),
+    (0xabc, 0xabc, JoiningType::Transparent), (0xac1, 0xac5, JoiningType::Transparent),
+    (0xac7, 0xac8, JoiningType::Transparent), (0xacd, 0xacd, JoiningType::Transparent),
+    (0xae2, 0xae3, JoiningType::Transparent), (0xafa, 0xaff, JoiningType::Transparent),
+    (0xb01, 0xb01, JoiningType::Transparent), (0xb3c, 0xb3c, JoiningType::Transparent),
+    (0xb3f, 0xb3f, JoiningType::Transparent), (0xb41, 0xb44, JoiningType::Transparent),
+    (0xb4d, 0xb4d, JoiningType::Transparent), (0xb55, 0xb56, JoiningType::Transparent),
+    (0xb62, 0xb63, JoiningType::Transparent), (0xb82, 0xb82, JoiningType::Transparent),
+    (0xbc0, 0xbc0, JoiningType::Transparent), (0xbcd, 0xbcd, JoiningType::Transparent),
+    (0xc00, 0xc00, JoiningType::Transparent), (0xc04, 0xc04, JoiningType::Transparent),
+    (0xc3c, 0xc3c, JoiningType::Transparent), (0xc3e, 0xc40, JoiningType::Transparent),
+    (0xc46, 0xc48, JoiningType::Transparent), (0xc4a, 0xc4d, JoiningType::Transparent),
+    (0xc55, 0xc56, JoiningType::Transparent), (0xc62, 0xc63, JoiningType::Transparent),
+    (0xc81, 0xc81, JoiningType::Transparent), (0xcbc, 0xcbc, JoiningType::Transparent),
+    (0xcbf, 0xcbf, JoiningType::Transparent), (0xcc6, 0xcc6, JoiningType::Transparent),
+    (0xccc, 0xccd, JoiningType::Transparent), (0xce2, 0xce3, JoiningType::Transparent),
+    (0xd00, 0xd01, JoiningType::Transparent), (0xd3b, 0xd3c, JoiningType::Transparent),
+    (0xd41, 0xd44, JoiningType::Transparent), (0xd4d, 0xd4d, JoiningType::Transparent),
+    (0xd62, 0xd63, JoiningType::Transparent), (0xd81, 0xd81, JoiningType::Transparent),
+    (0xdca, 0xdca, JoiningType::Transparent), (0xdd2, 0xdd4, JoiningType::Transparent),
+    (0xdd6, 0xdd6, JoiningType::Transparent), (0xe31, 0xe31, JoiningType::Transparent),
+    (0xe34, 0xe3a, JoiningType::Transparent), (0xe47, 0xe4e, JoiningType::Transparent),
+    (0xeb1, 0xeb1, JoiningType::Transparent), (0xeb4, 0xebc, JoiningType::Transparent),
+    (0xec8, 0xece, JoiningType::Transparent), (0xf18, 0xf19, JoiningType::Transparent),
+    (0xf35, 0xf35, JoiningType::Transparent), (0xf37, 0xf37, JoiningType::Transparent),
+    (0xf39, 0xf39, JoiningType::Transparent), (0xf71, 0xf7e, JoiningType::Transparent),
+    (0xf80, 0xf84, JoiningType::Transparent), (0xf86, 0xf87, JoiningType::Transparent),
+    (0xf8d, 0xf97, JoiningType::Transparent), (0xf99, 0xfbc, JoiningType::Transparent),
+    (0xfc6, 0xfc6, JoiningType::Transparent), (0x102d, 0x1030, JoiningType::Transparent),
+    (0x1032, 0x1037, JoiningType::Transparent), (0x1039, 0x103a, JoiningType::Transparent),
+    (0x103d, 0x103e, JoiningType::Transparent), (0x1058, 0x1059, JoiningType::Transparent),
+    (0x105e, 0x1060, JoiningType::Transparent), (0x1071, 0x1074, JoiningType::Transparent),
+    (0x1082, 0x1082, JoiningType::Transparent), (0x1085, 0x1086, JoiningType::Transparent),
+    (0x108d, 0x108d, JoiningType::Transparent), (0x109d, 0x109d, JoiningType::Transparent),
+    (0x135d, 0x135f, JoiningType::Transparent), (0x1712, 0x1714, JoiningType::Transparent),
+    (0x1732, 0x1733, JoiningType::Transparent), (0x1752, 0x1753, JoiningType::Transparent),
+    (0x1772, 0x1773, JoiningType::Transparent), (0x17b4, 0x17b5, JoiningType::Transparent),
+    (0x17b7, 0x17bd, JoiningType::Transparent), (0x17c6, 0x17c6, JoiningType::Transparent),
+    (0x17c9, 0x17d3, JoiningType::Transparent), (0x17dd, 0x17dd, JoiningType::Transparent),
+    (0x1807, 0x1807, JoiningType::Dual), (0x180b, 0x180d, JoiningType::Transparent),
+    (0x180f, 0x180f, JoiningType::Transparent), (0x1820, 0x1878, JoiningType::Dual),
+    (0x1885, 0x1886, JoiningType::Transparent), (0x1887, 0x18a8, JoiningType::Dual),
+    (0x18a9, 0x18a9, JoiningType::Transparent), (0x18aa, 0x18aa, JoiningType::Dual),
+    (0x1920, 0x1922, JoiningType::Transparent), (0x1927, 0x1928, JoiningType::Transparent),
+    (0x1932, 0x1932, JoiningType::Transparent), (0x1939, 0x193b, JoiningType::Transparent),
+    (0x1a17, 0x1a18, JoiningType::Transparent), (0x1a1b, 0x1a1b, JoiningType::Transparent),
+    (0x1a56, 0x1a56, JoiningType::Transparent), (0x1a58, 0x1a5e, JoiningType::Transparent),
+    (0x1a60, 0x1a60, JoiningType::Transparent), (0x1a62, 0x1a62, JoiningType::Transparent),
+    (0x1a65, 0x1a6c, JoiningType::Transparent), (0x1a73, 0x1a7c, JoiningType::Transparent),
+    (0x1a7f, 0x1a7f, JoiningType::Transparent), (0x1ab0, 0x1ace, JoiningType::Transparent),
+    (0x1b00, 0x1b03, JoiningType::Transparent), (0x1b34, 0x1b34, JoiningType::Transparent),
+    (0x1b36, 0x1b3a, JoiningType::Transparent), (0x1b3c, 0x1b3c, JoiningType::Transparent),
+    (0x1b42, 0x1b42, JoiningType::Transparent), (0x1b6b, 0x1b73, JoiningType::Transparent),
+    (0x1b80, 0x1b81, JoiningType::Transparent), (0x1ba2, 0x1ba5, JoiningType::Transparent),
+    (0x1ba8, 0x1ba9, JoiningType::Transparent), (0x1bab, 0x1bad, JoiningType::Transparent),
+    (0x1be6, 0x1be6, JoiningType::Transparent), (0x1be8, 0x1be9, JoiningType::Transparent),
+    (0x1bed, 0x1bed, JoiningType::Transparent), (0x1bef, 0x1bf1, JoiningType::Transparent),
+    (0x1c2c, 0x1c33, JoiningType::Transparent), (0x1c36, 0x1c37, JoiningType::Transparent),
+    (0x1cd0, 0x1cd2, JoiningType::Transparent), (0x1cd4, 0x1ce0, JoiningType::Transparent),
+    (0x1ce2, 0x1ce8, JoiningType::Transparent), (0x1ced, 0x1ced, JoiningType::Transparent),
+    (0x1cf4, 0x1cf4, JoiningType::Transparent), (0x1cf8, 0x1cf9, JoiningType::Transparent),
+    (0x1dc0, 0x1dff, JoiningType::Transparent), (0x200b, 0x200b, JoiningType::Transparent),
+    (0x200e, 0x200f, JoiningType::Transparent), (0x202a, 0x202e, JoiningType::Transparent),
+    (0x2060, 0x2064, JoiningType::Transparent), (0x206a, 0x206f, JoiningType::Transparent),
+    (0x20d0, 0x20f0, JoiningType::Transparent), (0x2cef, 0x2cf1, JoiningType::Transparent),
+    (0x2d7f, 0x2d7f, JoiningType::Transparent), (0x2de0, 0x2dff, JoiningType::Transparent),
+    (0x302a, 0x302d, JoiningType::Transparent), (0x3099, 0x309a, JoiningType::Transparent),
+    (0xa66f, 0xa672, JoiningType::Transparent), (0xa674, 0xa67d, JoiningType::Transparent),
+    (0xa69e, 0xa69f, JoiningType::Transparent), (0xa6f0, 0xa6f1, JoiningType::Transparent),
+    (0xa802, 0xa802, JoiningType::Transparent), (0xa806, 0xa806, JoiningType::Transparent),
+    (0xa80b, 0xa80b, JoiningType::Transparent), (0xa825, 0xa826, JoiningType::Transparent),
+    (0xa82c, 0xa82c, JoiningType::Transparent), (0xa840, 0xa871, JoiningType::Dual),
+    (0xa872, 0xa872, JoiningType::Left), (0xa8c4, 0xa8c5, JoiningType::Transparent),
+    (0xa8e0, 0xa8f1, JoiningType::Transparent), (0xa8ff, 0xa8ff, JoiningType::Transparent),
+    (0xa926, 0xa92d, JoiningType::Transparent), (0xa947, 0xa951, JoiningType::Transparent),
+    (0xa980, 0xa982, JoiningType::Transparent), (0xa9b3, 0xa9b3, JoiningType::Transparent),
+    (0xa9b6, 0xa9b9, JoiningType::Transparent), (0xa9bc, 0xa9bd, JoiningType::Transparent),
+    (0xa9e5, 0xa9e5, JoiningType::Transparent), (0xaa29, 0xaa2e, JoiningType::Transparent),
+    (0xaa31, 0xaa32, JoiningType::Transparent), (0xaa35, 0xaa36, JoiningType::Transparent),
+    (0xaa43, 0xaa43, JoiningType::Transparent), (0xaa4c, 0xaa4c, JoiningType::Transparent),
+    (0xaa7c, 0xaa7c, JoiningType::Transparent), (0xaab0, 0xaab0, JoiningType::Transparent),
+    (0xaab2, 0xaab4, JoiningType::Transparent), (0xaab7, 0xaab8, JoiningType::Transparent),
+    (0xaabe, 0xaabf, JoiningType::Transparent), (0xaac1, 0xaac1, JoiningType::Transparent),
+    (0xaaec, 0xaaed, JoiningType::Transparent), (0xaaf6, 0xaaf6, JoiningType::Transparent),
+    (0xabe5, 0xabe5, JoiningType::Transparent), (0xabe8, 0xabe8, JoiningType::Transparent),
+    (0xabed, 0xabed, JoiningType::Transparent), (0xfb1e, 0xfb1e, JoiningType::Transparent),
+    (0xfe00, 0xfe0f, JoiningType::Transparent), (0xfe20, 0xfe2f, JoiningType::Transparent),
+    (0xfeff, 0xfeff, JoiningType::Transparent), (0xfff9, 0xfffb, JoiningType::Transparent),
+    (0x101fd, 0x101fd, JoiningType::Transparent), (0x102e0, 0x102e0, JoiningType::Transparent),
+    (0x10376, 0x1037a, JoiningType::Transparent), (0x10a01, 0x10a03, JoiningType::Transparent),
+    (0x10a05, 0x10a06, JoiningType::Transparent), (0x10a0c, 0x10a0f, JoiningType::Transparent),
+    (0x10a38, 0x10a3a, JoiningType::Transparent), (0x10a3f, 0x10a3f, JoiningType::Transparent),
+    (0x10ac0, 0x10ac4, JoiningType::Dual), (0x10ac5, 0x10ac5, JoiningType::Right),
+    (0x10ac7, 0x10ac7, JoiningType::Right), (0x10ac9, 0x10aca, JoiningType::Right),
+    (0x10acd, 0x10acd, JoiningType::Left), (0x10ace, 0x10ad2, JoiningType::Right),
+    (0x10ad3, 0x10ad6, JoiningType::Dual), (0x10ad7, 0x10ad7, JoiningType::Left),
+    (0x10ad8, 0x10adc, JoiningType::Dual), (0x10add, 0x10add, JoiningType::Right),
+    (0x10ade, 0x10ae0, JoiningType::Dual), (0x10ae1, 0x10ae1, JoiningType::Right),
+    (0x10ae4, 0x10ae4, JoiningType::Right), (0x10ae5, 0x10ae6, JoiningType::Transparent),
+    (0x10aeb, 0x10aee, JoiningType::Dual), (0x10aef, 0x10aef, JoiningType::Right),
+    (0x10b80, 0x10b80, JoiningType::Dual), (0x10b81, 0x10b81, JoiningType::Right),
+    (0x10b82, 0x10b82, JoiningType::Dual), (0x10b83, 0x10b85, JoiningType::Right),
+    (0x10b86, 0x10b88, JoiningType::Dual), (0x10b89, 0x10b89, JoiningType::Right),
+    (0x10b8a, 0x10b8b, JoiningType::Dual), (0x10b8c, 0x10b8c, JoiningType::Right),
+    (0x10b8d, 0x10b8d, JoiningType::Dual), (0x10b8e, 0x10b8f, JoiningType::Right),
+    (0x10b90, 0x10b90, JoiningType::Dual), (0x10b91, 0x10b91, JoiningType::Right),
+    (0x10ba9, 0x10bac, JoiningType::Right), (0x10bad, 0x10bae, JoiningType::Dual),
+    (0x10d00, 0x10d00, JoiningType::Left), (0x10d01, 0x10d21, JoiningType::Dual),
+    (0x10d22, 0x10d22, JoiningType::Right), (0x10d23, 0x10d23, JoiningType::Dual),
+    (0x10d24, 0x10d27, JoiningType::Transparent), (0x10eab, 0x10eac, JoiningType::Transparent),
+    (0x10efd, 0x10eff, JoiningType::Transparent), (0x10f30, 0x10f32, JoiningType::Dual),
+    (0x10f33, 0x10f33, JoiningType::Right), (0x10f34, 0x10f44, JoiningType::Dual),
+    (0x10f46, 0x10f50, JoiningType::Transparent), (0x10f51, 0x10f53, JoiningType::Dual),
+    (0x10f54, 0x10f54, JoiningType::Right), (0x10f70, 0x10f73, JoiningType::Dual),
+    (0x10f74, 0x10f75, JoiningType::Right), (0x10f76, 0x10f81, JoiningType::Dual),
+    (0x10f82, 0x10f85, JoiningType::Transparent), (0x10fb0, 0x10fb0, JoiningType::Dual),
+    (0x10fb2, 0x10fb3, JoiningType::Dual), (0x10fb4, 0x10fb6, JoiningType::Right),
+    (0x10fb8, 0x10fb8, JoiningType::Dual), (0x10fb9, 0x10fba, JoiningType::Right),
+    (0x10fbb, 0x10fbc, JoiningType::Dual), (0x10fbd, 0x10fbd, JoiningType::Right),
+    (0x10fbe, 0x10fbf, JoiningType::Dual), (0x10fc1, 0x10fc1, JoiningType::Dual),
+    (0x10fc2, 0x10fc3, JoiningType::Right), (0x10fc4, 0x10fc4, JoiningType::Dual),
+    (0x10fc9, 0x10fc9, JoiningType::Right), (0x10fca, 0x10fca, JoiningType::Dual),
+    (0x10fcb, 0x10fcb, JoiningType::Left), (0x11001, 0x11001, JoiningType::Transparent),
+    (0x11038, 0x11046, JoiningType::Transparent), (0x11070, 0x11070, JoiningType::Transparent),
+    (0x11073, 0x11074, JoiningType::Transparent), (0x1107f, 0x11081, JoiningType::Transparent),
+    (0x110b3, 0x110b6, JoiningType::Transparent), (0x110b9, 0x110ba, JoiningType::Transparent),
+    (0x110c2, 0x110c2, JoiningType::Transparent), (0x11100, 0x11102, JoiningType::Transparent),
+    (0x11127, 0x1112b, JoiningType::Transparent), (0x1112d, 0x11134, JoiningType::Transparent),
+    (0x11173, 0x11173, JoiningType::Transparent), (0x11180, 0x11181, JoiningType::Transparent),
+    (0x111b6, 0x111be, JoiningType::Transparent), (0x111c9, 0x111cc, JoiningType::Transparent),
+    (0x111cf, 0x111cf, JoiningType::Transparent), (0x1122f, 0x11231, JoiningType::Transparent),
+    (0x11234, 0x11234, JoiningType::Transparent), (0x11236, 0x11237, JoiningType::Transparent),
+    (0x1123e, 0x1123e, JoiningType::Transparent), (0x11241, 0x11241, JoiningType::Transparent),
+    (0x112df, 0x112df, JoiningType::Transparent), (0x112e3, 0x112ea, JoiningType::Transparent),
+    (0x11300, 0x11301, JoiningType::Transparent), (0x1133b, 0x1133c, JoiningType::Transparent),
+    (0x11340, 0x11340, JoiningType::Transparent), (0x11366, 0x1136c, JoiningType::Transparent),
+    (0x11370, 0x11374, JoiningType::Transparent), (0x11438, 0x1143f, JoiningType::Transparent),
+    (0x11442, 0x11444, JoiningType::Transparent), (0x11446, 0x11446, JoiningType::Transparent),
+    (0x1145e, 0x1145e, JoiningType::Transparent), (0x114b3, 0x114b8, JoiningType::Transparent),
+    (0x114ba, 0x114ba, JoiningType::Transparent), (0x114bf, 0x114c0, JoiningType::Transparent),
+    (0x114c2, 0x114c3, JoiningType::Transparent), (0x115b2, 0x115b5, JoiningType::Transparent),
+    (0x115bc, 0x115bd, JoiningType::Transparent), (0x115bf, 0x115c0, JoiningType::Transparent),
+    (0x115dc, 0x115dd, JoiningType::Transparent), (0x11633, 0x1163a, JoiningType::Transparent),
+    (0x1163d, 0x1163d, JoiningType::Transparent), (0x1163f, 0x11640, JoiningType::Transparent),
+    (0x116ab, 0x116ab, JoiningType::Transparent), (0x116ad, 0x116ad, JoiningType::Transparent),
+    (0x116b0, 0x116b5, JoiningType::Transparent), (0x116b7, 0x116b7, JoiningType::Transparent),
+    (0x1171d, 0x1171f, JoiningType::Transparent), (0x11722, 0x11725, JoiningType::Transparent),
+    (0x11727, 0x1172b, JoiningType::Transparent), (0x1182f, 0x11837, JoiningType::Transparent),
+    (0x11839, 0x1183a, JoiningType::Transparent), (0x1193b, 0x1193c, JoiningType::Transparent),
+    (0x1193e, 0x1193e, JoiningType::Transparent), (0x11943, 0x11943, JoiningType::Transparent),
+    (0x119d4, 0x119d7, JoiningType::Transparent), (0x119da, 0x119db, JoiningType::Transparent),
+    (0x119e0, 0x119e0, JoiningType::Transparent), (0x11a01, 0x11a0a, JoiningType::Transparent),
+    (0x11a33, 0x11a38, JoiningType::Transparent), (0x11a3b, 0x11a3e, JoiningType::Transparent),
+    (0x11a47, 0x11a47, JoiningType::Transparent), (0x11a51, 0x11a56, JoiningType::Transparent),
+    (0x11a59, 0x11a5b, JoiningType::Transparent), (0x11a8a, 0x11a96, JoiningType::Transparent),
+    (0x11a98, 0x11a99, JoiningType::Transparent), (0x11c30, 0x11c36, JoiningType::Transparent),
+    (0x11c38, 0x11c3d, JoiningType::Transparent), (0x11c3f, 0x11c3f, JoiningType::Transparent),
+    (0x11c92, 0x11ca7, JoiningType::Transparent), (0x11caa, 0x11cb0, JoiningType::Transparent),
+    (0x11cb2, 0x11cb3, JoiningType::Transparent), (0x11cb5, 0x11cb6, JoiningType::Transparent),
+    (0x11d31, 0x11d36, JoiningType::Transparent), (0x11d3a, 0x11d3a, JoiningType::Transparent),
+    (0x11d3c, 0x11d3d, JoiningType::Transparent), (0x11d3f, 0x11d45, JoiningType::Transparent),
+    (0x11d47, 0x11d47, JoiningType::Transparent), (0x11d90, 0x11d91, JoiningType::Transparent),
+    (0x11d95, 0x11d95, JoiningType::Transparent), (0x11d97, 0x11d97, JoiningType::Transparent),
+    (0x11ef3, 0x11ef4, JoiningType::Transparent), (0x11f00, 0x11f01, JoiningType::Transparent),
+    (0x11f36, 0x11f3a, JoiningType::Transparent), (0x11f40, 0x11f40, JoiningType::Transparent),
+    (0x11f42, 0x11f42, JoiningType::Transparent), (0x13430, 0x13440, JoiningType::Transparent),
+    (0x13447, 0x13455, JoiningType::Transparent), (0x16af0, 0x16af4, JoiningType::Transparent),
+    (0x16b30, 0x16b36, JoiningType::Transparent), (0x16f4f, 0x16f4f, JoiningType::Transparent),
+    (0x16f8f, 0x16f92, JoiningType::Transparent), (0x16fe4, 0x16fe4, JoiningType::Transparent),
+    (0x1bc9d, 0x1bc9e, JoiningType::Transparent), (0x1bca0, 0x1bca3, JoiningType::Transparent),
+    (0x1cf00, 0x1cf2d, JoiningType::Transparent), (0x1cf30, 0x1cf46, JoiningType::Transparent),
+    (0x1d167, 0x1d169, JoiningType::Transparent), (0x1d173, 0x1d182, JoiningType::Transparent),
+    (0x1d185, 0x1d18b, JoiningType::Transparent), (0x1d1aa, 0x1d1ad, JoiningType::Transparent),
+    (0x1d242, 0x1d244, JoiningType::Transparent), (0x1da00, 0x1da36, JoiningType::Transparent),
+    (0x1da3b, 0x1da6c, JoiningType::Transparent), (0x1da75, 0x1da75, JoiningType::Transparent),
+    (0x1da84, 0x1da84, JoiningType::Transparent), (0x1da9b, 0x1da9f, JoiningType::Transparent),
+    (0x1daa1, 0x1daaf, JoiningType::Transparent), (0x1e000, 0x1e006, JoiningType::Transparent),
+    (0x1e008, 0x1e018, JoiningType::Transparent), (0x1e01b, 0x1e021, JoiningType::Transparent),
+    (0x1e023, 0x1e024, JoiningType::Transparent), (0x1e026, 0x1e02a, JoiningType::Transparent),
+    (0x1e08f, 0x1e08f, JoiningType::Transparent), (0x1e130, 0x1e136, JoiningType::Transparent),
+    (0x1e2ae, 0x1e2ae, JoiningType::Transparent), (0x1e2ec, 0x1e2ef, JoiningType::Transparent),
+    (0x1e4ec, 0x1e4ef, JoiningType::Transparent), (0x1e8d0, 0x1e8d6, JoiningType::Transparent),
+    (0x1e900, 0x1e943, JoiningType::Dual), (0x1e944, 0x1e94b, JoiningType::Transparent),
+    (0xe0001, 0xe0001, JoiningType::Transparent), (0xe0020, 0xe007f, JoiningType::Transparent),
+    (0xe0100, 0xe01ef, JoiningType::Transparent),
+];
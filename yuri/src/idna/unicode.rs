@@ -0,0 +1,292 @@
+//! Unicode property lookups & NFC over the generated tables
+
+use core::cmp::Ordering;
+
+use super::tables::{
+    BIDI_CLASS, COMBINING_CLASS, COMPOSITIONS, DECOMPOSED, DECOMPOSITIONS, JOINING_TYPE, MAPPED,
+    MAPPED_INDEX, MAPPING, MARKS,
+};
+
+/// UTS #46 status with the index of the first mapped code point of a range
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum Status {
+    Valid,
+    Ignored,
+    Mapped(u16),
+    Disallowed,
+    DisallowedStd3Valid,
+    DisallowedStd3Mapped(u16),
+}
+
+/// Bidi_Class as used by the RFC 5893 Bidi Rule
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum BidiClass {
+    LeftToRight,
+    RightToLeft,
+    ArabicLetter,
+    ArabicNumber,
+    EuropeanNumber,
+    EuropeanSeparator,
+    CommonSeparator,
+    EuropeanTerminator,
+    OtherNeutral,
+    BoundaryNeutral,
+    NonspacingMark,
+    /// Any other e.g. whitespace or the explicit formatting classes
+    Other,
+}
+
+/// Joining_Type as used by the RFC 5892 CONTEXTJ rules
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum JoiningType {
+    Left,
+    Dual,
+    Right,
+    Transparent,
+    NonJoining,
+}
+
+/// Canonical_Combining_Class of a virama
+pub(super) const VIRAMA: u8 = 9;
+
+/// UTS #46 status of the code point with the mapping when mapped
+pub(super) fn mapping(c: char) -> (Status, &'static [char]) {
+    let c = u32::from(c);
+    let pos = MAPPING.partition_point(|(first, _)| *first <= c);
+    let (first, status) = MAPPING[pos.saturating_sub(1)];
+    match status {
+        Status::Mapped(base) | Status::DisallowedStd3Mapped(base) => {
+            let (offset, len) = MAPPED_INDEX[usize::from(base) + (c - first) as usize];
+            let offset = usize::from(offset);
+            (status, &MAPPED[offset..offset + usize::from(len)])
+        }
+        _ => (status, &[]),
+    }
+}
+
+fn in_range(first: u32, last: u32, c: char) -> Ordering {
+    match u32::from(c) {
+        c if c < first => Ordering::Greater,
+        c if c > last => Ordering::Less,
+        _ => Ordering::Equal,
+    }
+}
+
+fn find<T: Copy>(table: &[(u32, u32, T)], c: char) -> Option<T> {
+    table
+        .binary_search_by(|(first, last, _)| in_range(*first, *last, c))
+        .ok()
+        .map(|pos| table[pos].2)
+}
+
+pub(super) fn combining_class(c: char) -> u8 {
+    find(COMBINING_CLASS, c).unwrap_or(0)
+}
+
+pub(super) fn bidi_class(c: char) -> BidiClass {
+    find(BIDI_CLASS, c).unwrap_or(BidiClass::LeftToRight)
+}
+
+pub(super) fn joining_type(c: char) -> JoiningType {
+    find(JOINING_TYPE, c).unwrap_or(JoiningType::NonJoining)
+}
+
+/// General_Category Mark e.g. a combining diacritic
+pub(super) fn is_mark(c: char) -> bool {
+    MARKS
+        .binary_search_by(|(first, last)| in_range(*first, *last, c))
+        .is_ok()
+}
+
+//--------------------------------------------------------
+// NFC - UAX #15
+//--------------------------------------------------------
+
+const HANGUL_S_BASE: u32 = 0xac00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11a7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_N_COUNT: u32 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+const HANGUL_S_COUNT: u32 = HANGUL_L_COUNT * HANGUL_N_COUNT;
+
+/// Full canonical decomposition, at most 4 code points
+fn decompose(c: char) -> ([char; 4], usize) {
+    let mut out = [c, '\0', '\0', '\0'];
+    let s_index = u32::from(c).wrapping_sub(HANGUL_S_BASE);
+    if s_index < HANGUL_S_COUNT {
+        let jamo = |base, offset| char::from_u32(base + offset).unwrap_or(c);
+        out[0] = jamo(HANGUL_L_BASE, s_index / HANGUL_N_COUNT);
+        out[1] = jamo(HANGUL_V_BASE, (s_index % HANGUL_N_COUNT) / HANGUL_T_COUNT);
+        return match s_index % HANGUL_T_COUNT {
+            0 => (out, 2),
+            t => {
+                out[2] = jamo(HANGUL_T_BASE, t);
+                (out, 3)
+            }
+        };
+    }
+    match DECOMPOSITIONS.binary_search_by_key(&u32::from(c), |(cp, _, _)| *cp) {
+        Ok(pos) => {
+            let (_, offset, len) = DECOMPOSITIONS[pos];
+            let (offset, len) = (usize::from(offset), usize::from(len));
+            out[..len].copy_from_slice(&DECOMPOSED[offset..offset + len]);
+            (out, len)
+        }
+        Err(_) => (out, 1),
+    }
+}
+
+/// Primary composite of the pair
+fn compose(a: char, b: char) -> Option<char> {
+    let (a, b) = (u32::from(a), u32::from(b));
+    let l_index = a.wrapping_sub(HANGUL_L_BASE);
+    let v_index = b.wrapping_sub(HANGUL_V_BASE);
+    if l_index < HANGUL_L_COUNT && v_index < HANGUL_V_COUNT {
+        let lv = HANGUL_S_BASE + (l_index * HANGUL_V_COUNT + v_index) * HANGUL_T_COUNT;
+        return char::from_u32(lv);
+    }
+    let s_index = a.wrapping_sub(HANGUL_S_BASE);
+    let t_index = b.wrapping_sub(HANGUL_T_BASE);
+    if s_index < HANGUL_S_COUNT
+        && s_index % HANGUL_T_COUNT == 0
+        && (1..HANGUL_T_COUNT).contains(&t_index)
+    {
+        return char::from_u32(a + t_index);
+    }
+    COMPOSITIONS
+        .binary_search_by_key(&(a, b), |(first, second, _)| (*first, *second))
+        .ok()
+        .map(|pos| COMPOSITIONS[pos].2)
+}
+
+/// Code points from a starter up to the next, longer runs of combining
+/// marks are split as in the Stream-Safe Text Format - UAX #15 s. 13
+struct Segment {
+    buf: [char; 32],
+    len: usize,
+}
+
+impl Segment {
+    fn push(&mut self, c: char) {
+        self.buf[self.len] = c;
+        self.len += 1;
+    }
+    /// Canonical ordering then the canonical composition
+    fn compose(&mut self) {
+        let buf = &mut self.buf[..self.len];
+        let start = usize::from(buf.first().is_some_and(|c| combining_class(*c) == 0));
+        for i in start + 1..buf.len() {
+            let mut j = i;
+            while j > start && combining_class(buf[j - 1]) > combining_class(buf[j]) {
+                buf.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+        if start == 0 {
+            return;
+        }
+        let (mut kept, mut last_class) = (1, 0);
+        for i in 1..buf.len() {
+            let (c, class) = (buf[i], combining_class(buf[i]));
+            let blocked = kept > 1 && last_class >= class;
+            match compose(buf[0], c).filter(|_| !blocked) {
+                Some(composed) => buf[0] = composed,
+                None => {
+                    buf[kept] = c;
+                    kept += 1;
+                    last_class = class;
+                }
+            }
+        }
+        self.len = kept;
+    }
+    fn flush<E>(&mut self, emit: &mut impl FnMut(char) -> Result<(), E>) -> Result<(), E> {
+        let len = core::mem::take(&mut self.len);
+        self.buf[..len].iter().try_for_each(|c| emit(*c))
+    }
+}
+
+/// Normalize the chars into NFC calling emit with each
+pub(super) fn nfc<E>(
+    chars: impl Iterator<Item = char>,
+    mut emit: impl FnMut(char) -> Result<(), E>,
+) -> Result<(), E> {
+    let mut segment = Segment {
+        buf: ['\0'; 32],
+        len: 0,
+    };
+    for c in chars {
+        let (decomposed, len) = decompose(c);
+        for c in &decomposed[..len] {
+            let starter = combining_class(*c) == 0;
+            if (starter && segment.len > 0) || segment.len == segment.buf.len() {
+                segment.compose();
+                // Starters composing e.g. Hangul jamo
+                let composed = match segment.len {
+                    1 if starter => compose(segment.buf[0], *c),
+                    _ => None,
+                };
+                if let Some(composed) = composed {
+                    segment.buf[0] = composed;
+                    continue;
+                }
+                segment.flush(&mut emit)?;
+            }
+            segment.push(*c);
+        }
+    }
+    segment.compose();
+    segment.flush(&mut emit)
+}
+
+/// Whether the chars are in NFC
+pub(super) fn is_nfc(s: &str) -> bool {
+    let mut chars = s.chars();
+    nfc(s.chars(), |c| match chars.next() == Some(c) {
+        true => Ok(()),
+        false => Err(()),
+    })
+    .is_ok()
+        && chars.next().is_none()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("a\u{308}", "ä")]
+    #[case("ä\u{323}", "ạ\u{308}")]
+    #[case("\u{1100}\u{1161}\u{11a8}", "각")]
+    #[case("각", "각")]
+    #[case("\u{2126}", "Ω")]
+    #[case("\u{1e0b}\u{323}", "\u{1e0d}\u{307}")]
+    #[case("\u{308}a", "\u{308}a")]
+    #[case("\u{9c7}\u{9be}", "\u{9cb}")]
+    fn nfc_cases(#[case] input: &str, #[case] expected: &str) {
+        let mut buf = [0u8; 32];
+        let mut w = crate::buf::SliceWriter::new(&mut buf);
+        nfc(input.chars(), |c| core::fmt::Write::write_char(&mut w, c)).unwrap();
+        assert_eq!(w.into_str(), expected);
+        assert!(is_nfc(expected));
+        assert_eq!(is_nfc(input), input == expected);
+    }
+
+    #[test]
+    fn properties() {
+        assert_eq!(mapping('A'), (Status::Mapped(0), &['a'][..]));
+        assert_eq!(mapping('a'), (Status::Valid, &[][..]));
+        assert_eq!(mapping('\u{ad}'), (Status::Ignored, &[][..]));
+        assert_eq!(mapping('\u{2163}').1, &['i', 'v'][..]);
+        assert_eq!(mapping('_').0, Status::DisallowedStd3Valid);
+        assert_eq!(combining_class('\u{94d}'), VIRAMA);
+        assert_eq!(bidi_class('\u{5d0}'), BidiClass::RightToLeft);
+        assert_eq!(bidi_class('\u{661}'), BidiClass::ArabicNumber);
+        assert_eq!(joining_type('\u{628}'), JoiningType::Dual);
+        assert!(is_mark('\u{300}') && !is_mark('a'));
+    }
+}
@@ -6,10 +6,11 @@
     unused_qualifications
 )]
 #![allow(clippy::single_match, rustdoc::bare_urls)]
+#![cfg_attr(test, allow(clippy::unwrap_used))]
 #![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
 #![doc = include_str!("../README.md")]
 
-#[cfg(all(feature = "alloc", not(feature = "std")))]
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
 //---------------------------------------------------------
@@ -30,14 +31,46 @@ pub(crate) use error::*;
 mod uri;
 pub use uri::*;
 
+//--------------------------------------------------------
+// Percent-encoding
+//--------------------------------------------------------
+
+mod percent;
+pub use percent::*;
+
+//--------------------------------------------------------
+// IDNA
+//--------------------------------------------------------
+
+#[cfg(feature = "idna")]
+mod idna;
+#[cfg(feature = "idna")]
+pub use idna::*;
+
 //--------------------------------------------------------
 // Parsing implementations with type conversions
 //--------------------------------------------------------
 
 pub(crate) mod parser;
 
+//--------------------------------------------------------
+// Fixed buffer writing
+//--------------------------------------------------------
+
+pub(crate) mod buf;
+
 //--------------------------------------------------------
 // Builder implementations with type conversions
 //--------------------------------------------------------
 
-pub(crate) mod builder;
+#[cfg(feature = "builder")]
+mod builder;
+#[cfg(feature = "builder")]
+pub use builder::*;
+
+//--------------------------------------------------------
+// WHATWG URL Standard compatible parsing
+//--------------------------------------------------------
+
+#[cfg(feature = "whatwg")]
+mod whatwg;
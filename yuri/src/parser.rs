@@ -15,10 +15,12 @@ use query::QueryToken;
 mod fragment;
 use fragment::FragmentToken;
 
-use crate::{
-    error::{SchemeError, UriError},
-    Uri,
-};
+mod strict;
+
+mod options;
+
+use crate::error::{AuthorityError, UriError};
+use crate::{Authority, Fragment, Host, Iri, ParseOptions, Path, Query, Uri, UriRef};
 
 use crate::Scheme;
 use logos::{Lexer, Logos};
@@ -26,86 +28,280 @@ use logos::{Lexer, Logos};
 impl<'uri> TryFrom<&'uri str> for Uri<'uri> {
     type Error = UriError<'uri>;
     fn try_from(raw: &'uri str) -> Result<Uri<'uri>, Self::Error> {
-        let mut lexer: Lexer<'uri, SchemeToken<'uri>> = SchemeToken::lexer(raw);
-        let scheme = scheme::parse_scheme(&mut lexer).map_err(|e| UriError::Scheme(e))?;
+        parse_uri(raw, &ParseOptions::new())
+    }
+}
+
+/// Parse a URI applying the given options
+pub(crate) fn parse_uri<'uri>(
+    raw: &'uri str,
+    opts: &ParseOptions,
+) -> Result<Uri<'uri>, UriError<'uri>> {
+    let raw = options::prepare(raw, opts)?;
+
+    let mut lexer: Lexer<'uri, SchemeToken<'uri>> = SchemeToken::lexer(raw);
+    let scheme = scheme::parse_scheme(&mut lexer).map_err(UriError::Scheme)?;
+
+    let scheme_specific_start = lexer.span().end;
+
+    let flexible_authority = !opts.require_slashes
+        && matches!(scheme, Scheme::Http(_) | Scheme::Ftp(_) | Scheme::Ldap(_));
+    let components = parse_components(raw, lexer, flexible_authority)?;
+
+    if components.authority.is_none() && scheme.requires_authority() && opts.require_slashes {
+        return Err(UriError::Authority(AuthorityError::MissingSlashes));
+    }
+    options::check(raw, opts, &components)?;
 
-        if lexer.remainder().starts_with("//") {
-            lexer.bump(2);
+    let Components {
+        authority,
+        path,
+        query,
+        fragment,
+    } = components;
+
+    // Scheme specific data for the non-hierarchical e.g. mailto:, urn:
+    let scheme_data_raw = match authority {
+        None => {
+            let scheme_specific_end = match fragment {
+                Some(ref f) => raw.len() - f.raw_fragment.len() - 1,
+                None => raw.len(),
+            };
+            Some(&raw[scheme_specific_start..scheme_specific_end])
         }
+        Some(_) => None,
+    };
+    // Known schemes given in another case e.g. HTTP: are kept as given
+    let raw_scheme = &raw[..scheme_specific_start - 1];
+    let scheme_data: crate::SchemeData<'uri> = crate::SchemeData {
+        raw: scheme_data_raw,
+        raw_scheme: (raw_scheme != scheme.as_str()).then_some(raw_scheme),
+    };
 
-        let res = match scheme {
-            Scheme::Http(_) | Scheme::Ftp(_) | Scheme::Ldap(_) => {
-                let mut authority_lexer: Lexer<'uri, AuthorityToken<'uri>> = lexer.morph();
-                let (l_authority, l_carry) = authority::parse_authority(&mut authority_lexer)
-                    .map_err(|e| UriError::Authority(e))?;
-                lexer = authority_lexer.morph();
-                (l_authority, l_carry)
-            }
-            _ => {
-                return Err(UriError::Scheme(SchemeError::Unimplemented(scheme)));
-            }
+    Ok(Uri {
+        scheme,
+        authority,
+        path,
+        query,
+        fragment,
+        scheme_data,
+    })
+}
+
+impl<'uri> TryFrom<&'uri str> for UriRef<'uri> {
+    type Error = UriError<'uri>;
+    fn try_from(raw: &'uri str) -> Result<UriRef<'uri>, Self::Error> {
+        parse_uri_ref(raw, &ParseOptions::new())
+    }
+}
+
+/// Parse a URI reference applying the given options
+pub(crate) fn parse_uri_ref<'uri>(
+    raw: &'uri str,
+    opts: &ParseOptions,
+) -> Result<UriRef<'uri>, UriError<'uri>> {
+    let raw = options::prepare(raw, opts)?;
+
+    // RFC 3986 s. 4.2 - scheme is present only if ":" comes before any "/?#"
+    match raw.find([':', '/', '?', '#']) {
+        Some(pos) if raw.as_bytes()[pos] == b':' => return parse_uri(raw, opts).map(Into::into),
+        _ => {}
+    }
+
+    let lexer: Lexer<'uri, SchemeToken<'uri>> = SchemeToken::lexer(raw);
+    let components = parse_components(raw, lexer, false)?;
+    options::check(raw, opts, &components)?;
+
+    let Components {
+        authority,
+        path,
+        query,
+        fragment,
+    } = components;
+
+    Ok(UriRef {
+        scheme: None,
+        authority,
+        path,
+        query,
+        fragment,
+        scheme_data: crate::SchemeData {
+            raw: None,
+            raw_scheme: None,
+        },
+    })
+}
+
+/// Parse an IRI validating the components against RFC 3987
+pub(crate) fn parse_iri(raw: &str) -> Result<Iri<'_>, UriError<'_>> {
+    let Uri {
+        scheme,
+        authority,
+        path,
+        query,
+        fragment,
+        scheme_data,
+    } = parse_uri(raw, &ParseOptions::new())?;
+    strict::check_strict(
+        raw,
+        true,
+        authority.as_ref(),
+        path.as_ref(),
+        query.as_ref(),
+        fragment.as_ref(),
+    )?;
+    Ok(Iri {
+        scheme,
+        authority,
+        path,
+        query,
+        fragment,
+        scheme_data,
+    })
+}
+
+/// Components following the scheme or the whole of a relative reference
+struct Components<'uri> {
+    authority: Option<Authority<'uri>>,
+    path: Option<Path<'uri>>,
+    query: Option<Query<'uri>>,
+    fragment: Option<Fragment<'uri>>,
+}
+
+/// Parse hier-part / relative-part with the query and fragment
+fn parse_components<'uri>(
+    raw: &'uri str,
+    mut lexer: Lexer<'uri, SchemeToken<'uri>>,
+    flexible_authority: bool,
+) -> Result<Components<'uri>, UriError<'uri>> {
+    let has_slashes = lexer.remainder().starts_with("//");
+    if has_slashes {
+        lexer.bump(2);
+    }
+
+    //*****************************************
+    // Authority
+    //*****************************************
+
+    // Authority follows "//" or is given flexibly without for hierarchical schemes
+    let empty_authority = has_slashes
+        && (lexer.remainder().is_empty() || lexer.remainder().starts_with(['/', '?', '#']));
+    let res = if empty_authority {
+        // reg-name may be empty e.g. file:///etc/passwd or foo://
+        let at = raw.len() - lexer.remainder().len();
+        let mut authority_lexer: Lexer<'uri, AuthorityToken<'uri>> = lexer.morph();
+        let l_carry = match authority_lexer.next() {
+            Some(Ok(AuthorityToken::MaybePathStart(start))) => Some(start),
+            _ => None,
         };
-        let authority = Some(res.0);
-
-        //*****************************************
-        // Path
-        //*****************************************
-
-        // There is no possibility to revd and lexer tokens are not really Peekable
-        // so we need to hack our way with next token
-        let path_res = match res.1 {
-            Some("/") => {
-                let mut path_lexer: Lexer<'uri, PathToken<'uri>> = lexer.morph();
-                let res = path::parse_path(&mut path_lexer).map_err(|e| UriError::Path(e))?;
-                lexer = path_lexer.morph();
-                res
-            }
-            _ => (None, res.1),
+        lexer = authority_lexer.morph();
+        let l_authority = Authority {
+            userinfo: None,
+            raw_host: &raw[at..at],
+            host: Host::RegName(&raw[at..at]),
+            port: None,
+            raw_port: None,
+            without_slashes: false,
         };
-        let path = path_res.0;
-
-        //*****************************************
-        // Query
-        //*****************************************
-
-        let query_res = match path_res.1 {
-            Some("?") => {
-                let mut query_lexer: Lexer<'uri, QueryToken<'uri>> = lexer.morph();
-                let res = query::parse_query(&mut query_lexer).map_err(|e| UriError::Query(e))?;
-                lexer = query_lexer.morph();
-                res
+        (Some(l_authority), l_carry)
+    } else if has_slashes || flexible_authority {
+        let mut authority_lexer: Lexer<'uri, AuthorityToken<'uri>> = lexer.morph();
+        let (mut l_authority, l_carry) =
+            authority::parse_authority(&mut authority_lexer).map_err(UriError::Authority)?;
+        l_authority.without_slashes = !has_slashes;
+        lexer = authority_lexer.morph();
+        (Some(l_authority), l_carry)
+    } else {
+        (None, None)
+    };
+    let authority = res.0;
+
+    //*****************************************
+    // Path
+    //*****************************************
+
+    // There is no possibility to revd and lexer tokens are not really Peekable
+    // so we need to hack our way with next token
+    let path_res = match res.1 {
+        _ if authority.is_none() => {
+            // path-rootless / path-absolute e.g. mailto:John.Doe@example.com
+            // or relative-path / path-absolute e.g. ../index.html
+            let mut path_lexer: Lexer<'uri, PathToken<'uri>> = lexer.morph();
+            let res = path::parse_path(&mut path_lexer).map_err(UriError::Path)?;
+            lexer = path_lexer.morph();
+            res
+        }
+        Some("/") => {
+            // Path includes the leading "/" already consumed as the carry
+            let path_start = lexer.span().start;
+            let mut path_lexer: Lexer<'uri, PathToken<'uri>> = lexer.morph();
+            let res = path::parse_path(&mut path_lexer).map_err(UriError::Path)?;
+            lexer = path_lexer.morph();
+            let path_len = 1 + res.0.map(|p| p.raw_path.len()).unwrap_or(0);
+            let path = Path {
+                raw_path: &raw[path_start..path_start + path_len],
+            };
+            (Some(path), res.1)
+        }
+        _ => (None, res.1),
+    };
+    let path = path_res.0;
+
+    //*****************************************
+    // Query
+    //*****************************************
+
+    let query_res = match path_res.1 {
+        Some("?") => {
+            let mut query_lexer: Lexer<'uri, QueryToken<'uri>> = lexer.morph();
+            let query_start = query_lexer.span().end;
+            let res = query::parse_query(&mut query_lexer).map_err(UriError::Query)?;
+            lexer = query_lexer.morph();
+            // Empty query after ? is still a query
+            match res {
+                (None, carry) => (
+                    Some(Query {
+                        raw_query: &raw[query_start..query_start],
+                    }),
+                    carry,
+                ),
+                res => res,
             }
-            _ => (None, path_res.1),
-        };
-        let query = query_res.0;
-
-        //*****************************************
-        // Fragment
-        //*****************************************
-
-        let fragment_res = match query_res.1 {
-            Some("#") => {
-                let mut fragment_lexer: Lexer<'uri, FragmentToken<'uri>> = lexer.morph();
-                let res = fragment::parse_fragment(&mut fragment_lexer)
-                    .map_err(|e| UriError::Fragment(e))?;
-                //lexer = path_lexer.morph();
-                res
+        }
+        _ => (None, path_res.1),
+    };
+    let query = query_res.0;
+
+    //*****************************************
+    // Fragment
+    //*****************************************
+
+    let fragment_res = match query_res.1 {
+        Some("#") => {
+            let mut fragment_lexer: Lexer<'uri, FragmentToken<'uri>> = lexer.morph();
+            let fragment_start = fragment_lexer.span().end;
+            let res = fragment::parse_fragment(&mut fragment_lexer).map_err(UriError::Fragment)?;
+            // Empty fragment after # is still a fragment
+            match res {
+                (None, carry) => (
+                    Some(Fragment {
+                        raw_fragment: &raw[fragment_start..fragment_start],
+                    }),
+                    carry,
+                ),
+                res => res,
             }
-            _ => (None, query_res.1),
-        };
-        let fragment = fragment_res.0;
-
-        let scheme_data: crate::SchemeData<'uri> = crate::SchemeData { raw: None };
-
-        Ok(Uri {
-            scheme,
-            authority,
-            path,
-            query,
-            fragment,
-            scheme_data,
-        })
-    }
+        }
+        _ => (None, query_res.1),
+    };
+    let fragment = fragment_res.0;
+
+    Ok(Components {
+        authority,
+        path,
+        query,
+        fragment,
+    })
 }
 
 #[cfg(test)]
@@ -113,6 +309,170 @@ mod test {
 
     use super::*;
     use crate::Authority;
+    use rstest::rstest;
+
+    const RFC3986_S112: &str = include_str!("../test_data/valid/rfc3986_s112.txt");
+
+    #[test]
+    fn parse_rfc3986_s112_corpus() {
+        for line in RFC3986_S112.lines().filter(|l| !l.is_empty()) {
+            let res = Uri::new(line);
+            assert!(res.is_ok(), "{} -> {:?}", line, res);
+            assert_eq!(format!("{}", res.unwrap()), line);
+        }
+    }
+
+    const fn rel(
+        authority: Option<&'static str>,
+        path: Option<&'static str>,
+        query: Option<&'static str>,
+        fragment: Option<&'static str>,
+    ) -> UriRef<'static> {
+        UriRef {
+            scheme: None,
+            authority: match authority {
+                Some(host) => Some(Authority {
+                    userinfo: None,
+                    raw_host: host,
+                    host: Host::RegName(host),
+                    port: None,
+                    raw_port: None,
+                    without_slashes: false,
+                }),
+                None => None,
+            },
+            path: match path {
+                Some(raw_path) => Some(Path { raw_path }),
+                None => None,
+            },
+            query: match query {
+                Some(raw_query) => Some(Query { raw_query }),
+                None => None,
+            },
+            fragment: match fragment {
+                Some(raw_fragment) => Some(Fragment { raw_fragment }),
+                None => None,
+            },
+            scheme_data: crate::SchemeData {
+                raw: None,
+                raw_scheme: None,
+            },
+        }
+    }
+
+    #[rstest]
+    #[case("//foobar.test/p", rel(Some("foobar.test"), Some("/p"), None, None))]
+    #[case("//foobar.test", rel(Some("foobar.test"), None, None, None))]
+    #[case("/index.html?x=1", rel(None, Some("/index.html"), Some("x=1"), None))]
+    #[case("../up/./x#top", rel(None, Some("../up/./x"), None, Some("top")))]
+    #[case("g;x=1/../y", rel(None, Some("g;x=1/../y"), None, None))]
+    #[case("?y", rel(None, None, Some("y"), None))]
+    #[case("#s", rel(None, None, None, Some("s")))]
+    #[case("", rel(None, None, None, None))]
+    fn parse_relative_ref(#[case] s: &'static str, #[case] expected: UriRef<'static>) {
+        let res = UriRef::new(s).unwrap();
+        assert_eq!(res, expected);
+        assert!(res.is_relative());
+        assert_eq!(format!("{}", res), s);
+    }
+
+    #[test]
+    fn parse_ref_with_scheme() {
+        let res = UriRef::new("https://foobar.test/a?b#c").unwrap();
+        assert_eq!(res.scheme, Some(Scheme::Http(true)));
+        let uri: Uri<'_> = res.try_into().unwrap();
+        assert_eq!(format!("{}", uri), "https://foobar.test/a?b#c");
+        let res = UriRef::new("HTTPS:foobar.test:/a").unwrap();
+        assert_eq!(format!("{}", res), "HTTPS:foobar.test:/a");
+    }
+
+    #[rstest]
+    #[case("file:///etc/passwd", Some("/etc/passwd"), None)]
+    #[case("foo://", None, None)]
+    #[case("foo://?q", None, Some("q"))]
+    fn parse_empty_authority(
+        #[case] s: &'static str,
+        #[case] raw_path: Option<&'static str>,
+        #[case] raw_query: Option<&'static str>,
+    ) {
+        let uri = Uri::new(s).unwrap();
+        assert_eq!(uri.authority.as_ref().map(|a| a.raw_host), Some(""));
+        assert_eq!(uri.path.map(|p| p.raw_path), raw_path);
+        assert_eq!(uri.query.map(|q| q.raw_query), raw_query);
+    }
+
+    #[rstest]
+    #[case("http://user@")]
+    #[case("http://user@/p")]
+    #[case("http://user:pw@?q")]
+    fn parse_missing_host(#[case] s: &'static str) {
+        assert_eq!(
+            Uri::new(s),
+            Err(UriError::Authority(AuthorityError::MissingHost))
+        );
+    }
+
+    #[test]
+    fn relative_ref_is_not_uri() {
+        let res = UriRef::new("/index.html").unwrap();
+        assert_eq!(
+            Uri::try_from(res),
+            Err(UriError::Scheme(crate::SchemeError::Nothing))
+        );
+    }
+
+    #[rstest]
+    #[case(
+        "ftp://ftp.is.co.za/rfc/rfc1808.txt",
+        Scheme::Ftp(false),
+        "ftp.is.co.za"
+    )]
+    #[case(
+        "ldap://[2001:db8::7]/c=GB?objectClass?one",
+        Scheme::Ldap(false),
+        "[2001:db8::7]"
+    )]
+    #[case("telnet://192.0.2.16:80/", Scheme::Telnet, "192.0.2.16")]
+    #[case("ftps://files.test/", Scheme::Ftp(true), "files.test")]
+    #[case("ldaps://dir.test/", Scheme::Ldap(true), "dir.test")]
+    #[case("gopher://gopher.test/", Scheme::Unknown("gopher"), "gopher.test")]
+    fn parse_known_schemes(
+        #[case] s: &'static str,
+        #[case] scheme: Scheme<'static>,
+        #[case] host: &'static str,
+    ) {
+        let uri = Uri::new(s).unwrap();
+        assert_eq!(uri.scheme, scheme);
+        assert_eq!(uri.authority.unwrap().raw_host, host);
+    }
+
+    #[rstest]
+    #[case("mailto:John.Doe@example.com", "John.Doe@example.com", None)]
+    #[case(
+        "news:comp.infosystems.www.servers.unix",
+        "comp.infosystems.www.servers.unix",
+        None
+    )]
+    #[case("tel:+1-816-555-1212", "+1-816-555-1212", None)]
+    #[case(
+        "urn:oasis:names:specification:docbook:dtd:xml:4.1.2",
+        "oasis:names:specification:docbook:dtd:xml:4.1.2",
+        None
+    )]
+    #[case("mailto:a@b.test?subject=hi#x", "a@b.test", Some("subject=hi"))]
+    fn parse_rootless(
+        #[case] s: &'static str,
+        #[case] path: &'static str,
+        #[case] query: Option<&'static str>,
+    ) {
+        let uri = Uri::new(s).unwrap();
+        assert_eq!(uri.authority, None);
+        assert_eq!(uri.path, Some(Path { raw_path: path }));
+        assert_eq!(uri.query.map(|q| q.raw_query), query);
+        let scheme_specific = &s[s.find(':').unwrap() + 1..];
+        let scheme_specific = scheme_specific.split('#').next().unwrap();
+        assert_eq!(uri.scheme_data.raw(), Some(scheme_specific));
+    }
 
     #[test]
     fn parse_https_host_only() {
@@ -124,7 +484,10 @@ mod test {
             Some(Authority {
                 userinfo: None,
                 raw_host: "foobar.test",
-                port: None
+                host: Host::RegName("foobar.test"),
+                port: None,
+                raw_port: None,
+                without_slashes: false,
             })
         );
     }
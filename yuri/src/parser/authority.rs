@@ -10,7 +10,7 @@ pub(super) enum AuthorityToken<'uri> {
     #[token("@", priority = 200)]
     At,
 
-    #[regex(r"\[[a-f0-9:]+\]", |lex| lex.slice(), priority = 250)]
+    #[regex(r"\[[a-zA-Z0-9\-._~!$&'()*+,;=:%]+\]", |lex| lex.slice(), priority = 250)]
     MaybeIpv6Hostname(&'uri str),
 
     #[token(":", priority = 200)]
@@ -43,17 +43,54 @@ pub(super) fn parse_authority<'uri>(
     let mut first_bit: Option<&'uri str> = None;
     let mut second_bit: Option<&'uri str> = None;
     let mut port: Option<u16> = None;
+    let mut raw_port: Option<&'uri str> = None;
     let mut carry: Option<&'uri str> = None;
 
+    // Authorization continued up to the given end e.g. pw:x in user:pw:x@
+    let source = lexer.source();
+    let extend = |bit: &'uri str, end: usize| {
+        let start = bit.as_ptr() as usize - source.as_ptr() as usize;
+        &source[start..end]
+    };
+
     while let Some(token) = lexer.next() {
         match token {
-            Ok(AuthorityToken::At) if stage == Stage::WantSecondBit || stage == Stage::WantAt => {
+            Ok(AuthorityToken::At) if stage == Stage::WantSecondBit => {
+                // Empty authorization e.g. user:@ or ending in ":" e.g. user:pw:@
+                let at = lexer.span().start;
+                second_bit = Some(match second_bit {
+                    Some(bit) => extend(bit, at),
+                    None => &lexer.source()[at..at],
+                });
+                raw_port = None;
+                stage = Stage::WantHost;
+            }
+            Ok(AuthorityToken::At) if stage == Stage::WantAt || stage == Stage::SeenFirstBit => {
+                raw_port = None;
                 stage = Stage::WantHost;
             }
             Ok(AuthorityToken::Colon) if stage == Stage::SeenFirstBit => {
+                // Empty port e.g. host: unless userinfo follows
+                let end = lexer.span().end;
+                raw_port = Some(&lexer.source()[end..end]);
+                stage = Stage::WantSecondBit;
+            }
+            Ok(AuthorityToken::Colon)
+                if stage == Stage::Nowhere && has_userinfo(lexer.remainder()) =>
+            {
+                // Empty user e.g. :pw@
+                let start = lexer.span().start;
+                first_bit = Some(&lexer.source()[start..start]);
+                stage = Stage::WantSecondBit;
+            }
+            Ok(AuthorityToken::Colon) if stage == Stage::WantAt => {
+                // Authorization may contain ":" e.g. user:pw:x@
                 stage = Stage::WantSecondBit;
             }
             Ok(AuthorityToken::Colon) if stage == Stage::GotHost => {
+                // Empty port e.g. host:
+                let end = lexer.span().end;
+                raw_port = Some(&lexer.source()[end..end]);
                 stage = Stage::WantPort;
             }
             Ok(AuthorityToken::MaybePathStart(start)) => {
@@ -69,7 +106,10 @@ pub(super) fn parse_authority<'uri>(
                 stage = Stage::SeenFirstBit;
             }
             Ok(AuthorityToken::MaybeSomethingElse(something)) if stage == Stage::WantSecondBit => {
-                second_bit = Some(something);
+                second_bit = Some(match second_bit {
+                    Some(bit) => extend(bit, lexer.span().end),
+                    None => something,
+                });
                 stage = Stage::WantAt;
             }
             Ok(AuthorityToken::MaybeSomethingElse(something)) if stage == Stage::WantHost => {
@@ -81,6 +121,7 @@ pub(super) fn parse_authority<'uri>(
                 stage = Stage::GotHost;
             }
             Ok(AuthorityToken::MaybeSomethingElse(something)) if stage == Stage::WantPort => {
+                raw_port = Some(something);
                 port = Some(something.parse().map_err(|_| AuthorityError::InvalidPort)?);
                 stage = Stage::GotPort;
             }
@@ -105,6 +146,11 @@ pub(super) fn parse_authority<'uri>(
         return Err(AuthorityError::ParsedNothing);
     }
 
+    // Nothing follows the userinfo e.g. user@
+    if stage == Stage::WantHost {
+        return Err(AuthorityError::MissingHost);
+    }
+
     if host.is_none() {
         if let Some(first_bit) = first_bit {
             if let Some(second_bit) = second_bit {
@@ -113,12 +159,16 @@ pub(super) fn parse_authority<'uri>(
                         .parse()
                         .map_err(|_| AuthorityError::InvalidPort)?,
                 );
+                raw_port = Some(second_bit);
             }
             return Ok((
                 Authority {
                     userinfo: None,
                     raw_host: first_bit,
+                    host: first_bit.try_into()?,
                     port,
+                    raw_port: raw_port.filter(|raw| crate::non_canonical_port(raw)),
+                    without_slashes: false,
                 },
                 carry,
             ));
@@ -127,19 +177,18 @@ pub(super) fn parse_authority<'uri>(
     }
 
     if let Some(host) = host {
-        let userinfo = if let Some(first_bit) = first_bit {
-            Some(UserInfo {
-                raw_user: first_bit,
-                raw_authorization: second_bit,
-            })
-        } else {
-            None
-        };
+        let userinfo = first_bit.map(|first_bit| UserInfo {
+            raw_user: first_bit,
+            raw_authorization: second_bit,
+        });
         return Ok((
             Authority {
                 userinfo,
                 raw_host: host,
+                host: host.try_into()?,
                 port,
+                raw_port: raw_port.filter(|raw| crate::non_canonical_port(raw)),
+                without_slashes: false,
             },
             carry,
         ));
@@ -148,6 +197,14 @@ pub(super) fn parse_authority<'uri>(
     Err(AuthorityError::InvalidAuthority)
 }
 
+/// Whether an "@" comes before the end of the authority
+fn has_userinfo(remainder: &str) -> bool {
+    remainder
+        .split(['/', '?', '#'])
+        .next()
+        .is_some_and(|authority| authority.contains('@'))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -160,7 +217,7 @@ mod test {
             raw_authorization: auth,
         })
     }
-    const fn auth(
+    fn auth(
         userinfo: Option<UserInfo<'static>>,
         host: &'static str,
         port: Option<u16>,
@@ -168,7 +225,10 @@ mod test {
         Authority {
             userinfo,
             raw_host: host,
+            host: host.try_into().unwrap(),
             port,
+            raw_port: None,
+            without_slashes: false,
         }
     }
 
@@ -186,17 +246,22 @@ mod test {
     #[case(
         "user:authorization@foo.test:/path/nowhere?foo=bar",
         "path/nowhere?foo=bar",
-        Ok((auth(userinfo("user", Some("authorization")), "foo.test", None), Some("/")))
+        Ok((Authority { raw_port: Some(""), ..auth(userinfo("user", Some("authorization")), "foo.test", None) }, Some("/")))
     )]
     #[case(
         "user:authorization@foo.test/path/nowhere?foo=bar",
         "path/nowhere?foo=bar",
         Ok((auth(userinfo("user", Some("authorization")), "foo.test", None), Some("/")))
     )]
+    #[case(
+        "user@foo.test:8181/path/nowhere?foo=bar",
+        "path/nowhere?foo=bar",
+        Ok((auth(userinfo("user", None), "foo.test", Some(8181)), Some("/")))
+    )]
     #[case(
         "user:@foo.test/path/nowhere?foo=bar",
         "path/nowhere?foo=bar",
-        Ok((auth(userinfo("user", None), "foo.test", None), Some("/")))
+        Ok((auth(userinfo("user", Some("")), "foo.test", None), Some("/")))
     )]
     #[case(
         "foo.test/path/nowhere?foo=bar",
@@ -208,6 +273,16 @@ mod test {
         "path/nowhere?foo=bar",
         Ok((auth(None, "foo.test", Some(800)), Some("/")))
     )]
+    #[case(
+        "foo.test:0800/",
+        "",
+        Ok((Authority { raw_port: Some("0800"), ..auth(None, "foo.test", Some(800)) }, Some("/")))
+    )]
+    #[case(
+        "foo.test:/",
+        "",
+        Ok((Authority { raw_port: Some(""), ..auth(None, "foo.test", None) }, Some("/")))
+    )]
     #[case(
         "foo.test:800?foo=bar",
         "foo=bar",
@@ -227,6 +302,50 @@ mod test {
         ":800/path/nowhere?foo=bar", "800/path/nowhere?foo=bar",
         Err(AuthorityError::ParsingDetailed(ParsingDetail { component: "authority", span_start: 0, span_end: 1, source: ":800/path/nowhere?foo=bar", clipped_span: ":", clipped_remaining: ":800/path/nowhere?foo=bar" }))
     )]
+    #[case(
+        "[2001:DB8::7]:800?foo=bar",
+        "foo=bar",
+        Ok((auth(None, "[2001:DB8::7]", Some(800)), Some("?")))
+    )]
+    #[case(
+        "[::ffff:192.0.2.1]:80/",
+        "",
+        Ok((auth(None, "[::ffff:192.0.2.1]", Some(80)), Some("/")))
+    )]
+    #[case(
+        "user:pw@[fe80::1%25eth0]:8080/",
+        "",
+        Ok((auth(userinfo("user", Some("pw")), "[fe80::1%25eth0]", Some(8080)), Some("/")))
+    )]
+    #[case(
+        "[v7.foo]/",
+        "",
+        Ok((auth(None, "[v7.foo]", None), Some("/")))
+    )]
+    #[case(
+        "user:pw@[zz::1]:800/",
+        "",
+        Err(AuthorityError::InvalidHost("[zz::1]"))
+    )]
+    #[case("999.1.1.1/", "", Err(AuthorityError::InvalidHost("999.1.1.1")))]
+    #[case(
+        "user:pw:x@foo.test/",
+        "",
+        Ok((auth(userinfo("user", Some("pw:x")), "foo.test", None), Some("/")))
+    )]
+    #[case(
+        "user:pw:@foo.test/",
+        "",
+        Ok((auth(userinfo("user", Some("pw:")), "foo.test", None), Some("/")))
+    )]
+    #[case(
+        ":pw@foo.test/",
+        "",
+        Ok((auth(userinfo("", Some("pw")), "foo.test", None), Some("/")))
+    )]
+    #[case("foo.test:80:81/", "", Err(AuthorityError::InvalidPort))]
+    #[case("user@", "", Err(AuthorityError::MissingHost))]
+    #[case("user:pw@/path", "path", Err(AuthorityError::MissingHost))]
     #[case(
         "/path/nowhere?foo=bar",
         "path/nowhere?foo=bar",
@@ -6,7 +6,7 @@ use crate::{Scheme, SchemeError};
 
 #[derive(Debug, Logos)]
 pub(super) enum SchemeToken<'uri> {
-    #[regex(r"[a-z]+", |lex| lex.slice(), priority = 200)]
+    #[regex(r"[a-zA-Z][a-zA-Z0-9+\-.]*", |lex| lex.slice(), priority = 200)]
     MaybeScheme(&'uri str),
 
     #[regex(r":")]
@@ -51,6 +51,7 @@ pub fn parse_scheme<'uri>(
 mod test {
     use super::*;
     use crate::ParsingDetail;
+    use rstest::rstest;
 
     #[test]
     fn parse_http() {
@@ -81,7 +82,7 @@ mod test {
     }
     #[test]
     fn parse_error() {
-        let mut lexer = SchemeToken::lexer("http-");
+        let mut lexer = SchemeToken::lexer("http_");
         let p = parse_scheme(&mut lexer);
         assert_eq!(
             p,
@@ -89,10 +90,44 @@ mod test {
                 component: "scheme",
                 span_start: 4,
                 span_end: 5,
-                source: "http-",
-                clipped_span: "-",
-                clipped_remaining: "-"
+                source: "http_",
+                clipped_span: "_",
+                clipped_remaining: "_"
             }))
         );
     }
+    #[test]
+    fn parse_error_leading_digit() {
+        let mut lexer = SchemeToken::lexer("1http:");
+        let p = parse_scheme(&mut lexer);
+        assert_eq!(
+            p,
+            Err(SchemeError::ParsingDetailed(ParsingDetail {
+                component: "scheme",
+                span_start: 0,
+                span_end: 1,
+                source: "1http:",
+                clipped_span: "1",
+                clipped_remaining: "1http:"
+            }))
+        );
+    }
+    #[rstest]
+    #[case("svn+ssh://svn.test", Scheme::Unknown("svn+ssh"), "//svn.test")]
+    #[case("coap+tcp://coap.test", Scheme::Unknown("coap+tcp"), "//coap.test")]
+    #[case("z39.50r://z.test", Scheme::Unknown("z39.50r"), "//z.test")]
+    #[case("ms-settings:display", Scheme::Unknown("ms-settings"), "display")]
+    #[case("HTTP://foobar.test", Scheme::Http(false), "//foobar.test")]
+    #[case("Https://foobar.test", Scheme::Http(true), "//foobar.test")]
+    #[case("URN:isbn:0451450523", Scheme::Urn, "isbn:0451450523")]
+    fn parse_full_grammar(
+        #[case] s: &'static str,
+        #[case] expected: Scheme<'static>,
+        #[case] remaining: &'static str,
+    ) {
+        let mut lexer = SchemeToken::lexer(s);
+        let p = parse_scheme(&mut lexer);
+        assert_eq!(p, Ok(expected));
+        assert_eq!(lexer.remainder(), remaining);
+    }
 }
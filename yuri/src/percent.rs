@@ -0,0 +1,546 @@
+//! Percent-encoding - RFC 3986 s. 2.1
+
+use crate::buf::SliceWriter;
+use crate::error::{PercentDecodeError, PercentEncodeError};
+
+/// Percent-decode the given raw component
+pub fn percent_decode(raw: &str) -> PercentDecode<'_> {
+    PercentDecode {
+        bytes: raw.as_bytes(),
+        plus_as_space: false,
+    }
+}
+
+/// Decode as application/x-www-form-urlencoded where "+" is a space
+pub fn form_decode(raw: &str) -> PercentDecode<'_> {
+    PercentDecode {
+        bytes: raw.as_bytes(),
+        plus_as_space: true,
+    }
+}
+
+/// Iterator decoding percent-encoded bytes without allocating.
+///
+/// Malformed escapes e.g. "%G1" or a trailing "%" are passed through as-is,
+/// see [`PercentDecode::decode_into`] for strict decoding.
+#[derive(Clone, Debug)]
+pub struct PercentDecode<'a> {
+    bytes: &'a [u8],
+    plus_as_space: bool,
+}
+
+fn hex_value(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decode the escape at the start of the given bytes if valid
+fn decode_escape(bytes: &[u8]) -> Option<u8> {
+    match bytes {
+        [b'%', hi, lo, ..] => Some(hex_value(*hi)? << 4 | hex_value(*lo)?),
+        _ => None,
+    }
+}
+
+impl<'a> Iterator for PercentDecode<'a> {
+    type Item = u8;
+    fn next(&mut self) -> Option<u8> {
+        let (first, rest) = self.bytes.split_first()?;
+        match decode_escape(self.bytes) {
+            Some(decoded) => {
+                self.bytes = &self.bytes[3..];
+                Some(decoded)
+            }
+            None => {
+                self.bytes = rest;
+                match *first {
+                    b'+' if self.plus_as_space => Some(b' '),
+                    b => Some(b),
+                }
+            }
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.bytes.len().div_ceil(3), Some(self.bytes.len()))
+    }
+}
+
+impl<'a> PercentDecode<'a> {
+    /// Decoded chars with invalid UTF-8 replaced by U+FFFD
+    pub fn chars(self) -> DecodeChars<'a> {
+        DecodeChars { bytes: self }
+    }
+    /// Strictly decode into the given buffer returning the decoded length.
+    pub fn decode_into(self, buf: &mut [u8]) -> Result<usize, PercentDecodeError> {
+        let mut pos = 0;
+        let mut len = 0;
+        let bytes = self.bytes;
+        while pos < bytes.len() {
+            let decoded = match bytes[pos] {
+                b'%' if bytes.len() < pos + 3 => {
+                    return Err(PercentDecodeError::TruncatedEscape(pos))
+                }
+                b'%' => {
+                    decode_escape(&bytes[pos..]).ok_or(PercentDecodeError::InvalidEscape(pos))?
+                }
+                b'+' if self.plus_as_space => b' ',
+                b => b,
+            };
+            pos += if bytes[pos] == b'%' { 3 } else { 1 };
+            *buf.get_mut(len).ok_or(PercentDecodeError::BufferTooSmall)? = decoded;
+            len += 1;
+        }
+        Ok(len)
+    }
+}
+
+/// Iterator of decoded chars, see [`PercentDecode::chars`]
+#[derive(Clone, Debug)]
+pub struct DecodeChars<'a> {
+    bytes: PercentDecode<'a>,
+}
+
+impl<'a> Iterator for DecodeChars<'a> {
+    type Item = char;
+    fn next(&mut self) -> Option<char> {
+        let first = self.bytes.next()?;
+        let width = match first {
+            0x00..=0x7f => return Some(char::from(first)),
+            0xc2..=0xdf => 2,
+            0xe0..=0xef => 3,
+            0xf0..=0xf4 => 4,
+            _ => return Some(char::REPLACEMENT_CHARACTER),
+        };
+        let mut utf8 = [first, 0, 0, 0];
+        for continuation in utf8.iter_mut().take(width).skip(1) {
+            // Only consume continuation bytes so the next char starts cleanly
+            let mut peek = self.bytes.clone();
+            match peek.next() {
+                Some(b) if b & 0xc0 == 0x80 => {
+                    *continuation = b;
+                    self.bytes = peek;
+                }
+                _ => return Some(char::REPLACEMENT_CHARACTER),
+            }
+        }
+        core::str::from_utf8(&utf8[..width])
+            .ok()
+            .and_then(|s| s.chars().next())
+            .or(Some(char::REPLACEMENT_CHARACTER))
+    }
+}
+
+/// Characters allowed unencoded by component - RFC 3986 s. 3
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum EncodeSet {
+    /// segment = *pchar
+    PathSegment,
+    /// path = *( pchar / "/" )
+    Path,
+    /// query = *( pchar / "/" / "?" )
+    Query,
+    /// query key without the pair separators "&" ";" "=" and "+" as space
+    QueryKey,
+    /// query value without the pair separators "&" ";" and "+" as space
+    QueryValue,
+    /// fragment = *( pchar / "/" / "?" )
+    Fragment,
+    /// userinfo without the ":" separating user and authorization
+    UserInfo,
+    /// userinfo after the first ":" where any further ":" is allowed
+    Authorization,
+    /// reg-name = *( unreserved / pct-encoded / sub-delims )
+    RegName,
+    /// application/x-www-form-urlencoded with ALPHA / DIGIT and "*-._"
+    /// where a space is written as "+"
+    Form,
+    /// unreserved only e.g. for canonical query strings
+    Unreserved,
+}
+
+fn is_unreserved(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~')
+}
+
+fn is_sub_delim(b: u8) -> bool {
+    matches!(
+        b,
+        b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'='
+    )
+}
+
+fn is_pchar(b: u8) -> bool {
+    is_unreserved(b) || is_sub_delim(b) || matches!(b, b':' | b'@')
+}
+
+impl EncodeSet {
+    /// Whether the byte is allowed unencoded
+    pub(crate) fn allows(self, b: u8) -> bool {
+        match self {
+            Self::PathSegment => is_pchar(b),
+            Self::Path => is_pchar(b) || b == b'/',
+            Self::Query | Self::Fragment => is_pchar(b) || matches!(b, b'/' | b'?'),
+            Self::QueryKey => {
+                (is_pchar(b) || matches!(b, b'/' | b'?')) && !matches!(b, b'&' | b';' | b'=' | b'+')
+            }
+            Self::QueryValue => {
+                (is_pchar(b) || matches!(b, b'/' | b'?')) && !matches!(b, b'&' | b';' | b'+')
+            }
+            Self::UserInfo | Self::RegName => is_unreserved(b) || is_sub_delim(b),
+            Self::Authorization => is_unreserved(b) || is_sub_delim(b) || b == b':',
+            Self::Form => b.is_ascii_alphanumeric() || matches!(b, b'*' | b'-' | b'.' | b'_'),
+            Self::Unreserved => is_unreserved(b),
+        }
+    }
+    /// Position of the first byte that is neither allowed nor a valid escape
+    pub(crate) fn find_invalid(self, raw: &str) -> Option<usize> {
+        let bytes = raw.as_bytes();
+        let mut pos = 0;
+        while pos < bytes.len() {
+            match bytes[pos] {
+                b'%' if decode_escape(&bytes[pos..]).is_some() => pos += 3,
+                b if self.allows(b) => pos += 1,
+                _ => return Some(pos),
+            }
+        }
+        None
+    }
+}
+
+/// RFC 3987 s. 2.2 - ucschar
+fn is_ucschar(c: char) -> bool {
+    let c = u32::from(c);
+    matches!(c, 0xa0..=0xd7ff | 0xf900..=0xfdcf | 0xfdf0..=0xffef)
+        || (0x10000..=0xdfffd).contains(&c) && c & 0xfffe != 0xfffe
+        || (0xe1000..=0xefffd).contains(&c) && c & 0xfffe != 0xfffe
+}
+
+/// RFC 3987 s. 2.2 - iprivate
+fn is_iprivate(c: char) -> bool {
+    matches!(u32::from(c), 0xe000..=0xf8ff | 0xf0000..=0xffffd | 0x100000..=0x10fffd)
+}
+
+impl EncodeSet {
+    /// As [`EncodeSet::find_invalid`] with the RFC 3987 ucschar allowed
+    /// and iprivate in the query
+    pub(crate) fn find_invalid_iri(self, raw: &str) -> Option<usize> {
+        let bytes = raw.as_bytes();
+        let mut pos = 0;
+        while let Some(c) = raw[pos..].chars().next() {
+            match c {
+                '%' if decode_escape(&bytes[pos..]).is_some() => pos += 3,
+                c if c.is_ascii() && self.allows(c as u8) => pos += 1,
+                c if is_ucschar(c) || (self == Self::Query && is_iprivate(c)) => {
+                    pos += c.len_utf8()
+                }
+                _ => return Some(pos),
+            }
+        }
+        None
+    }
+}
+
+/// core::fmt::Write adapter percent-encoding all non-ASCII
+/// e.g. mapping an IRI to a URI as per RFC 3987 s. 3.1
+pub(crate) struct EncodeNonAscii<'w, W> {
+    pub(crate) inner: &'w mut W,
+}
+
+impl<'w, W: fmt::Write> fmt::Write for EncodeNonAscii<'w, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c.is_ascii() {
+                true => self.inner.write_char(c)?,
+                false => {
+                    for b in c.encode_utf8(&mut [0; 4]).bytes() {
+                        write!(self.inner, "%{:02X}", b)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Percent-encode a single path segment, "/" is encoded
+pub fn encode_path_segment(input: &str) -> PercentEncode<'_> {
+    PercentEncode::new(input, EncodeSet::PathSegment)
+}
+
+/// Percent-encode a query key, "&" ";" "=" and "+" are encoded
+pub fn encode_query_key(input: &str) -> PercentEncode<'_> {
+    PercentEncode::new(input, EncodeSet::QueryKey)
+}
+
+/// Percent-encode a query value, "&" ";" and "+" are encoded
+pub fn encode_query_value(input: &str) -> PercentEncode<'_> {
+    PercentEncode::new(input, EncodeSet::QueryValue)
+}
+
+/// Encode as application/x-www-form-urlencoded where a space is "+" and
+/// all but ALPHA / DIGIT and "*-._" is percent-encoded
+pub fn form_encode(input: &str) -> PercentEncode<'_> {
+    PercentEncode::new(input, EncodeSet::Form)
+}
+
+/// Percent-encode all but the unreserved where a space is "%20"
+/// e.g. for canonical query strings of signed requests
+pub fn encode_unreserved(input: &str) -> PercentEncode<'_> {
+    PercentEncode::new(input, EncodeSet::Unreserved)
+}
+
+/// Percent-encode a fragment
+pub fn encode_fragment(input: &str) -> PercentEncode<'_> {
+    PercentEncode::new(input, EncodeSet::Fragment)
+}
+
+/// Percent-encode a userinfo user or authorization part, ":" is encoded
+pub fn encode_userinfo(input: &str) -> PercentEncode<'_> {
+    PercentEncode::new(input, EncodeSet::UserInfo)
+}
+
+/// Percent-encoding of a component value without allocating.
+///
+/// Write it through [`core::fmt::Display`] / [`PercentEncode::write_to`]
+/// or into a byte buffer with [`PercentEncode::encode_into`].
+#[derive(Clone, Debug)]
+pub struct PercentEncode<'a> {
+    input: &'a str,
+    set: EncodeSet,
+}
+
+const UPPER_HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+impl<'a> PercentEncode<'a> {
+    pub(crate) fn new(input: &'a str, set: EncodeSet) -> Self {
+        Self { input, set }
+    }
+    /// Write the encoded value into the given writer
+    pub fn write_to<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        let bytes = self.input.as_bytes();
+        let mut run_start = 0;
+        for (pos, b) in bytes.iter().enumerate() {
+            if self.set.allows(*b) {
+                continue;
+            }
+            // Non-ASCII is always encoded so runs start & end on char boundaries
+            if run_start < pos {
+                w.write_str(&self.input[run_start..pos])?;
+            }
+            run_start = pos + 1;
+            if *b == b' ' && self.set == EncodeSet::Form {
+                w.write_char('+')?;
+                continue;
+            }
+            let escape = [
+                b'%',
+                UPPER_HEX[usize::from(b >> 4)],
+                UPPER_HEX[usize::from(b & 0x0f)],
+            ];
+            for c in escape {
+                w.write_char(char::from(c))?;
+            }
+        }
+        w.write_str(&self.input[run_start..])
+    }
+    /// Encode into the given buffer returning the encoded str
+    pub fn encode_into<'b>(&self, buf: &'b mut [u8]) -> Result<&'b str, PercentEncodeError> {
+        let mut w = SliceWriter::new(buf);
+        self.write_to(&mut w)
+            .map_err(|_| PercentEncodeError::BufferTooSmall)?;
+        Ok(w.into_str())
+    }
+}
+
+/// Percent-encoding normalisation as per RFC 3986 s. 6.2.2.2
+///
+/// Escapes of unreserved characters are decoded and the hex digits of the
+/// remaining escapes are uppercased e.g. "%7efoo%2f" yields "~foo%2F".
+#[derive(Clone, Debug)]
+pub(crate) struct PercentNormalize<'a> {
+    bytes: &'a [u8],
+    pending: [u8; 2],
+    pending_pos: usize,
+    lowercase: bool,
+}
+
+impl<'a> PercentNormalize<'a> {
+    /// Normalise the given raw component, lowercasing e.g. for the host
+    pub(crate) fn new(raw: &'a str, lowercase: bool) -> Self {
+        Self {
+            bytes: raw.as_bytes(),
+            pending: [0; 2],
+            pending_pos: 2,
+            lowercase,
+        }
+    }
+    fn case(&self, b: u8) -> u8 {
+        match self.lowercase {
+            true => b.to_ascii_lowercase(),
+            false => b,
+        }
+    }
+}
+
+impl<'a> Iterator for PercentNormalize<'a> {
+    type Item = u8;
+    fn next(&mut self) -> Option<u8> {
+        if let Some(b) = self.pending.get(self.pending_pos) {
+            self.pending_pos += 1;
+            return Some(*b);
+        }
+        let (first, rest) = self.bytes.split_first()?;
+        match decode_escape(self.bytes) {
+            Some(b) => {
+                self.bytes = &self.bytes[3..];
+                if is_unreserved(b) {
+                    return Some(self.case(b));
+                }
+                self.pending = [
+                    UPPER_HEX[usize::from(b >> 4)],
+                    UPPER_HEX[usize::from(b & 0x0f)],
+                ];
+                self.pending_pos = 0;
+                Some(b'%')
+            }
+            None => {
+                self.bytes = rest;
+                Some(self.case(*first))
+            }
+        }
+    }
+}
+
+use core::fmt;
+use core::fmt::Display;
+
+impl<'a> Display for PercentEncode<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("foo%20bar", b"foo bar")]
+    #[case("%7Efoo%2fbar", b"~foo/bar")]
+    #[case("%G1%", b"%G1%")]
+    #[case("100%", b"100%")]
+    #[case("%e4%be%8b", "例".as_bytes())]
+    fn decode_bytes(#[case] raw: &'static str, #[case] expected: &'static [u8]) {
+        let decoded: Vec<u8> = percent_decode(raw).collect();
+        assert_eq!(decoded, expected);
+    }
+
+    #[test]
+    fn decode_form() {
+        let decoded: String = form_decode("a+b%2Bc").chars().collect();
+        assert_eq!(decoded, "a b+c");
+        let mut buf = [0u8; 8];
+        let len = form_decode("a+b%2Bc").decode_into(&mut buf).unwrap();
+        assert_eq!(&buf[..len], b"a b+c");
+        let decoded: Vec<u8> = percent_decode("a+b").collect();
+        assert_eq!(decoded, b"a+b");
+    }
+
+    #[rstest]
+    #[case("foo%20bar", "foo bar")]
+    #[case("%e4%be%8b%E3%81%88", "例え")]
+    #[case("a%ffb", "a\u{fffd}b")]
+    #[case("a%e4%beb", "a\u{fffd}b")]
+    fn decode_chars(#[case] raw: &'static str, #[case] expected: &'static str) {
+        let decoded: String = percent_decode(raw).chars().collect();
+        assert_eq!(decoded, expected);
+    }
+
+    #[rstest]
+    #[case("foo%20bar", Ok(7))]
+    #[case("%G1", Err(PercentDecodeError::InvalidEscape(0)))]
+    #[case("ab%1G", Err(PercentDecodeError::InvalidEscape(2)))]
+    #[case("ab%", Err(PercentDecodeError::TruncatedEscape(2)))]
+    #[case("ab%2", Err(PercentDecodeError::TruncatedEscape(2)))]
+    #[case("0123456789", Err(PercentDecodeError::BufferTooSmall))]
+    fn decode_into(#[case] raw: &'static str, #[case] expected: Result<usize, PercentDecodeError>) {
+        let mut buf = [0u8; 8];
+        assert_eq!(percent_decode(raw).decode_into(&mut buf), expected);
+    }
+
+    #[rstest]
+    #[case(encode_path_segment("a b/c;d=e"), "a%20b%2Fc;d=e")]
+    #[case(encode_path_segment("100%"), "100%25")]
+    #[case(encode_path_segment("例"), "%E4%BE%8B")]
+    #[case(encode_query_key("a=b&c+d/e?"), "a%3Db%26c%2Bd/e?")]
+    #[case(encode_query_value("a=b&c d;e"), "a=b%26c%20d%3Be")]
+    #[case(encode_fragment("top #1/?"), "top%20%231/?")]
+    #[case(encode_userinfo("user:p@ss"), "user%3Ap%40ss")]
+    #[case(encode_userinfo("-._~!$&'()*+,;="), "-._~!$&'()*+,;=")]
+    fn encode(#[case] encoded: PercentEncode<'static>, #[case] expected: &'static str) {
+        assert_eq!(format!("{}", encoded), expected);
+        let decoded: String = percent_decode(expected).chars().collect();
+        assert_eq!(decoded, encoded.input);
+    }
+
+    #[rstest]
+    #[case(form_encode("a b"), "a+b")]
+    #[case(form_encode("a+b&c=d"), "a%2Bb%26c%3Dd")]
+    #[case(form_encode("/?:@!$'(),~"), "%2F%3F%3A%40%21%24%27%28%29%2C%7E")]
+    #[case(form_encode("*-._"), "*-._")]
+    #[case(form_encode("例 x"), "%E4%BE%8B+x")]
+    #[case(encode_unreserved("a b+c/~"), "a%20b%2Bc%2F~")]
+    #[case(encode_unreserved("*例"), "%2A%E4%BE%8B")]
+    fn encode_query_sets(#[case] encoded: PercentEncode<'static>, #[case] expected: &'static str) {
+        assert_eq!(format!("{}", encoded), expected);
+        let decoded: String = form_decode(expected).chars().collect();
+        assert_eq!(decoded, encoded.input);
+    }
+
+    #[test]
+    fn encode_into_buffer() {
+        let mut buf = [0u8; 8];
+        assert_eq!(
+            encode_path_segment("a b").encode_into(&mut buf),
+            Ok("a%20b")
+        );
+        assert_eq!(
+            encode_path_segment("a b c").encode_into(&mut buf),
+            Err(PercentEncodeError::BufferTooSmall)
+        );
+    }
+
+    #[rstest]
+    #[case(EncodeSet::Path, "/a%20b/c", None)]
+    #[case(EncodeSet::Path, "/a b", Some(2))]
+    #[case(EncodeSet::Path, "/a%G1", Some(2))]
+    #[case(EncodeSet::Query, "q=a&m=s/?", None)]
+    #[case(EncodeSet::Fragment, "f#", Some(1))]
+    fn find_invalid(
+        #[case] set: EncodeSet,
+        #[case] raw: &'static str,
+        #[case] expected: Option<usize>,
+    ) {
+        assert_eq!(set.find_invalid(raw), expected);
+    }
+
+    #[rstest]
+    #[case("%7efoo%2fbar", false, "~foo%2Fbar")]
+    #[case("%41%42%c3%a4", false, "AB%C3%A4")]
+    #[case("Foo.%41%2a", true, "foo.a%2A")]
+    #[case("100%", false, "100%")]
+    #[case("%G1", false, "%G1")]
+    fn normalize_bytes(
+        #[case] raw: &'static str,
+        #[case] lowercase: bool,
+        #[case] expected: &'static str,
+    ) {
+        let normalized: Vec<u8> = PercentNormalize::new(raw, lowercase).collect();
+        assert_eq!(normalized, expected.as_bytes());
+    }
+}
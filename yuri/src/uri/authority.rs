@@ -7,22 +7,67 @@ pub struct Authority<'uri> {
     pub userinfo: Option<UserInfo<'uri>>,
     /// Raw unparsed host
     pub raw_host: &'uri str,
-    /// Port if supplied, otherwise default by scheme
+    /// Host parsed from the raw host
+    pub host: Host<'uri>,
+    /// Port if supplied, see [`crate::Uri::effective_port`] for the default by scheme
     pub port: Option<u16>,
+    /// Port as given when not written back the same e.g. "" or "0080"
+    pub(crate) raw_port: Option<&'uri str>,
+    /// Given without the "//" as accepted for the hierarchical schemes
+    pub(crate) without_slashes: bool,
 }
 
-use crate::error::AuthorityError;
+use crate::error::{AuthorityError, PercentDecodeError};
+use crate::percent::EncodeSet;
+use crate::{percent_decode, Host, PercentDecode};
 
 impl<'uri> Authority<'uri> {
     /// Construct new with host
-    #[cfg(feature = "builder")]
     pub fn from_host_str(host: &'uri str) -> Result<Self, AuthorityError<'uri>> {
         Ok(Self {
             userinfo: None,
             raw_host: host,
+            host: Host::parse_checked(host)?,
             port: None,
+            raw_port: None,
+            without_slashes: false,
         })
     }
+    /// Raw port as given when it is not written back the same from the
+    /// port e.g. "" for "foobar.test:" or "0080" for "foobar.test:0080"
+    pub fn raw_port(&self) -> Option<&'uri str> {
+        let raw_port = self.raw_port?;
+        let port = match raw_port.is_empty() {
+            true => None,
+            false => raw_port.parse().ok(),
+        };
+        (port == self.port).then_some(raw_port)
+    }
+    /// Check the parts given directly would parse back the same
+    pub(crate) fn check(&self) -> Result<(), AuthorityError<'uri>> {
+        if let Some(userinfo) = &self.userinfo {
+            let invalid_user = EncodeSet::UserInfo
+                .find_invalid(userinfo.raw_user)
+                .is_some();
+            let invalid_authorization = userinfo
+                .raw_authorization
+                .is_some_and(|auth| EncodeSet::Authorization.find_invalid(auth).is_some());
+            if invalid_user || invalid_authorization {
+                return Err(AuthorityError::InvalidAuthority);
+            }
+        }
+        match Host::parse_checked(self.raw_host)? == self.host {
+            true => Ok(()),
+            false => Err(AuthorityError::InvalidHost(self.raw_host)),
+        }
+    }
+}
+
+/// Whether the port as given differs from the parsed port written back
+pub(crate) fn non_canonical_port(raw_port: &str) -> bool {
+    raw_port.is_empty()
+        || (raw_port.len() > 1 && raw_port.starts_with('0'))
+        || !raw_port.bytes().all(|b| b.is_ascii_digit())
 }
 
 /// Authority userinfo
@@ -41,6 +86,18 @@ impl<'uri> UserInfo<'uri> {
     pub fn raw_authorization(&self) -> Option<&'uri str> {
         self.raw_authorization
     }
+    /// Percent-decoded user part
+    pub fn decode_user(&self) -> PercentDecode<'uri> {
+        percent_decode(self.raw_user)
+    }
+    /// Strictly percent-decode the user part into the given buffer returning the decoded length
+    pub fn decode_user_into(&self, buf: &mut [u8]) -> Result<usize, PercentDecodeError> {
+        self.decode_user().decode_into(buf)
+    }
+    /// Percent-decoded authorization part
+    pub fn decode_authorization(&self) -> Option<PercentDecode<'uri>> {
+        self.raw_authorization.map(percent_decode)
+    }
     /// Set raw authorization part
     /// **NOTE**: RFC has deprecated this field for cleartext passwords which are insecure.
     #[cfg(feature = "builder")]
@@ -49,3 +106,31 @@ impl<'uri> UserInfo<'uri> {
         self.raw_authorization = auth;
     }
 }
+
+use core::fmt;
+use core::fmt::Display;
+
+impl<'uri> Display for Authority<'uri> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(userinfo) = &self.userinfo {
+            write!(f, "{}@", userinfo)?;
+        }
+        f.write_str(self.raw_host)?;
+        match (self.raw_port(), self.port) {
+            (Some(raw_port), _) => write!(f, ":{}", raw_port)?,
+            (None, Some(port)) => write!(f, ":{}", port)?,
+            (None, None) => {}
+        }
+        Ok(())
+    }
+}
+
+impl<'uri> Display for UserInfo<'uri> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.raw_user)?;
+        if let Some(authorization) = self.raw_authorization {
+            write!(f, ":{}", authorization)?;
+        }
+        Ok(())
+    }
+}
@@ -6,3 +6,26 @@ pub struct Fragment<'uri> {
     /// Raw fragment
     pub raw_fragment: &'uri str,
 }
+
+use crate::error::PercentDecodeError;
+use crate::{percent_decode, PercentDecode};
+
+impl<'uri> Fragment<'uri> {
+    /// Percent-decoded bytes, see [`PercentDecode::chars`] for chars
+    pub fn decode(&self) -> PercentDecode<'uri> {
+        percent_decode(self.raw_fragment)
+    }
+    /// Strictly percent-decode into the given buffer returning the decoded length
+    pub fn decode_into(&self, buf: &mut [u8]) -> Result<usize, PercentDecodeError> {
+        self.decode().decode_into(buf)
+    }
+}
+
+use core::fmt;
+use core::fmt::Display;
+
+impl<'uri> Display for Fragment<'uri> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.raw_fragment)
+    }
+}
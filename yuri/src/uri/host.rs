@@ -0,0 +1,268 @@
+//! URI Host types
+
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Host - RFC 3986 s. 3.2.2
+#[derive(Clone, Debug, PartialEq)]
+pub enum Host<'uri> {
+    /// Registered name e.g. DNS name
+    RegName(&'uri str),
+    /// IPv4 address in dotted-decimal
+    Ipv4(Ipv4Addr),
+    /// IPv6 address literal without the brackets
+    Ipv6(Ipv6Addr),
+    /// IPv6 address literal with a RFC 6874 zone e.g. [fe80::1%25eth0]
+    Ipv6Zone {
+        /// Address
+        addr: Ipv6Addr,
+        /// Raw zone ID after the "%25"
+        zone: &'uri str,
+    },
+    /// IPvFuture literal e.g. [v7.foo]
+    IpvFuture {
+        /// Version in hex
+        version: &'uri str,
+        /// Address data
+        data: &'uri str,
+    },
+}
+
+use crate::error::AuthorityError;
+#[cfg(feature = "idna")]
+use crate::error::IdnaError;
+use crate::percent::EncodeSet;
+
+impl<'uri> TryFrom<&'uri str> for Host<'uri> {
+    type Error = AuthorityError<'uri>;
+    fn try_from(raw: &'uri str) -> Result<Self, Self::Error> {
+        let invalid = || AuthorityError::InvalidHost(raw);
+
+        if let Some(literal) = raw.strip_prefix('[') {
+            let literal = literal.strip_suffix(']').ok_or_else(invalid)?;
+            return match literal.strip_prefix(['v', 'V']) {
+                Some(future) => parse_ipvfuture(future).ok_or_else(invalid),
+                None => match literal.split_once("%25") {
+                    Some((addr, zone)) if is_zone_id(zone) => addr
+                        .parse()
+                        .map(|addr| Self::Ipv6Zone { addr, zone })
+                        .map_err(|_| invalid()),
+                    Some(_) => Err(invalid()),
+                    None => literal.parse().map(Self::Ipv6).map_err(|_| invalid()),
+                },
+            };
+        }
+
+        // IPv4address otherwise a reg-name e.g. "1.2.3." or "01.2.3.4" except
+        // for dec-octet shaped out of range octets e.g. "999.1.1.1" which are
+        // rejected as a deliberate deviation from RFC 3986 s. 3.2.2
+        let mut octets = [0u16; 4];
+        let mut count = 0;
+        for octet in raw.split('.') {
+            match (octets.get_mut(count), parse_dec_octet(octet)) {
+                (Some(slot), Some(value)) => *slot = value,
+                _ => return Ok(Self::RegName(raw)),
+            }
+            count += 1;
+        }
+        match (count, octets.map(u8::try_from)) {
+            (4, [Ok(a), Ok(b), Ok(c), Ok(d)]) => Ok(Self::Ipv4(Ipv4Addr::new(a, b, c, d))),
+            (4, _) => Err(invalid()),
+            _ => Ok(Self::RegName(raw)),
+        }
+    }
+}
+
+impl<'uri> Host<'uri> {
+    /// Parse as per [`Host::try_from`] with a reg-name limited to
+    /// unreserved, pct-encoded & sub-delims - RFC 3986 s. 3.2.2
+    pub(crate) fn parse_checked(raw: &'uri str) -> Result<Self, AuthorityError<'uri>> {
+        match Self::try_from(raw)? {
+            Self::RegName(name) if EncodeSet::RegName.find_invalid(name).is_some() => {
+                Err(AuthorityError::InvalidHost(raw))
+            }
+            host => Ok(host),
+        }
+    }
+    /// IP address if the host is an IPv4 / IPv6 address
+    pub fn ip_addr(&self) -> Option<IpAddr> {
+        match self {
+            Self::Ipv4(addr) => Some(IpAddr::V4(*addr)),
+            Self::Ipv6(addr) | Self::Ipv6Zone { addr, .. } => Some(IpAddr::V6(*addr)),
+            _ => None,
+        }
+    }
+    /// Raw IPv6 zone ID if any e.g. "eth0" for [fe80::1%25eth0]
+    pub fn zone_id(&self) -> Option<&'uri str> {
+        match self {
+            Self::Ipv6Zone { zone, .. } => Some(zone),
+            _ => None,
+        }
+    }
+    /// Host with a reg-name converted into Unicode in the given buffer,
+    /// see [`domain_to_unicode`](crate::domain_to_unicode).
+    /// IP addresses are written as-is.
+    #[cfg(feature = "idna")]
+    pub fn to_unicode<'b>(&self, buf: &'b mut [u8]) -> Result<&'b str, IdnaError<'uri>> {
+        match self {
+            Self::RegName(name) => crate::domain_to_unicode(name, buf),
+            host => write_host(host, buf),
+        }
+    }
+    /// Host with a reg-name converted into ASCII in the given buffer,
+    /// see [`domain_to_ascii`](crate::domain_to_ascii).
+    /// IP addresses are written as-is.
+    #[cfg(feature = "idna")]
+    pub fn to_ascii<'b>(&self, buf: &'b mut [u8]) -> Result<&'b str, IdnaError<'uri>> {
+        match self {
+            Self::RegName(name) => crate::domain_to_ascii(name, buf),
+            host => write_host(host, buf),
+        }
+    }
+}
+
+#[cfg(feature = "idna")]
+fn write_host<'b>(host: &Host<'_>, buf: &'b mut [u8]) -> Result<&'b str, IdnaError<'static>> {
+    use core::fmt::Write;
+    let mut w = crate::buf::SliceWriter::new(buf);
+    write!(w, "{}", host).map_err(|_| IdnaError::BufferTooSmall)?;
+    Ok(w.into_str())
+}
+
+/// RFC 3986 s. 3.2.2 - dec-octet shaped digits without the 255 limit
+fn parse_dec_octet(raw: &str) -> Option<u16> {
+    let leading_zero = raw.len() > 1 && raw.starts_with('0');
+    match (1..=3).contains(&raw.len()) && !leading_zero {
+        true if raw.bytes().all(|b| b.is_ascii_digit()) => raw.parse().ok(),
+        _ => None,
+    }
+}
+
+/// RFC 6874 ZoneID = 1*( unreserved / pct-encoded )
+fn is_zone_id(zone: &str) -> bool {
+    let bytes = zone.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if bytes.len() > i + 2
+                && bytes[i + 1].is_ascii_hexdigit()
+                && bytes[i + 2].is_ascii_hexdigit() =>
+            {
+                i += 3
+            }
+            b if b.is_ascii_alphanumeric() || b"-._~".contains(&b) => i += 1,
+            _ => return false,
+        }
+    }
+    !zone.is_empty()
+}
+
+/// "v" 1*HEXDIG "." 1*( unreserved / sub-delims / ":" ) without the "v"
+fn parse_ipvfuture(future: &str) -> Option<Host<'_>> {
+    let (version, data) = future.split_once('.')?;
+    let valid_version = !version.is_empty() && version.bytes().all(|b| b.is_ascii_hexdigit());
+    let valid_data = !data.is_empty()
+        && data
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:".contains(&b));
+    (valid_version && valid_data).then_some(Host::IpvFuture { version, data })
+}
+
+use core::fmt;
+use core::fmt::Display;
+
+impl<'uri> Display for Host<'uri> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RegName(name) => f.write_str(name),
+            Self::Ipv4(addr) => write!(f, "{}", addr),
+            Self::Ipv6(addr) => write!(f, "[{}]", addr),
+            Self::Ipv6Zone { addr, zone } => write!(f, "[{}%25{}]", addr, zone),
+            Self::IpvFuture { version, data } => write!(f, "[v{}.{}]", version, data),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("foobar.test", Ok(Host::RegName("foobar.test")))]
+    #[case("localhost", Ok(Host::RegName("localhost")))]
+    #[case("192.0.2.16", Ok(Host::Ipv4(Ipv4Addr::new(192, 0, 2, 16))))]
+    #[case("1.2.3", Ok(Host::RegName("1.2.3")))]
+    #[case(
+        "[2001:db8::7]",
+        Ok(Host::Ipv6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 7)))
+    )]
+    #[case("[::1]", Ok(Host::Ipv6(Ipv6Addr::LOCALHOST)))]
+    #[case(
+        "[2001:DB8::7]",
+        Ok(Host::Ipv6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 7)))
+    )]
+    #[case("[::ffff:192.0.2.1]", Ok(Host::Ipv6(Ipv4Addr::new(192, 0, 2, 1).to_ipv6_mapped())))]
+    #[case("[fe80::1%25eth0]", Ok(Host::Ipv6Zone { addr: Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1), zone: "eth0" }))]
+    #[case("[fe80::1%25en%2F1]", Ok(Host::Ipv6Zone { addr: Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1), zone: "en%2F1" }))]
+    #[case("[fe80::1%eth0]", Err(AuthorityError::InvalidHost("[fe80::1%eth0]")))]
+    #[case("[fe80::1%25]", Err(AuthorityError::InvalidHost("[fe80::1%25]")))]
+    #[case(
+        "[fe80::1%25eth!]",
+        Err(AuthorityError::InvalidHost("[fe80::1%25eth!]"))
+    )]
+    #[case("[v7.foo:bar]", Ok(Host::IpvFuture { version: "7", data: "foo:bar" }))]
+    #[case("[vA1.x]", Ok(Host::IpvFuture { version: "A1", data: "x" }))]
+    #[case("999.1.1.1", Err(AuthorityError::InvalidHost("999.1.1.1")))]
+    #[case("256.1.1.1", Err(AuthorityError::InvalidHost("256.1.1.1")))]
+    #[case("1.2.3.", Ok(Host::RegName("1.2.3.")))]
+    #[case("01.2.3.4", Ok(Host::RegName("01.2.3.4")))]
+    #[case("1.2.3.4.5", Ok(Host::RegName("1.2.3.4.5")))]
+    #[case("1000.1.1.1", Ok(Host::RegName("1000.1.1.1")))]
+    #[case("0.0.0.0", Ok(Host::Ipv4(Ipv4Addr::UNSPECIFIED)))]
+    #[case("255.255.255.255", Ok(Host::Ipv4(Ipv4Addr::BROADCAST)))]
+    #[case("[zz::1]", Err(AuthorityError::InvalidHost("[zz::1]")))]
+    #[case("[::1", Err(AuthorityError::InvalidHost("[::1")))]
+    #[case("[v.x]", Err(AuthorityError::InvalidHost("[v.x]")))]
+    #[case("[v7.]", Err(AuthorityError::InvalidHost("[v7.]")))]
+    #[case("[vg.x]", Err(AuthorityError::InvalidHost("[vg.x]")))]
+    fn host_from_str(
+        #[case] s: &'static str,
+        #[case] expected: Result<Host<'static>, AuthorityError<'static>>,
+    ) {
+        assert_eq!(Host::try_from(s), expected);
+    }
+
+    #[rstest]
+    #[case("foobar.test")]
+    #[case("192.0.2.16")]
+    #[case("[2001:db8::7]")]
+    #[case("[v7.foo:bar]")]
+    #[case("[fe80::1%25eth0]")]
+    fn host_to_str(#[case] s: &'static str) {
+        assert_eq!(format!("{}", Host::try_from(s).unwrap()), s);
+    }
+
+    #[test]
+    fn host_zone_id() {
+        let host = Host::try_from("[fe80::1%25eth0]").unwrap();
+        assert_eq!(host.zone_id(), Some("eth0"));
+        assert_eq!(
+            host.ip_addr(),
+            Some(IpAddr::V6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1)))
+        );
+        assert_eq!(Host::RegName("foobar.test").ip_addr(), None);
+    }
+
+    #[cfg(feature = "idna")]
+    #[rstest]
+    #[case("xn--bcher-kva.example", "bücher.example", "xn--bcher-kva.example")]
+    #[case("Bücher.example", "bücher.example", "xn--bcher-kva.example")]
+    #[case("192.0.2.16", "192.0.2.16", "192.0.2.16")]
+    #[case("[2001:db8::7]", "[2001:db8::7]", "[2001:db8::7]")]
+    fn host_idna(#[case] s: &'static str, #[case] unicode: &str, #[case] ascii: &str) {
+        let host = Host::try_from(s).unwrap();
+        let mut buf = [0u8; 64];
+        assert_eq!(host.to_unicode(&mut buf), Ok(unicode));
+        assert_eq!(host.to_ascii(&mut buf), Ok(ascii));
+    }
+}
@@ -0,0 +1,152 @@
+//! RFC 3987 Internationalized Resource Identifiers
+
+use core::fmt;
+use core::fmt::Display;
+
+use crate::buf::SliceWriter;
+use crate::error::{IriError, UriError};
+use crate::percent::EncodeNonAscii;
+use crate::{Authority, Fragment, Path, Query, Scheme, SchemeData, Uri};
+
+/// IRI - a URI allowing the RFC 3987 ucschar e.g. "https://例え.テスト/パス"
+/// sharing the component types with [`Uri`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Iri<'iri> {
+    /// Scheme
+    pub scheme: Scheme<'iri>,
+    /// Authority
+    pub authority: Option<Authority<'iri>>,
+    /// Path
+    pub path: Option<Path<'iri>>,
+    /// Query
+    pub query: Option<Query<'iri>>,
+    /// Fragment
+    pub fragment: Option<Fragment<'iri>>,
+    /// Scheme based data
+    pub scheme_data: SchemeData<'iri>,
+}
+
+impl<'iri> Iri<'iri> {
+    /// Construct a new IRI from &str rejecting anything outside the
+    /// RFC 3987 ABNF of each component where the non-ASCII must be
+    /// ucschar or iprivate in the query.
+    ///
+    /// ```rust
+    /// assert!(yuri::Iri::new("https://例え.テスト/パス?q=値").is_ok());
+    /// assert!(yuri::Iri::new("https://foobar.test/\u{fffe}").is_err());
+    /// ```
+    pub fn new(input: &'iri str) -> Result<Self, UriError<'iri>> {
+        crate::parser::parse_iri(input)
+    }
+    /// Map into a URI as per RFC 3987 s. 3.1 percent-encoding the non-ASCII
+    /// as UTF-8 into the given buffer without allocating.
+    ///
+    /// The host is percent-encoded too, see `Host::to_ascii` with the
+    /// `idna` feature for the IDNA form.
+    ///
+    /// ```rust
+    /// let iri = yuri::Iri::new("https://foobar.test/パス?q=値").unwrap();
+    /// let mut buf = [0u8; 64];
+    /// let uri = iri.to_uri(&mut buf).unwrap();
+    /// assert_eq!(
+    ///     format!("{}", uri),
+    ///     "https://foobar.test/%E3%83%91%E3%82%B9?q=%E5%80%A4"
+    /// );
+    /// ```
+    pub fn to_uri<'buf>(&self, buf: &'buf mut [u8]) -> Result<Uri<'buf>, IriError<'buf>> {
+        let mut w = SliceWriter::new(buf);
+        self.write_uri_to(&mut w)
+            .map_err(|_| IriError::BufferTooSmall)?;
+        Uri::new(w.into_str()).map_err(IriError::Parse)
+    }
+    /// Serialize as the mapped URI into the given writer, see [`Iri::to_uri`].
+    pub fn write_uri_to<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        self.as_uri().write_to(&mut EncodeNonAscii { inner: w })
+    }
+    /// Serialize the IRI into the given writer without allocating.
+    pub fn write_to<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        self.as_uri().write_to(w)
+    }
+    /// Components as a URI for serializing
+    fn as_uri(&self) -> Uri<'iri> {
+        Uri {
+            scheme: self.scheme.clone(),
+            authority: self.authority.clone(),
+            path: self.path.clone(),
+            query: self.query.clone(),
+            fragment: self.fragment.clone(),
+            scheme_data: self.scheme_data.clone(),
+        }
+    }
+}
+
+impl<'iri> Display for Iri<'iri> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::{AuthorityError, FragmentError, PathError, QueryError};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        "https://例え.テスト/パス?q=値",
+        "https://%E4%BE%8B%E3%81%88.%E3%83%86%E3%82%B9%E3%83%88/%E3%83%91%E3%82%B9?q=%E5%80%A4"
+    )]
+    #[case(
+        "https://foobar.test/résumé#§1",
+        "https://foobar.test/r%C3%A9sum%C3%A9#%C2%A71"
+    )]
+    #[case(
+        "https://usér@foobar.test/?\u{e000}",
+        "https://us%C3%A9r@foobar.test/?%EE%80%80"
+    )]
+    #[case(
+        "https://foobar.test/a%20b/\u{10000}",
+        "https://foobar.test/a%20b/%F0%90%80%80"
+    )]
+    #[case("urn:example:ä", "urn:example:%C3%A4")]
+    #[case("https://[2001:db8::7]/", "https://[2001:db8::7]/")]
+    fn iri_to_uri(#[case] s: &'static str, #[case] expected: &str) {
+        let iri = Iri::new(s).unwrap();
+        assert_eq!(format!("{}", iri), s);
+        let mut buf = [0u8; 128];
+        let uri = iri.to_uri(&mut buf).unwrap();
+        assert_eq!(format!("{}", uri), expected);
+        assert_eq!(Uri::new_strict(expected), Ok(uri));
+    }
+
+    #[rstest]
+    #[case("https://foobar.test/a b", "path", 21)]
+    #[case("https://foobar.test/\u{fffe}", "path", 20)]
+    #[case("https://foobar.test/\u{e000}", "path", 20)]
+    #[case("https://foobar.test/?\u{e0001}", "query", 21)]
+    #[case("https://foobar.test/#\u{2028}\u{e000}", "fragment", 24)]
+    #[case("https://foo\u{fdd0}bar.test/", "authority", 11)]
+    fn iri_rejects(
+        #[case] s: &'static str,
+        #[case] component: &'static str,
+        #[case] span_start: usize,
+    ) {
+        let detail = match Iri::new(s) {
+            Err(UriError::Authority(AuthorityError::ParsingDetailed(d)))
+            | Err(UriError::Path(PathError::ParsingDetailed(d)))
+            | Err(UriError::Query(QueryError::ParsingDetailed(d)))
+            | Err(UriError::Fragment(FragmentError::ParsingDetailed(d))) => d,
+            res => panic!("{} -> {:?}", s, res),
+        };
+        assert_eq!(detail.component, component);
+        assert_eq!(detail.span_start, span_start);
+    }
+
+    #[test]
+    fn iri_to_uri_buffer_too_small() {
+        let iri = Iri::new("https://foobar.test/パス").unwrap();
+        let mut buf = [0u8; 24];
+        assert_eq!(iri.to_uri(&mut buf), Err(IriError::BufferTooSmall));
+    }
+}
@@ -0,0 +1,268 @@
+//! RFC 3986 s. 6.2.2 Syntax-based normalisation
+
+use core::fmt::Write;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use super::path::remove_dot_segments;
+use super::Path;
+use crate::buf::SliceWriter;
+use crate::error::NormalizeError;
+use crate::percent::PercentNormalize;
+use crate::{Scheme, Uri};
+
+impl<'uri> Uri<'uri> {
+    /// Normalise this URI into the given buffer as per RFC 3986 s. 6.2.2
+    /// and s. 6.2.3 without allocating.
+    ///
+    /// The scheme and host are lowercased, percent-encoding is normalised,
+    /// dot segments are removed from an absolute path, a default port is
+    /// dropped and an empty path is written as "/" for the known schemes
+    /// requiring an authority.
+    ///
+    /// ```rust
+    /// let uri = yuri::Uri::new("HTTP://Example.COM:80/a/../%7efoo").unwrap();
+    /// let mut buf = [0u8; 64];
+    /// let normalized = uri.normalize_into(&mut buf).unwrap();
+    /// assert_eq!(format!("{}", normalized), "http://example.com/~foo");
+    /// ```
+    pub fn normalize_into<'buf>(
+        &self,
+        buf: &'buf mut [u8],
+    ) -> Result<Uri<'buf>, NormalizeError<'buf>> {
+        let len = self.normalize_to(buf)?;
+        // Only ASCII is changed within the valid UTF-8 copied from self
+        let normalized = core::str::from_utf8(&buf[..len]).unwrap_or_default();
+        Uri::new(normalized).map_err(NormalizeError::Parse)
+    }
+    /// Normalise this URI into a String, see [`Uri::normalize_into`].
+    #[cfg(feature = "alloc")]
+    pub fn normalized(&self) -> Result<String, NormalizeError<'static>> {
+        // Normalising never grows a component beyond the "/" for an empty path
+        let mut counter = crate::buf::LenCounter(1);
+        self.write_to(&mut counter)
+            .map_err(|_| NormalizeError::BufferTooSmall)?;
+        let mut buf = alloc::vec![0u8; counter.0];
+        let len = self.normalize_to(&mut buf)?;
+        Ok(String::from_utf8_lossy(&buf[..len]).into_owned())
+    }
+    /// Whether both URIs are equivalent after normalisation without allocating,
+    /// see [`Uri::normalize_into`].
+    ///
+    /// ```rust
+    /// let a = yuri::Uri::new("HTTP://Example.COM:80/%7Efoo").unwrap();
+    /// let b = yuri::Uri::new("http://example.com/~foo").unwrap();
+    /// assert_ne!(a, b);
+    /// assert!(a.eq_normalized(&b));
+    /// ```
+    pub fn eq_normalized(&self, other: &Uri<'_>) -> bool {
+        let scheme_eq = match (&self.scheme, &other.scheme) {
+            (Scheme::Unknown(a), Scheme::Unknown(b)) => a.eq_ignore_ascii_case(b),
+            (a, b) => a == b,
+        };
+        let authority_eq = match (&self.authority, &other.authority) {
+            (None, None) => true,
+            (Some(a), Some(b)) => {
+                let userinfo_eq = match (&a.userinfo, &b.userinfo) {
+                    (None, None) => true,
+                    (Some(a), Some(b)) => {
+                        eq_pct(a.raw_user, b.raw_user, false)
+                            && eq_opt_pct(a.raw_authorization, b.raw_authorization)
+                    }
+                    _ => false,
+                };
+                userinfo_eq
+                    && eq_pct(a.raw_host, b.raw_host, true)
+                    && self.normalized_port() == other.normalized_port()
+            }
+            _ => false,
+        };
+        scheme_eq
+            && authority_eq
+            && eq_path(self.normalized_raw_path(), other.normalized_raw_path())
+            && eq_opt_pct(
+                self.query.as_ref().map(|q| q.raw_query),
+                other.query.as_ref().map(|q| q.raw_query),
+            )
+            && eq_opt_pct(
+                self.fragment.as_ref().map(|f| f.raw_fragment),
+                other.fragment.as_ref().map(|f| f.raw_fragment),
+            )
+    }
+    /// Port unless it is the default by scheme
+    fn normalized_port(&self) -> Option<u16> {
+        let default_port = self.scheme.default_port();
+        self.authority
+            .as_ref()?
+            .port
+            .filter(|port| Some(*port) != default_port)
+    }
+    /// RFC 3986 s. 6.2.3 - empty path is "/" for schemes requiring an authority
+    fn normalized_raw_path(&self) -> &'uri str {
+        match &self.path {
+            Some(path) if !path.raw_path.is_empty() => path.raw_path,
+            _ if self.authority.is_some() && self.scheme.requires_authority() => "/",
+            _ => "",
+        }
+    }
+    /// Write the normalised URI returning the length written
+    fn normalize_to(&self, buf: &mut [u8]) -> Result<usize, NormalizeError<'static>> {
+        let mut w = SliceWriter::new(buf);
+        let too_small = |_| NormalizeError::BufferTooSmall;
+
+        write!(w, "{}:", self.scheme).map_err(too_small)?;
+        w.buf[..w.len].make_ascii_lowercase();
+        if let Some(authority) = &self.authority {
+            w.write_str("//").map_err(too_small)?;
+            if let Some(userinfo) = &authority.userinfo {
+                write_pct(&mut w, userinfo.raw_user, false).map_err(too_small)?;
+                if let Some(authorization) = userinfo.raw_authorization {
+                    w.write_char(':').map_err(too_small)?;
+                    write_pct(&mut w, authorization, false).map_err(too_small)?;
+                }
+                w.write_char('@').map_err(too_small)?;
+            }
+            write_pct(&mut w, authority.raw_host, true).map_err(too_small)?;
+            if let Some(port) = self.normalized_port() {
+                write!(w, ":{}", port).map_err(too_small)?;
+            }
+        }
+        let path_start = w.len;
+        let raw_path = self.normalized_raw_path();
+        write_pct(&mut w, raw_path, false).map_err(too_small)?;
+        if raw_path.starts_with('/') {
+            w.len = path_start + remove_dot_segments(&mut w.buf[path_start..w.len]);
+        }
+        if let Some(query) = &self.query {
+            w.write_char('?').map_err(too_small)?;
+            write_pct(&mut w, query.raw_query, false).map_err(too_small)?;
+        }
+        if let Some(fragment) = &self.fragment {
+            w.write_char('#').map_err(too_small)?;
+            write_pct(&mut w, fragment.raw_fragment, false).map_err(too_small)?;
+        }
+        Ok(w.len)
+    }
+}
+
+fn write_pct(w: &mut SliceWriter<'_>, raw: &str, lowercase: bool) -> core::fmt::Result {
+    PercentNormalize::new(raw, lowercase).try_for_each(|b| w.push(b))
+}
+
+fn eq_pct(a: &str, b: &str, lowercase: bool) -> bool {
+    PercentNormalize::new(a, lowercase).eq(PercentNormalize::new(b, lowercase))
+}
+
+fn eq_opt_pct(a: Option<&str>, b: Option<&str>) -> bool {
+    match (a, b) {
+        (None, None) => true,
+        (Some(a), Some(b)) => eq_pct(a, b, false),
+        _ => false,
+    }
+}
+
+/// Compare absolute paths segment by segment from the end with the
+/// dot segments removed, otherwise compare as-is
+fn eq_path(a: &str, b: &str) -> bool {
+    match (a.starts_with('/'), b.starts_with('/')) {
+        (true, true) => {
+            let (mut a, mut b) = (dotless_segments_rev(a), dotless_segments_rev(b));
+            loop {
+                match (a.next(), b.next()) {
+                    (None, None) => return true,
+                    (Some(a), Some(b)) if eq_pct(a, b, false) => {}
+                    _ => return false,
+                }
+            }
+        }
+        (false, false) => eq_pct(a, b, false),
+        _ => false,
+    }
+}
+
+/// Segments of an absolute path in reverse as left by RFC 3986 s. 5.2.4
+///
+/// Each ".." drops the closest preceding segment and a trailing "." or ".."
+/// leaves an empty segment behind e.g. "/a/b/.." yields "", "a".
+fn dotless_segments_rev(raw_path: &str) -> impl Iterator<Item = &str> {
+    let mut segments = Path { raw_path }.segments().rev();
+    let mut skip = 0usize;
+    let mut is_last = true;
+    core::iter::from_fn(move || loop {
+        let segment = segments.next()?;
+        let was_last = core::mem::replace(&mut is_last, false);
+        let dot = PercentNormalize::new(segment, false).eq(*b".");
+        let dot_dot = PercentNormalize::new(segment, false).eq(*b"..");
+        if dot || dot_dot {
+            skip += usize::from(dot_dot);
+            if was_last {
+                return Some("");
+            }
+        } else if skip > 0 {
+            skip -= 1;
+        } else {
+            return Some(segment);
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("HTTP://Example.COM:80/%7Efoo", "http://example.com/~foo")]
+    #[case("https://FOO@Foobar.TEST:443", "https://FOO@foobar.test/")]
+    #[case("https://foobar.test:8443/a/./b/../c", "https://foobar.test:8443/a/c")]
+    #[case(
+        "http://foobar.test/%2e%2E/a%2fb?q=%7e%3d#%61",
+        "http://foobar.test/a%2Fb?q=~%3D#a"
+    )]
+    #[case("http://[2001:DB8::1]/", "http://[2001:db8::1]/")]
+    #[case("Gopher://Foobar.test", "gopher://foobar.test")]
+    #[case("urn:Oasis:./names", "urn:Oasis:./names")]
+    fn normalize(#[case] s: &'static str, #[case] expected: &'static str) {
+        let uri = Uri::new(s).unwrap();
+        let mut buf = [0u8; 64];
+        let normalized = uri.normalize_into(&mut buf).unwrap();
+        assert_eq!(format!("{}", normalized), expected);
+        #[cfg(feature = "alloc")]
+        assert_eq!(uri.normalized().unwrap(), expected);
+    }
+
+    #[test]
+    fn normalize_buffer_too_small() {
+        let uri = Uri::new("http://foobar.test/").unwrap();
+        let mut buf = [0u8; 8];
+        assert_eq!(
+            uri.normalize_into(&mut buf),
+            Err(NormalizeError::BufferTooSmall)
+        );
+    }
+
+    #[rstest]
+    #[case("HTTP://Example.COM:80/%7Efoo", "http://example.com/~foo", true)]
+    #[case("http://foobar.test", "http://foobar.test/", true)]
+    #[case("http://foobar.test/a/b/..", "http://foobar.test/a/", true)]
+    #[case("http://foobar.test/a/b/.", "http://foobar.test/a/b", false)]
+    #[case("http://foobar.test/a/%2E%2E/../b", "http://foobar.test/b", true)]
+    #[case("http://foobar.test/../..", "http://foobar.test/", true)]
+    #[case("http://foobar.test/a//b/..", "http://foobar.test/a/", false)]
+    #[case("http://foobar.test/a%2fb", "http://foobar.test/a/b", false)]
+    #[case("http://foobar.test:8080/", "http://foobar.test/", false)]
+    #[case("http://User@foobar.test/", "http://user@foobar.test/", false)]
+    #[case("http://foobar.test/?", "http://foobar.test/", false)]
+    #[case("http://foobar.test/#a", "http://foobar.test/#%61", true)]
+    #[case("gopher://foobar.test", "gopher://foobar.test/", false)]
+    #[case("Foo:bar", "foo:bar", true)]
+    #[case("urn:a/../b", "urn:b", false)]
+    fn eq_normalized(#[case] a: &'static str, #[case] b: &'static str, #[case] expected: bool) {
+        let (a, b) = (Uri::new(a).unwrap(), Uri::new(b).unwrap());
+        assert_eq!(a.eq_normalized(&b), expected);
+        assert_eq!(b.eq_normalized(&a), expected);
+        #[cfg(feature = "alloc")]
+        assert_eq!(a.normalized() == b.normalized(), expected);
+    }
+}
@@ -0,0 +1,232 @@
+//! Component offsets into a serialized URI for the owned forms
+
+use core::fmt;
+use core::fmt::Write;
+use core::net::{Ipv4Addr, Ipv6Addr};
+use core::ops::Range;
+
+use crate::{Authority, Fragment, Host, Path, Query, Scheme, SchemeData, Uri, UserInfo};
+
+/// Components as offsets standing in for the `'uri` borrow
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct UriOffsets {
+    scheme: SchemeOffsets,
+    authority: Option<AuthorityOffsets>,
+    path: Option<Range<usize>>,
+    query: Option<Range<usize>>,
+    fragment: Option<Range<usize>>,
+    scheme_data: Option<Range<usize>>,
+}
+
+/// Scheme as given with the known one kept classified
+#[derive(Clone, Debug, PartialEq)]
+struct SchemeOffsets {
+    known: Option<Scheme<'static>>,
+    raw: Range<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct AuthorityOffsets {
+    user: Option<Range<usize>>,
+    authorization: Option<Range<usize>>,
+    raw_host: Range<usize>,
+    host: HostOffsets,
+    port: Option<u16>,
+    raw_port: Option<Range<usize>>,
+    without_slashes: bool,
+}
+
+/// Host with the borrowed parts as offsets
+#[derive(Clone, Debug, PartialEq)]
+enum HostOffsets {
+    RegName(Range<usize>),
+    Ipv4(Ipv4Addr),
+    Ipv6(Ipv6Addr),
+    Ipv6Zone {
+        addr: Ipv6Addr,
+        zone: Range<usize>,
+    },
+    IpvFuture {
+        version: Range<usize>,
+        data: Range<usize>,
+    },
+}
+
+/// core::fmt::Write keeping count of the bytes written while holding
+/// on to the first error so the offsets can be recorded regardless
+pub(crate) struct Recorder<W> {
+    pub(crate) inner: W,
+    pub(crate) len: usize,
+    pub(crate) failed: bool,
+}
+
+impl<W: Write> Recorder<W> {
+    pub(crate) fn new(inner: W) -> Self {
+        Self {
+            inner,
+            len: 0,
+            failed: false,
+        }
+    }
+    /// Write returning the offsets
+    fn push(&mut self, part: &str) -> Range<usize> {
+        let start = self.len;
+        let _ = self.write_str(part);
+        start..self.len
+    }
+}
+
+impl<W: Write> Write for Recorder<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if !self.failed && self.inner.write_str(s).is_err() {
+            self.failed = true;
+        }
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+/// Offsets of sub within the part written at the given offsets falling
+/// back on the whole part when sub was not borrowed from the part
+fn offsets_within(sub: &str, part: &str, at: &Range<usize>) -> Range<usize> {
+    let start = (sub.as_ptr() as usize).wrapping_sub(part.as_ptr() as usize);
+    match start.checked_add(sub.len()) {
+        Some(end) if end <= part.len() => at.start + start..at.start + end,
+        _ => at.clone(),
+    }
+}
+
+impl UriOffsets {
+    /// Serialize the URI as per [`Uri::write_to`] recording the offsets
+    pub(crate) fn record<W: Write>(uri: &Uri<'_>, w: &mut Recorder<W>) -> Self {
+        let known = match uri.scheme {
+            Scheme::Http(tls) => Some(Scheme::Http(tls)),
+            Scheme::Ftp(tls) => Some(Scheme::Ftp(tls)),
+            Scheme::Ldap(tls) => Some(Scheme::Ldap(tls)),
+            Scheme::Telnet => Some(Scheme::Telnet),
+            Scheme::Urn => Some(Scheme::Urn),
+            Scheme::Unknown(_) => None,
+        };
+        let scheme = SchemeOffsets {
+            known,
+            raw: w.push(uri.raw_scheme()),
+        };
+        w.push(":");
+        let scheme_specific_start = w.len;
+        let authority = uri.authority.as_ref().map(|a| {
+            if !a.without_slashes {
+                w.push("//");
+            }
+            let user = a.userinfo.as_ref().map(|u| w.push(u.raw_user));
+            let authorization = a.userinfo.as_ref().and_then(|u| {
+                u.raw_authorization.map(|auth| {
+                    w.push(":");
+                    w.push(auth)
+                })
+            });
+            if user.is_some() {
+                w.push("@");
+            }
+            let raw_host = w.push(a.raw_host);
+            let host = match a.host {
+                Host::RegName(name) => {
+                    HostOffsets::RegName(offsets_within(name, a.raw_host, &raw_host))
+                }
+                Host::Ipv4(addr) => HostOffsets::Ipv4(addr),
+                Host::Ipv6(addr) => HostOffsets::Ipv6(addr),
+                Host::Ipv6Zone { addr, zone } => HostOffsets::Ipv6Zone {
+                    addr,
+                    zone: offsets_within(zone, a.raw_host, &raw_host),
+                },
+                Host::IpvFuture { version, data } => HostOffsets::IpvFuture {
+                    version: offsets_within(version, a.raw_host, &raw_host),
+                    data: offsets_within(data, a.raw_host, &raw_host),
+                },
+            };
+            let raw_port = match (a.raw_port(), a.port) {
+                (Some(raw_port), _) => {
+                    w.push(":");
+                    Some(w.push(raw_port))
+                }
+                (None, Some(port)) => {
+                    let _ = write!(w, ":{}", port);
+                    None
+                }
+                (None, None) => None,
+            };
+            AuthorityOffsets {
+                user,
+                authorization,
+                raw_host,
+                host,
+                port: a.port,
+                raw_port,
+                without_slashes: a.without_slashes,
+            }
+        });
+        let path = uri.path.as_ref().map(|p| w.push(p.raw_path));
+        let query = uri.query.as_ref().map(|q| {
+            w.push("?");
+            w.push(q.raw_query)
+        });
+        // Scheme specific part is what follows the scheme up to the fragment
+        let scheme_data = uri.scheme_data.raw.map(|_| scheme_specific_start..w.len);
+        let fragment = uri.fragment.as_ref().map(|f| {
+            w.push("#");
+            w.push(f.raw_fragment)
+        });
+        Self {
+            scheme,
+            authority,
+            path,
+            query,
+            fragment,
+            scheme_data,
+        }
+    }
+    /// Components borrowed from the recorded serialization
+    pub(crate) fn to_uri<'s>(&self, s: &'s str) -> Uri<'s> {
+        let raw_scheme = &s[self.scheme.raw.clone()];
+        let scheme = match &self.scheme.known {
+            Some(scheme) => scheme.clone(),
+            None => Scheme::Unknown(raw_scheme),
+        };
+        let authority = self.authority.as_ref().map(|a| Authority {
+            userinfo: a.user.clone().map(|user| UserInfo {
+                raw_user: &s[user],
+                raw_authorization: a.authorization.clone().map(|auth| &s[auth]),
+            }),
+            raw_host: &s[a.raw_host.clone()],
+            host: match &a.host {
+                HostOffsets::RegName(name) => Host::RegName(&s[name.clone()]),
+                HostOffsets::Ipv4(addr) => Host::Ipv4(*addr),
+                HostOffsets::Ipv6(addr) => Host::Ipv6(*addr),
+                HostOffsets::Ipv6Zone { addr, zone } => Host::Ipv6Zone {
+                    addr: *addr,
+                    zone: &s[zone.clone()],
+                },
+                HostOffsets::IpvFuture { version, data } => Host::IpvFuture {
+                    version: &s[version.clone()],
+                    data: &s[data.clone()],
+                },
+            },
+            port: a.port,
+            raw_port: a.raw_port.clone().map(|raw_port| &s[raw_port]),
+            without_slashes: a.without_slashes,
+        });
+        let scheme_data = SchemeData {
+            raw: self.scheme_data.clone().map(|d| &s[d]),
+            raw_scheme: (raw_scheme != scheme.as_str()).then_some(raw_scheme),
+        };
+        Uri {
+            scheme,
+            authority,
+            path: self.path.clone().map(|p| Path { raw_path: &s[p] }),
+            query: self.query.clone().map(|q| Query { raw_query: &s[q] }),
+            fragment: self.fragment.clone().map(|f| Fragment {
+                raw_fragment: &s[f],
+            }),
+            scheme_data,
+        }
+    }
+}
@@ -6,3 +6,175 @@ pub struct Path<'uri> {
     /// Raw path
     pub raw_path: &'uri str,
 }
+
+use crate::error::{PathError, PercentDecodeError};
+use crate::{percent_decode, PercentDecode};
+
+impl<'uri> Path<'uri> {
+    /// Percent-decoded bytes, see [`PercentDecode::chars`] for chars
+    pub fn decode(&self) -> PercentDecode<'uri> {
+        percent_decode(self.raw_path)
+    }
+    /// Strictly percent-decode into the given buffer returning the decoded length
+    pub fn decode_into(&self, buf: &mut [u8]) -> Result<usize, PercentDecodeError> {
+        self.decode().decode_into(buf)
+    }
+    /// Raw "/" separated segments after the leading "/" if any
+    ///
+    /// e.g. "/a/b/" gives "a", "b", "" and an empty path gives nothing.
+    pub fn segments(&self) -> Segments<'uri> {
+        let raw = self.raw_path.strip_prefix('/').unwrap_or(self.raw_path);
+        Segments {
+            split: (!self.raw_path.is_empty()).then(|| raw.split('/')),
+        }
+    }
+    /// Whether the path begins with "/"
+    pub fn is_absolute(&self) -> bool {
+        self.raw_path.starts_with('/')
+    }
+    /// Whether the path ends with "/"
+    pub fn has_trailing_slash(&self) -> bool {
+        self.raw_path.ends_with('/')
+    }
+    /// Remove "." and ".." segments as per RFC 3986 s. 5.2.4 into the given buffer
+    /// e.g. "/a/b/../c/./d" becomes "/a/c/d"
+    pub fn remove_dot_segments<'b>(&self, buf: &'b mut [u8]) -> Result<Path<'b>, PathError<'b>> {
+        let len = self.raw_path.len();
+        let buf = buf.get_mut(..len).ok_or(PathError::BufferTooSmall)?;
+        buf.copy_from_slice(self.raw_path.as_bytes());
+        let len = remove_dot_segments(buf);
+        let written: &'b [u8] = &buf[..len];
+        // Only whole segments ending at ASCII "/" are removed
+        let raw_path = core::str::from_utf8(written).unwrap_or_default();
+        Ok(Path { raw_path })
+    }
+}
+
+/// Double-ended iterator of raw path segments, see [`Path::segments`]
+#[derive(Clone, Debug)]
+pub struct Segments<'uri> {
+    split: Option<core::str::Split<'uri, char>>,
+}
+
+impl<'uri> Iterator for Segments<'uri> {
+    type Item = &'uri str;
+    fn next(&mut self) -> Option<&'uri str> {
+        self.split.as_mut()?.next()
+    }
+}
+
+impl<'uri> DoubleEndedIterator for Segments<'uri> {
+    fn next_back(&mut self) -> Option<&'uri str> {
+        self.split.as_mut()?.next_back()
+    }
+}
+
+use core::fmt;
+use core::fmt::Display;
+
+impl<'uri> Display for Path<'uri> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.raw_path)
+    }
+}
+
+/// RFC 3986 s. 5.2.4 - Remove dot segments in place returning the new length
+///
+/// Output never grows past the input so the same buffer is used for both.
+pub(crate) fn remove_dot_segments(buf: &mut [u8]) -> usize {
+    let len = buf.len();
+    let mut r = 0;
+    let mut w = 0;
+    while r < len {
+        let input = &buf[r..len];
+        if input.starts_with(b"../") {
+            r += 3;
+        } else if input.starts_with(b"./") || input.starts_with(b"/./") {
+            r += 2;
+        } else if input == b"/." {
+            r += 1;
+            buf[r] = b'/';
+        } else if input.starts_with(b"/../") {
+            r += 3;
+            w = pop_segment(buf, w);
+        } else if input == b"/.." {
+            r += 2;
+            buf[r] = b'/';
+            w = pop_segment(buf, w);
+        } else if input == b"." || input == b".." {
+            r = len;
+        } else {
+            let start = r;
+            r += 1;
+            while r < len && buf[r] != b'/' {
+                r += 1;
+            }
+            buf.copy_within(start..r, w);
+            w += r - start;
+        }
+    }
+    w
+}
+
+/// Remove the last segment and its preceding "/" from the output
+fn pop_segment(buf: &[u8], w: usize) -> usize {
+    buf[..w].iter().rposition(|b| *b == b'/').unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("/a/b", &["a", "b"])]
+    #[case("/a/b/", &["a", "b", ""])]
+    #[case("/", &[""])]
+    #[case("a/b", &["a", "b"])]
+    #[case("", &[])]
+    #[case("//a", &["", "a"])]
+    fn path_segments(#[case] raw_path: &'static str, #[case] expected: &[&str]) {
+        let path = Path { raw_path };
+        assert_eq!(path.segments().collect::<Vec<_>>(), expected);
+        let mut reversed: Vec<_> = path.segments().rev().collect();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+    }
+
+    #[test]
+    fn path_segments_double_ended() {
+        let path = Path { raw_path: "/a/b/c" };
+        let mut segments = path.segments();
+        assert_eq!(segments.next(), Some("a"));
+        assert_eq!(segments.next_back(), Some("c"));
+        assert_eq!(segments.next(), Some("b"));
+        assert_eq!(segments.next_back(), None);
+    }
+
+    #[rstest]
+    #[case("/a/b/", true, true)]
+    #[case("/a", true, false)]
+    #[case("a/", false, true)]
+    #[case("", false, false)]
+    fn path_shape(#[case] raw_path: &'static str, #[case] absolute: bool, #[case] trailing: bool) {
+        let path = Path { raw_path };
+        assert_eq!(path.is_absolute(), absolute);
+        assert_eq!(path.has_trailing_slash(), trailing);
+    }
+
+    #[rstest]
+    #[case("/a/b/c/./../../g", Ok("/a/g"))]
+    #[case("mid/content=5/../6", Ok("mid/6"))]
+    #[case("/../../etc/passwd", Ok("/etc/passwd"))]
+    #[case("/a/b/..", Ok("/a/"))]
+    #[case("/a/%2E%2E/b", Ok("/a/%2E%2E/b"))]
+    #[case("/a/./b/../../../../c/d/e/f", Err(PathError::BufferTooSmall))]
+    fn path_remove_dot_segments(
+        #[case] raw_path: &'static str,
+        #[case] expected: Result<&'static str, PathError<'static>>,
+    ) {
+        let mut buf = [0u8; 20];
+        let res = Path { raw_path }.remove_dot_segments(&mut buf);
+        assert_eq!(res.map(|p| p.raw_path), expected);
+    }
+}
@@ -6,3 +6,169 @@ pub struct Query<'uri> {
     /// Raw query
     pub raw_query: &'uri str,
 }
+
+use crate::error::PercentDecodeError;
+use crate::{form_decode, percent_decode, PercentDecode};
+
+impl<'uri> Query<'uri> {
+    /// Percent-decoded bytes, see [`PercentDecode::chars`] for chars
+    pub fn decode(&self) -> PercentDecode<'uri> {
+        percent_decode(self.raw_query)
+    }
+    /// Strictly percent-decode into the given buffer returning the decoded length
+    pub fn decode_into(&self, buf: &mut [u8]) -> Result<usize, PercentDecodeError> {
+        self.decode().decode_into(buf)
+    }
+    /// Raw key / value pairs separated by "&"
+    ///
+    /// Value is None when there is no "=" e.g. "flag" in "flag&k=v"
+    pub fn pairs(&self) -> QueryPairs<'uri> {
+        QueryPairs::new(self.raw_query, false)
+    }
+    /// Raw key / value pairs separated by either "&" or ";"
+    pub fn pairs_with_semicolons(&self) -> QueryPairs<'uri> {
+        QueryPairs::new(self.raw_query, true)
+    }
+    /// First raw value for the raw key, key without "=" has an empty value
+    pub fn get(&self, key: &str) -> Option<&'uri str> {
+        self.get_all(key).next()
+    }
+    /// All raw values for the raw key, key without "=" has an empty value
+    pub fn get_all<'k>(&self, key: &'k str) -> impl Iterator<Item = &'uri str> + 'k
+    where
+        'uri: 'k,
+    {
+        self.pairs()
+            .filter(move |(k, _)| *k == key)
+            .map(|(_, v)| v.unwrap_or(""))
+    }
+    /// Key / value pairs decoded as application/x-www-form-urlencoded
+    pub fn decoded_pairs(
+        &self,
+    ) -> impl Iterator<Item = (PercentDecode<'uri>, PercentDecode<'uri>)> {
+        self.pairs()
+            .map(|(k, v)| (form_decode(k), form_decode(v.unwrap_or(""))))
+    }
+    /// First value decoded as application/x-www-form-urlencoded for the decoded key
+    pub fn get_decoded(&self, key: &str) -> Option<PercentDecode<'uri>> {
+        self.pairs()
+            .find(|(k, _)| form_decode(k).eq(key.bytes()))
+            .map(|(_, v)| form_decode(v.unwrap_or("")))
+    }
+}
+
+/// Iterator of raw key / value pairs, see [`Query::pairs`]
+#[derive(Clone, Debug)]
+pub struct QueryPairs<'uri> {
+    remaining: &'uri str,
+    semicolons: bool,
+}
+
+impl<'uri> QueryPairs<'uri> {
+    fn new(raw_query: &'uri str, semicolons: bool) -> Self {
+        Self {
+            remaining: raw_query,
+            semicolons,
+        }
+    }
+}
+
+impl<'uri> Iterator for QueryPairs<'uri> {
+    type Item = (&'uri str, Option<&'uri str>);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.remaining.is_empty() {
+                return None;
+            }
+            let end = if self.semicolons {
+                self.remaining.find(['&', ';'])
+            } else {
+                self.remaining.find('&')
+            };
+            let (pair, rest) = match end {
+                Some(end) => (&self.remaining[..end], &self.remaining[end + 1..]),
+                None => (self.remaining, ""),
+            };
+            self.remaining = rest;
+            // Empty pairs e.g. "a=1&&b=2" are skipped
+            if pair.is_empty() {
+                continue;
+            }
+            return Some(match pair.split_once('=') {
+                Some((key, value)) => (key, Some(value)),
+                None => (pair, None),
+            });
+        }
+    }
+}
+
+use core::fmt;
+use core::fmt::Display;
+
+impl<'uri> Display for Query<'uri> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.raw_query)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("q=a&m=s", &[("q", Some("a")), ("m", Some("s"))])]
+    #[case("flag&k=v&", &[("flag", None), ("k", Some("v"))])]
+    #[case("a=1&&b=&c==", &[("a", Some("1")), ("b", Some("")), ("c", Some("="))])]
+    #[case("a=1;b=2", &[("a", Some("1;b=2"))])]
+    #[case("", &[])]
+    fn query_pairs(#[case] raw_query: &'static str, #[case] expected: &[(&str, Option<&str>)]) {
+        let pairs: Vec<_> = Query { raw_query }.pairs().collect();
+        assert_eq!(pairs, expected);
+    }
+
+    #[test]
+    fn query_pairs_with_semicolons() {
+        let query = Query {
+            raw_query: "a=1;b=2&c",
+        };
+        let pairs: Vec<_> = query.pairs_with_semicolons().collect();
+        assert_eq!(pairs, [("a", Some("1")), ("b", Some("2")), ("c", None)]);
+    }
+
+    #[test]
+    fn query_get() {
+        let query = Query {
+            raw_query: "a=1&flag&a=2&b=x%20y",
+        };
+        assert_eq!(query.get("a"), Some("1"));
+        assert_eq!(query.get("flag"), Some(""));
+        assert_eq!(query.get("missing"), None);
+        assert_eq!(query.get_all("a").collect::<Vec<_>>(), ["1", "2"]);
+        assert_eq!(query.get("b"), Some("x%20y"));
+    }
+
+    #[test]
+    fn query_decoded() {
+        let query = Query {
+            raw_query: "first+name=J%C3%B6rg+M&q=a%2Bb",
+        };
+        let name: String = query.get_decoded("first name").unwrap().chars().collect();
+        assert_eq!(name, "Jörg M");
+        let q: Vec<u8> = query.get_decoded("q").unwrap().collect();
+        assert_eq!(q, b"a+b");
+        assert!(query.get_decoded("first+name").is_none());
+
+        let pairs: Vec<(String, String)> = query
+            .decoded_pairs()
+            .map(|(k, v)| (k.chars().collect(), v.chars().collect()))
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                ("first name".to_string(), "Jörg M".to_string()),
+                ("q".to_string(), "a+b".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,382 @@
+//! Query string building without allocating
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::Range;
+
+use crate::buf::SliceWriter;
+use crate::error::PercentEncodeError;
+use crate::{encode_unreserved, form_decode, form_encode, PercentEncode, Query};
+
+/// Encoding of the query keys and values
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum QueryEncoding {
+    /// application/x-www-form-urlencoded where a space is "+", see [`form_encode`]
+    #[default]
+    Form,
+    /// All but the unreserved percent-encoded where a space is "%20" e.g. for
+    /// canonical query strings of signed requests, see [`encode_unreserved`]
+    Unreserved,
+}
+
+impl QueryEncoding {
+    fn encode(self, input: &str) -> PercentEncode<'_> {
+        match self {
+            Self::Form => form_encode(input),
+            Self::Unreserved => encode_unreserved(input),
+        }
+    }
+}
+
+/// Serialize key / value pairs as a query into any [`core::fmt::Write`]
+///
+/// Keys and values are application/x-www-form-urlencoded unless another
+/// [`QueryEncoding`] is given.
+///
+/// ```rust
+/// let mut s = String::new();
+/// let mut query = yuri::QuerySerializer::new(&mut s);
+/// query.append("q", "a&b c").unwrap();
+/// query.append_raw("flag", None).unwrap();
+/// assert_eq!(s, "q=a%26b+c&flag");
+/// ```
+#[derive(Debug)]
+pub struct QuerySerializer<W> {
+    w: W,
+    empty: bool,
+    encoding: QueryEncoding,
+}
+
+impl<W: fmt::Write> QuerySerializer<W> {
+    /// Start serializing into the given writer form-urlencoding
+    pub fn new(w: W) -> Self {
+        Self::with_encoding(w, QueryEncoding::Form)
+    }
+    /// Start serializing into the given writer with the given encoding
+    pub fn with_encoding(w: W, encoding: QueryEncoding) -> Self {
+        Self {
+            w,
+            empty: true,
+            encoding,
+        }
+    }
+    fn separator(&mut self) -> fmt::Result {
+        match core::mem::replace(&mut self.empty, false) {
+            true => Ok(()),
+            false => self.w.write_char('&'),
+        }
+    }
+    /// Append a key / value pair encoding both
+    pub fn append(&mut self, key: &str, value: &str) -> fmt::Result {
+        self.separator()?;
+        self.encoding.encode(key).write_to(&mut self.w)?;
+        self.w.write_char('=')?;
+        self.encoding.encode(value).write_to(&mut self.w)
+    }
+    /// Append an already encoded key / value pair as-is, without "=" when
+    /// there is no value
+    pub fn append_raw(&mut self, raw_key: &str, raw_value: Option<&str>) -> fmt::Result {
+        self.separator()?;
+        self.w.write_str(raw_key)?;
+        match raw_value {
+            Some(raw_value) => write!(self.w, "={}", raw_value),
+            None => Ok(()),
+        }
+    }
+    /// Writer given
+    pub fn into_inner(self) -> W {
+        self.w
+    }
+}
+
+/// Query Builder into a fixed buffer with editing of the pairs in place
+///
+/// ```rust
+/// use yuri::{Query, QueryBuilder, QueryEncoding};
+///
+/// let signed = Query { raw_query: "b=2&sig=x&a=1" };
+/// let mut buf = [0u8; 32];
+/// let canonical = QueryBuilder::with_encoding(&mut buf, QueryEncoding::Unreserved)
+///     .extend_query(&signed)
+///     .unwrap()
+///     .remove("sig")
+///     .append("c", "d e")
+///     .unwrap()
+///     .sort()
+///     .build();
+/// assert_eq!(canonical.raw_query, "a=1&b=2&c=d%20e");
+/// ```
+pub struct QueryBuilder<'b> {
+    inner: QuerySerializer<SliceWriter<'b>>,
+}
+
+impl<'b> QueryBuilder<'b> {
+    /// Start building into the given buffer form-urlencoding
+    pub fn new(buf: &'b mut [u8]) -> Self {
+        Self::with_encoding(buf, QueryEncoding::Form)
+    }
+    /// Start building into the given buffer with the given encoding
+    pub fn with_encoding(buf: &'b mut [u8], encoding: QueryEncoding) -> Self {
+        Self {
+            inner: QuerySerializer::with_encoding(SliceWriter::new(buf), encoding),
+        }
+    }
+    /// Append a key / value pair encoding both
+    pub fn append(mut self, key: &str, value: &str) -> Result<Self, PercentEncodeError> {
+        self.inner
+            .append(key, value)
+            .map_err(|_| PercentEncodeError::BufferTooSmall)?;
+        Ok(self)
+    }
+    /// Append all the raw pairs of an existing Query as-is
+    pub fn extend_query(mut self, query: &Query<'_>) -> Result<Self, PercentEncodeError> {
+        for (raw_key, raw_value) in query.pairs() {
+            self.inner
+                .append_raw(raw_key, raw_value)
+                .map_err(|_| PercentEncodeError::BufferTooSmall)?;
+        }
+        Ok(self)
+    }
+    /// Remove all pairs with the key compared decoded as per
+    /// [`Query::get_decoded`]
+    pub fn remove(mut self, key: &str) -> Self {
+        let written = self.inner.w.len;
+        let (mut read, mut len) = (0, 0);
+        while read < written {
+            let pair = self.pair_at(read);
+            let next = pair.end + 1;
+            if !self.has_key(pair.clone(), key) {
+                if len > 0 {
+                    self.inner.w.buf[len] = b'&';
+                    len += 1;
+                }
+                self.inner.w.buf.copy_within(pair.clone(), len);
+                len += pair.len();
+            }
+            read = next;
+        }
+        self.truncate(len);
+        self
+    }
+    /// Replace the value of the first pair with the key in place removing
+    /// any others, otherwise append the pair
+    pub fn replace(self, key: &str, value: &str) -> Result<Self, PercentEncodeError> {
+        let first = self.pairs().find(|pair| self.has_key(pair.clone(), key));
+        let mut builder = self.remove(key).append(key, value)?;
+        if let Some(first) = first {
+            let last = builder.pair_before(builder.inner.w.len);
+            builder.move_pair(first.start, last);
+        }
+        Ok(builder)
+    }
+    /// Sort the pairs by the raw key then the raw value keeping the order
+    /// of equal pairs e.g. for canonical query strings
+    pub fn sort(mut self) -> Self {
+        let first = self.pairs().next();
+        let mut sorted_end = match first {
+            Some(first) => first.end,
+            None => return self,
+        };
+        while sorted_end < self.inner.w.len {
+            let pair = self.pair_at(sorted_end + 1);
+            let insert_at = self
+                .pairs()
+                .take_while(|sorted| sorted.start < pair.start)
+                .find(|sorted| self.cmp_pairs(sorted.clone(), pair.clone()) == Ordering::Greater);
+            let end = pair.end;
+            if let Some(insert_at) = insert_at {
+                self.move_pair(insert_at.start, pair);
+            }
+            sorted_end = end;
+        }
+        self
+    }
+    /// Build the Query
+    pub fn build(self) -> Query<'b> {
+        Query {
+            raw_query: self.inner.w.into_str(),
+        }
+    }
+    fn written(&self) -> &str {
+        // Only whole str pieces are written and moved
+        core::str::from_utf8(&self.inner.w.buf[..self.inner.w.len]).unwrap_or_default()
+    }
+    fn truncate(&mut self, len: usize) {
+        self.inner.w.len = len;
+        self.inner.empty = len == 0;
+    }
+    /// Pair starting at the given offset up to the next "&"
+    fn pair_at(&self, start: usize) -> Range<usize> {
+        let end = self.written()[start..]
+            .find('&')
+            .map_or(self.inner.w.len, |pos| start + pos);
+        start..end
+    }
+    /// Pair ending at the given offset
+    fn pair_before(&self, end: usize) -> Range<usize> {
+        let start = self.written()[..end].rfind('&').map_or(0, |pos| pos + 1);
+        start..end
+    }
+    fn pairs(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        let mut start = 0;
+        core::iter::from_fn(move || {
+            if start >= self.inner.w.len {
+                return None;
+            }
+            let pair = self.pair_at(start);
+            start = pair.end + 1;
+            Some(pair)
+        })
+    }
+    fn split_pair(&self, pair: Range<usize>) -> (&str, &str) {
+        let raw = &self.written()[pair];
+        raw.split_once('=').unwrap_or((raw, ""))
+    }
+    fn has_key(&self, pair: Range<usize>, key: &str) -> bool {
+        form_decode(self.split_pair(pair).0).eq(key.bytes())
+    }
+    fn cmp_pairs(&self, a: Range<usize>, b: Range<usize>) -> Ordering {
+        self.split_pair(a).cmp(&self.split_pair(b))
+    }
+    /// Move the pair to the given pair start before it keeping the "&"
+    /// separators in place
+    fn move_pair(&mut self, to: usize, pair: Range<usize>) {
+        let buf = &mut self.inner.w.buf[to..pair.end];
+        // "a&b&c" to "ca&b&" then to "c&a&b"
+        buf.rotate_right(pair.len());
+        buf[pair.len()..].rotate_right(1);
+    }
+}
+
+impl fmt::Debug for QueryBuilder<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QueryBuilder")
+            .field("written", &self.written())
+            .field("encoding", &self.inner.encoding)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", "")]
+    #[case("a=1", "a=1")]
+    #[case("b=2&a=1", "a=1&b=2")]
+    #[case("a1=x&a=2&a=1&b", "a=1&a=2&a1=x&b")]
+    #[case("c=3&b=2&a=1&a=0", "a=0&a=1&b=2&c=3")]
+    #[case("x=%E4%BE%8B&X=1", "X=1&x=%E4%BE%8B")]
+    fn query_builder_sort(#[case] raw_query: &str, #[case] expected: &str) {
+        let mut buf = [0u8; 64];
+        let query = QueryBuilder::new(&mut buf)
+            .extend_query(&Query { raw_query })
+            .unwrap()
+            .sort()
+            .build();
+        assert_eq!(query.raw_query, expected);
+    }
+
+    #[rstest]
+    #[case("a=1&b=2&a=3", "a", "b=2")]
+    #[case("a=1&b=2&a=3", "b", "a=1&a=3")]
+    #[case("first+name=x&q=1", "first name", "q=1")]
+    #[case("flag&q=1", "flag", "q=1")]
+    #[case("q=1", "missing", "q=1")]
+    #[case("a=1", "a", "")]
+    fn query_builder_remove(#[case] raw_query: &str, #[case] key: &str, #[case] expected: &str) {
+        let mut buf = [0u8; 64];
+        let query = QueryBuilder::new(&mut buf)
+            .extend_query(&Query { raw_query })
+            .unwrap()
+            .remove(key)
+            .build();
+        assert_eq!(query.raw_query, expected);
+    }
+
+    #[rstest]
+    #[case("a=1&b=2&a=3", "a", "new value", "a=new+value&b=2")]
+    #[case("x=0&a=1&b=2&a=3", "a", "9", "x=0&a=9&b=2")]
+    #[case("b=2", "a", "1", "b=2&a=1")]
+    #[case("", "a", "1", "a=1")]
+    fn query_builder_replace(
+        #[case] raw_query: &str,
+        #[case] key: &str,
+        #[case] value: &str,
+        #[case] expected: &str,
+    ) {
+        let mut buf = [0u8; 64];
+        let query = QueryBuilder::new(&mut buf)
+            .extend_query(&Query { raw_query })
+            .unwrap()
+            .replace(key, value)
+            .unwrap()
+            .build();
+        assert_eq!(query.raw_query, expected);
+    }
+
+    #[rstest]
+    #[case("a b", "c d", "a+b=c+d", "a%20b=c%20d")]
+    #[case("a+b", "1+1", "a%2Bb=1%2B1", "a%2Bb=1%2B1")]
+    #[case("a&b", "c&d", "a%26b=c%26d", "a%26b=c%26d")]
+    #[case("a=b", "c=d", "a%3Db=c%3Dd", "a%3Db=c%3Dd")]
+    #[case("a/b", "/c?", "a%2Fb=%2Fc%3F", "a%2Fb=%2Fc%3F")]
+    #[case("例", "ü~*", "%E4%BE%8B=%C3%BC%7E*", "%E4%BE%8B=%C3%BC~%2A")]
+    fn query_builder_encoding(
+        #[case] key: &str,
+        #[case] value: &str,
+        #[case] form: &str,
+        #[case] unreserved: &str,
+    ) {
+        let mut buf = [0u8; 64];
+        let query = QueryBuilder::new(&mut buf)
+            .append(key, value)
+            .unwrap()
+            .build();
+        assert_eq!(query.raw_query, form);
+        let decoded: Vec<u8> = query.get_decoded(key).unwrap().collect();
+        assert_eq!(decoded, value.as_bytes());
+        let mut buf = [0u8; 64];
+        let query = QueryBuilder::with_encoding(&mut buf, QueryEncoding::Unreserved)
+            .append(key, value)
+            .unwrap()
+            .build();
+        assert_eq!(query.raw_query, unreserved);
+        let decoded: Vec<u8> = query.get_decoded(key).unwrap().collect();
+        assert_eq!(decoded, value.as_bytes());
+    }
+
+    #[test]
+    fn query_builder_debug() {
+        let mut buf = [0u8; 16];
+        let builder = QueryBuilder::new(&mut buf).append("a", "b c").unwrap();
+        assert_eq!(
+            format!("{:?}", builder),
+            "QueryBuilder { written: \"a=b+c\", encoding: Form }"
+        );
+    }
+
+    #[test]
+    fn query_builder_buffer_too_small() {
+        let mut buf = [0u8; 8];
+        let res = QueryBuilder::new(&mut buf)
+            .append("a", "1")
+            .unwrap()
+            .append("b", "long");
+        assert!(matches!(res, Err(PercentEncodeError::BufferTooSmall)));
+    }
+
+    #[test]
+    fn query_serializer_roundtrip() {
+        let mut s = String::new();
+        let mut serializer = QuerySerializer::new(&mut s);
+        serializer.append("k=", "a+b&c").unwrap();
+        serializer.append("例", "").unwrap();
+        assert_eq!(s, "k%3D=a%2Bb%26c&%E4%BE%8B=");
+        let query = Query { raw_query: &s };
+        let decoded: Vec<u8> = query.get_decoded("k=").unwrap().collect();
+        assert_eq!(decoded, b"a+b&c");
+    }
+}
@@ -0,0 +1,98 @@
+//! URI Reference types
+
+use crate::{Authority, Fragment, ParseOptions, Path, Query, Scheme, SchemeData, Uri};
+
+/// URI-reference - either a full URI or a relative reference
+/// e.g. "/index.html?x=1" from a HTTP/1.1 request target
+#[derive(Debug, Clone, PartialEq)]
+pub struct UriRef<'uri> {
+    /// Scheme if this is a full URI
+    pub scheme: Option<Scheme<'uri>>,
+    /// Authority
+    pub authority: Option<Authority<'uri>>,
+    /// Path
+    pub path: Option<Path<'uri>>,
+    /// Query
+    pub query: Option<Query<'uri>>,
+    /// Fragment
+    pub fragment: Option<Fragment<'uri>>,
+    /// Scheme based data
+    pub scheme_data: SchemeData<'uri>,
+}
+
+use crate::error::{SchemeError, UriError};
+
+impl<'uri> UriRef<'uri> {
+    /// Construct a new URI reference from &str
+    pub fn new(input: &'uri str) -> Result<Self, UriError<'uri>> {
+        input.try_into()
+    }
+    /// Construct a new URI reference from &str applying the given [`ParseOptions`]
+    pub fn new_with(input: &'uri str, opts: &ParseOptions) -> Result<Self, UriError<'uri>> {
+        crate::parser::parse_uri_ref(input, opts)
+    }
+    /// Construct a new URI reference from &str rejecting anything outside
+    /// the RFC 3986 ABNF e.g. for a security sensitive request target,
+    /// see [`Uri::new_strict`].
+    pub fn new_strict(input: &'uri str) -> Result<Self, UriError<'uri>> {
+        Self::new_with(input, &ParseOptions::strict())
+    }
+    /// Whether this is a relative reference without a scheme
+    pub fn is_relative(&self) -> bool {
+        self.scheme.is_none()
+    }
+    /// Serialize the reference into the given writer without allocating.
+    pub fn write_to<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        let raw_scheme = self
+            .scheme
+            .as_ref()
+            .map(|scheme| self.scheme_data.scheme_as_given(scheme));
+        super::write_components(
+            w,
+            raw_scheme,
+            self.authority.as_ref(),
+            self.path.as_ref(),
+            self.query.as_ref(),
+            self.fragment.as_ref(),
+        )
+    }
+}
+
+impl<'uri> From<Uri<'uri>> for UriRef<'uri> {
+    fn from(uri: Uri<'uri>) -> Self {
+        Self {
+            scheme: Some(uri.scheme),
+            authority: uri.authority,
+            path: uri.path,
+            query: uri.query,
+            fragment: uri.fragment,
+            scheme_data: uri.scheme_data,
+        }
+    }
+}
+
+impl<'uri> TryFrom<UriRef<'uri>> for Uri<'uri> {
+    type Error = UriError<'uri>;
+    fn try_from(uri_ref: UriRef<'uri>) -> Result<Self, Self::Error> {
+        let scheme = uri_ref
+            .scheme
+            .ok_or(UriError::Scheme(SchemeError::Nothing))?;
+        Ok(Self {
+            scheme,
+            authority: uri_ref.authority,
+            path: uri_ref.path,
+            query: uri_ref.query,
+            fragment: uri_ref.fragment,
+            scheme_data: uri_ref.scheme_data,
+        })
+    }
+}
+
+use core::fmt;
+use core::fmt::Display;
+
+impl<'uri> Display for UriRef<'uri> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
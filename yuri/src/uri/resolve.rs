@@ -0,0 +1,265 @@
+//! RFC 3986 s. 5 Reference resolution
+
+use core::fmt::Write;
+
+#[cfg(feature = "alloc")]
+use crate::UriBuf;
+
+use super::path::remove_dot_segments;
+use crate::buf::SliceWriter;
+use crate::error::ResolveError;
+use crate::{Authority, Query, Uri, UriRef};
+
+/// Where the target path comes from - RFC 3986 s. 5.2.2
+enum TargetPath<'a> {
+    /// Base path as-is
+    Base(&'a str),
+    /// Reference path with dot segments removed
+    Reference(&'a str),
+    /// Base path up to the last "/" merged with the reference path
+    Merge(&'a str, &'a str),
+}
+
+impl<'uri> Uri<'uri> {
+    /// Resolve a reference against this base URI into the given buffer
+    /// as per RFC 3986 s. 5.2 without allocating.
+    ///
+    /// ```rust
+    /// let base = yuri::Uri::new("http://a/b/c/d;p?q").unwrap();
+    /// let reference = yuri::UriRef::new("../g").unwrap();
+    /// let mut buf = [0u8; 64];
+    /// let resolved = base.resolve(&reference, &mut buf).unwrap();
+    /// assert_eq!(format!("{}", resolved), "http://a/b/g");
+    /// ```
+    pub fn resolve<'buf>(
+        &self,
+        reference: &UriRef<'_>,
+        buf: &'buf mut [u8],
+    ) -> Result<Uri<'buf>, ResolveError<'buf>> {
+        let len = self.resolve_into(reference, buf)?;
+        // Only whole str pieces are written & dot segments end at ASCII "/"
+        let resolved = core::str::from_utf8(&buf[..len]).unwrap_or_default();
+        Uri::new(resolved).map_err(ResolveError::Parse)
+    }
+    /// Parse the given reference and resolve it against this base URI
+    /// into the given buffer, see [`Uri::resolve`]. The resolved URI only
+    /// borrows the buffer.
+    pub fn join<'e, 'buf: 'e>(
+        &self,
+        reference: &'e str,
+        buf: &'buf mut [u8],
+    ) -> Result<Uri<'buf>, ResolveError<'e>> {
+        let reference = UriRef::new(reference).map_err(ResolveError::Parse)?;
+        self.resolve(&reference, buf)
+    }
+    /// Resolve a reference against this base URI into an owned [`UriBuf`],
+    /// see [`Uri::resolve`].
+    ///
+    /// ```rust
+    /// let base = yuri::Uri::new("http://a/b/c/d;p?q").unwrap();
+    /// let reference = yuri::UriRef::new("../g").unwrap();
+    /// let resolved = base.resolve_owned(&reference).unwrap();
+    /// assert_eq!(resolved.as_ref().path.unwrap().raw_path, "/b/g");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn resolve_owned(&self, reference: &UriRef<'_>) -> Result<UriBuf, ResolveError<'static>> {
+        // Target is at most base + reference + "/" from merging + "/."
+        let mut counter = crate::buf::LenCounter(3);
+        self.write_to(&mut counter)
+            .and_then(|_| reference.write_to(&mut counter))
+            .map_err(|_| ResolveError::BufferTooSmall)?;
+        let mut buf = alloc::vec![0u8; counter.0];
+        let len = self.resolve_into(reference, &mut buf)?;
+        // Only whole str pieces are written & dot segments end at ASCII "/"
+        let resolved = core::str::from_utf8(&buf[..len]).unwrap_or_default();
+        UriBuf::new(resolved).map_err(|_| ResolveError::InvalidTarget)
+    }
+    /// Write the resolved target URI returning the length written
+    fn resolve_into(
+        &self,
+        reference: &UriRef<'_>,
+        buf: &mut [u8],
+    ) -> Result<usize, ResolveError<'static>> {
+        let ref_path = reference.path.as_ref().map(|p| p.raw_path).unwrap_or("");
+        let base_path = self.path.as_ref().map(|p| p.raw_path).unwrap_or("");
+
+        let (authority, path, query): (Option<&Authority<'_>>, _, Option<&Query<'_>>) =
+            if reference.scheme.is_some() || reference.authority.is_some() {
+                (
+                    reference.authority.as_ref(),
+                    TargetPath::Reference(ref_path),
+                    reference.query.as_ref(),
+                )
+            } else if ref_path.is_empty() {
+                let query = reference.query.as_ref().or(self.query.as_ref());
+                (self.authority.as_ref(), TargetPath::Base(base_path), query)
+            } else if ref_path.starts_with('/') {
+                (
+                    self.authority.as_ref(),
+                    TargetPath::Reference(ref_path),
+                    reference.query.as_ref(),
+                )
+            } else {
+                // RFC 3986 s. 5.2.3 - Merge paths
+                let base_dir = match base_path.rfind('/') {
+                    _ if self.authority.is_some() && base_path.is_empty() => "/",
+                    Some(pos) => &base_path[..=pos],
+                    None => "",
+                };
+                (
+                    self.authority.as_ref(),
+                    TargetPath::Merge(base_dir, ref_path),
+                    reference.query.as_ref(),
+                )
+            };
+        let scheme = reference.scheme.as_ref().unwrap_or(&self.scheme);
+
+        let mut w = SliceWriter::new(buf);
+        let too_small = |_| ResolveError::BufferTooSmall;
+
+        write!(w, "{}:", scheme).map_err(too_small)?;
+        if let Some(authority) = authority {
+            write!(w, "//{}", authority).map_err(too_small)?;
+        }
+        let path_start = w.len;
+        match path {
+            TargetPath::Base(path) => w.write_str(path).map_err(too_small)?,
+            TargetPath::Reference(path) => {
+                w.write_str(path).map_err(too_small)?;
+                w.len = path_start + remove_dot_segments(&mut w.buf[path_start..w.len]);
+            }
+            TargetPath::Merge(base_dir, path) => {
+                w.write_str(base_dir).map_err(too_small)?;
+                w.write_str(path).map_err(too_small)?;
+                w.len = path_start + remove_dot_segments(&mut w.buf[path_start..w.len]);
+            }
+        }
+        // Keep a path starting with "//" from reading as an authority
+        if authority.is_none() && w.buf[path_start..w.len].starts_with(b"//") {
+            w.insert_str(path_start, "/.").map_err(too_small)?;
+        }
+        if let Some(query) = query {
+            write!(w, "?{}", query).map_err(too_small)?;
+        }
+        if let Some(fragment) = &reference.fragment {
+            write!(w, "#{}", fragment).map_err(too_small)?;
+        }
+        Ok(w.len)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    const BASE: &str = "http://a/b/c/d;p?q";
+
+    // RFC 3986 s. 5.4.1 Normal Examples & s. 5.4.2 Abnormal Examples
+    #[rstest]
+    #[case("g:h", "g:h")]
+    #[case("g", "http://a/b/c/g")]
+    #[case("./g", "http://a/b/c/g")]
+    #[case("g/", "http://a/b/c/g/")]
+    #[case("/g", "http://a/g")]
+    #[case("//g", "http://g")]
+    #[case("?y", "http://a/b/c/d;p?y")]
+    #[case("g?y", "http://a/b/c/g?y")]
+    #[case("#s", "http://a/b/c/d;p?q#s")]
+    #[case("g#s", "http://a/b/c/g#s")]
+    #[case("g?y#s", "http://a/b/c/g?y#s")]
+    #[case(";x", "http://a/b/c/;x")]
+    #[case("g;x", "http://a/b/c/g;x")]
+    #[case("g;x?y#s", "http://a/b/c/g;x?y#s")]
+    #[case("", "http://a/b/c/d;p?q")]
+    #[case(".", "http://a/b/c/")]
+    #[case("./", "http://a/b/c/")]
+    #[case("..", "http://a/b/")]
+    #[case("../", "http://a/b/")]
+    #[case("../g", "http://a/b/g")]
+    #[case("../..", "http://a/")]
+    #[case("../../", "http://a/")]
+    #[case("../../g", "http://a/g")]
+    #[case("../../../g", "http://a/g")]
+    #[case("../../../../g", "http://a/g")]
+    #[case("/./g", "http://a/g")]
+    #[case("/../g", "http://a/g")]
+    #[case("g.", "http://a/b/c/g.")]
+    #[case(".g", "http://a/b/c/.g")]
+    #[case("g..", "http://a/b/c/g..")]
+    #[case("..g", "http://a/b/c/..g")]
+    #[case("./../g", "http://a/b/g")]
+    #[case("./g/.", "http://a/b/c/g/")]
+    #[case("g/./h", "http://a/b/c/g/h")]
+    #[case("g/../h", "http://a/b/c/h")]
+    #[case("g;x=1/./y", "http://a/b/c/g;x=1/y")]
+    #[case("g;x=1/../y", "http://a/b/c/y")]
+    #[case("g?y/./x", "http://a/b/c/g?y/./x")]
+    #[case("g?y/../x", "http://a/b/c/g?y/../x")]
+    #[case("g#s/./x", "http://a/b/c/g#s/./x")]
+    #[case("g#s/../x", "http://a/b/c/g#s/../x")]
+    fn rfc3986_s54_examples(#[case] reference: &'static str, #[case] expected: &'static str) {
+        let base = Uri::new(BASE).unwrap();
+        let mut buf = [0u8; 64];
+        let resolved = base.join(reference, &mut buf).unwrap();
+        assert_eq!(format!("{}", resolved), expected);
+
+        #[cfg(feature = "alloc")]
+        {
+            let reference = UriRef::new(reference).unwrap();
+            let resolved = base.resolve_owned(&reference).unwrap();
+            assert_eq!(resolved.as_str(), expected);
+        }
+    }
+
+    #[test]
+    fn join_outlives_reference() {
+        let base = Uri::new(BASE).unwrap();
+        let mut buf = [0u8; 64];
+        let resolved = {
+            let reference = String::from("../g?x");
+            base.join(&reference, &mut buf).unwrap()
+        };
+        assert_eq!(resolved.query.unwrap().raw_query, "x");
+    }
+
+    #[test]
+    fn resolve_merge_empty_base_path() {
+        let base = Uri::new("https://foobar.test").unwrap();
+        let mut buf = [0u8; 64];
+        let resolved = base.join("index.html", &mut buf).unwrap();
+        assert_eq!(format!("{}", resolved), "https://foobar.test/index.html");
+    }
+
+    #[test]
+    fn resolve_path_without_authority() {
+        let base = Uri::new("foo:/a/b").unwrap();
+        let mut buf = [0u8; 64];
+        let resolved = base.join("..//g", &mut buf).unwrap();
+        assert_eq!(format!("{}", resolved), "foo:/.//g");
+        assert!(resolved.authority.is_none());
+    }
+
+    #[test]
+    fn resolve_buffer_too_small() {
+        let base = Uri::new(BASE).unwrap();
+        let mut buf = [0u8; 8];
+        assert_eq!(
+            base.join("../g", &mut buf),
+            Err(ResolveError::BufferTooSmall)
+        );
+    }
+
+    #[rstest]
+    #[case("/a/b/c/./../../g", "/a/g")]
+    #[case("mid/content=5/../6", "mid/6")]
+    #[case("/..", "/")]
+    #[case("a/..", "/")]
+    #[case("", "")]
+    fn dot_segments(#[case] path: &'static str, #[case] expected: &'static str) {
+        let mut buf = [0u8; 32];
+        buf[..path.len()].copy_from_slice(path.as_bytes());
+        let len = remove_dot_segments(&mut buf[..path.len()]);
+        assert_eq!(&buf[..len], expected.as_bytes());
+    }
+}
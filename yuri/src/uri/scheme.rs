@@ -22,6 +22,31 @@ pub enum Scheme<'uri> {
 #[derive(Clone, Debug, PartialEq)]
 pub struct SchemeData<'uri> {
     pub(crate) raw: Option<&'uri str>,
+    pub(crate) raw_scheme: Option<&'uri str>,
+}
+
+impl<'uri> SchemeData<'uri> {
+    /// Raw parsed scheme specific part without the fragment when there is no authority
+    /// e.g. "John.Doe@example.com" for mailto:John.Doe@example.com
+    ///
+    /// Only kept as parsed and not kept in step with the components: the
+    /// setters changing the authority, path or query clear it, so read
+    /// [`Uri::path`](crate::Uri::path) & [`Uri::query`](crate::Uri::query) instead.
+    pub fn raw(&self) -> Option<&'uri str> {
+        self.raw
+    }
+    /// Raw parsed scheme when a known [`Scheme`] was given in another case
+    /// e.g. "HTTP" for HTTP://foobar.test
+    pub fn raw_scheme(&self) -> Option<&'uri str> {
+        self.raw_scheme
+    }
+    /// Scheme as given unless the Scheme was changed since
+    pub(crate) fn scheme_as_given(&self, scheme: &Scheme<'uri>) -> &'uri str {
+        match self.raw_scheme {
+            Some(raw) if raw.eq_ignore_ascii_case(scheme.as_str()) => raw,
+            _ => scheme.as_str(),
+        }
+    }
 }
 
 /// Whether the Scheme indicates explicit TLS
@@ -29,33 +54,98 @@ pub type SchemeTls = bool;
 
 use crate::error::SchemeError;
 
+impl<'uri> Scheme<'uri> {
+    /// Whether the Scheme mandates an Authority (host) component
+    pub fn requires_authority(&self) -> bool {
+        matches!(
+            self,
+            Self::Http(_) | Self::Ftp(_) | Self::Ldap(_) | Self::Telnet
+        )
+    }
+    /// Default port for the Scheme if there is one
+    pub fn default_port(&self) -> Option<u16> {
+        match self {
+            Self::Http(false) => Some(80),
+            Self::Http(true) => Some(443),
+            Self::Ftp(false) => Some(21),
+            Self::Ftp(true) => Some(990),
+            Self::Ldap(false) => Some(389),
+            Self::Ldap(true) => Some(636),
+            Self::Telnet => Some(23),
+            _ => None,
+        }
+    }
+    /// Whether the Scheme can carry an Authority component at all
+    pub fn allows_authority(&self) -> bool {
+        !matches!(self, Self::Urn)
+    }
+    /// Scheme name with the known schemes in lowercase
+    pub fn as_str(&self) -> &'uri str {
+        match self {
+            Self::Http(false) => "http",
+            Self::Http(true) => "https",
+            Self::Ftp(false) => "ftp",
+            Self::Ftp(true) => "ftps",
+            Self::Ldap(false) => "ldap",
+            Self::Ldap(true) => "ldaps",
+            Self::Telnet => "telnet",
+            Self::Urn => "urn",
+            Self::Unknown(s) => s,
+        }
+    }
+    /// Whether the Scheme would parse back the same e.g. not an Unknown
+    /// "a:b" or "http" given for a known Scheme
+    pub(crate) fn is_valid(&self) -> bool {
+        match self {
+            Self::Unknown(raw) => Self::try_from(*raw).as_ref() == Ok(self),
+            _ => true,
+        }
+    }
+}
+
 impl<'uri> TryFrom<&'uri str> for Scheme<'uri> {
     type Error = SchemeError<'uri>;
     fn try_from(raw: &'uri str) -> Result<Self, Self::Error> {
-        match raw {
-            "http" => Ok(Self::Http(false)),
-            "https" => Ok(Self::Http(true)),
-            _ => Err(SchemeError::Invalid),
+        // Scheme names are case-insensitive - RFC 3986 s. 3.1
+        let known = KNOWN_SCHEMES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(raw));
+        match known {
+            Some((_, scheme)) => Ok(scheme.clone()),
+            None if is_scheme_name(raw) => Ok(Self::Unknown(raw)),
+            None => Err(SchemeError::Invalid),
         }
     }
 }
 
+/// Known schemes by their lowercase name
+const KNOWN_SCHEMES: [(&str, Scheme<'static>); 8] = [
+    ("http", Scheme::Http(false)),
+    ("https", Scheme::Http(true)),
+    ("ftp", Scheme::Ftp(false)),
+    ("ftps", Scheme::Ftp(true)),
+    ("ldap", Scheme::Ldap(false)),
+    ("ldaps", Scheme::Ldap(true)),
+    ("telnet", Scheme::Telnet),
+    ("urn", Scheme::Urn),
+];
+
+/// RFC 3986 s. 3.1 - ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )
+fn is_scheme_name(raw: &str) -> bool {
+    let mut bytes = raw.bytes();
+    match bytes.next() {
+        Some(first) if first.is_ascii_alphabetic() => {}
+        _ => return false,
+    }
+    bytes.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+}
+
 use core::fmt;
 use core::fmt::Display;
 
 impl<'uri> Display for Scheme<'uri> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Http(false) => write!(f, "http"),
-            Self::Http(true) => write!(f, "https"),
-            Self::Ftp(false) => write!(f, "ftp"),
-            Self::Ftp(true) => write!(f, "ftps"),
-            Self::Ldap(false) => write!(f, "ldap"),
-            Self::Ldap(true) => write!(f, "ldaps"),
-            Self::Telnet => write!(f, "telnet"),
-            Self::Urn => write!(f, "urn"),
-            Self::Unknown(s) => write!(f, "{}", s),
-        }
+        f.write_str(self.as_str())
     }
 }
 
@@ -63,6 +153,7 @@ impl<'uri> Display for Scheme<'uri> {
 mod test {
 
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn http_from_str() {
@@ -74,6 +165,56 @@ mod test {
         let s: Scheme<'static> = "https".try_into().unwrap();
         assert_eq!(s, Scheme::Http(true));
     }
+    #[rstest]
+    #[case("ftp", Ok(Scheme::Ftp(false)))]
+    #[case("ftps", Ok(Scheme::Ftp(true)))]
+    #[case("ldap", Ok(Scheme::Ldap(false)))]
+    #[case("ldaps", Ok(Scheme::Ldap(true)))]
+    #[case("telnet", Ok(Scheme::Telnet))]
+    #[case("urn", Ok(Scheme::Urn))]
+    #[case("mailto", Ok(Scheme::Unknown("mailto")))]
+    #[case("svn+ssh", Ok(Scheme::Unknown("svn+ssh")))]
+    #[case("z39.50r", Ok(Scheme::Unknown("z39.50r")))]
+    #[case("ms-settings", Ok(Scheme::Unknown("ms-settings")))]
+    #[case("HTTP", Ok(Scheme::Http(false)))]
+    #[case("hTtPs", Ok(Scheme::Http(true)))]
+    #[case("URN", Ok(Scheme::Urn))]
+    #[case("MailTo", Ok(Scheme::Unknown("MailTo")))]
+    #[case("", Err(SchemeError::Invalid))]
+    #[case("1http", Err(SchemeError::Invalid))]
+    #[case("+http", Err(SchemeError::Invalid))]
+    #[case("ht tp", Err(SchemeError::Invalid))]
+    #[case("ht_tp", Err(SchemeError::Invalid))]
+    fn scheme_from_str(
+        #[case] s: &'static str,
+        #[case] expected: Result<Scheme<'static>, SchemeError<'static>>,
+    ) {
+        assert_eq!(Scheme::try_from(s), expected);
+    }
+    #[rstest]
+    #[case("ftp")]
+    #[case("ftps")]
+    #[case("ldap")]
+    #[case("ldaps")]
+    #[case("telnet")]
+    #[case("urn")]
+    #[case("svn+ssh")]
+    fn scheme_to_str_roundtrip(#[case] s: &'static str) {
+        assert_eq!(format!("{}", Scheme::try_from(s).unwrap()), s);
+    }
+    #[rstest]
+    #[case(Scheme::Http(false), Some(80))]
+    #[case(Scheme::Http(true), Some(443))]
+    #[case(Scheme::Ftp(false), Some(21))]
+    #[case(Scheme::Ftp(true), Some(990))]
+    #[case(Scheme::Ldap(false), Some(389))]
+    #[case(Scheme::Ldap(true), Some(636))]
+    #[case(Scheme::Telnet, Some(23))]
+    #[case(Scheme::Urn, None)]
+    #[case(Scheme::Unknown("mailto"), None)]
+    fn scheme_default_port(#[case] scheme: Scheme<'static>, #[case] expected: Option<u16>) {
+        assert_eq!(scheme.default_port(), expected);
+    }
     #[test]
     fn https_to_str() {
         let s = format!("{}", Scheme::Http(true));
@@ -0,0 +1,302 @@
+//! Setters keeping a parsed or built URI consistent
+
+use crate::error::{AuthorityError, BuilderError};
+use crate::percent::EncodeSet;
+use crate::{Authority, Fragment, Host, Path, Query, Scheme, Uri};
+
+impl<'uri> Uri<'uri> {
+    /// Set the Scheme checking an Unknown Scheme name and it against the Authority
+    pub fn set_scheme(&mut self, scheme: Scheme<'uri>) -> Result<(), BuilderError<'uri>> {
+        if !scheme.is_valid() {
+            return Err(BuilderError::InvalidScheme(scheme.as_str()));
+        }
+        check_authority(&scheme, self.authority.is_some())?;
+        self.scheme = scheme;
+        self.scheme_data.raw_scheme = None;
+        Ok(())
+    }
+    /// Set or remove the Authority checking it against the Scheme and Path
+    /// with the host and userinfo limited to what would parse back the same.
+    ///
+    /// Setters changing the authority, path or query clear the
+    /// [`SchemeData::raw`](crate::SchemeData::raw) part as parsed.
+    ///
+    /// ```rust
+    /// use yuri::{error::BuilderError, Authority, Uri};
+    ///
+    /// let mut uri = Uri::new("foo:bar").unwrap();
+    /// let authority = Authority::from_host_str("foobar.test").unwrap();
+    /// assert_eq!(
+    ///     uri.set_authority(Some(authority)),
+    ///     Err(BuilderError::PathNotAbsolute("bar"))
+    /// );
+    /// ```
+    pub fn set_authority(
+        &mut self,
+        authority: Option<Authority<'uri>>,
+    ) -> Result<(), BuilderError<'uri>> {
+        check_authority(&self.scheme, authority.is_some())?;
+        if let Some(authority) = &authority {
+            authority.check().map_err(BuilderError::Authority)?;
+        }
+        if let Some(path) = &self.path {
+            check_path(path.raw_path, authority.is_some())?;
+        }
+        self.authority = authority;
+        self.scheme_data.raw = None;
+        Ok(())
+    }
+    /// Set the host keeping any userinfo and port, an Authority is
+    /// added when there is none
+    pub fn set_host(&mut self, raw_host: &'uri str) -> Result<(), BuilderError<'uri>> {
+        let host = Host::parse_checked(raw_host).map_err(BuilderError::Authority)?;
+        let authority = match self.authority.clone() {
+            Some(authority) => Authority {
+                raw_host,
+                host,
+                ..authority
+            },
+            None => Authority {
+                userinfo: None,
+                raw_host,
+                host,
+                port: None,
+                raw_port: None,
+                without_slashes: false,
+            },
+        };
+        self.set_authority(Some(authority))
+    }
+    /// Set or remove the port which requires an Authority
+    pub fn set_port(&mut self, port: Option<u16>) -> Result<(), BuilderError<'uri>> {
+        match &mut self.authority {
+            Some(authority) => {
+                authority.port = port;
+                authority.raw_port = None;
+            }
+            None if port.is_some() => {
+                return Err(BuilderError::Authority(AuthorityError::MissingHost))
+            }
+            None => {}
+        }
+        Ok(())
+    }
+    /// Set the Path checking it against the Authority clearing
+    /// [`SchemeData::raw`](crate::SchemeData::raw)
+    pub fn set_path(&mut self, path: Path<'uri>) -> Result<(), BuilderError<'uri>> {
+        check_path(path.raw_path, self.authority.is_some())?;
+        self.path = Some(path);
+        self.scheme_data.raw = None;
+        Ok(())
+    }
+    /// Set or remove the Query clearing [`SchemeData::raw`](crate::SchemeData::raw)
+    pub fn set_query(&mut self, query: Option<Query<'uri>>) -> Result<(), BuilderError<'uri>> {
+        if let Some(query) = &query {
+            if EncodeSet::Query.find_invalid(query.raw_query).is_some() {
+                return Err(BuilderError::InvalidQuery(query.raw_query));
+            }
+        }
+        self.query = query;
+        self.scheme_data.raw = None;
+        Ok(())
+    }
+    /// Set or remove the Fragment
+    pub fn set_fragment(
+        &mut self,
+        fragment: Option<Fragment<'uri>>,
+    ) -> Result<(), BuilderError<'uri>> {
+        if let Some(fragment) = &fragment {
+            if EncodeSet::Fragment
+                .find_invalid(fragment.raw_fragment)
+                .is_some()
+            {
+                return Err(BuilderError::InvalidFragment(fragment.raw_fragment));
+            }
+        }
+        self.fragment = fragment;
+        Ok(())
+    }
+    /// Remove the Fragment
+    pub fn clear_fragment(&mut self) {
+        self.fragment = None;
+    }
+}
+
+/// Scheme requiring or not allowing an Authority
+fn check_authority<'uri>(
+    scheme: &Scheme<'uri>,
+    has_authority: bool,
+) -> Result<(), BuilderError<'uri>> {
+    match has_authority {
+        true if !scheme.allows_authority() => {
+            Err(BuilderError::AuthorityNotAllowed(scheme.clone()))
+        }
+        false if scheme.requires_authority() => Err(BuilderError::MissingAuthority(scheme.clone())),
+        _ => Ok(()),
+    }
+}
+
+/// RFC 3986 s. 3.3 - path-abempty with authority, otherwise must not look like one
+pub(crate) fn check_path(raw_path: &str, has_authority: bool) -> Result<(), BuilderError<'_>> {
+    if EncodeSet::Path.find_invalid(raw_path).is_some() {
+        return Err(BuilderError::InvalidPath(raw_path));
+    }
+    if has_authority && !raw_path.is_empty() && !raw_path.starts_with('/') {
+        return Err(BuilderError::PathNotAbsolute(raw_path));
+    }
+    if !has_authority && raw_path.starts_with("//") {
+        return Err(BuilderError::PathAmbiguous(raw_path));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::UserInfo;
+    use rstest::rstest;
+
+    #[test]
+    fn set_components() {
+        let mut uri = Uri::new("HTTP://user@foobar.test:8080/a?q=1#top").unwrap();
+        uri.set_scheme(Scheme::Http(true)).unwrap();
+        uri.set_host("[2001:db8::7]").unwrap();
+        uri.set_port(None).unwrap();
+        uri.set_path(Path { raw_path: "/b/c" }).unwrap();
+        uri.set_query(Some(Query { raw_query: "x=2" })).unwrap();
+        uri.clear_fragment();
+        assert_eq!(format!("{}", uri), "https://user@[2001:db8::7]/b/c?x=2");
+        uri.set_query(None).unwrap();
+        uri.set_fragment(Some(Fragment { raw_fragment: "f" }))
+            .unwrap();
+        assert_eq!(format!("{}", uri), "https://user@[2001:db8::7]/b/c#f");
+    }
+    #[test]
+    fn set_host_adds_authority() {
+        let mut uri = Uri::new("foo:/a").unwrap();
+        assert_eq!(uri.scheme_data.raw(), Some("/a"));
+        uri.set_host("foobar.test").unwrap();
+        uri.set_port(Some(8080)).unwrap();
+        assert_eq!(format!("{}", uri), "foo://foobar.test:8080/a");
+        assert_eq!(uri.scheme_data.raw(), None);
+    }
+    #[test]
+    fn set_path_query_clear_scheme_data() {
+        let mut uri = Uri::new("mailto:a@foobar.test?subject=x").unwrap();
+        assert_eq!(uri.scheme_data.raw(), Some("a@foobar.test?subject=x"));
+        uri.set_query(None).unwrap();
+        assert_eq!(uri.scheme_data.raw(), None);
+        uri.set_path(Path {
+            raw_path: "b@foobar.test",
+        })
+        .unwrap();
+        assert_eq!(format!("{}", uri), "mailto:b@foobar.test");
+    }
+    #[test]
+    fn set_rejects() {
+        let mut uri = Uri::new("foo:bar").unwrap();
+        assert_eq!(
+            uri.set_host("foobar.test"),
+            Err(BuilderError::PathNotAbsolute("bar"))
+        );
+        assert_eq!(
+            uri.set_port(Some(80)),
+            Err(BuilderError::Authority(AuthorityError::MissingHost))
+        );
+        assert_eq!(
+            uri.set_scheme(Scheme::Http(false)),
+            Err(BuilderError::MissingAuthority(Scheme::Http(false)))
+        );
+        assert_eq!(
+            uri.set_scheme(Scheme::Unknown("a:b/c")),
+            Err(BuilderError::InvalidScheme("a:b/c"))
+        );
+        assert_eq!(
+            uri.set_scheme(Scheme::Unknown("urn")),
+            Err(BuilderError::InvalidScheme("urn"))
+        );
+        assert_eq!(
+            uri.set_path(Path { raw_path: "//x" }),
+            Err(BuilderError::PathAmbiguous("//x"))
+        );
+        assert_eq!(
+            uri.set_query(Some(Query { raw_query: "a#b" })),
+            Err(BuilderError::InvalidQuery("a#b"))
+        );
+        assert_eq!(format!("{}", uri), "foo:bar");
+
+        let mut uri = Uri::new("https://foobar.test/").unwrap();
+        assert_eq!(
+            uri.set_host("999.1.1.1"),
+            Err(BuilderError::Authority(AuthorityError::InvalidHost(
+                "999.1.1.1"
+            )))
+        );
+        assert_eq!(
+            uri.set_authority(None),
+            Err(BuilderError::MissingAuthority(Scheme::Http(true)))
+        );
+        assert_eq!(
+            uri.set_scheme(Scheme::Urn),
+            Err(BuilderError::AuthorityNotAllowed(Scheme::Urn))
+        );
+        assert_eq!(format!("{}", uri), "https://foobar.test/");
+    }
+
+    #[rstest]
+    #[case("evil.test/x?y#")]
+    #[case("a@b")]
+    #[case("a:80")]
+    #[case("a b")]
+    #[case("[::1")]
+    fn set_host_rejects(#[case] raw_host: &'static str) {
+        let mut uri = Uri::new("https://foobar.test/").unwrap();
+        assert_eq!(
+            uri.set_host(raw_host),
+            Err(BuilderError::Authority(AuthorityError::InvalidHost(
+                raw_host
+            )))
+        );
+        assert_eq!(
+            Authority::from_host_str(raw_host),
+            Err(AuthorityError::InvalidHost(raw_host))
+        );
+        assert_eq!(format!("{}", uri), "https://foobar.test/");
+    }
+    #[test]
+    fn set_authority_rejects() {
+        let mut uri = Uri::new("https://foobar.test/").unwrap();
+        let authority = Authority::from_host_str("x").unwrap();
+        let host = Authority {
+            raw_host: "x/y",
+            host: Host::RegName("x/y"),
+            ..authority.clone()
+        };
+        assert_eq!(
+            uri.set_authority(Some(host)),
+            Err(BuilderError::Authority(AuthorityError::InvalidHost("x/y")))
+        );
+        let mismatched = Authority {
+            raw_host: "192.0.2.1",
+            ..authority.clone()
+        };
+        assert_eq!(
+            uri.set_authority(Some(mismatched)),
+            Err(BuilderError::Authority(AuthorityError::InvalidHost(
+                "192.0.2.1"
+            )))
+        );
+        let userinfo = Authority {
+            userinfo: Some(UserInfo {
+                raw_user: "a@b",
+                raw_authorization: None,
+            }),
+            ..authority
+        };
+        assert_eq!(
+            uri.set_authority(Some(userinfo)),
+            Err(BuilderError::Authority(AuthorityError::InvalidAuthority))
+        );
+        assert_eq!(format!("{}", uri), "https://foobar.test/");
+    }
+}